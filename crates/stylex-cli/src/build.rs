@@ -0,0 +1,253 @@
+use std::{
+  env, fs, io,
+  path::{Path, PathBuf},
+};
+
+use log::{debug, warn};
+use stylex_css::stylesheet::{
+  ProcessStylexRulesOptions, class_name_manifest::ClassNameManifest, process_stylex_rules,
+  report::stylesheet_report,
+};
+use stylex_structures::stylex_options::StyleXOptionsParams;
+
+use crate::{
+  compile::compile_file,
+  manifest::{Manifest, ManifestEntry, content_hash},
+  sources::{SourceFilter, collect_sources, output_path, to_slash_path},
+};
+
+/// Everything one build needs, resolved from the command line.
+pub(crate) struct BuildOptions {
+  pub(crate) src_dir: PathBuf,
+  pub(crate) out_dir: PathBuf,
+  pub(crate) css_file: PathBuf,
+  /// Where to write the JSON report on the stylesheet's size, if anywhere.
+  pub(crate) report_file: Option<PathBuf>,
+  /// The class-name manifest to check the build against and update, if any.
+  pub(crate) class_name_manifest: Option<PathBuf>,
+  /// Fails the build, rather than updating the class-name manifest, when a
+  /// declaration would get a new class name.
+  pub(crate) frozen_class_names: bool,
+  pub(crate) filter: SourceFilter,
+  pub(crate) config: StyleXOptionsParams,
+  /// Identifies `config` and the compiler version; a manifest written under a
+  /// different one is discarded.
+  pub(crate) config_hash: String,
+  pub(crate) stylesheet: ProcessStylexRulesOptions,
+  /// Compiles every file, whatever the manifest says.
+  pub(crate) force: bool,
+}
+
+/// What a build did, for the summary line and the exit code.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct BuildReport {
+  pub(crate) compiled: usize,
+  pub(crate) unchanged: usize,
+  pub(crate) removed: usize,
+  /// Source path and message of every file that failed to compile, and the
+  /// class-name manifest's path and message for each class a frozen manifest
+  /// refused to rename.
+  pub(crate) errors: Vec<(String, String)>,
+  /// Whether the stylesheet was written. It is not when any file failed, or
+  /// when its content did not change.
+  pub(crate) css_written: bool,
+}
+
+/// Compiles the sources that changed since the last build, deletes the
+/// outputs of sources that are gone, and rewrites the stylesheet from the
+/// rules of every source, reporting on its size when asked to.
+///
+/// The manifest is saved even when files fail, so the next build only
+/// retries those; the stylesheet is not, since it would be missing their
+/// rules. Nor is it written when a frozen class-name manifest refuses a
+/// rename, since the markup cached under the old names would lose its
+/// styles.
+pub(crate) fn build(options: &BuildOptions) -> io::Result<BuildReport> {
+  fs::create_dir_all(&options.out_dir)?;
+
+  // Absolute paths, so an output directory inside the source directory is
+  // recognised however either was spelled, and imports resolve from the file.
+  let src_dir = options.src_dir.canonicalize()?;
+  let out_dir = options.out_dir.canonicalize()?;
+
+  let cwd = env::current_dir()?;
+  let previous = if options.force {
+    Manifest::new(options.config_hash.as_str())
+  } else {
+    Manifest::load(&out_dir, &options.config_hash)
+  };
+  let mut manifest = Manifest::new(options.config_hash.as_str());
+  let mut report = BuildReport::default();
+
+  for relative_path in collect_sources(&src_dir, &out_dir, &options.filter) {
+    let key = to_slash_path(&relative_path);
+    let source_path = src_dir.join(&relative_path);
+    let code = match fs::read_to_string(&source_path) {
+      Ok(code) => code,
+      Err(error) => {
+        // Kept like the entry of a file that fails to compile.
+        if let Some(entry) = previous.files.get(&key) {
+          manifest.files.insert(key.clone(), entry.clone());
+        }
+
+        report
+          .errors
+          .push((key, format!("Failed to read file: {}", error)));
+        continue;
+      },
+    };
+    let hash = content_hash(&code);
+
+    if let Some(entry) = previous.files.get(&key).filter(|entry| {
+      entry.hash == hash && out_dir.join(&entry.output).is_file() && entry.dependencies_unchanged()
+    }) {
+      debug!("Unchanged: {}", key);
+      manifest.files.insert(key, entry.clone());
+      report.unchanged += 1;
+      continue;
+    }
+
+    let compiled = match compile_file(&source_path, code, &options.config, &cwd) {
+      Ok(compiled) => compiled,
+      Err(message) => {
+        // The last good entry stays, so the file is retried next build and its
+        // output is still cleaned up should the source go away.
+        if let Some(entry) = previous.files.get(&key) {
+          manifest.files.insert(key.clone(), entry.clone());
+        }
+
+        report.errors.push((key, message));
+        continue;
+      },
+    };
+
+    let output = output_path(&relative_path);
+    write_file(&out_dir.join(&output), &compiled.code)?;
+
+    debug!("Compiled: {}", key);
+    manifest.files.insert(
+      key,
+      ManifestEntry {
+        hash,
+        output,
+        rules: compiled.metadata,
+        dependencies: ManifestEntry::hash_dependencies(&compiled.dependencies),
+        class_names: compiled.class_names,
+      },
+    );
+    report.compiled += 1;
+  }
+
+  for (key, entry) in &previous.files {
+    if !manifest.files.contains_key(key) {
+      remove_file(&out_dir.join(&entry.output))?;
+      report.removed += 1;
+    }
+  }
+
+  manifest.save(&out_dir)?;
+
+  if report.errors.is_empty()
+    && let Some(path) = &options.class_name_manifest
+  {
+    check_class_names(path, options.frozen_class_names, &manifest, &mut report)?;
+  }
+
+  if report.errors.is_empty() {
+    let css = process_stylex_rules(&manifest.rules(), &options.stylesheet);
+
+    if fs::read_to_string(&options.css_file).ok().as_deref() != Some(css.as_str()) {
+      write_file(&options.css_file, &css)?;
+      report.css_written = true;
+    }
+
+    if let Some(report_file) = &options.report_file {
+      let size_report = stylesheet_report(
+        manifest
+          .files
+          .iter()
+          .map(|(key, entry)| (key.as_str(), entry.rules.as_slice())),
+      );
+      let json = serde_json::to_string_pretty(&size_report).map_err(io::Error::other)?;
+
+      write_file(report_file, &json)?;
+    }
+  }
+
+  Ok(report)
+}
+
+/// Records the class names of the build in the class-name manifest at `path`,
+/// which starts empty if there is none yet. Collisions are only warned about;
+/// renames are errors when `frozen`, and leave the file as it was.
+fn check_class_names(
+  path: &Path,
+  frozen: bool,
+  manifest: &Manifest,
+  report: &mut BuildReport,
+) -> io::Result<()> {
+  let previous = match fs::read_to_string(path) {
+    Ok(json) => Some(json),
+    Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+    Err(error) => return Err(error),
+  };
+
+  let mut class_name_manifest: ClassNameManifest = match &previous {
+    Some(json) => serde_json::from_str(json).map_err(|error| {
+      io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid class-name manifest {}: {}", path.display(), error),
+      )
+    })?,
+    None => ClassNameManifest::default(),
+  };
+
+  let changes = class_name_manifest.update(manifest.class_names());
+
+  for collision in &changes.collisions {
+    warn!("Class name collision: {}", collision);
+  }
+
+  if frozen && !changes.renamed.is_empty() {
+    let manifest_path = path.display().to_string();
+
+    report.errors.extend(
+      changes
+        .renamed
+        .iter()
+        .map(|rename| (manifest_path.clone(), rename.to_string())),
+    );
+
+    return Ok(());
+  }
+
+  let json = serde_json::to_string_pretty(&class_name_manifest).map_err(io::Error::other)?;
+
+  if previous.as_deref() != Some(json.as_str()) {
+    write_file(path, &json)?;
+  }
+
+  Ok(())
+}
+
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)?;
+  }
+
+  fs::write(path, contents)
+}
+
+fn remove_file(path: &Path) -> io::Result<()> {
+  match fs::remove_file(path) {
+    Err(error) if error.kind() == io::ErrorKind::NotFound => {
+      warn!("Output {} was already removed", path.display());
+      Ok(())
+    },
+    result => result,
+  }
+}
+
+#[cfg(test)]
+#[path = "tests/build_tests.rs"]
+mod tests;
@@ -0,0 +1,111 @@
+use std::{
+  collections::BTreeMap,
+  panic::{self, AssertUnwindSafe},
+  path::Path,
+  sync::Arc,
+};
+
+use stylex_macros::stylex_error::{SuppressPanicStderr, format_panic_message};
+use stylex_structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams};
+use stylex_transform::{
+  StyleXTransform,
+  compile::{parse_source, print_program, transform_program},
+};
+use stylex_types::structures::meta_data::MetaData;
+use swc_compiler_base::{PrintArgs, SourceMapsConfig};
+use swc_core::common::{FileName, GLOBALS, Globals, SourceMap, comments::SingleThreadedComments};
+
+/// One source file after the transform: its code, the rules it contributes
+/// to the stylesheet, the theme and `package.json` files it was compiled
+/// from, and the class name of each declaration it styles.
+#[derive(Debug)]
+pub(crate) struct CompiledFile {
+  pub(crate) code: String,
+  pub(crate) metadata: Vec<MetaData>,
+  pub(crate) dependencies: Vec<String>,
+  pub(crate) class_names: BTreeMap<String, String>,
+}
+
+/// Runs the StyleX transform over one file, the way the NAPI binding's
+/// `transform` does, minus everything that needs JS.
+///
+/// Every file gets a copy of `config` and a state of its own. A StyleX error
+/// is a panic inside the transform; it is caught here and returned as the
+/// file's error, so one bad file does not stop the build.
+pub(crate) fn compile_file(
+  file_path: &Path,
+  code: String,
+  config: &StyleXOptionsParams,
+  cwd: &Path,
+) -> Result<CompiledFile, String> {
+  let _suppress = SuppressPanicStderr::new();
+
+  let mut config = config.clone();
+  // Recording the keys changes nothing in the output, so they are always
+  // kept, whether or not this build checks a class-name manifest.
+  config.emit_class_name_keys = Some(true);
+
+  panic::catch_unwind(AssertUnwindSafe(|| {
+    let cm: Arc<SourceMap> = Default::default();
+    let filename = FileName::Real(file_path.to_path_buf());
+    let fm = cm.new_source_file(filename.clone().into(), code);
+
+    let plugin_pass = PluginPass {
+      cwd: Some(cwd.to_path_buf()),
+      filename: filename.clone(),
+    };
+
+    let comments = SingleThreadedComments::default();
+
+    let program = parse_source(&fm, Some(&comments))
+      .map_err(|error| format!("Failed to parse file `{}`: {:?}", filename, error))?;
+
+    GLOBALS.set(&Globals::default(), || {
+      let mut stylex: StyleXTransform<&SingleThreadedComments> =
+        StyleXTransform::new(&comments, plugin_pass, &mut config);
+
+      stylex.state.set_input_source_file(fm.clone());
+
+      let program = transform_program(program, &mut stylex);
+
+      let metadata: Vec<MetaData> = stylex
+        .state
+        .metadata()
+        .values()
+        .flatten()
+        .cloned()
+        .collect();
+      let dependencies = stylex.state.dependencies();
+      let class_names = stylex
+        .state
+        .class_name_keys()
+        .map(|keys| {
+          keys
+            .iter()
+            .map(|(key, class_name)| (key.clone(), class_name.clone()))
+            .collect()
+        })
+        .unwrap_or_default();
+      drop(stylex);
+
+      let output = print_program(
+        cm,
+        &program,
+        &comments,
+        PrintArgs {
+          source_map: SourceMapsConfig::Bool(false),
+          ..Default::default()
+        },
+      )
+      .map_err(|error| format!("[StyleX] Failed to print transformed code: {}", error))?;
+
+      Ok(CompiledFile {
+        code: output.code,
+        metadata,
+        dependencies,
+        class_names,
+      })
+    })
+  }))
+  .unwrap_or_else(|error| Err(format_panic_message(&error)))
+}
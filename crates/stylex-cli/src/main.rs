@@ -0,0 +1,203 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+mod build;
+mod compile;
+mod manifest;
+mod sources;
+
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+use stylex_css::stylesheet::{LayersOptions, ProcessStylexRulesOptions};
+use stylex_logs::initializer::initialize as initialize_logger;
+use stylex_structures::stylex_options::StyleXOptionsParams;
+
+use crate::{
+  build::{BuildOptions, BuildReport, build},
+  manifest::content_hash,
+  sources::SourceFilter,
+};
+
+/// The name of the stylesheet in the output directory unless `--css` says
+/// otherwise.
+const DEFAULT_CSS_FILE_NAME: &str = "stylex.css";
+
+#[derive(Parser)]
+#[clap(
+  name = "stylex",
+  version,
+  about = "Compile StyleX sources and extract their CSS, without a bundler."
+)]
+struct Cli {
+  #[clap(help = "Directory of sources to compile.", value_name = "SRC_DIR")]
+  src_dir: PathBuf,
+
+  #[clap(
+    short = 'o',
+    long,
+    help = "Directory the compiled files are written to, mirroring SRC_DIR.",
+    value_name = "DIR"
+  )]
+  out_dir: PathBuf,
+
+  #[clap(
+    long,
+    help = "Where to write the stylesheet. Defaults to stylex.css in the output directory.",
+    value_name = "FILE"
+  )]
+  css: Option<PathBuf>,
+
+  #[clap(
+    long,
+    help = "Also write a JSON report on what makes up the stylesheet's size, to track in CI.",
+    value_name = "FILE"
+  )]
+  report: Option<PathBuf>,
+
+  #[clap(
+    long,
+    help = "Check class names against this JSON manifest of past builds, and record new ones in it.",
+    value_name = "FILE"
+  )]
+  class_name_manifest: Option<PathBuf>,
+
+  #[clap(
+    long,
+    help = "Fail instead of updating the class-name manifest when a class would be renamed.",
+    requires = "class_name_manifest"
+  )]
+  frozen_class_names: bool,
+
+  #[clap(
+    short = 'c',
+    long,
+    help = "JSON file of StyleX options, as passed to the bundler plugins.",
+    value_name = "FILE"
+  )]
+  config: Option<PathBuf>,
+
+  #[clap(
+    long,
+    help = "Only compile files matching this glob, relative to SRC_DIR. Repeatable.",
+    value_name = "GLOB"
+  )]
+  include: Vec<String>,
+
+  #[clap(
+    long,
+    help = "Skip files matching this glob, relative to SRC_DIR. Repeatable.",
+    default_value = "**/node_modules/**",
+    value_name = "GLOB"
+  )]
+  exclude: Vec<String>,
+
+  #[clap(
+    long,
+    help = "Wrap each priority bucket of the stylesheet in its own @layer."
+  )]
+  use_layers: bool,
+
+  #[clap(
+    long,
+    help = "Compile every file, ignoring what the last build recorded."
+  )]
+  force: bool,
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn main() -> ExitCode {
+  initialize_logger();
+
+  let cli = Cli::parse();
+
+  match build_options(cli).and_then(|options| build(&options).map_err(|error| error.to_string())) {
+    Ok(report) => print_report(&report),
+    Err(message) => {
+      eprintln!("error: {}", message);
+      ExitCode::FAILURE
+    },
+  }
+}
+
+fn build_options(cli: Cli) -> Result<BuildOptions, String> {
+  let config_json = cli
+    .config
+    .as_ref()
+    .map(|path| {
+      fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path.display(), error))
+    })
+    .transpose()?;
+
+  let config: StyleXOptionsParams = match &config_json {
+    Some(json) => {
+      serde_json::from_str(json).map_err(|error| format!("Invalid config: {}", error))?
+    },
+    None => StyleXOptionsParams::default(),
+  };
+
+  let filter = SourceFilter::new(&cli.include, &cli.exclude)
+    .map_err(|error| format!("Invalid glob: {}", error))?;
+
+  let stylesheet = ProcessStylexRulesOptions {
+    use_layers: cli.use_layers.then(LayersOptions::default),
+    enable_ltr_rtl_comments: config.enable_ltr_rtl_comments.unwrap_or(false),
+    ..Default::default()
+  };
+
+  let config_hash = config_hash(config_json.as_deref().unwrap_or_default());
+
+  Ok(BuildOptions {
+    css_file: cli
+      .css
+      .unwrap_or_else(|| cli.out_dir.join(DEFAULT_CSS_FILE_NAME)),
+    report_file: cli.report,
+    class_name_manifest: cli.class_name_manifest,
+    frozen_class_names: cli.frozen_class_names,
+    src_dir: cli.src_dir,
+    out_dir: cli.out_dir,
+    filter,
+    config,
+    config_hash,
+    stylesheet,
+    force: cli.force,
+  })
+}
+
+/// A new compiler can compile the same source differently, so its version is
+/// part of the hash along with the config.
+fn config_hash(config_json: &str) -> String {
+  content_hash(format!("{}\n{}", env!("CARGO_PKG_VERSION"), config_json))
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn print_report(report: &BuildReport) -> ExitCode {
+  for (file, message) in &report.errors {
+    eprintln!("error: {}: {}", file, message);
+  }
+
+  println!(
+    "Compiled {} file(s), {} unchanged, {} removed{}.",
+    report.compiled,
+    report.unchanged,
+    report.removed,
+    if report.css_written {
+      "; stylesheet written"
+    } else {
+      ""
+    }
+  );
+
+  if report.errors.is_empty() {
+    ExitCode::SUCCESS
+  } else {
+    eprintln!(
+      "{} error(s); the stylesheet was not written.",
+      report.errors.len()
+    );
+    ExitCode::FAILURE
+  }
+}
+
+#[cfg(test)]
+#[path = "tests/main_tests.rs"]
+mod tests;
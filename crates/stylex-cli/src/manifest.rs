@@ -0,0 +1,136 @@
+use std::{
+  collections::BTreeMap,
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use stylex_types::structures::meta_data::MetaData;
+
+/// Written into the output directory; what makes the next build incremental.
+pub(crate) const MANIFEST_FILE_NAME: &str = ".stylex-manifest.json";
+
+/// What the last build compiled, keyed by source path relative to the source
+/// directory.
+///
+/// A file whose content hash still matches its entry, whose dependencies are
+/// unchanged, and whose output is still on disk, is not compiled again: its
+/// entry's rules go straight into the stylesheet. The whole manifest is
+/// dropped when `config_hash` changes, since a new config can change every
+/// file's output.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Manifest {
+  pub(crate) config_hash: String,
+  pub(crate) files: BTreeMap<String, ManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ManifestEntry {
+  /// Content hash of the source as last compiled.
+  pub(crate) hash: String,
+  /// The compiled file, relative to the output directory.
+  pub(crate) output: PathBuf,
+  pub(crate) rules: Vec<MetaData>,
+  /// Content hash of each theme and `package.json` file the source was
+  /// compiled from, by absolute path. Empty in manifests written before they
+  /// were recorded.
+  #[serde(default)]
+  pub(crate) dependencies: BTreeMap<String, String>,
+  /// The class name of each declaration the source styles, by the text it is
+  /// hashed from, for the class-name manifest. Empty in manifests written
+  /// before they were recorded.
+  #[serde(default)]
+  pub(crate) class_names: BTreeMap<String, String>,
+}
+
+impl ManifestEntry {
+  /// Hashes `dependencies` as they are on disk now. A file that cannot be read
+  /// gets an empty hash, which no later read matches.
+  pub(crate) fn hash_dependencies(dependencies: &[String]) -> BTreeMap<String, String> {
+    dependencies
+      .iter()
+      .map(|path| (path.clone(), file_hash(Path::new(path)).unwrap_or_default()))
+      .collect()
+  }
+
+  /// Whether every dependency still has the content it was compiled from.
+  pub(crate) fn dependencies_unchanged(&self) -> bool {
+    self
+      .dependencies
+      .iter()
+      .all(|(path, hash)| file_hash(Path::new(path)).as_ref() == Some(hash))
+  }
+}
+
+impl Manifest {
+  pub(crate) fn new(config_hash: impl Into<String>) -> Self {
+    Manifest {
+      config_hash: config_hash.into(),
+      files: BTreeMap::new(),
+    }
+  }
+
+  /// The manifest in `out_dir`, or an empty one when there is none, it cannot
+  /// be read, or it was written for another config.
+  pub(crate) fn load(out_dir: &Path, config_hash: &str) -> Self {
+    let path = out_dir.join(MANIFEST_FILE_NAME);
+
+    let manifest = match fs::read(&path) {
+      Ok(bytes) => serde_json::from_slice::<Manifest>(&bytes)
+        .inspect_err(|error| {
+          warn!(
+            "Ignoring unreadable manifest {}, rebuilding everything: {}",
+            path.display(),
+            error
+          )
+        })
+        .ok(),
+      Err(_) => None,
+    };
+
+    manifest
+      .filter(|manifest| manifest.config_hash == config_hash)
+      .unwrap_or_else(|| Manifest::new(config_hash))
+  }
+
+  pub(crate) fn save(&self, out_dir: &Path) -> io::Result<()> {
+    let json = serde_json::to_vec_pretty(self).map_err(io::Error::other)?;
+
+    fs::write(out_dir.join(MANIFEST_FILE_NAME), json)
+  }
+
+  /// Every rule the manifest records, in source path order.
+  pub(crate) fn rules(&self) -> Vec<MetaData> {
+    self
+      .files
+      .values()
+      .flat_map(|entry| entry.rules.iter().cloned())
+      .collect()
+  }
+
+  /// Every declaration key and class name pair the manifest records.
+  pub(crate) fn class_names(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.files.values().flat_map(|entry| {
+      entry
+        .class_names
+        .iter()
+        .map(|(key, class_name)| (key.as_str(), class_name.as_str()))
+    })
+  }
+}
+
+/// Hex digest identifying `bytes`, stable across builds and platforms.
+pub(crate) fn content_hash(bytes: impl AsRef<[u8]>) -> String {
+  format!("{:x}", md5::compute(bytes))
+}
+
+fn file_hash(path: &Path) -> Option<String> {
+  fs::read(path).ok().map(content_hash)
+}
+
+#[cfg(test)]
+#[path = "tests/manifest_tests.rs"]
+mod tests;
@@ -0,0 +1,139 @@
+use std::path::{Component, Path, PathBuf};
+
+use glob::{MatchOptions, Pattern, PatternError};
+use log::warn;
+use walkdir::WalkDir;
+
+/// Extensions of the files a build compiles. Anything else under the source
+/// directory is left where it is.
+const SOURCE_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+/// `*` stops at a `/` and `**` crosses them, as in the `include`/`exclude`
+/// patterns the bundler plugins take.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+  case_sensitive: true,
+  require_literal_separator: true,
+  require_literal_leading_dot: false,
+};
+
+/// The `--include`/`--exclude` globs, matched against paths relative to the
+/// source directory.
+#[derive(Debug, Default)]
+pub(crate) struct SourceFilter {
+  include: Vec<Pattern>,
+  exclude: Vec<Pattern>,
+}
+
+impl SourceFilter {
+  pub(crate) fn new(include: &[String], exclude: &[String]) -> Result<Self, PatternError> {
+    let compile = |patterns: &[String]| {
+      patterns
+        .iter()
+        .map(|pattern| Pattern::new(pattern))
+        .collect::<Result<Vec<_>, _>>()
+    };
+
+    Ok(SourceFilter {
+      include: compile(include)?,
+      exclude: compile(exclude)?,
+    })
+  }
+
+  /// An empty include list lets every file through; an exclude always wins.
+  pub(crate) fn matches(&self, relative_path: &str) -> bool {
+    let is_match = |pattern: &Pattern| pattern.matches_with(relative_path, MATCH_OPTIONS);
+
+    (self.include.is_empty() || self.include.iter().any(is_match))
+      && !self.exclude.iter().any(is_match)
+  }
+}
+
+/// The source files under `src_dir` a build compiles, relative to it and
+/// sorted, so builds and the manifest are deterministic.
+///
+/// `out_dir` is skipped when it sits inside `src_dir`, so a build never reads
+/// its own output back in. Declaration files carry no code and are skipped
+/// too.
+pub(crate) fn collect_sources(
+  src_dir: &Path,
+  out_dir: &Path,
+  filter: &SourceFilter,
+) -> Vec<PathBuf> {
+  let mut sources: Vec<PathBuf> = WalkDir::new(src_dir)
+    .follow_links(true)
+    .into_iter()
+    .filter_entry(|entry| entry.path() != out_dir)
+    .filter_map(|entry| match entry {
+      Ok(entry) => Some(entry),
+      Err(error) => {
+        warn!("Skipping an unreadable path: {}", error);
+        None
+      },
+    })
+    .filter(|entry| entry.file_type().is_file())
+    .filter_map(|entry| {
+      entry
+        .path()
+        .strip_prefix(src_dir)
+        .ok()
+        .map(Path::to_path_buf)
+    })
+    .filter(|relative_path| is_source_file(relative_path))
+    .filter(|relative_path| filter.matches(&to_slash_path(relative_path)))
+    .collect();
+
+  sources.sort();
+  sources
+}
+
+fn is_source_file(path: &Path) -> bool {
+  let is_declaration = path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .is_some_and(|name| {
+      [".d.ts", ".d.mts", ".d.cts"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    });
+
+  !is_declaration
+    && path
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .is_some_and(|extension| SOURCE_EXTENSIONS.contains(&extension))
+}
+
+/// Where a source's compiled code is written, relative to the output
+/// directory. TypeScript comes out as JavaScript; JSX stays JSX, since the
+/// transform does not compile it.
+pub(crate) fn output_path(relative_path: &Path) -> PathBuf {
+  let extension = match relative_path
+    .extension()
+    .and_then(|extension| extension.to_str())
+  {
+    Some("ts") => "js",
+    Some("tsx") => "jsx",
+    Some("mts") => "mjs",
+    Some("cts") => "cjs",
+    _ => return relative_path.to_path_buf(),
+  };
+
+  relative_path.with_extension(extension)
+}
+
+/// `relative_path` with `/` separators on every platform, the form globs are
+/// matched against and the manifest is keyed by.
+pub(crate) fn to_slash_path(relative_path: &Path) -> String {
+  relative_path
+    .components()
+    .filter_map(|component| match component {
+      Component::Normal(part) => Some(part.to_string_lossy()),
+      _ => None,
+    })
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+#[cfg(test)]
+#[path = "tests/sources_tests.rs"]
+mod tests;
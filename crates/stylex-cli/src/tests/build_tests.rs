@@ -0,0 +1,433 @@
+// Tests for incremental builds: compiling, skipping, cleaning up and
+// stylesheet output.
+// Source: crates/stylex-cli/src/build.rs
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::*;
+use crate::manifest::MANIFEST_FILE_NAME;
+
+const BUTTON: &str = r#"
+import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: { color: 'red' },
+});
+"#;
+
+const CARD: &str = r#"
+import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: { padding: 4 },
+});
+"#;
+
+struct Project {
+  root: PathBuf,
+}
+
+impl Project {
+  fn new(prefix: &str) -> Self {
+    let unique = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_nanos())
+      .unwrap_or_default();
+    let root = std::env::temp_dir().join(format!("{prefix}-{unique}"));
+    fs::create_dir_all(root.join("src")).expect("create src dir");
+
+    Project { root }
+  }
+
+  fn write(&self, relative_path: &str, code: &str) {
+    write_file(&self.root.join("src").join(relative_path), code).expect("write source");
+  }
+
+  fn options(&self) -> BuildOptions {
+    BuildOptions {
+      src_dir: self.root.join("src"),
+      out_dir: self.root.join("dist"),
+      css_file: self.root.join("dist/stylex.css"),
+      report_file: None,
+      class_name_manifest: None,
+      frozen_class_names: false,
+      filter: SourceFilter::default(),
+      config: StyleXOptionsParams::default(),
+      config_hash: "config".to_string(),
+      stylesheet: ProcessStylexRulesOptions::default(),
+      force: false,
+    }
+  }
+
+  fn build(&self) -> BuildReport {
+    build(&self.options()).expect("build runs")
+  }
+
+  fn dist(&self, relative_path: &str) -> PathBuf {
+    self.root.join("dist").join(relative_path)
+  }
+
+  fn css(&self) -> String {
+    fs::read_to_string(self.dist("stylex.css")).expect("stylesheet written")
+  }
+
+  fn class_name_options(&self, prefix: &str, frozen: bool) -> BuildOptions {
+    BuildOptions {
+      class_name_manifest: Some(self.root.join("class-names.json")),
+      frozen_class_names: frozen,
+      config: StyleXOptionsParams {
+        class_name_prefix: Some(prefix.to_string()),
+        ..StyleXOptionsParams::default()
+      },
+      config_hash: format!("config-{prefix}"),
+      ..self.options()
+    }
+  }
+
+  fn class_names(&self) -> serde_json::Value {
+    serde_json::from_slice(
+      &fs::read(self.root.join("class-names.json")).expect("class-name manifest written"),
+    )
+    .expect("valid JSON")
+  }
+}
+
+impl Drop for Project {
+  fn drop(&mut self) {
+    fs::remove_dir_all(&self.root).ok();
+  }
+}
+
+#[test]
+fn compiles_every_file_and_writes_the_stylesheet() {
+  let project = Project::new("stylex-cli-build-first");
+  project.write("button.tsx", BUTTON);
+  project.write("nested/card.ts", CARD);
+
+  let report = project.build();
+
+  assert_eq!(report.compiled, 2);
+  assert!(report.errors.is_empty());
+  assert!(report.css_written);
+
+  let button = fs::read_to_string(project.dist("button.jsx")).expect("button compiled");
+  assert!(!button.contains("stylex.create"));
+  assert!(project.dist("nested/card.js").is_file());
+  assert!(project.dist(MANIFEST_FILE_NAME).is_file());
+
+  let css = project.css();
+  assert!(css.contains("color:red"));
+  assert!(css.contains("padding:4px"));
+}
+
+#[test]
+fn a_second_build_compiles_nothing() {
+  let project = Project::new("stylex-cli-build-unchanged");
+  project.write("button.tsx", BUTTON);
+  project.build();
+
+  let report = project.build();
+
+  assert_eq!(
+    report,
+    BuildReport {
+      unchanged: 1,
+      ..Default::default()
+    }
+  );
+}
+
+#[test]
+fn only_changed_files_are_compiled_again() {
+  let project = Project::new("stylex-cli-build-changed");
+  project.write("button.tsx", BUTTON);
+  project.write("card.ts", CARD);
+  project.build();
+
+  project.write("card.ts", &CARD.replace("padding: 4", "padding: 8"));
+  let report = project.build();
+
+  assert_eq!((report.compiled, report.unchanged), (1, 1));
+  assert!(report.css_written);
+  assert!(project.css().contains("padding:8px"));
+  assert!(!project.css().contains("padding:4px"));
+}
+
+#[test]
+fn a_deleted_output_is_compiled_again() {
+  let project = Project::new("stylex-cli-build-deleted-output");
+  project.write("button.tsx", BUTTON);
+  project.build();
+
+  fs::remove_file(project.dist("button.jsx")).expect("remove output");
+  let report = project.build();
+
+  assert_eq!(report.compiled, 1);
+  assert!(project.dist("button.jsx").is_file());
+}
+
+#[test]
+fn outputs_of_deleted_sources_are_removed() {
+  let project = Project::new("stylex-cli-build-removed");
+  project.write("button.tsx", BUTTON);
+  project.write("card.ts", CARD);
+  project.build();
+
+  fs::remove_file(project.root.join("src/card.ts")).expect("remove source");
+  let report = project.build();
+
+  assert_eq!(report.removed, 1);
+  assert!(!project.dist("card.js").exists());
+  assert!(!project.css().contains("padding:4px"));
+}
+
+#[test]
+fn force_compiles_everything() {
+  let project = Project::new("stylex-cli-build-force");
+  project.write("button.tsx", BUTTON);
+  project.build();
+
+  let report = build(&BuildOptions {
+    force: true,
+    ..project.options()
+  })
+  .expect("build runs");
+
+  assert_eq!((report.compiled, report.unchanged), (1, 0));
+}
+
+#[test]
+fn a_new_config_compiles_everything() {
+  let project = Project::new("stylex-cli-build-config");
+  project.write("button.tsx", BUTTON);
+  project.build();
+
+  let report = build(&BuildOptions {
+    config_hash: "other".to_string(),
+    ..project.options()
+  })
+  .expect("build runs");
+
+  assert_eq!(report.compiled, 1);
+}
+
+#[test]
+fn a_failing_file_is_reported_and_keeps_the_stylesheet_unwritten() {
+  let project = Project::new("stylex-cli-build-error");
+  project.write("button.tsx", BUTTON);
+  project.write(
+    "broken.ts",
+    "import * as stylex from '@stylexjs/stylex';\nstylex.create({ a: { color: 'red' } });\n",
+  );
+
+  let report = project.build();
+
+  assert_eq!(report.compiled, 1);
+  assert_eq!(report.errors.len(), 1);
+  assert_eq!(report.errors[0].0, "broken.ts");
+  assert!(report.errors[0].1.contains("[StyleX]"));
+  assert!(!report.css_written);
+  assert!(!project.dist("stylex.css").exists());
+
+  fs::remove_file(project.root.join("src/broken.ts")).expect("remove source");
+  let report = project.build();
+
+  assert_eq!((report.compiled, report.unchanged), (0, 1));
+  assert!(report.css_written);
+}
+
+#[test]
+fn an_output_dir_inside_the_sources_is_not_compiled() {
+  let project = Project::new("stylex-cli-build-nested-out");
+  project.write("button.tsx", BUTTON);
+
+  let options = BuildOptions {
+    out_dir: project.root.join("src/dist"),
+    css_file: project.root.join("src/dist/stylex.css"),
+    ..project.options()
+  };
+  build(&options).expect("build runs");
+
+  let report = build(&options).expect("build runs");
+
+  assert_eq!((report.compiled, report.unchanged), (0, 1));
+}
+
+#[test]
+fn a_file_that_does_not_parse_is_reported() {
+  let project = Project::new("stylex-cli-build-parse-error");
+  project.write("broken.ts", "export const = ;");
+
+  let report = project.build();
+
+  assert_eq!(report.errors.len(), 1);
+  assert!(report.errors[0].1.contains("Failed to parse"));
+}
+
+#[test]
+fn a_file_that_cannot_be_read_is_reported_and_the_rest_compiled() {
+  let project = Project::new("stylex-cli-build-read-error");
+  project.write("button.tsx", BUTTON);
+  fs::write(project.root.join("src/binary.ts"), [0xff, 0xfe, 0x00]).expect("write source");
+
+  let report = project.build();
+
+  assert_eq!(report.compiled, 1);
+  assert_eq!(report.errors.len(), 1);
+  assert_eq!(report.errors[0].0, "binary.ts");
+  assert!(report.errors[0].1.contains("Failed to read file"));
+  assert!(!report.css_written);
+}
+
+#[test]
+fn an_output_already_gone_does_not_fail_the_cleanup() {
+  let project = Project::new("stylex-cli-build-removed-twice");
+  project.write("card.ts", CARD);
+  project.build();
+
+  fs::remove_file(project.root.join("src/card.ts")).expect("remove source");
+  fs::remove_file(project.dist("card.js")).expect("remove output");
+  let report = project.build();
+
+  assert_eq!(report.removed, 1);
+}
+
+#[test]
+fn an_unchanged_stylesheet_is_not_rewritten() {
+  let project = Project::new("stylex-cli-build-css-unchanged");
+  project.write("card.ts", CARD);
+  project.build();
+
+  project.write("other.ts", "export const answer = 42;");
+  let report = project.build();
+
+  assert_eq!(report.compiled, 1);
+  assert!(!report.css_written);
+}
+
+#[test]
+fn the_report_covers_unchanged_files_too() {
+  let project = Project::new("stylex-cli-build-report");
+  project.write("button.tsx", BUTTON);
+  project.write("card.ts", CARD);
+  project.build();
+
+  project.write("card.ts", &CARD.replace("padding: 4", "padding: 8"));
+  let options = BuildOptions {
+    report_file: Some(project.dist("report.json")),
+    ..project.options()
+  };
+  build(&options).expect("build runs");
+
+  let report: serde_json::Value =
+    serde_json::from_slice(&fs::read(project.dist("report.json")).expect("report written"))
+      .expect("valid JSON");
+
+  assert_eq!(report["rules"], 2);
+  let mut files: Vec<&str> = report["files"]
+    .as_array()
+    .expect("files")
+    .iter()
+    .map(|file| file["file"].as_str().expect("file name"))
+    .collect();
+  files.sort();
+  assert_eq!(files, ["button.tsx", "card.ts"]);
+}
+
+#[test]
+fn an_edited_theme_file_recompiles_the_files_that_import_it() {
+  let project = Project::new("stylex-cli-build-dependency");
+  fs::write(project.root.join("package.json"), r#"{"name":"app"}"#).expect("write package.json");
+  project.write(
+    "tokens.stylex.ts",
+    "import * as stylex from '@stylexjs/stylex';\nexport const colors = stylex.defineVars({ accent: 'red' });\n",
+  );
+  project.write(
+    "button.tsx",
+    "import * as stylex from '@stylexjs/stylex';\nimport { colors } from './tokens.stylex';\nexport const styles = stylex.create({ root: { color: colors.accent } });\n",
+  );
+  project.write("card.ts", CARD);
+  project.build();
+
+  project.write(
+    "tokens.stylex.ts",
+    "import * as stylex from '@stylexjs/stylex';\nexport const colors = stylex.defineVars({ accent: 'blue' });\n",
+  );
+  let report = project.build();
+
+  assert_eq!((report.compiled, report.unchanged), (2, 1));
+  assert!(project.css().contains("blue"));
+}
+
+#[test]
+fn the_class_name_manifest_records_every_declaration() {
+  let project = Project::new("stylex-cli-build-class-names");
+  project.write("button.tsx", BUTTON);
+  project.write("card.ts", CARD);
+
+  let report = build(&project.class_name_options("x", false)).expect("build runs");
+
+  assert!(report.errors.is_empty());
+  assert_eq!(
+    project.class_names()["classNames"]["<>colorrednull"],
+    "x1e2nbdu"
+  );
+  assert_eq!(
+    project.class_names()["classNames"]
+      .as_object()
+      .expect("class names")
+      .len(),
+    2
+  );
+}
+
+#[test]
+fn a_rename_updates_the_class_name_manifest() {
+  let project = Project::new("stylex-cli-build-class-names-rename");
+  project.write("button.tsx", BUTTON);
+  build(&project.class_name_options("x", false)).expect("build runs");
+
+  let report = build(&project.class_name_options("y", false)).expect("build runs");
+
+  assert!(report.errors.is_empty());
+  assert!(report.css_written);
+  assert_eq!(
+    project.class_names()["classNames"]["<>colorrednull"],
+    "y1e2nbdu"
+  );
+}
+
+#[test]
+fn a_frozen_class_name_manifest_refuses_a_rename() {
+  let project = Project::new("stylex-cli-build-class-names-frozen");
+  project.write("button.tsx", BUTTON);
+  build(&project.class_name_options("x", false)).expect("build runs");
+
+  let report = build(&project.class_name_options("y", true)).expect("build runs");
+
+  assert_eq!(
+    report.errors,
+    [(
+      project.root.join("class-names.json").display().to_string(),
+      "`x1e2nbdu` would be renamed `y1e2nbdu` (declaration `<>colorrednull`)".to_string()
+    )]
+  );
+  assert!(!report.css_written);
+  assert!(project.css().contains(".x1e2nbdu"));
+  assert_eq!(
+    project.class_names()["classNames"]["<>colorrednull"],
+    "x1e2nbdu"
+  );
+}
+
+#[test]
+fn an_invalid_class_name_manifest_is_an_error() {
+  let project = Project::new("stylex-cli-build-class-names-invalid");
+  project.write("button.tsx", BUTTON);
+  fs::write(project.root.join("class-names.json"), "not json").expect("write manifest");
+
+  let error =
+    build(&project.class_name_options("x", false)).expect_err("invalid manifest is an error");
+
+  assert!(error.to_string().contains("Invalid class-name manifest"));
+}
@@ -0,0 +1,98 @@
+// Tests for turning command-line arguments into build options.
+// Source: crates/stylex-cli/src/main.rs
+
+use super::*;
+
+fn parse(args: &[&str]) -> Cli {
+  Cli::parse_from(std::iter::once("stylex").chain(args.iter().copied()))
+}
+
+#[test]
+fn defaults() {
+  let options = build_options(parse(&["src", "-o", "dist"])).expect("valid options");
+
+  assert_eq!(
+    options.css_file,
+    PathBuf::from("dist").join(DEFAULT_CSS_FILE_NAME)
+  );
+  assert!(options.filter.matches("app.tsx"));
+  assert!(!options.filter.matches("node_modules/pkg/index.js"));
+  assert!(options.stylesheet.use_layers.is_none());
+  assert_eq!(options.report_file, None);
+  assert_eq!(options.class_name_manifest, None);
+  assert!(!options.frozen_class_names);
+  assert!(!options.force);
+}
+
+#[test]
+fn flags() {
+  let options = build_options(parse(&[
+    "src",
+    "--out-dir",
+    "dist",
+    "--css",
+    "public/app.css",
+    "--report",
+    "stylex-report.json",
+    "--class-name-manifest",
+    "class-names.json",
+    "--frozen-class-names",
+    "--include",
+    "components/**",
+    "--use-layers",
+    "--force",
+  ]))
+  .expect("valid options");
+
+  assert_eq!(options.css_file, PathBuf::from("public/app.css"));
+  assert_eq!(
+    options.report_file,
+    Some(PathBuf::from("stylex-report.json"))
+  );
+  assert_eq!(
+    options.class_name_manifest,
+    Some(PathBuf::from("class-names.json"))
+  );
+  assert!(options.frozen_class_names);
+  assert!(options.filter.matches("components/button.tsx"));
+  assert!(!options.filter.matches("pages/index.tsx"));
+  assert!(options.stylesheet.use_layers.is_some());
+  assert!(options.force);
+}
+
+#[test]
+fn frozen_class_names_need_a_manifest() {
+  let result = Cli::try_parse_from(["stylex", "src", "-o", "dist", "--frozen-class-names"]);
+
+  assert!(result.is_err());
+}
+
+#[test]
+fn a_missing_config_file_is_an_error() {
+  let error = build_options(parse(&[
+    "src",
+    "-o",
+    "dist",
+    "-c",
+    "/nonexistent/stylex.json",
+  ]))
+  .err()
+  .expect("missing config is an error");
+
+  assert!(error.contains("Cannot read"));
+}
+
+#[test]
+fn an_invalid_glob_is_an_error() {
+  let error = build_options(parse(&["src", "-o", "dist", "--include", "["]))
+    .err()
+    .expect("invalid glob is an error");
+
+  assert!(error.contains("Invalid glob"));
+}
+
+#[test]
+fn the_config_hash_tracks_the_config() {
+  assert_eq!(config_hash("{}"), config_hash("{}"));
+  assert_ne!(config_hash("{}"), config_hash("{\"dev\":true}"));
+}
@@ -0,0 +1,120 @@
+// Tests for loading, saving and invalidating the build manifest.
+// Source: crates/stylex-cli/src/manifest.rs
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::*;
+
+fn unique_temp_dir(prefix: &str) -> PathBuf {
+  let unique = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or_default();
+  let dir = std::env::temp_dir().join(format!("{prefix}-{unique}"));
+  fs::create_dir_all(&dir).expect("create temp dir");
+  dir
+}
+
+fn entry(hash: &str) -> ManifestEntry {
+  ManifestEntry {
+    hash: hash.to_string(),
+    output: PathBuf::from("a.js"),
+    rules: vec![],
+    dependencies: BTreeMap::new(),
+    class_names: BTreeMap::new(),
+  }
+}
+
+#[test]
+fn content_hash_is_stable_and_content_sensitive() {
+  assert_eq!(content_hash("a"), content_hash("a"));
+  assert_ne!(content_hash("a"), content_hash("b"));
+  assert_eq!(content_hash(""), "d41d8cd98f00b204e9800998ecf8427e");
+}
+
+#[test]
+fn a_missing_manifest_loads_empty() {
+  let dir = unique_temp_dir("stylex-cli-manifest-missing");
+
+  let manifest = Manifest::load(&dir, "config");
+
+  assert_eq!(manifest.config_hash, "config");
+  assert!(manifest.files.is_empty());
+
+  fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn a_saved_manifest_loads_back() {
+  let dir = unique_temp_dir("stylex-cli-manifest-roundtrip");
+  let mut manifest = Manifest::new("config");
+  manifest.files.insert("a.ts".to_string(), entry("hash"));
+
+  manifest.save(&dir).expect("save manifest");
+  let loaded = Manifest::load(&dir, "config");
+
+  assert_eq!(loaded.files.len(), 1);
+  assert_eq!(loaded.files["a.ts"].hash, "hash");
+
+  fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn a_manifest_for_another_config_is_discarded() {
+  let dir = unique_temp_dir("stylex-cli-manifest-config");
+  let mut manifest = Manifest::new("old");
+  manifest.files.insert("a.ts".to_string(), entry("hash"));
+  manifest.save(&dir).expect("save manifest");
+
+  let loaded = Manifest::load(&dir, "new");
+
+  assert_eq!(loaded.config_hash, "new");
+  assert!(loaded.files.is_empty());
+
+  fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn a_corrupt_manifest_is_discarded() {
+  let dir = unique_temp_dir("stylex-cli-manifest-corrupt");
+  fs::write(dir.join(MANIFEST_FILE_NAME), "{ not json").expect("write manifest");
+
+  assert!(Manifest::load(&dir, "config").files.is_empty());
+
+  fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn an_entry_notices_a_changed_dependency() {
+  let dir = unique_temp_dir("stylex-cli-manifest-dependency");
+  let tokens = dir.join("tokens.stylex.js").to_string_lossy().into_owned();
+  fs::write(&tokens, "a").expect("write dependency");
+
+  let mut entry = entry("hash");
+  entry.dependencies = ManifestEntry::hash_dependencies(std::slice::from_ref(&tokens));
+  assert!(entry.dependencies_unchanged());
+
+  fs::write(&tokens, "b").expect("write dependency");
+  assert!(!entry.dependencies_unchanged());
+
+  fs::remove_file(&tokens).expect("remove dependency");
+  assert!(!entry.dependencies_unchanged());
+
+  fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn a_manifest_without_dependencies_still_loads() {
+  let dir = unique_temp_dir("stylex-cli-manifest-old");
+  fs::write(
+    dir.join(MANIFEST_FILE_NAME),
+    r#"{"configHash":"config","files":{"a.ts":{"hash":"hash","output":"a.js","rules":[]}}}"#,
+  )
+  .expect("write manifest");
+
+  let manifest = Manifest::load(&dir, "config");
+
+  assert!(manifest.files["a.ts"].dependencies.is_empty());
+
+  fs::remove_dir_all(dir).ok();
+}
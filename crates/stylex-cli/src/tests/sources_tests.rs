@@ -0,0 +1,108 @@
+// Tests for source discovery, glob filtering and output naming.
+// Source: crates/stylex-cli/src/sources.rs
+
+use std::{
+  fs,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::*;
+
+fn unique_temp_dir(prefix: &str) -> PathBuf {
+  let unique = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or_default();
+  std::env::temp_dir().join(format!("{prefix}-{unique}"))
+}
+
+fn touch(root: &Path, relative_path: &str) {
+  let path = root.join(relative_path);
+  fs::create_dir_all(path.parent().expect("file has a parent")).expect("create dir");
+  fs::write(path, "").expect("write file");
+}
+
+fn filter(include: &[&str], exclude: &[&str]) -> SourceFilter {
+  let owned = |patterns: &[&str]| patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+
+  SourceFilter::new(&owned(include), &owned(exclude)).expect("valid globs")
+}
+
+#[test]
+fn an_empty_filter_matches_everything() {
+  assert!(SourceFilter::default().matches("src/a.ts"));
+}
+
+#[test]
+fn include_and_exclude_globs() {
+  let filter = filter(&["components/**"], &["**/*.test.tsx"]);
+
+  assert!(filter.matches("components/button.tsx"));
+  assert!(filter.matches("components/deep/nested/card.tsx"));
+  assert!(!filter.matches("pages/index.tsx"));
+  assert!(!filter.matches("components/button.test.tsx"));
+}
+
+#[test]
+fn a_single_star_does_not_cross_directories() {
+  let filter = filter(&["*.tsx"], &[]);
+
+  assert!(filter.matches("app.tsx"));
+  assert!(!filter.matches("nested/app.tsx"));
+}
+
+#[test]
+fn invalid_globs_are_reported() {
+  assert!(SourceFilter::new(&["[".to_string()], &[]).is_err());
+}
+
+#[test]
+fn collects_source_files_only_sorted_and_relative() {
+  let root = unique_temp_dir("stylex-cli-sources");
+  for file in [
+    "b.tsx",
+    "a.ts",
+    "nested/c.js",
+    "styles.css",
+    "types.d.ts",
+    "node_modules/pkg/index.js",
+    "dist/a.js",
+  ] {
+    touch(&root, file);
+  }
+
+  let sources = collect_sources(
+    &root,
+    &root.join("dist"),
+    &filter(&[], &["**/node_modules/**"]),
+  );
+
+  assert_eq!(
+    sources,
+    vec![
+      PathBuf::from("a.ts"),
+      PathBuf::from("b.tsx"),
+      PathBuf::from("nested/c.js")
+    ]
+  );
+
+  fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn typescript_outputs_become_javascript() {
+  assert_eq!(output_path(Path::new("a/b.ts")), PathBuf::from("a/b.js"));
+  assert_eq!(output_path(Path::new("a/b.tsx")), PathBuf::from("a/b.jsx"));
+  assert_eq!(output_path(Path::new("b.mts")), PathBuf::from("b.mjs"));
+  assert_eq!(output_path(Path::new("b.cts")), PathBuf::from("b.cjs"));
+  assert_eq!(output_path(Path::new("b.jsx")), PathBuf::from("b.jsx"));
+  assert_eq!(output_path(Path::new("b.js")), PathBuf::from("b.js"));
+}
+
+#[test]
+fn slash_paths_join_components_with_slashes() {
+  assert_eq!(
+    to_slash_path(&Path::new("a").join("b").join("c.ts")),
+    "a/b/c.ts"
+  );
+}
@@ -70,6 +70,27 @@ pub fn array_length_too_large(limit: usize) -> String {
   )
 }
 
+/// A call to a function the module declares is nested past the evaluator's
+/// budget — almost always a helper that recurses without reaching its base
+/// case. JavaScript would overflow its stack on the same call.
+pub fn function_calls_too_deep(limit: usize) -> String {
+  format!(
+    "Function calls are nested too deeply to evaluate at compile time.\nAt most {} calls can be in progress at once.\n\n",
+    limit
+  )
+}
+
+/// A `switch` compared its value against a case that is not a primitive.
+/// Objects match only themselves, and an evaluated object has no identity to
+/// compare, so no case can be chosen.
+pub static UNCOMPARABLE_SWITCH_CASE: &str =
+  "A switch case could not be compared at compile time.\nOnly primitive values can be matched.\n\n";
+
+/// A destructuring pattern was given a value it cannot take apart at compile
+/// time — anything but an object or array literal, or `null` and `undefined`,
+/// which JavaScript refuses to destructure at all.
+pub static UNREADABLE_DESTRUCTURING: &str = "A destructured value could not be read at compile time.\nOnly object and array literals can be destructured.\n\n";
+
 /// A global that only contributes methods was called as a function — `Math(x)`.
 ///
 /// It reaches the fold at all because its methods are foldable, so the callee
@@ -0,0 +1,33 @@
+use phf::phf_map;
+
+// The non-standard logical properties StyleX accepts, spelled as the standard
+// logical property browsers implement. Used instead of `logical_to_ltr` and
+// `logical_to_rtl` when logical styles are kept native.
+pub static LOGICAL_TO_STANDARD: phf::Map<&'static str, &'static str> = phf_map! {
+    "margin-start" => "margin-inline-start",
+    "margin-end" => "margin-inline-end",
+    "padding-start" => "padding-inline-start",
+    "padding-end" => "padding-inline-end",
+    "border-start" => "border-inline-start",
+    "border-end" => "border-inline-end",
+    "border-start-width" => "border-inline-start-width",
+    "border-end-width" => "border-inline-end-width",
+    "border-start-color" => "border-inline-start-color",
+    "border-end-color" => "border-inline-end-color",
+    "border-start-style" => "border-inline-start-style",
+    "border-end-style" => "border-inline-end-style",
+    "border-top-start-radius" => "border-start-start-radius",
+    "border-bottom-start-radius" => "border-end-start-radius",
+    "border-top-end-radius" => "border-start-end-radius",
+    "border-bottom-end-radius" => "border-end-end-radius",
+    "start" => "inset-inline-start",
+    "end" => "inset-inline-end",
+};
+
+// `float` and `clear` values, spelled as the standard logical keyword.
+pub static LOGICAL_VALUE_TO_STANDARD: phf::Map<&'static str, &'static str> = phf_map! {
+    "start" => "inline-start",
+    "inline-start" => "inline-start",
+    "end" => "inline-end",
+    "inline-end" => "inline-end",
+};
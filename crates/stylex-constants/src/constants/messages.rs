@@ -229,6 +229,14 @@ pub static THEME_VARS_MUST_BE_OBJECT: &str = "Theme variables must be defined as
 
 pub static RULE_SET_EMPTY: &str = "The style rule set is empty. At least one rule is required.";
 
+/// `quoted_value` arrives already quoted, the way `JSON.stringify` quotes it.
+pub fn invalid_property_value(property: &str, quoted_value: &str, expected: &str) -> String {
+  format!(
+    "{} is not a valid value for {}. Expected {}.",
+    quoted_value, property, expected
+  )
+}
+
 pub fn expected_call_expression(fn_name: &str) -> String {
   format!(
     "{}(): Expected a call expression. Ensure the value is a direct function call.",
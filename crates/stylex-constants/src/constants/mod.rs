@@ -5,6 +5,7 @@ pub mod evaluation_errors;
 pub mod length_units;
 pub mod logical_to_ltr;
 pub mod logical_to_rtl;
+pub mod logical_to_standard;
 pub mod long_hand_logical;
 pub mod long_hand_physical;
 pub mod messages;
@@ -50,6 +50,14 @@ fn test_not_a_function() {
   );
 }
 
+#[test]
+fn test_function_calls_too_deep() {
+  assert_eq!(
+    function_calls_too_deep(64),
+    "Function calls are nested too deeply to evaluate at compile time.\nAt most 64 calls can be in progress at once.\n\n"
+  );
+}
+
 #[test]
 fn test_static_constants() {
   assert!(!PATH_WITHOUT_NODE.is_empty());
@@ -58,4 +66,6 @@ fn test_static_constants() {
   assert!(!NON_CONSTANT.is_empty());
   assert!(!UNDEFINED_CONST.is_empty());
   assert!(!OBJECT_METHOD.is_empty());
+  assert!(!UNCOMPARABLE_SWITCH_CASE.is_empty());
+  assert!(!UNREADABLE_DESTRUCTURING.is_empty());
 }
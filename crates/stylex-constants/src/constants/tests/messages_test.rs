@@ -91,6 +91,14 @@ fn test_cannot_generate_hash() {
   assert!(result.starts_with("Unable to generate hash for create()"));
 }
 
+#[test]
+fn test_invalid_property_value() {
+  assert_eq!(
+    invalid_property_value("color", "\"rde\"", "<color>"),
+    "\"rde\" is not a valid value for color. Expected <color>."
+  );
+}
+
 #[test]
 fn test_expected_call_expression() {
   let result = expected_call_expression("defineVars");
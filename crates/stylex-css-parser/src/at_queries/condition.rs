@@ -0,0 +1,439 @@
+/*!
+Boolean conditions shared by `@supports` and `@container`.
+
+Both preludes combine parenthesized tests with `not`, `and` and `or` under the
+same rules: `not` takes exactly one operand, `and` and `or` never mix at one
+level without parentheses, and every operand is either a nested condition in
+parentheses or a feature of the rule's own kind. [`condition_parser`] applies
+those rules once, over whichever feature parser the rule supplies.
+
+The component-value helpers at the bottom serve the parts of a feature that
+are free-form CSS -- a declaration value, a selector, a `calc()` -- which are
+checked for balance and respelled with canonical whitespace rather than parsed
+into a grammar of their own.
+*/
+
+use crate::{
+  CssParseError,
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Debug, Display};
+
+/// A condition over features of type `F`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition<F> {
+  Feature(F),
+  Not(Box<Condition<F>>),
+  And(Vec<Condition<F>>),
+  Or(Vec<Condition<F>>),
+}
+
+impl<F> Condition<F> {
+  /// Flattens `and` nested in `and` and `or` nested in `or`, and cancels a
+  /// double `not`. Neither changes what the condition matches; both change
+  /// how it is spelled, which is what a class hash is taken over.
+  pub fn normalize(self) -> Self {
+    match self {
+      Condition::Feature(feature) => Condition::Feature(feature),
+      Condition::Not(operand) => match operand.normalize() {
+        Condition::Not(inner) => *inner,
+        operand => Condition::Not(Box::new(operand)),
+      },
+      Condition::And(operands) => {
+        let mut flattened = Vec::with_capacity(operands.len());
+        for operand in operands {
+          match operand.normalize() {
+            Condition::And(inner) => flattened.extend(inner),
+            operand => flattened.push(operand),
+          }
+        }
+        Condition::And(flattened)
+      },
+      Condition::Or(operands) => {
+        let mut flattened = Vec::with_capacity(operands.len());
+        for operand in operands {
+          match operand.normalize() {
+            Condition::Or(inner) => flattened.extend(inner),
+            operand => flattened.push(operand),
+          }
+        }
+        Condition::Or(flattened)
+      },
+    }
+  }
+
+  /// The condition as an operand of `not`, `and` or `or`: a feature already
+  /// carries its own parentheses, anything else gets a pair.
+  fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+  where
+    F: Display,
+  {
+    match self {
+      Condition::Feature(feature) => write!(f, "{}", feature),
+      condition => write!(f, "({})", condition),
+    }
+  }
+}
+
+impl<F: Display> Display for Condition<F> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (operands, combinator) = match self {
+      Condition::Feature(feature) => return write!(f, "{}", feature),
+      Condition::Not(operand) => {
+        write!(f, "not ")?;
+        return operand.fmt_operand(f);
+      },
+      Condition::And(operands) => (operands, " and "),
+      Condition::Or(operands) => (operands, " or "),
+    };
+
+    for (index, operand) in operands.iter().enumerate() {
+      if index > 0 {
+        write!(f, "{}", combinator)?;
+      }
+      operand.fmt_operand(f)?;
+    }
+    Ok(())
+  }
+}
+
+/// Parse `not <in-parens>`, `<in-parens> [and <in-parens>]*` or
+/// `<in-parens> [or <in-parens>]*`, where `<in-parens>` is a parenthesized
+/// condition or whatever `feature` accepts.
+///
+/// `feature` is a constructor rather than a parser so the grammar can recurse
+/// into parentheses without building itself eagerly.
+pub(crate) fn condition_parser<F: Clone + Debug + 'static>(
+  feature: fn() -> TokenParser<F>,
+) -> TokenParser<Condition<F>> {
+  TokenParser::new(
+    move |tokens| {
+      if is_keyword(tokens.peek_infallible(), "not") {
+        let _ = tokens.consume_next_token();
+        expect_whitespace(tokens, "not")?;
+
+        let operand = (in_parens_parser(feature).run)(tokens)?;
+        return Ok(Condition::Not(Box::new(operand)));
+      }
+
+      let mut operands = vec![(in_parens_parser(feature).run)(tokens)?];
+      let mut combinator: Option<&'static str> = None;
+
+      loop {
+        let checkpoint = tokens.save_position();
+        skip_whitespace(tokens);
+
+        let next = tokens.peek_infallible();
+        let keyword = if is_keyword(next.clone(), "and") {
+          "and"
+        } else if is_keyword(next, "or") {
+          "or"
+        } else {
+          let _ = tokens.restore_position(checkpoint);
+          break;
+        };
+
+        if combinator.is_some_and(|seen| seen != keyword) {
+          return Err(CssParseError::ParseError {
+            message: "`and` and `or` cannot be mixed without parentheses".to_string(),
+          });
+        }
+
+        let _ = tokens.consume_next_token();
+        expect_whitespace(tokens, keyword)?;
+
+        operands.push((in_parens_parser(feature).run)(tokens)?);
+        combinator = Some(keyword);
+      }
+
+      Ok(match combinator {
+        Some("and") => Condition::And(operands),
+        Some(_) => Condition::Or(operands),
+        None => operands.swap_remove(0),
+      })
+    },
+    "condition_parser",
+  )
+}
+
+/// A parenthesized condition, or a feature.
+fn in_parens_parser<F: Clone + Debug + 'static>(
+  feature: fn() -> TokenParser<F>,
+) -> TokenParser<Condition<F>> {
+  TokenParser::one_of(vec![
+    nested_condition_parser(feature),
+    feature().map(Condition::Feature, Some("condition_feature")),
+  ])
+}
+
+fn nested_condition_parser<F: Clone + Debug + 'static>(
+  feature: fn() -> TokenParser<F>,
+) -> TokenParser<Condition<F>> {
+  TokenParser::new(
+    move |tokens| {
+      expect_token(tokens, SimpleToken::LeftParen)?;
+      skip_whitespace(tokens);
+
+      let condition = (condition_parser(feature).run)(tokens)?;
+
+      skip_whitespace(tokens);
+      expect_token(tokens, SimpleToken::RightParen)?;
+      Ok(condition)
+    },
+    "nested_condition_parser",
+  )
+}
+
+fn is_keyword(token: Option<SimpleToken>, keyword: &str) -> bool {
+  matches!(token, Some(SimpleToken::Ident(word)) if word.eq_ignore_ascii_case(keyword))
+}
+
+/// Consume whitespace, returning how many tokens were skipped.
+pub(crate) fn skip_whitespace(tokens: &mut TokenList) -> usize {
+  let mut skipped = 0;
+  while let Some(SimpleToken::Whitespace | SimpleToken::Comment(_)) = tokens.peek_infallible() {
+    let _ = tokens.consume_next_token();
+    skipped += 1;
+  }
+  skipped
+}
+
+fn expect_whitespace(tokens: &mut TokenList, after: &str) -> Result<(), CssParseError> {
+  if skip_whitespace(tokens) == 0 {
+    return Err(CssParseError::ParseError {
+      message: format!("Expected whitespace after `{}`", after),
+    });
+  }
+  Ok(())
+}
+
+pub(crate) fn expect_token(
+  tokens: &mut TokenList,
+  expected: SimpleToken,
+) -> Result<(), CssParseError> {
+  match tokens.consume_next_token_infallible() {
+    Some(token) if token == expected => Ok(()),
+    token => Err(CssParseError::ParseError {
+      message: format!("Expected {:?}, got {:?}", expected, token),
+    }),
+  }
+}
+
+/// Consume the rule's own at-keyword when present, and the whitespace after it.
+///
+/// Like [`crate::at_queries::MediaQuery::parser`], the keyword is optional so
+/// a bare condition parses too.
+pub(crate) fn skip_at_keyword(tokens: &mut TokenList, keyword: &str) -> Result<(), CssParseError> {
+  if let Some(SimpleToken::AtKeyword(found)) = tokens.peek_infallible() {
+    if !found.eq_ignore_ascii_case(keyword) {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected @{} at-keyword", keyword),
+      });
+    }
+    let _ = tokens.consume_next_token();
+  }
+  skip_whitespace(tokens);
+  Ok(())
+}
+
+/// Consume the tokens up to -- not including -- the `)` that closes the block
+/// the parser is in.
+pub(crate) fn block_contents(tokens: &mut TokenList) -> Result<Vec<SimpleToken>, CssParseError> {
+  let mut contents = Vec::new();
+  let mut depth = 0usize;
+
+  loop {
+    match tokens.peek_infallible() {
+      None => {
+        return Err(CssParseError::ParseError {
+          message: "Expected closing parenthesis".to_string(),
+        });
+      },
+      Some(SimpleToken::RightParen) if depth == 0 => return Ok(contents),
+      Some(token) => {
+        match token {
+          SimpleToken::Function(_) | SimpleToken::LeftParen | SimpleToken::Delim('[') => depth += 1,
+          SimpleToken::RightParen | SimpleToken::Delim(']') => depth = depth.saturating_sub(1),
+          _ => {},
+        }
+        let _ = tokens.consume_next_token();
+        contents.push(token);
+      },
+    }
+  }
+}
+
+/// Respell a run of component values with canonical whitespace: runs of
+/// whitespace collapse to one space, none is kept inside brackets or before a
+/// comma, and exactly one follows a comma. Delimiters in `spaced_delims` get
+/// one space on either side when they sit outside any bracket -- that is how
+/// selector combinators are written.
+///
+/// Tokens no value may contain -- a bad string or URL, a `;`, a `{` -- are
+/// rejected, as is an empty run.
+pub(crate) fn serialize_component_values(
+  values: &[SimpleToken],
+  spaced_delims: &[char],
+) -> Result<String, CssParseError> {
+  let mut out = String::new();
+  let mut depth = 0usize;
+  let mut pending_space = false;
+  let mut previous: Option<&SimpleToken> = None;
+  let mut previous_spaced = false;
+
+  for token in values {
+    match token {
+      SimpleToken::Whitespace | SimpleToken::Comment(_) => {
+        pending_space = true;
+        continue;
+      },
+      SimpleToken::Unknown(_)
+      | SimpleToken::Semicolon
+      | SimpleToken::Delim('{' | '}')
+      | SimpleToken::LeftBrace
+      | SimpleToken::RightBrace => {
+        return Err(CssParseError::InvalidToken {
+          token: format!("{:?}", token),
+        });
+      },
+      _ => {},
+    }
+
+    let closes = matches!(
+      token,
+      SimpleToken::RightParen | SimpleToken::RightBracket | SimpleToken::Delim(']')
+    );
+    if closes {
+      depth = depth
+        .checked_sub(1)
+        .ok_or_else(|| CssParseError::ParseError {
+          message: "Unbalanced brackets".to_string(),
+        })?;
+    }
+
+    let spaced = depth == 0 && matches!(token, SimpleToken::Delim(c) if spaced_delims.contains(c));
+    let after_open = out.ends_with('(') || out.ends_with('[');
+    let wants_space = pending_space || spaced || previous_spaced;
+    if wants_space
+      && !out.is_empty()
+      && !after_open
+      && !closes
+      && !matches!(token, SimpleToken::Comma)
+    {
+      out.push(' ');
+    }
+
+    // A number written straight after an identifier or another number can
+    // only have been split off by its sign, and the tokenizer drops a `+`.
+    let glued = !pending_space
+      && matches!(
+        previous,
+        Some(
+          SimpleToken::Ident(_)
+            | SimpleToken::Number(_)
+            | SimpleToken::Dimension { .. }
+            | SimpleToken::Percentage(_)
+        )
+      );
+    push_token(&mut out, token, glued);
+
+    if matches!(
+      token,
+      SimpleToken::Function(_)
+        | SimpleToken::LeftParen
+        | SimpleToken::LeftBracket
+        | SimpleToken::Delim('[')
+    ) {
+      depth += 1;
+    }
+
+    pending_space = matches!(token, SimpleToken::Comma);
+    previous_spaced = spaced;
+    previous = Some(token);
+  }
+
+  if out.is_empty() {
+    return Err(CssParseError::ParseError {
+      message: "Expected a value".to_string(),
+    });
+  }
+  if depth != 0 {
+    return Err(CssParseError::ParseError {
+      message: "Unbalanced brackets".to_string(),
+    });
+  }
+  Ok(out)
+}
+
+fn push_token(out: &mut String, token: &SimpleToken, glued: bool) {
+  let sign = |value: f64| if glued && value >= 0.0 { "+" } else { "" };
+
+  match token {
+    SimpleToken::Ident(ident) => out.push_str(ident),
+    SimpleToken::Function(name) => {
+      out.push_str(name);
+      out.push('(');
+    },
+    SimpleToken::AtKeyword(keyword) => {
+      out.push('@');
+      out.push_str(keyword);
+    },
+    SimpleToken::Hash(hash) => {
+      out.push('#');
+      out.push_str(hash);
+    },
+    SimpleToken::String(string) => {
+      out.push('"');
+      for ch in string.chars() {
+        if ch == '"' || ch == '\\' {
+          out.push('\\');
+        }
+        out.push(ch);
+      }
+      out.push('"');
+    },
+    SimpleToken::Url(url) => {
+      out.push_str("url(");
+      out.push_str(url);
+      out.push(')');
+    },
+    SimpleToken::Number(value) => {
+      out.push_str(sign(*value));
+      out.push_str(&format_number(*value));
+    },
+    SimpleToken::Dimension { value, unit } => {
+      out.push_str(sign(*value));
+      out.push_str(&format_number(*value));
+      out.push_str(unit);
+    },
+    SimpleToken::Percentage(value) => {
+      out.push_str(sign(*value));
+      out.push_str(&format_number(value * 100.0));
+      out.push('%');
+    },
+    SimpleToken::Delim(ch) => out.push(*ch),
+    SimpleToken::LeftParen => out.push('('),
+    SimpleToken::RightParen => out.push(')'),
+    SimpleToken::LeftBracket => out.push('['),
+    SimpleToken::RightBracket => out.push(']'),
+    SimpleToken::LeftBrace => out.push('{'),
+    SimpleToken::RightBrace => out.push('}'),
+    SimpleToken::Comma => out.push(','),
+    SimpleToken::Semicolon => out.push(';'),
+    SimpleToken::Colon => out.push(':'),
+    SimpleToken::Whitespace => out.push(' '),
+    SimpleToken::Comment(_) | SimpleToken::Unknown(_) => {},
+  }
+}
+
+/// The shortest spelling of a tokenized number. The tokenizer reads numbers
+/// as `f32`, so they are printed at that precision: `0.1` stays `0.1` instead
+/// of growing the digits of its `f64` widening.
+pub(crate) fn format_number(value: f64) -> String {
+  (value as f32).to_string()
+}
+
+#[cfg(test)]
+#[path = "../tests/at_queries/condition_test.rs"]
+mod tests;
@@ -0,0 +1,710 @@
+/*!
+`@container` query parsing and representation.
+
+A container query is a comma-separated list of conditions, each naming a
+container, querying one, or both: `@container sidebar (width >= 400px)`. A
+query combines size features, `style()` queries and `scroll-state()` queries
+with `not`, `and` and `or`.
+
+Size features are respelled in range syntax, so `(min-width: 400px)` and
+`(400px <= width)` both become `(width >= 400px)`. Media queries go the other
+way, to `min-`/`max-`, because range syntax in `@media` is younger than the
+browsers StyleX supports; every browser that ships `@container` reads range
+syntax, so here it is the shorter and more uniform spelling.
+*/
+
+use super::condition::{
+  Condition, block_contents, condition_parser, expect_token, format_number,
+  serialize_component_values, skip_at_keyword, skip_whitespace,
+};
+use crate::{
+  CssParseError,
+  css_types::length::Length,
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Display};
+
+/// Size features that compare against a value, and so take range syntax and
+/// the `min-`/`max-` prefixes.
+const RANGE_FEATURES: [&str; 5] = [
+  "width",
+  "height",
+  "inline-size",
+  "block-size",
+  "aspect-ratio",
+];
+
+/// Size features with a fixed set of keyword values.
+const DISCRETE_FEATURES: [&str; 1] = ["orientation"];
+
+/// Math functions a size value may be computed with.
+const MATH_FUNCTIONS: [&str; 4] = ["calc", "min", "max", "clamp"];
+
+/// Idents that cannot name a container: the combinator keywords, `none`, and
+/// the CSS-wide keywords.
+const RESERVED_NAMES: [&str; 10] = [
+  "none",
+  "and",
+  "or",
+  "not",
+  "initial",
+  "inherit",
+  "unset",
+  "revert",
+  "revert-layer",
+  "default",
+];
+
+/// The value a size feature is compared against.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SizeValue {
+  Length {
+    value: f64,
+    unit: String,
+  },
+  Number(f64),
+  Ratio(f64, f64),
+  Keyword(String),
+  /// A `calc()`, `min()`, `max()` or `clamp()`, respelled but not evaluated.
+  Math(String),
+}
+
+impl Display for SizeValue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SizeValue::Length { value, unit } => write!(f, "{}{}", format_number(*value), unit),
+      SizeValue::Number(value) => write!(f, "{}", format_number(*value)),
+      SizeValue::Ratio(numerator, denominator) => write!(
+        f,
+        "{} / {}",
+        format_number(*numerator),
+        format_number(*denominator)
+      ),
+      SizeValue::Keyword(keyword) => write!(f, "{}", keyword),
+      SizeValue::Math(math) => write!(f, "{}", math),
+    }
+  }
+}
+
+/// One end of a range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeBound {
+  pub value: SizeValue,
+  pub inclusive: bool,
+}
+
+impl SizeBound {
+  fn operator(&self, lower: bool) -> &'static str {
+    match (lower, self.inclusive) {
+      (true, true) => ">=",
+      (true, false) => ">",
+      (false, true) => "<=",
+      (false, false) => "<",
+    }
+  }
+}
+
+/// A size feature test.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SizeFeature {
+  /// `(width)`: true when the feature is non-zero.
+  Boolean(String),
+  /// `(orientation: portrait)`, `(width: 400px)`
+  Plain { name: String, value: SizeValue },
+  /// `(width > 400px)`, `(400px <= width < 800px)`
+  Range {
+    name: String,
+    lower: Option<SizeBound>,
+    upper: Option<SizeBound>,
+  },
+}
+
+impl Display for SizeFeature {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SizeFeature::Boolean(name) => write!(f, "({})", name),
+      SizeFeature::Plain { name, value } => write!(f, "({}: {})", name, value),
+      SizeFeature::Range {
+        name,
+        lower: Some(lower),
+        upper: Some(upper),
+      } => {
+        // Both ends read left to right, smallest first.
+        let lower_op = if lower.inclusive { "<=" } else { "<" };
+        write!(
+          f,
+          "({} {} {} {} {})",
+          lower.value,
+          lower_op,
+          name,
+          upper.operator(false),
+          upper.value
+        )
+      },
+      SizeFeature::Range {
+        name,
+        lower: Some(bound),
+        upper: None,
+      } => write!(f, "({} {} {})", name, bound.operator(true), bound.value),
+      SizeFeature::Range {
+        name,
+        lower: None,
+        upper: Some(bound),
+      } => write!(f, "({} {} {})", name, bound.operator(false), bound.value),
+      SizeFeature::Range {
+        name,
+        lower: None,
+        upper: None,
+      } => write!(f, "({})", name),
+    }
+  }
+}
+
+/// A property test inside `style()`, or a state test inside `scroll-state()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleFeature {
+  pub property: String,
+  pub value: Option<String>,
+}
+
+impl StyleFeature {
+  fn declaration(&self) -> String {
+    match &self.value {
+      Some(value) => format!("{}: {}", self.property, value),
+      None => self.property.clone(),
+    }
+  }
+}
+
+impl Display for StyleFeature {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "({})", self.declaration())
+  }
+}
+
+/// A single test in a container query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerFeature {
+  Size(SizeFeature),
+  Style(Condition<StyleFeature>),
+  ScrollState(Condition<StyleFeature>),
+}
+
+impl Display for ContainerFeature {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (function, condition) = match self {
+      ContainerFeature::Size(size) => return write!(f, "{}", size),
+      ContainerFeature::Style(condition) => ("style", condition),
+      ContainerFeature::ScrollState(condition) => ("scroll-state", condition),
+    };
+
+    // A lone test needs no parentheses of its own inside the function's.
+    match condition {
+      Condition::Feature(feature) => write!(f, "{}({})", function, feature.declaration()),
+      condition => write!(f, "{}({})", function, condition),
+    }
+  }
+}
+
+/// One entry of a container query's comma-separated list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerCondition {
+  pub name: Option<String>,
+  pub query: Option<Condition<ContainerFeature>>,
+}
+
+impl Display for ContainerCondition {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match (&self.name, &self.query) {
+      (Some(name), Some(query)) => write!(f, "{} {}", name, query),
+      (Some(name), None) => write!(f, "{}", name),
+      (None, Some(query)) => write!(f, "{}", query),
+      (None, None) => Ok(()),
+    }
+  }
+}
+
+/// A parsed `@container` prelude.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerQuery {
+  pub conditions: Vec<ContainerCondition>,
+}
+
+impl ContainerQuery {
+  pub fn new(conditions: Vec<ContainerCondition>) -> Self {
+    Self {
+      conditions: conditions
+        .into_iter()
+        .map(|condition| ContainerCondition {
+          name: condition.name,
+          query: condition.query.map(Condition::normalize),
+        })
+        .collect(),
+    }
+  }
+
+  pub fn parser() -> TokenParser<ContainerQuery> {
+    TokenParser::new(
+      |tokens| {
+        skip_at_keyword(tokens, "container")?;
+
+        let mut conditions = vec![(container_condition_parser().run)(tokens)?];
+        loop {
+          skip_whitespace(tokens);
+          if tokens.peek_infallible() != Some(SimpleToken::Comma) {
+            break;
+          }
+          let _ = tokens.consume_next_token();
+          skip_whitespace(tokens);
+          conditions.push((container_condition_parser().run)(tokens)?);
+        }
+
+        Ok(ContainerQuery::new(conditions))
+      },
+      "container_query_parser",
+    )
+  }
+}
+
+impl Display for ContainerQuery {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "@container ")?;
+    for (index, condition) in self.conditions.iter().enumerate() {
+      if index > 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{}", condition)?;
+    }
+    Ok(())
+  }
+}
+
+/// Validate a container query string
+pub fn validate_container_query(input: &str) -> Result<ContainerQuery, String> {
+  if !super::media_query::MediaQuery::has_balanced_parens(input) {
+    return Err(super::messages::ContainerQueryErrors::UNBALANCED_PARENS.to_string());
+  }
+
+  ContainerQuery::parser()
+    .parse_to_end(input)
+    .map_err(|_| super::messages::ContainerQueryErrors::SYNTAX_ERROR.to_string())
+}
+
+/// `[<container-name>]? [<container-query>]?`, with at least one of the two.
+fn container_condition_parser() -> TokenParser<ContainerCondition> {
+  TokenParser::new(
+    |tokens| {
+      let mut name = None;
+
+      if let Some(SimpleToken::Ident(ident)) = tokens.peek_infallible()
+        && !ident.eq_ignore_ascii_case("not")
+      {
+        if RESERVED_NAMES
+          .iter()
+          .any(|reserved| ident.eq_ignore_ascii_case(reserved))
+        {
+          return Err(CssParseError::ParseError {
+            message: format!("`{}` cannot name a container", ident),
+          });
+        }
+        let _ = tokens.consume_next_token();
+        name = Some(ident);
+
+        let checkpoint = tokens.save_position();
+        skip_whitespace(tokens);
+        if matches!(tokens.peek_infallible(), None | Some(SimpleToken::Comma)) {
+          let _ = tokens.restore_position(checkpoint);
+          return Ok(ContainerCondition { name, query: None });
+        }
+      }
+
+      let query = (condition_parser(container_feature_parser).run)(tokens)?;
+      Ok(ContainerCondition {
+        name,
+        query: Some(query),
+      })
+    },
+    "container_condition_parser",
+  )
+}
+
+fn container_feature_parser() -> TokenParser<ContainerFeature> {
+  TokenParser::new(
+    |tokens| match tokens.consume_next_token_infallible() {
+      Some(SimpleToken::LeftParen) => {
+        let contents = block_contents(tokens)?;
+        expect_token(tokens, SimpleToken::RightParen)?;
+        size_feature(&contents).map(ContainerFeature::Size)
+      },
+      Some(SimpleToken::Function(name)) if name.eq_ignore_ascii_case("style") => {
+        function_query(tokens).map(ContainerFeature::Style)
+      },
+      Some(SimpleToken::Function(name)) if name.eq_ignore_ascii_case("scroll-state") => {
+        function_query(tokens).map(ContainerFeature::ScrollState)
+      },
+      token => Err(CssParseError::ParseError {
+        message: format!("Expected a container feature, got {:?}", token),
+      }),
+    },
+    "container_feature_parser",
+  )
+}
+
+/// The body of `style(…)` or `scroll-state(…)`, after the function token: a
+/// condition over parenthesized tests, or one bare test.
+fn function_query(tokens: &mut TokenList) -> Result<Condition<StyleFeature>, CssParseError> {
+  skip_whitespace(tokens);
+
+  let condition = TokenParser::one_of(vec![
+    condition_parser(style_feature_in_parens_parser),
+    TokenParser::new(style_declaration, "style_declaration").map(Condition::Feature, None),
+  ]);
+  let condition = (condition.run)(tokens)?.normalize();
+
+  skip_whitespace(tokens);
+  expect_token(tokens, SimpleToken::RightParen)?;
+  Ok(condition)
+}
+
+fn style_feature_in_parens_parser() -> TokenParser<StyleFeature> {
+  TokenParser::new(
+    |tokens| {
+      expect_token(tokens, SimpleToken::LeftParen)?;
+      skip_whitespace(tokens);
+      let feature = style_declaration(tokens)?;
+      expect_token(tokens, SimpleToken::RightParen)?;
+      Ok(feature)
+    },
+    "style_feature_in_parens_parser",
+  )
+}
+
+/// `<property>` or `<property>: <value>`, up to the closing parenthesis.
+fn style_declaration(tokens: &mut TokenList) -> Result<StyleFeature, CssParseError> {
+  let property = match tokens.consume_next_token_infallible() {
+    Some(SimpleToken::Ident(property)) if property.starts_with("--") => property,
+    Some(SimpleToken::Ident(property)) => property.to_ascii_lowercase(),
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected a property name, got {:?}", token),
+      });
+    },
+  };
+
+  skip_whitespace(tokens);
+  let value = if tokens.peek_infallible() == Some(SimpleToken::Colon) {
+    let _ = tokens.consume_next_token();
+    Some(serialize_component_values(&block_contents(tokens)?, &[])?)
+  } else {
+    None
+  };
+
+  if tokens.peek_infallible() != Some(SimpleToken::RightParen) {
+    return Err(CssParseError::ParseError {
+      message: "Expected closing parenthesis after style feature".to_string(),
+    });
+  }
+
+  Ok(StyleFeature { property, value })
+}
+
+/// A comparison operator in range syntax.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+  Less { inclusive: bool },
+  Greater { inclusive: bool },
+  Equal,
+}
+
+/// Parse the inside of a size feature's parentheses. The feature name may sit
+/// on either side of the operator, so the contents are split at the operators
+/// first and each side is read once the name is known.
+fn size_feature(contents: &[SimpleToken]) -> Result<SizeFeature, CssParseError> {
+  let (segments, comparisons) = split_comparisons(contents)?;
+
+  match (segments.as_slice(), comparisons.as_slice()) {
+    ([segment], []) => match feature_name(segment) {
+      Some(name) if is_known_feature(&name) => Ok(SizeFeature::Boolean(name)),
+      Some(_) => Err(unknown_feature()),
+      None => plain_feature(segment),
+    },
+    ([left, right], [comparison]) => {
+      let (name, value, comparison) = match (feature_name(left), feature_name(right)) {
+        (Some(name), _) if is_range_feature(&name) => (name, right, *comparison),
+        (_, Some(name)) if is_range_feature(&name) => (name, left, comparison.reversed()),
+        _ => return Err(unknown_feature()),
+      };
+      let value = size_value(&name, value)?;
+
+      Ok(match comparison {
+        Comparison::Equal => SizeFeature::Plain { name, value },
+        Comparison::Greater { inclusive } => SizeFeature::Range {
+          name,
+          lower: Some(SizeBound { value, inclusive }),
+          upper: None,
+        },
+        Comparison::Less { inclusive } => SizeFeature::Range {
+          name,
+          lower: None,
+          upper: Some(SizeBound { value, inclusive }),
+        },
+      })
+    },
+    ([first, middle, last], [left_comparison, right_comparison]) => {
+      let name = feature_name(middle)
+        .filter(|name| is_range_feature(name))
+        .ok_or_else(unknown_feature)?;
+      let first = size_value(&name, first)?;
+      let last = size_value(&name, last)?;
+
+      let (lower, upper) = match (left_comparison, right_comparison) {
+        (Comparison::Less { inclusive: left }, Comparison::Less { inclusive: right }) => (
+          SizeBound {
+            value: first,
+            inclusive: *left,
+          },
+          SizeBound {
+            value: last,
+            inclusive: *right,
+          },
+        ),
+        (Comparison::Greater { inclusive: left }, Comparison::Greater { inclusive: right }) => (
+          SizeBound {
+            value: last,
+            inclusive: *right,
+          },
+          SizeBound {
+            value: first,
+            inclusive: *left,
+          },
+        ),
+        _ => {
+          return Err(CssParseError::ParseError {
+            message: "Both comparisons in a range must point the same way".to_string(),
+          });
+        },
+      };
+
+      Ok(SizeFeature::Range {
+        name,
+        lower: Some(lower),
+        upper: Some(upper),
+      })
+    },
+    _ => Err(CssParseError::ParseError {
+      message: "Invalid range syntax".to_string(),
+    }),
+  }
+}
+
+/// `(<name>: <value>)`, where `min-` and `max-` names become ranges.
+fn plain_feature(segment: &[SimpleToken]) -> Result<SizeFeature, CssParseError> {
+  let colon = segment
+    .iter()
+    .position(|token| *token == SimpleToken::Colon)
+    .ok_or_else(unknown_feature)?;
+  let name = feature_name(&segment[..colon]).ok_or_else(unknown_feature)?;
+  let value_tokens = &segment[colon + 1..];
+
+  if let Some(base) = name
+    .strip_prefix("min-")
+    .filter(|base| is_range_feature(base))
+  {
+    return Ok(SizeFeature::Range {
+      name: base.to_string(),
+      lower: Some(SizeBound {
+        value: size_value(base, value_tokens)?,
+        inclusive: true,
+      }),
+      upper: None,
+    });
+  }
+
+  if let Some(base) = name
+    .strip_prefix("max-")
+    .filter(|base| is_range_feature(base))
+  {
+    return Ok(SizeFeature::Range {
+      name: base.to_string(),
+      lower: None,
+      upper: Some(SizeBound {
+        value: size_value(base, value_tokens)?,
+        inclusive: true,
+      }),
+    });
+  }
+
+  if !is_known_feature(&name) {
+    return Err(unknown_feature());
+  }
+  let value = size_value(&name, value_tokens)?;
+  Ok(SizeFeature::Plain { name, value })
+}
+
+/// Split a size feature's contents at its top-level comparison operators.
+fn split_comparisons(
+  contents: &[SimpleToken],
+) -> Result<(Vec<&[SimpleToken]>, Vec<Comparison>), CssParseError> {
+  let mut segments = Vec::new();
+  let mut comparisons = Vec::new();
+  let mut start = 0;
+  let mut depth = 0usize;
+  let mut index = 0;
+
+  while index < contents.len() {
+    let token = &contents[index];
+    match token {
+      SimpleToken::Function(_) | SimpleToken::LeftParen | SimpleToken::Delim('[') => depth += 1,
+      SimpleToken::RightParen | SimpleToken::Delim(']') => depth = depth.saturating_sub(1),
+      SimpleToken::Delim(op @ ('<' | '>' | '=')) if depth == 0 => {
+        // `<=` and `>=` are two delimiters with nothing between them.
+        let inclusive = *op != '=' && contents.get(index + 1) == Some(&SimpleToken::Delim('='));
+        comparisons.push(match op {
+          '<' => Comparison::Less { inclusive },
+          '>' => Comparison::Greater { inclusive },
+          _ => Comparison::Equal,
+        });
+        segments.push(&contents[start..index]);
+
+        index += if inclusive { 2 } else { 1 };
+        start = index;
+        continue;
+      },
+      _ => {},
+    }
+    index += 1;
+  }
+  segments.push(&contents[start..]);
+
+  Ok((segments, comparisons))
+}
+
+impl Comparison {
+  /// The same comparison read from the other side.
+  fn reversed(self) -> Self {
+    match self {
+      Comparison::Less { inclusive } => Comparison::Greater { inclusive },
+      Comparison::Greater { inclusive } => Comparison::Less { inclusive },
+      Comparison::Equal => Comparison::Equal,
+    }
+  }
+}
+
+fn trim_whitespace(tokens: &[SimpleToken]) -> &[SimpleToken] {
+  let is_space = |token: &SimpleToken| matches!(token, SimpleToken::Whitespace);
+  let start = tokens
+    .iter()
+    .position(|token| !is_space(token))
+    .unwrap_or(tokens.len());
+  let end = tokens
+    .iter()
+    .rposition(|token| !is_space(token))
+    .map_or(start, |end| end + 1);
+  &tokens[start..end]
+}
+
+/// The lowercased feature name when `tokens` is a single identifier.
+fn feature_name(tokens: &[SimpleToken]) -> Option<String> {
+  match trim_whitespace(tokens) {
+    [SimpleToken::Ident(name)] => Some(name.to_ascii_lowercase()),
+    _ => None,
+  }
+}
+
+fn is_range_feature(name: &str) -> bool {
+  RANGE_FEATURES.contains(&name)
+}
+
+fn is_known_feature(name: &str) -> bool {
+  is_range_feature(name) || DISCRETE_FEATURES.contains(&name)
+}
+
+fn unknown_feature() -> CssParseError {
+  CssParseError::ParseError {
+    message: "Unknown container size feature".to_string(),
+  }
+}
+
+/// Read the value `name` is compared against.
+fn size_value(name: &str, tokens: &[SimpleToken]) -> Result<SizeValue, CssParseError> {
+  let tokens = trim_whitespace(tokens);
+  let invalid = || CssParseError::InvalidValue {
+    value: format!("{:?} for `{}`", tokens, name),
+  };
+
+  match name {
+    "orientation" => match tokens {
+      [SimpleToken::Ident(keyword)]
+        if keyword.eq_ignore_ascii_case("portrait")
+          || keyword.eq_ignore_ascii_case("landscape") =>
+      {
+        Ok(SizeValue::Keyword(keyword.to_ascii_lowercase()))
+      },
+      _ => Err(invalid()),
+    },
+    "aspect-ratio" => {
+      let significant: Vec<&SimpleToken> = tokens
+        .iter()
+        .filter(|token| !matches!(token, SimpleToken::Whitespace))
+        .collect();
+
+      match significant.as_slice() {
+        [SimpleToken::Number(value)] if *value >= 0.0 => Ok(SizeValue::Number(*value)),
+        [
+          SimpleToken::Number(numerator),
+          SimpleToken::Delim('/'),
+          SimpleToken::Number(denominator),
+        ] if *numerator >= 0.0 && *denominator >= 0.0 => {
+          Ok(SizeValue::Ratio(*numerator, *denominator))
+        },
+        _ => Err(invalid()),
+      }
+    },
+    _ => match tokens {
+      [SimpleToken::Dimension { value, unit }]
+        if Length::units()
+          .iter()
+          .any(|known| known.eq_ignore_ascii_case(unit)) =>
+      {
+        Ok(SizeValue::Length {
+          value: *value,
+          unit: unit.to_ascii_lowercase(),
+        })
+      },
+      [SimpleToken::Number(value)] if *value == 0.0 => Ok(SizeValue::Number(0.0)),
+      [SimpleToken::Function(function), ..]
+        if is_single_call(tokens)
+          && MATH_FUNCTIONS
+            .iter()
+            .any(|math| function.eq_ignore_ascii_case(math)) =>
+      {
+        serialize_component_values(tokens, &[]).map(SizeValue::Math)
+      },
+      _ => Err(invalid()),
+    },
+  }
+}
+
+/// Whether `tokens` is exactly one function call: the function opened by the
+/// first token closes at the last.
+fn is_single_call(tokens: &[SimpleToken]) -> bool {
+  let mut depth = 0usize;
+  for (index, token) in tokens.iter().enumerate() {
+    match token {
+      SimpleToken::Function(_) | SimpleToken::LeftParen | SimpleToken::Delim('[') => depth += 1,
+      SimpleToken::RightParen | SimpleToken::Delim(']') => {
+        depth = depth.saturating_sub(1);
+        if depth == 0 {
+          return index == tokens.len() - 1;
+        }
+      },
+      _ => {},
+    }
+  }
+  false
+}
+
+#[cfg(test)]
+#[path = "../tests/at_queries/container_query_test.rs"]
+mod tests;
@@ -1,5 +1,5 @@
 /*!
-Error messages for media query parsing and validation.
+Error messages for at-rule query parsing and validation.
 */
 
 pub struct MediaQueryErrors;
@@ -9,6 +9,20 @@ impl MediaQueryErrors {
   pub const UNBALANCED_PARENS: &'static str = "Unbalanced parentheses in media query.";
 }
 
+pub struct SupportsQueryErrors;
+
+impl SupportsQueryErrors {
+  pub const SYNTAX_ERROR: &'static str = "Invalid @supports query syntax.";
+  pub const UNBALANCED_PARENS: &'static str = "Unbalanced parentheses in @supports query.";
+}
+
+pub struct ContainerQueryErrors;
+
+impl ContainerQueryErrors {
+  pub const SYNTAX_ERROR: &'static str = "Invalid @container query syntax.";
+  pub const UNBALANCED_PARENS: &'static str = "Unbalanced parentheses in @container query.";
+}
+
 #[cfg(test)]
 #[path = "../tests/at_queries/messages_tests.rs"]
 mod tests;
@@ -1,14 +1,21 @@
 /*!
 At-rule parsing functionality.
 
-This module contains parsers for CSS at-rules, particularly media queries.
-Includes support for complex media query parsing, validation, and transformation.
+This module contains parsers for CSS at-rule preludes: media queries,
+`@supports` conditions and `@container` queries. Includes support for complex
+query parsing, validation, and transformation.
 */
 
+pub mod condition;
+pub mod container_query;
 pub mod media_query;
 pub mod media_query_transform;
 pub mod messages;
+pub mod supports_query;
 
+pub use condition::Condition;
+pub use container_query::{ContainerQuery, validate_container_query};
 pub use media_query::{MediaQuery, MediaQueryRule, validate_media_query};
 pub use media_query_transform::last_media_query_wins_transform;
-pub use messages::MediaQueryErrors;
+pub use messages::{ContainerQueryErrors, MediaQueryErrors, SupportsQueryErrors};
+pub use supports_query::{SupportsQuery, validate_supports_query};
@@ -0,0 +1,239 @@
+/*!
+`@supports` condition parsing and representation.
+
+A supports condition tests declarations (`(display: grid)`), selectors
+(`selector(:has(a))`), font capabilities (`font-tech(variations)`,
+`font-format(woff2)`) and at-rules (`at-rule(@layer)`), combined with `not`,
+`and` and `or`. Parsing respells the condition canonically, so two spellings
+of one condition produce one string.
+
+Anything else in parentheses or behind a function name is CSS's
+`<general-enclosed>`: valid syntax that a browser which does not know it treats
+as false. It is kept, with its whitespace respelled, so a test newer than this
+parser still compiles. A known test with a malformed argument is rejected.
+*/
+
+use super::condition::{
+  Condition, block_contents, condition_parser, expect_token, serialize_component_values,
+  skip_at_keyword, skip_whitespace,
+};
+use crate::{
+  CssParseError,
+  token_parser::TokenParser,
+  token_types::{SimpleToken, TokenList},
+};
+use std::fmt::{self, Display};
+
+/// Combinators a selector spells with a space on either side.
+const SELECTOR_COMBINATORS: [char; 3] = ['>', '+', '~'];
+
+/// A single test in a supports condition.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupportsFeature {
+  /// `(property: value)`
+  Declaration { property: String, value: String },
+  /// `selector(<complex-selector>)`
+  Selector(String),
+  /// `font-tech(<font-tech>)`
+  FontTech(String),
+  /// `font-format(<font-format>)`
+  FontFormat(String),
+  /// `at-rule(<at-keyword-token>)`, with the keyword's `@`
+  AtRule(String),
+  /// `<general-enclosed>`, parentheses or function name included
+  GeneralEnclosed(String),
+}
+
+impl Display for SupportsFeature {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SupportsFeature::Declaration { property, value } => write!(f, "({}: {})", property, value),
+      SupportsFeature::Selector(selector) => write!(f, "selector({})", selector),
+      SupportsFeature::FontTech(tech) => write!(f, "font-tech({})", tech),
+      SupportsFeature::FontFormat(format) => write!(f, "font-format({})", format),
+      SupportsFeature::AtRule(keyword) => write!(f, "at-rule({})", keyword),
+      SupportsFeature::GeneralEnclosed(test) => write!(f, "{}", test),
+    }
+  }
+}
+
+/// A parsed `@supports` prelude.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupportsQuery {
+  pub condition: Condition<SupportsFeature>,
+}
+
+impl SupportsQuery {
+  pub fn new(condition: Condition<SupportsFeature>) -> Self {
+    Self {
+      condition: condition.normalize(),
+    }
+  }
+
+  pub fn parser() -> TokenParser<SupportsQuery> {
+    TokenParser::new(
+      |tokens| {
+        skip_at_keyword(tokens, "supports")?;
+        let condition = (condition_parser(supports_feature_parser).run)(tokens)?;
+        skip_whitespace(tokens);
+        Ok(SupportsQuery::new(condition))
+      },
+      "supports_query_parser",
+    )
+  }
+}
+
+impl Display for SupportsQuery {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "@supports {}", self.condition)
+  }
+}
+
+/// Validate a supports query string
+pub fn validate_supports_query(input: &str) -> Result<SupportsQuery, String> {
+  if !super::media_query::MediaQuery::has_balanced_parens(input) {
+    return Err(super::messages::SupportsQueryErrors::UNBALANCED_PARENS.to_string());
+  }
+
+  SupportsQuery::parser()
+    .parse_to_end(input)
+    .map_err(|_| super::messages::SupportsQueryErrors::SYNTAX_ERROR.to_string())
+}
+
+fn supports_feature_parser() -> TokenParser<SupportsFeature> {
+  TokenParser::new(
+    |tokens| match tokens.consume_next_token_infallible() {
+      Some(SimpleToken::LeftParen) => {
+        let checkpoint = tokens.save_position();
+        declaration_feature(tokens).or_else(|_| {
+          let _ = tokens.restore_position(checkpoint);
+          let contents = block_contents(tokens)?;
+          expect_token(tokens, SimpleToken::RightParen)?;
+          general_enclosed("", &contents)
+        })
+      },
+      Some(SimpleToken::Function(name)) => {
+        let name = name.to_ascii_lowercase();
+        let contents = block_contents(tokens)?;
+        expect_token(tokens, SimpleToken::RightParen)?;
+
+        match name.as_str() {
+          "selector" => selector_argument(&contents).map(SupportsFeature::Selector),
+          "font-tech" => keyword_argument(&contents).map(SupportsFeature::FontTech),
+          "font-format" => keyword_argument(&contents).map(SupportsFeature::FontFormat),
+          "at-rule" => at_keyword_argument(&contents).map(SupportsFeature::AtRule),
+          _ => general_enclosed(&name, &contents),
+        }
+      },
+      token => Err(CssParseError::ParseError {
+        message: format!("Expected a supports feature, got {:?}", token),
+      }),
+    },
+    "supports_feature_parser",
+  )
+}
+
+/// The inside of `(property: value)`, after the opening parenthesis.
+fn declaration_feature(tokens: &mut TokenList) -> Result<SupportsFeature, CssParseError> {
+  skip_whitespace(tokens);
+
+  let property = match tokens.consume_next_token_infallible() {
+    Some(SimpleToken::Ident(property)) if property.starts_with("--") => property,
+    Some(SimpleToken::Ident(property)) => property.to_ascii_lowercase(),
+    token => {
+      return Err(CssParseError::ParseError {
+        message: format!("Expected a property name, got {:?}", token),
+      });
+    },
+  };
+
+  skip_whitespace(tokens);
+  expect_token(tokens, SimpleToken::Colon)?;
+
+  let value = serialize_component_values(&block_contents(tokens)?, &[])?;
+  expect_token(tokens, SimpleToken::RightParen)?;
+
+  Ok(SupportsFeature::Declaration { property, value })
+}
+
+/// A test this parser does not know, kept as written but for whitespace.
+/// `name` is empty for a parenthesized test.
+fn general_enclosed(
+  name: &str,
+  contents: &[SimpleToken],
+) -> Result<SupportsFeature, CssParseError> {
+  let empty = contents
+    .iter()
+    .all(|token| matches!(token, SimpleToken::Whitespace | SimpleToken::Comment(_)));
+  let contents = if empty {
+    String::new()
+  } else {
+    serialize_component_values(contents, &[])?
+  };
+  Ok(SupportsFeature::GeneralEnclosed(format!(
+    "{}({})",
+    name, contents
+  )))
+}
+
+/// The argument of `selector()`: one complex selector, so no top-level comma
+/// and no combinator with nothing on one side of it.
+fn selector_argument(contents: &[SimpleToken]) -> Result<String, CssParseError> {
+  let mut depth = 0usize;
+  for token in contents {
+    match token {
+      SimpleToken::Function(_) | SimpleToken::LeftParen | SimpleToken::Delim('[') => depth += 1,
+      SimpleToken::RightParen | SimpleToken::Delim(']') => depth = depth.saturating_sub(1),
+      SimpleToken::Comma if depth == 0 => {
+        return Err(CssParseError::ParseError {
+          message: "selector() takes a single selector, not a list".to_string(),
+        });
+      },
+      _ => {},
+    }
+  }
+
+  let significant = |token: &&SimpleToken| !matches!(token, SimpleToken::Whitespace);
+  let dangling = |token: Option<&SimpleToken>| matches!(token, Some(SimpleToken::Delim(c)) if SELECTOR_COMBINATORS.contains(c));
+  if dangling(contents.iter().find(significant)) || dangling(contents.iter().rfind(significant)) {
+    return Err(CssParseError::ParseError {
+      message: "A selector cannot start or end with a combinator".to_string(),
+    });
+  }
+
+  serialize_component_values(contents, &SELECTOR_COMBINATORS)
+}
+
+/// The argument of `font-tech()` and `font-format()`: a single keyword.
+fn keyword_argument(contents: &[SimpleToken]) -> Result<String, CssParseError> {
+  let mut significant = contents
+    .iter()
+    .filter(|token| !matches!(token, SimpleToken::Whitespace));
+
+  match (significant.next(), significant.next()) {
+    (Some(SimpleToken::Ident(keyword)), None) => Ok(keyword.to_ascii_lowercase()),
+    _ => Err(CssParseError::ParseError {
+      message: "Expected a single keyword".to_string(),
+    }),
+  }
+}
+
+/// The argument of `at-rule()`: a single at-keyword.
+fn at_keyword_argument(contents: &[SimpleToken]) -> Result<String, CssParseError> {
+  let mut significant = contents
+    .iter()
+    .filter(|token| !matches!(token, SimpleToken::Whitespace));
+
+  match (significant.next(), significant.next()) {
+    (Some(SimpleToken::AtKeyword(keyword)), None) => {
+      Ok(format!("@{}", keyword.to_ascii_lowercase()))
+    },
+    _ => Err(CssParseError::ParseError {
+      message: "Expected a single at-keyword".to_string(),
+    }),
+  }
+}
+
+#[cfg(test)]
+#[path = "../tests/at_queries/supports_query_test.rs"]
+mod tests;
@@ -0,0 +1,117 @@
+// Tests for the shared condition grammar and component-value respelling.
+// Source: crates/stylex-css-parser/src/at_queries/condition.rs
+
+use super::*;
+use crate::token_types::TokenList;
+
+fn feature() -> TokenParser<String> {
+  TokenParser::new(
+    |tokens| {
+      expect_token(tokens, SimpleToken::LeftParen)?;
+      let name = match tokens.consume_next_token_infallible() {
+        Some(SimpleToken::Ident(name)) => name,
+        token => {
+          return Err(CssParseError::ParseError {
+            message: format!("{:?}", token),
+          });
+        },
+      };
+      expect_token(tokens, SimpleToken::RightParen)?;
+      Ok(format!("({})", name))
+    },
+    "test_feature",
+  )
+}
+
+fn parse(input: &str) -> Result<String, CssParseError> {
+  condition_parser(feature)
+    .parse_to_end(input)
+    .map(|condition| condition.normalize().to_string())
+}
+
+fn respell(input: &str, spaced: &[char]) -> Result<String, CssParseError> {
+  serialize_component_values(&TokenList::new(input).tokens, spaced)
+}
+
+#[test]
+fn parses_each_combinator() {
+  assert_eq!(parse("(a)").unwrap(), "(a)");
+  assert_eq!(parse("not (a)").unwrap(), "not (a)");
+  assert_eq!(parse("(a) and (b) and (c)").unwrap(), "(a) and (b) and (c)");
+  assert_eq!(parse("(a)   or\n(b)").unwrap(), "(a) or (b)");
+  assert_eq!(parse("NOT (a)").unwrap(), "not (a)");
+}
+
+#[test]
+fn keeps_parentheses_that_group() {
+  assert_eq!(
+    parse("(a) and ((b) or (c))").unwrap(),
+    "(a) and ((b) or (c))"
+  );
+  assert_eq!(parse("not ((a) and (b))").unwrap(), "not ((a) and (b))");
+}
+
+#[test]
+fn normalizes_redundant_structure() {
+  assert_eq!(parse("((a))").unwrap(), "(a)");
+  assert_eq!(
+    parse("(a) and ((b) and (c))").unwrap(),
+    "(a) and (b) and (c)"
+  );
+  assert_eq!(parse("((a) or (b)) or (c)").unwrap(), "(a) or (b) or (c)");
+  assert_eq!(parse("not (not (a))").unwrap(), "(a)");
+}
+
+#[test]
+fn rejects_malformed_conditions() {
+  for input in [
+    "",
+    "()",
+    "(a) and (b) or (c)",
+    "not (a) and (b)",
+    "not not (a)",
+    "(a) and",
+    "and (a)",
+    "(a) and(b)",
+    "not(a)",
+    "(a) (b)",
+  ] {
+    assert!(parse(input).is_err(), "expected {:?} to be rejected", input);
+  }
+}
+
+#[test]
+fn respells_component_values_with_canonical_whitespace() {
+  assert_eq!(respell("  grid  ", &[]).unwrap(), "grid");
+  assert_eq!(
+    respell("rgb( 0 0 0 / 50% )", &[]).unwrap(),
+    "rgb(0 0 0 / 50%)"
+  );
+  assert_eq!(respell("a ,b,c", &[]).unwrap(), "a, b, c");
+  assert_eq!(respell("\"a \\\"b\\\"\"", &[]).unwrap(), "\"a \\\"b\\\"\"");
+  assert_eq!(respell("0.1px 10%", &[]).unwrap(), "0.1px 10%");
+}
+
+#[test]
+fn keeps_the_sign_of_a_number_glued_to_an_identifier() {
+  assert_eq!(respell("2n+1", &[]).unwrap(), "2n+1");
+  assert_eq!(respell("-n+3", &[]).unwrap(), "-n+3");
+  assert_eq!(respell("2n-1", &[]).unwrap(), "2n-1");
+}
+
+#[test]
+fn spaces_requested_delimiters_outside_brackets() {
+  assert_eq!(respell("a>b  ~c", &['>', '~']).unwrap(), "a > b ~ c");
+  assert_eq!(
+    respell(":nth-child(2n + 1)+b", &['+']).unwrap(),
+    ":nth-child(2n + 1) + b"
+  );
+  assert_eq!(respell("[data-x=\"y\"]", &[]).unwrap(), "[data-x=\"y\"]");
+}
+
+#[test]
+fn rejects_values_no_condition_may_hold() {
+  assert!(respell("", &[]).is_err());
+  assert!(respell("   ", &[]).is_err());
+  assert!(respell("a; b", &[]).is_err());
+}
@@ -0,0 +1,197 @@
+// Tests for @container query parsing, validation and respelling.
+// Source: crates/stylex-css-parser/src/at_queries/container_query.rs
+
+use super::*;
+use crate::at_queries::ContainerQueryErrors;
+
+fn canonical(input: &str) -> String {
+  match validate_container_query(input) {
+    Ok(query) => query.to_string(),
+    Err(error) => panic!("expected {:?} to parse: {}", input, error),
+  }
+}
+
+#[test]
+fn respells_size_features_in_range_syntax() {
+  assert_eq!(
+    canonical("@container (min-width:400px)"),
+    "@container (width >= 400px)"
+  );
+  assert_eq!(
+    canonical("@container (width >= 400px)"),
+    "@container (width >= 400px)"
+  );
+  assert_eq!(
+    canonical("@container (400px <= width)"),
+    "@container (width >= 400px)"
+  );
+  assert_eq!(
+    canonical("@container (MAX-INLINE-SIZE: 30EM)"),
+    "@container (inline-size <= 30em)"
+  );
+  assert_eq!(
+    canonical("@container (block-size > 10cqh)"),
+    "@container (block-size > 10cqh)"
+  );
+  assert_eq!(
+    canonical("@container (20rem > height)"),
+    "@container (height < 20rem)"
+  );
+}
+
+#[test]
+fn respells_double_ranges_smallest_first() {
+  assert_eq!(
+    canonical("@container (400px <= width < 800px)"),
+    "@container (400px <= width < 800px)"
+  );
+  assert_eq!(
+    canonical("@container (800px > width >= 400px)"),
+    "@container (400px <= width < 800px)"
+  );
+}
+
+#[test]
+fn parses_plain_and_boolean_features() {
+  assert_eq!(canonical("@container (width)"), "@container (width)");
+  assert_eq!(
+    canonical("@container (orientation: PORTRAIT)"),
+    "@container (orientation: portrait)"
+  );
+  assert_eq!(
+    canonical("@container (width = 400px)"),
+    "@container (width: 400px)"
+  );
+  assert_eq!(canonical("@container (width: 0)"), "@container (width: 0)");
+  assert_eq!(
+    canonical("@container (aspect-ratio > 16/9)"),
+    "@container (aspect-ratio > 16 / 9)"
+  );
+  assert_eq!(
+    canonical("@container (min-aspect-ratio: 1.5)"),
+    "@container (aspect-ratio >= 1.5)"
+  );
+  assert_eq!(
+    canonical("@container (width > calc( 10em + 2px ))"),
+    "@container (width > calc(10em + 2px))"
+  );
+}
+
+#[test]
+fn parses_named_containers() {
+  assert_eq!(
+    canonical("@container sidebar (min-width: 400px)"),
+    "@container sidebar (width >= 400px)"
+  );
+  assert_eq!(canonical("@container sidebar"), "@container sidebar");
+  assert_eq!(
+    canonical("@container Card not (width < 10px)"),
+    "@container Card not (width < 10px)"
+  );
+  assert_eq!(
+    canonical("@container a (width > 1px),b (height > 1px)"),
+    "@container a (width > 1px), b (height > 1px)"
+  );
+}
+
+#[test]
+fn parses_style_queries() {
+  assert_eq!(
+    canonical("@container style(--theme:dark)"),
+    "@container style(--theme: dark)"
+  );
+  assert_eq!(
+    canonical("@container style((--theme: dark))"),
+    "@container style(--theme: dark)"
+  );
+  assert_eq!(canonical("@container style(--a)"), "@container style(--a)");
+  assert_eq!(
+    canonical("@container style((--a: 1) and (--b: 2))"),
+    "@container style((--a: 1) and (--b: 2))"
+  );
+  assert_eq!(
+    canonical("@container style(not (--a: 1))"),
+    "@container style(not (--a: 1))"
+  );
+  assert_eq!(
+    canonical("@container card (width > 1px) and style(--theme: dark)"),
+    "@container card (width > 1px) and style(--theme: dark)"
+  );
+}
+
+#[test]
+fn parses_scroll_state_queries() {
+  assert_eq!(
+    canonical("@container scroll-state( stuck : top )"),
+    "@container scroll-state(stuck: top)"
+  );
+}
+
+#[test]
+fn equivalent_spellings_share_one_canonical_form() {
+  let spellings = [
+    "@container (min-width:400px) and ((max-width: 800px))",
+    "@container (width >= 400px) and (width <= 800px)",
+    "@container (400px <= width) and (800px >= width)",
+    "@container not (not ((min-width: 400px) and (max-width: 800px)))",
+  ];
+
+  for spelling in spellings {
+    assert_eq!(
+      canonical(spelling),
+      "@container (width >= 400px) and (width <= 800px)",
+      "{}",
+      spelling
+    );
+  }
+}
+
+#[test]
+fn rejects_malformed_queries() {
+  for input in [
+    "@container",
+    "@container ()",
+    "@container (min-width:)",
+    "@container (width >= )",
+    "@container (colour > 1px)",
+    "@container (min-orientation: portrait)",
+    "@container (orientation > portrait)",
+    "@container (orientation: sideways)",
+    "@container (width > 50%)",
+    "@container (width > 10)",
+    "@container (width > 1px red)",
+    "@container (width = 1px = 2px)",
+    "@container (1px < width > 2px)",
+    "@container (width < 1px < height)",
+    "@container (min-width)",
+    "@container (width > 1px) and (height > 1px) or (width < 2px)",
+    "@container none (width > 1px)",
+    "@container and (width > 1px)",
+    "@container inherit",
+    "@container sidebar sidebar",
+    "@container style()",
+    "@container style(--a: )",
+    "@container unknown(a)",
+    "@container (width > 1px),",
+    "@media (width > 1px)",
+  ] {
+    assert_eq!(
+      validate_container_query(input).map(|query| query.to_string()),
+      Err(ContainerQueryErrors::SYNTAX_ERROR.to_string()),
+      "{}",
+      input
+    );
+  }
+}
+
+#[test]
+fn reports_unbalanced_parentheses() {
+  for input in ["@container (width > 1px", "@container width > 1px)"] {
+    assert_eq!(
+      validate_container_query(input).map(|query| query.to_string()),
+      Err(ContainerQueryErrors::UNBALANCED_PARENS.to_string()),
+      "{}",
+      input
+    );
+  }
+}
@@ -20,3 +20,23 @@ fn test_error_message_content_validation() {
     "Unbalanced parentheses in media query."
   );
 }
+
+#[test]
+fn test_supports_and_container_error_messages() {
+  assert_eq!(
+    SupportsQueryErrors::SYNTAX_ERROR,
+    "Invalid @supports query syntax."
+  );
+  assert_eq!(
+    SupportsQueryErrors::UNBALANCED_PARENS,
+    "Unbalanced parentheses in @supports query."
+  );
+  assert_eq!(
+    ContainerQueryErrors::SYNTAX_ERROR,
+    "Invalid @container query syntax."
+  );
+  assert_eq!(
+    ContainerQueryErrors::UNBALANCED_PARENS,
+    "Unbalanced parentheses in @container query."
+  );
+}
@@ -0,0 +1,176 @@
+// Tests for @supports condition parsing, validation and respelling.
+// Source: crates/stylex-css-parser/src/at_queries/supports_query.rs
+
+use super::*;
+use crate::at_queries::SupportsQueryErrors;
+
+fn canonical(input: &str) -> String {
+  match validate_supports_query(input) {
+    Ok(query) => query.to_string(),
+    Err(error) => panic!("expected {:?} to parse: {}", input, error),
+  }
+}
+
+#[test]
+fn parses_declarations() {
+  assert_eq!(
+    canonical("@supports (display: grid)"),
+    "@supports (display: grid)"
+  );
+  assert_eq!(
+    canonical("@supports (display:grid)"),
+    "@supports (display: grid)"
+  );
+  assert_eq!(
+    canonical("@supports (  DISPLAY :  grid  )"),
+    "@supports (display: grid)"
+  );
+  assert_eq!(
+    canonical("@supports(display: grid)"),
+    "@supports (display: grid)"
+  );
+  assert_eq!(
+    canonical("@supports (color: rgb(0 0 0 / 50%))"),
+    "@supports (color: rgb(0 0 0 / 50%))"
+  );
+  assert_eq!(
+    canonical("@supports (--Custom: 1)"),
+    "@supports (--Custom: 1)"
+  );
+}
+
+#[test]
+fn parses_selector_tests() {
+  assert_eq!(
+    canonical("@supports selector(:has(a))"),
+    "@supports selector(:has(a))"
+  );
+  assert_eq!(
+    canonical("@supports selector( a>b )"),
+    "@supports selector(a > b)"
+  );
+  assert_eq!(
+    canonical("@supports selector(a  +  b~c)"),
+    "@supports selector(a + b ~ c)"
+  );
+  assert_eq!(
+    canonical("@supports selector([data-state=\"open\"] .item)"),
+    "@supports selector([data-state=\"open\"] .item)"
+  );
+  assert_eq!(
+    canonical("@supports selector(li:nth-child(2n+1))"),
+    "@supports selector(li:nth-child(2n+1))"
+  );
+}
+
+#[test]
+fn parses_font_tests() {
+  assert_eq!(
+    canonical("@supports font-tech(COLOR-COLRv1)"),
+    "@supports font-tech(color-colrv1)"
+  );
+  assert_eq!(
+    canonical("@supports font-format( woff2 )"),
+    "@supports font-format(woff2)"
+  );
+}
+
+#[test]
+fn parses_at_rule_tests() {
+  assert_eq!(
+    canonical("@supports at-rule(@layer)"),
+    "@supports at-rule(@layer)"
+  );
+  assert_eq!(
+    canonical("@supports AT-RULE( @Container )"),
+    "@supports at-rule(@container)"
+  );
+}
+
+#[test]
+fn keeps_tests_it_does_not_know() {
+  assert_eq!(
+    canonical("@supports (display: grid) or (foo)"),
+    "@supports (display: grid) or (foo)"
+  );
+  assert_eq!(
+    canonical("@supports not foo( bar  baz )"),
+    "@supports not foo(bar baz)"
+  );
+  assert_eq!(canonical("@supports (display)"), "@supports (display)");
+  assert_eq!(canonical("@supports ()"), "@supports ()");
+}
+
+#[test]
+fn parses_combinations() {
+  assert_eq!(
+    canonical("@supports not (display: grid)"),
+    "@supports not (display: grid)"
+  );
+  assert_eq!(
+    canonical("@supports (display: grid) and (not (display: inline-grid))"),
+    "@supports (display: grid) and (not (display: inline-grid))"
+  );
+  assert_eq!(
+    canonical("@supports ((display: grid) or (display: flex)) and selector(:has(a))"),
+    "@supports ((display: grid) or (display: flex)) and selector(:has(a))"
+  );
+}
+
+#[test]
+fn equivalent_spellings_share_one_canonical_form() {
+  let spellings = [
+    "@supports (display:grid) and ((gap:1px) and selector(a>b))",
+    "@supports ((display: grid)) and (gap: 1px) and selector(a > b)",
+    "@supports not (not ((display: grid) and (gap: 1px) and selector( a > b )))",
+  ];
+
+  for spelling in spellings {
+    assert_eq!(
+      canonical(spelling),
+      "@supports (display: grid) and (gap: 1px) and selector(a > b)",
+      "{}",
+      spelling
+    );
+  }
+}
+
+#[test]
+fn rejects_malformed_conditions() {
+  for input in [
+    "@supports",
+    "@supports display: grid",
+    "@supports (display: grid) and",
+    "@supports (display: grid) and (gap: 1px) or (color: red)",
+    "@supports not (display: grid) and (gap: 1px)",
+    "@supports selector()",
+    "@supports selector(a, b)",
+    "@supports selector(> a)",
+    "@supports selector(a +)",
+    "@supports font-tech()",
+    "@supports font-tech(a b)",
+    "@supports at-rule(layer)",
+    "@supports at-rule(@layer @media)",
+    "@supports (display: grid) (gap: 1px)",
+    "@media (display: grid)",
+  ] {
+    assert_eq!(
+      validate_supports_query(input).map(|query| query.to_string()),
+      Err(SupportsQueryErrors::SYNTAX_ERROR.to_string()),
+      "{}",
+      input
+    );
+  }
+}
+
+#[test]
+fn reports_unbalanced_parentheses() {
+  for input in ["@supports (display: grid", "@supports display: grid)"] {
+    assert_eq!(
+      validate_supports_query(input).map(|query| query.to_string()),
+      Err(SupportsQueryErrors::UNBALANCED_PARENS.to_string()),
+      "{}",
+      input
+    );
+  }
+}
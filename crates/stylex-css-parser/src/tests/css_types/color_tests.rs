@@ -19,6 +19,25 @@ fn test_named_color_validation() {
   assert!(!NamedColor::is_valid_named_color(""));
 }
 
+#[test]
+fn test_named_color_rgba() {
+  assert_eq!(
+    NamedColor::new("white").rgba(),
+    Some(Rgba::new(255, 255, 255, 1.0))
+  );
+  assert_eq!(
+    NamedColor::new("RebeccaPurple").rgba(),
+    Some(Rgba::new(102, 51, 153, 1.0))
+  );
+  assert_eq!(
+    NamedColor::new("transparent").rgba(),
+    Some(Rgba::new(0, 0, 0, 0.0))
+  );
+
+  // No fixed value
+  assert_eq!(NamedColor::new("currentcolor").rgba(), None);
+}
+
 #[test]
 fn test_named_color_display() {
   let color = NamedColor::new("red".to_string());
@@ -105,14 +105,34 @@ fn map_css_token_close_parenthesis_produces_right_paren() {
 }
 
 #[test]
-fn map_css_token_square_bracket_block_produces_delim_bracket() {
-  // [a] => CssToken::SquareBracketBlock => SimpleToken::Delim('[')
+fn square_bracket_block_keeps_its_contents() {
+  // [a] => CssToken::SquareBracketBlock => Delim('['), contents, Delim(']')
   let list = TokenList::new("[a]");
-  assert!(!list.tokens.is_empty());
   assert_eq!(
-    list.tokens[0],
-    SimpleToken::Delim('['),
-    "[ block should produce Delim('[') token"
+    list.tokens,
+    vec![
+      SimpleToken::Delim('['),
+      SimpleToken::Ident("a".to_string()),
+      SimpleToken::Delim(']'),
+    ],
+    "[ block should keep the tokens between its brackets"
+  );
+}
+
+#[test]
+fn nested_square_bracket_block_keeps_its_contents() {
+  let list = TokenList::new("selector([data-x=\"y\"])");
+  assert_eq!(
+    list.tokens,
+    vec![
+      SimpleToken::Function("selector".to_string()),
+      SimpleToken::Delim('['),
+      SimpleToken::Ident("data-x".to_string()),
+      SimpleToken::Delim('='),
+      SimpleToken::String("y".to_string()),
+      SimpleToken::Delim(']'),
+      SimpleToken::RightParen,
+    ]
   );
 }
 
@@ -152,6 +152,18 @@ fn tokenize_nested_content(parser: &mut Parser, tokens: &mut Vec<SimpleToken>) {
         // Add closing parenthesis
         tokens.push(SimpleToken::RightParen);
       },
+      // Handle nested SquareBracketBlock the same way, so attribute selectors
+      // keep their contents
+      CssToken::SquareBracketBlock => {
+        tokens.push(SimpleToken::Delim('['));
+
+        parse_nested_or_panic(parser, |nested_parser| {
+          tokenize_nested_content(nested_parser, tokens);
+          Ok(())
+        });
+
+        tokens.push(SimpleToken::Delim(']'));
+      },
       // Handle nested Function tokens
       CssToken::Function(func_name) => {
         // Add the function name token
@@ -212,6 +224,18 @@ fn tokenize_all(input: &str) -> Vec<SimpleToken> {
         // Add closing parenthesis (cssparser consumes it automatically)
         tokens.push(SimpleToken::RightParen);
       },
+      // Expand SquareBracketBlock tokens too; without this the bracket's
+      // contents would be skipped along with the block
+      CssToken::SquareBracketBlock => {
+        tokens.push(SimpleToken::Delim('['));
+
+        parse_nested_or_panic(&mut parser, |nested_parser| {
+          tokenize_nested_content(nested_parser, &mut tokens);
+          Ok(())
+        });
+
+        tokens.push(SimpleToken::Delim(']'));
+      },
       // Handle all other tokens normally
       _ => {
         tokens.push(map_css_token(t));
@@ -1,6 +1,9 @@
 use std::borrow::Cow;
 
-use stylex_constants::constants::logical_to_ltr::{INLINE_PROPERTY_TO_LTR, PROPERTY_TO_LTR};
+use stylex_constants::constants::{
+  logical_to_ltr::{INLINE_PROPERTY_TO_LTR, PROPERTY_TO_LTR},
+  logical_to_standard::{LOGICAL_TO_STANDARD, LOGICAL_VALUE_TO_STANDARD},
+};
 use stylex_enums::style_resolution::StyleResolution;
 use stylex_structures::{
   pair::{Pair, PairCow},
@@ -12,6 +15,10 @@ pub fn generate_ltr<'a>(pair: &'a Pair, options: &StyleXStateOptions) -> PairCow
   let style_resolution = &options.style_resolution;
   let key = pair.key.as_str();
 
+  if options.enable_native_logical_styles {
+    return property_to_standard(pair);
+  }
+
   if style_resolution == &StyleResolution::LegacyExpandShorthands {
     if !enable_logical_styles_polyfill {
       return PairCow::borrowed(pair);
@@ -91,3 +98,25 @@ fn property_to_ltr(pair: &Pair) -> PairCow<'_> {
     },
   }
 }
+
+/// Spells a logical property or `float`/`clear` value the standard way, which
+/// holds for both directions. Values with no logical form, such as
+/// `background-position: start`, still get their physical LTR value.
+fn property_to_standard(pair: &Pair) -> PairCow<'_> {
+  match pair.key.as_str() {
+    "float" | "clear" => match LOGICAL_VALUE_TO_STANDARD.get(pair.value.as_str()) {
+      Some(&value) => PairCow {
+        key: Cow::Borrowed(pair.key.as_str()),
+        value: Cow::Borrowed(value),
+      },
+      None => PairCow::borrowed(pair),
+    },
+    key => match LOGICAL_TO_STANDARD.get(key) {
+      Some(&standard) => PairCow {
+        key: Cow::Borrowed(standard),
+        value: Cow::Borrowed(pair.value.as_str()),
+      },
+      None => property_to_ltr(pair),
+    },
+  }
+}
@@ -29,6 +29,14 @@ fn property_to_rtl<'a>(pair: &'a Pair, options: &StyleXStateOptions) -> Option<P
       key: Cow::Borrowed(pair.key.as_str()),
       value: Cow::Borrowed(value),
     }),
+    _ => value_to_rtl(pair, options),
+  }
+}
+
+/// The RTL override of a value no logical property or keyword can express,
+/// so it needs one even when logical styles are kept native.
+fn value_to_rtl<'a>(pair: &'a Pair, options: &StyleXStateOptions) -> Option<PairCow<'a>> {
+  match pair.key.as_str() {
     "background-position" => {
       // Bail out unless the value carries a bare `start`/`end` keyword.
       // Directional-neutral values such as `center` or physical values such as
@@ -84,6 +92,10 @@ pub fn generate_rtl<'a>(pair: &'a Pair, options: &StyleXStateOptions) -> Option<
   let style_resolution = &options.style_resolution;
   let key = pair.key.as_str();
 
+  if options.enable_native_logical_styles {
+    return value_to_rtl(pair, options);
+  }
+
   if style_resolution == &StyleResolution::LegacyExpandShorthands {
     if !enable_logical_styles_polyfill {
       return None;
@@ -24,9 +24,17 @@
 //!
 //! ## What is not here
 //!
-//! No pass understands hex colours, letter case or quote characters, so none of
-//! them can alter those. Read the absence as deliberate: it is what makes two
-//! compilers agree on a value neither of them has an opinion about.
+//! No pass that always runs understands hex colours, letter case or quote
+//! characters, so none of them can alter those. Read the absence as
+//! deliberate: it is what makes two compilers agree on a value neither of them
+//! has an opinion about.
+//!
+//! [`normalize_colors`] is the exception that proves it. It respells colors,
+//! case included, and so it runs only when `enableColorNormalization` asks for
+//! it — knowing that a class it renames is one the reference compiler does not
+//! produce. It is appended after the passes that always run, so it reads
+//! whitespace already canonicalized, and before the font-size conversion,
+//! which never sees a color.
 //!
 //! Exponent notation is not in that list. Every pass that re-spells a number
 //! goes through `to_js_string`, which spells it the way JavaScript's
@@ -37,9 +45,10 @@ use postcss_value_parser::{ValueParser, stringify};
 use stylex_structures::stylex_state_options::StyleXStateOptions;
 
 use crate::css::normalizers::{
-  convert_camel_case_values::convert_camel_cased_values, detect_unclosed_fns::detect_unclosed_fns,
-  detect_unclosed_strings::detect_unclosed_strings, font_size_px_to_rem::convert_font_size_to_rem,
-  leading_zero::normalize_leading_zero, quotes::normalize_quotes, timings::normalize_timings,
+  colors::normalize_colors, convert_camel_case_values::convert_camel_cased_values,
+  detect_unclosed_fns::detect_unclosed_fns, detect_unclosed_strings::detect_unclosed_strings,
+  font_size_px_to_rem::convert_font_size_to_rem, leading_zero::normalize_leading_zero,
+  quotes::normalize_quotes, timings::normalize_timings,
   unprefixed_custom_properties::detect_unprefixed_custom_properties,
   whitespace::normalize_whitespace, zero_dimensions::normalize_zero_dimensions,
 };
@@ -53,10 +62,10 @@ use crate::css::normalizers::{
 /// sequences of nine meaning different things is one too many, so the list
 /// below is passes and the ports stay normalizers.
 ///
-/// Four of the ten read the property — three to decide whether they apply at
-/// all, one to name the declaration in a rejection. It is passed to all ten
-/// anyway, which is what lets the fold below be a list rather than ten call
-/// sites.
+/// Five of the eleven read the property — three to decide whether they apply
+/// at all, one to decide whether a name is a color, one to name the
+/// declaration in a rejection. It is passed to all eleven anyway, which is
+/// what lets the fold below be a list rather than eleven call sites.
 type Pass = fn(&mut ValueParser, &str);
 
 /// The passes that always run, in the order they run in.
@@ -84,6 +93,10 @@ pub fn normalize_value(value: &str, key: &str, options: &StyleXStateOptions) ->
     pass(&mut ast, key);
   }
 
+  if options.enable_color_normalization {
+    normalize_colors(&mut ast, key);
+  }
+
   if options.enable_font_size_px_to_rem {
     convert_font_size_to_rem(&mut ast, key);
   }
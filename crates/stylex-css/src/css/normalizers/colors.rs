@@ -0,0 +1,352 @@
+//! Local pass, not a port. See `normalize_value.rs` for the ordered list.
+//!
+//! The reference compiler leaves every color as the author wrote it, so this
+//! pass is opt-in: with it on, a color it respells hashes to a class the
+//! reference compiler would not produce. That is the trade the option asks
+//! for — `#FFFFFF`, `#fff`, `white` and `rgb(255 255 255)` become one class.
+
+use postcss_value_parser::{Node, NodeKind, ValueParser, stringify_node};
+use stylex_css_parser::css_types::{Color, Hsl, Hsla, NamedColor, Rgba};
+use stylex_utils::{number::to_js_string, string::dashify};
+
+/// The color functions the parser reads and this pass respells. `lch()`,
+/// `oklch()`, `oklab()` and `color()` are left as written: they reach colors
+/// `#rrggbb` cannot spell, so there is no shorter form to choose.
+const COLOR_FUNCTIONS: &[&str] = &["rgb", "rgba", "hsl", "hsla"];
+
+/// Properties whose value may name a color, beyond `color` and every
+/// property ending in `-color`, spelled as in CSS.
+///
+/// A name is only read as a color where the property takes one. Elsewhere the
+/// same identifier is something else entirely — `animationName: 'tan'` names
+/// keyframes, `gridArea: 'red'` names an area — and respelling it would break
+/// the declaration. Hex and color functions cannot be anything but colors, so
+/// they are respelled under any property.
+const COLOR_PROPERTIES: &[&str] = &[
+  "background",
+  "background-image",
+  "border",
+  "border-block",
+  "border-block-end",
+  "border-block-start",
+  "border-bottom",
+  "border-inline",
+  "border-inline-end",
+  "border-inline-start",
+  "border-left",
+  "border-right",
+  "border-top",
+  "box-shadow",
+  "column-rule",
+  "fill",
+  "outline",
+  "stroke",
+  "text-decoration",
+  "text-emphasis",
+  "text-shadow",
+];
+
+/// The named colors spelled shorter than their shortest hex, sorted by that
+/// hex so a lookup can binary-search it. Where two names share a color the
+/// first in alphabetical order is kept, so `#808080` is always `gray`.
+const SHORTER_NAMES: &[((u8, u8, u8), &str)] = &[
+  ((0x00, 0x00, 0x80), "navy"),
+  ((0x00, 0x80, 0x00), "green"),
+  ((0x00, 0x80, 0x80), "teal"),
+  ((0x4b, 0x00, 0x82), "indigo"),
+  ((0x80, 0x00, 0x00), "maroon"),
+  ((0x80, 0x00, 0x80), "purple"),
+  ((0x80, 0x80, 0x00), "olive"),
+  ((0x80, 0x80, 0x80), "gray"),
+  ((0xa0, 0x52, 0x2d), "sienna"),
+  ((0xa5, 0x2a, 0x2a), "brown"),
+  ((0xc0, 0xc0, 0xc0), "silver"),
+  ((0xcd, 0x85, 0x3f), "peru"),
+  ((0xd2, 0xb4, 0x8c), "tan"),
+  ((0xda, 0x70, 0xd6), "orchid"),
+  ((0xdd, 0xa0, 0xdd), "plum"),
+  ((0xee, 0x82, 0xee), "violet"),
+  ((0xf0, 0xe6, 0x8c), "khaki"),
+  ((0xf0, 0xff, 0xff), "azure"),
+  ((0xf5, 0xde, 0xb3), "wheat"),
+  ((0xf5, 0xf5, 0xdc), "beige"),
+  ((0xfa, 0x80, 0x72), "salmon"),
+  ((0xfa, 0xf0, 0xe6), "linen"),
+  ((0xff, 0x00, 0x00), "red"),
+  ((0xff, 0x63, 0x47), "tomato"),
+  ((0xff, 0x7f, 0x50), "coral"),
+  ((0xff, 0xa5, 0x00), "orange"),
+  ((0xff, 0xc0, 0xcb), "pink"),
+  ((0xff, 0xd7, 0x00), "gold"),
+  ((0xff, 0xe4, 0xc4), "bisque"),
+  ((0xff, 0xfa, 0xfa), "snow"),
+  ((0xff, 0xff, 0xf0), "ivory"),
+];
+
+/// How far a channel computed from `hsl()` may sit from a whole number and
+/// still be spelled as one. Well under what 8-bit color can show.
+const CHANNEL_TOLERANCE: f64 = 1e-4;
+
+/// Respells every color it can read in the shortest form that means the same
+/// color.
+///
+/// An opaque color becomes the shortest of its hex and, where the property
+/// takes names, its name: `#FFFFFF` and `rgb(255 255 255)` become `#fff`,
+/// `#ff0000` becomes `red`. A translucent one becomes hex when its alpha is a
+/// whole step of 1/255 and `rgb(r g b/a)` otherwise. An `hsl()` whose channels
+/// are not whole numbers cannot be spelled as hex without rounding the color,
+/// so it keeps its hue, saturation and lightness in the one spelling every
+/// `hsl()` and `hsla()` shares.
+///
+/// Anything the parser cannot read is left as written — a four-digit hex, an
+/// `rgb()` with a fractional or percentage channel, one built from `var()`.
+/// The parser truncates a fractional channel, so this pass declines rather
+/// than respell a color it would have changed.
+pub fn normalize_colors(ast: &mut ValueParser, key: &str) {
+  let names_are_colors = names_are_colors(key);
+
+  ast.walk(
+    |node, _| match node.kind {
+      NodeKind::Function => {
+        let name = node.value.to_ascii_lowercase();
+
+        // The body of `url()` is a path, and `url(#abc)` is a fragment.
+        if name == "url" {
+          return false;
+        }
+
+        if node.unclosed || !COLOR_FUNCTIONS.contains(&name.as_str()) {
+          return true;
+        }
+
+        match respell_function(node, names_are_colors) {
+          Some(spelling) => {
+            let (start, end) = (node.source_index, node.source_end_index);
+            *node = Node::new(NodeKind::Word, spelling, start, end);
+            false
+          },
+          None => true,
+        }
+      },
+      NodeKind::Word => {
+        if let Some(spelling) = respell_word(&node.value, names_are_colors) {
+          node.value = spelling;
+        }
+        true
+      },
+      _ => true,
+    },
+    false,
+  );
+}
+
+/// Whether `key` takes a color, whichever way the author spelled it:
+/// `borderColor` and `'border-color'` name the same property.
+fn names_are_colors(key: &str) -> bool {
+  let key = dashify(key);
+
+  key == "color" || key.ends_with("-color") || COLOR_PROPERTIES.contains(&key.as_ref())
+}
+
+fn respell_word(word: &str, names_are_colors: bool) -> Option<String> {
+  let is_candidate =
+    word.starts_with('#') || (names_are_colors && NamedColor::is_valid_named_color(word));
+
+  if !is_candidate {
+    return None;
+  }
+
+  let rgba = match Color::parse().parse_to_end(word).ok()? {
+    Color::Hash(hash) => Rgba::new(hash.r(), hash.g(), hash.b(), hash.a()),
+    Color::Named(named) => named.rgba()?,
+    _ => return None,
+  };
+
+  Some(spell_rgba(&rgba, names_are_colors))
+}
+
+fn respell_function(node: &Node, names_are_colors: bool) -> Option<String> {
+  let color = Color::parse().parse_to_end(&stringify_node(node)).ok()?;
+
+  match color {
+    Color::Rgb(rgb) if has_whole_channels(node) => Some(spell_rgba(
+      &Rgba::new(rgb.r, rgb.g, rgb.b, 1.0),
+      names_are_colors,
+    )),
+    Color::Rgba(rgba) if has_whole_channels(node) => Some(spell_rgba(&rgba, names_are_colors)),
+    Color::Hsl(Hsl { h, s, l }) => Some(spell_hsla(
+      hue_degrees(h.value, &h.unit)?,
+      s.value,
+      l.value,
+      1.0,
+      names_are_colors,
+    )),
+    Color::Hsla(Hsla { h, s, l, a }) => Some(spell_hsla(
+      hue_degrees(h.value, &h.unit)?,
+      s.value,
+      l.value,
+      a,
+      names_are_colors,
+    )),
+    _ => None,
+  }
+}
+
+/// Whether the three channels of an `rgb()` or `rgba()` are written as whole
+/// numbers. The parser reads `rgb(10.6 0 0)` as `rgb(10 0 0)`, so anything
+/// else would be respelled as a different color.
+fn has_whole_channels(node: &Node) -> bool {
+  let Some(arguments) = node.nodes.as_ref() else {
+    return false;
+  };
+
+  let channels: Vec<&Node> = arguments
+    .iter()
+    .filter(|argument| argument.kind == NodeKind::Word)
+    .take(3)
+    .collect();
+
+  channels.len() == 3
+    && channels.iter().all(|channel| {
+      !channel.value.is_empty() && channel.value.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
+fn spell_rgba(rgba: &Rgba, names_are_colors: bool) -> String {
+  let Some(alpha) = alpha_byte(f64::from(rgba.a)) else {
+    return format!(
+      "rgb({} {} {}/{})",
+      rgba.r,
+      rgba.g,
+      rgba.b,
+      spell_number(shortest(rgba.a))
+    );
+  };
+
+  let hex = spell_hex(&[rgba.r, rgba.g, rgba.b, alpha]);
+
+  if alpha == u8::MAX && names_are_colors {
+    let name = SHORTER_NAMES
+      .binary_search_by(|(channels, _)| channels.cmp(&(rgba.r, rgba.g, rgba.b)))
+      .ok()
+      .map(|index| SHORTER_NAMES[index].1);
+
+    if let Some(name) = name {
+      return name.to_string();
+    }
+  }
+
+  hex
+}
+
+/// The alpha as a hex byte, when it is exactly one. An alpha hex cannot spell
+/// — `.5` is 127.5/255 — is left to `rgb()`.
+fn alpha_byte(alpha: f64) -> Option<u8> {
+  let scaled = alpha.clamp(0.0, 1.0) * 255.0;
+
+  match (scaled - scaled.round()).abs() < CHANNEL_TOLERANCE {
+    true => Some(scaled.round() as u8),
+    false => None,
+  }
+}
+
+/// `#rrggbb` — or `#rrggbbaa` when translucent — in lowercase, shortened to
+/// `#rgb` or `#rgba` when every byte repeats its digit.
+fn spell_hex(bytes: &[u8; 4]) -> String {
+  let bytes = match bytes[3] {
+    u8::MAX => &bytes[..3],
+    _ => &bytes[..],
+  };
+
+  match bytes.iter().all(|byte| byte >> 4 == byte & 0xf) {
+    true => bytes.iter().fold(String::from("#"), |hex, byte| {
+      format!("{}{:x}", hex, byte & 0xf)
+    }),
+    false => bytes.iter().fold(String::from("#"), |hex, byte| {
+      format!("{}{:02x}", hex, byte)
+    }),
+  }
+}
+
+fn spell_hsla(
+  hue: f64,
+  saturation: f32,
+  lightness: f32,
+  alpha: f32,
+  names_are_colors: bool,
+) -> String {
+  let saturation = shortest(saturation);
+  let lightness = shortest(lightness);
+
+  if let Some((r, g, b)) = hsl_to_rgb(hue, saturation / 100.0, lightness / 100.0) {
+    return spell_rgba(&Rgba::new(r, g, b, alpha), names_are_colors);
+  }
+
+  let alpha = shortest(alpha);
+  let alpha = match alpha < 1.0 {
+    true => format!("/{}", spell_number(alpha)),
+    false => String::new(),
+  };
+
+  format!(
+    "hsl({} {}% {}%{})",
+    spell_number(hue),
+    spell_number(saturation),
+    spell_number(lightness),
+    alpha
+  )
+}
+
+/// The hue in degrees, rounded well past what a color can show so that
+/// `.1turn` reads back as `36` rather than a float's approximation of it.
+fn hue_degrees(value: f32, unit: &str) -> Option<f64> {
+  let value = shortest(value);
+
+  let degrees = match unit {
+    "deg" => value,
+    "turn" => value * 360.0,
+    "grad" => value * 0.9,
+    "rad" => value.to_degrees(),
+    _ => return None,
+  };
+
+  Some((degrees * 1e6).round() / 1e6)
+}
+
+/// The sRGB channels of an `hsl()` color, when every one is a whole number.
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> Option<(u8, u8, u8)> {
+  let saturation = saturation.clamp(0.0, 1.0);
+  let lightness = lightness.clamp(0.0, 1.0);
+
+  let channel = |n: f64| {
+    let k = (n + hue / 30.0).rem_euclid(12.0);
+    let a = saturation * lightness.min(1.0 - lightness);
+    let value = (lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)) * 255.0;
+
+    match (value - value.round()).abs() < CHANNEL_TOLERANCE {
+      true => Some(value.round() as u8),
+      false => None,
+    }
+  };
+
+  Some((channel(0.0)?, channel(8.0)?, channel(4.0)?))
+}
+
+/// The `f64` an `f32` was written as: the parser reads `.1` as the nearest
+/// `f32`, and widening that directly spells it `0.10000000149011612`.
+fn shortest(value: f32) -> f64 {
+  value.to_string().parse().unwrap_or(f64::from(value))
+}
+
+/// A number spelled the way the leading-zero pass leaves every other number.
+fn spell_number(value: f64) -> String {
+  let spelled = to_js_string(value);
+
+  match spelled.strip_prefix("0.") {
+    Some(fraction) => format!(".{}", fraction),
+    None => match spelled.strip_prefix("-0.") {
+      Some(fraction) => format!("-.{}", fraction),
+      None => spelled,
+    },
+  }
+}
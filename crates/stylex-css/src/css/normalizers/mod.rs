@@ -3,6 +3,7 @@ use stylex_macros::stylex_panic;
 
 use crate::css::common::build_error_css_rule;
 
+pub mod colors;
 pub mod convert_camel_case_values;
 pub mod detect_unclosed_fns;
 pub mod detect_unclosed_strings;
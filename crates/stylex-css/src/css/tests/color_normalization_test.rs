@@ -0,0 +1,144 @@
+//! The opt-in color pass, asserted through the whole pipeline.
+//!
+//! Unlike the parity tables, these expectations are not reference verdicts:
+//! the reference compiler has no such pass, and every respelled value here is
+//! one it would leave as written. What they pin instead is the pass's own
+//! contract — equivalent colors meet on one spelling, and a color is never
+//! respelled into a different one.
+
+use crate::css::{
+  normalize_value::normalize_value,
+  tests::support::{color_normalizing_options, default_options},
+};
+
+/// One case: the authored value, the property it is declared for, and the
+/// declaration text with color normalization on.
+type Case = (&'static str, &'static str, &'static str);
+
+fn check(cases: &[Case]) {
+  let options = color_normalizing_options();
+
+  for (value, key, expected) in cases {
+    let actual = normalize_value(value, key, &options);
+
+    assert_eq!(&actual, expected, "normalizing `{key}: {value}`");
+  }
+}
+
+#[test]
+fn equivalent_spellings_of_one_color_meet() {
+  check(&[
+    ("#FFFFFF", "color", "#fff"),
+    ("#fff", "color", "#fff"),
+    ("white", "color", "#fff"),
+    ("WHITE", "color", "#fff"),
+    ("rgb(255 255 255)", "color", "#fff"),
+    ("rgb(255, 255, 255)", "color", "#fff"),
+    ("rgba(255,255,255,1)", "color", "#fff"),
+    ("#ffffffff", "color", "#fff"),
+    ("hsl(0 0% 100%)", "color", "#fff"),
+    ("hsl(0, 0%, 100%)", "color", "#fff"),
+  ]);
+}
+
+#[test]
+fn hex_is_lowercased_and_shortened_where_every_byte_repeats() {
+  check(&[
+    ("#AABBCC", "color", "#abc"),
+    ("#A1B2C3", "color", "#a1b2c3"),
+    ("#11223344", "color", "#1234"),
+    ("#11223345", "color", "#11223345"),
+  ]);
+}
+
+#[test]
+fn a_name_wins_only_where_it_is_shorter() {
+  check(&[
+    ("#ff0000", "color", "red"),
+    ("rgb(0, 0, 128)", "backgroundColor", "navy"),
+    ("#808080", "borderColor", "gray"),
+    ("grey", "color", "gray"),
+    ("black", "color", "#000"),
+    ("rebeccapurple", "color", "#639"),
+    ("transparent", "color", "#0000"),
+  ]);
+}
+
+#[test]
+fn a_dashed_key_takes_a_color_like_its_camel_case_spelling() {
+  check(&[
+    ("white", "border-color", "#fff"),
+    ("white", "outlineColor", "#fff"),
+    ("0 0 4px White", "box-shadow", "0 0 4px #fff"),
+    ("1px solid #FF0000", "border-top", "1px solid red"),
+    ("tan", "animation-name", "tan"),
+  ]);
+}
+
+#[test]
+fn names_are_only_read_where_the_property_takes_a_color() {
+  check(&[
+    ("1px solid #FF0000", "border", "1px solid red"),
+    ("0 0 4px White", "boxShadow", "0 0 4px #fff"),
+    ("tan", "animationName", "tan"),
+    ("red", "gridArea", "red"),
+    ("#FF0000", "gridArea", "#f00"),
+    ("white", "--brand", "white"),
+    ("#FFFFFF", "--brand", "#fff"),
+  ]);
+}
+
+#[test]
+fn a_translucent_color_is_hex_only_when_its_alpha_is_a_whole_step() {
+  check(&[
+    ("rgba(0, 0, 0, 0)", "color", "#0000"),
+    ("rgba(0, 0, 0, .2)", "color", "#0003"),
+    ("rgba(0,0,0,0.5)", "color", "rgb(0 0 0/.5)"),
+    ("rgb(0 0 0 / 50%)", "color", "rgb(0 0 0/.5)"),
+    ("rgba(0 0 0 / .5)", "color", "rgb(0 0 0/.5)"),
+  ]);
+}
+
+#[test]
+fn an_hsl_that_hex_cannot_spell_keeps_one_hsl_spelling() {
+  check(&[
+    ("hsl(0, 100%, 50%)", "color", "red"),
+    ("hsl(120deg 50% 25%)", "color", "hsl(120 50% 25%)"),
+    ("hsl(120, 50%, 25%)", "color", "hsl(120 50% 25%)"),
+    ("hsla(.5turn, 50%, 25%, .5)", "color", "hsl(180 50% 25%/.5)"),
+    ("hsl(180deg 50% 25% / .5)", "color", "hsl(180 50% 25%/.5)"),
+  ]);
+}
+
+#[test]
+fn colors_are_found_inside_other_functions() {
+  check(&[
+    (
+      "linear-gradient(#FFFFFF, rgb(0 0 0))",
+      "backgroundImage",
+      "linear-gradient(#fff,#000)",
+    ),
+    ("var(--x, #FFFFFF)", "color", "var(--x,#fff)"),
+  ]);
+}
+
+#[test]
+fn what_the_pass_cannot_read_exactly_is_left_as_written() {
+  check(&[
+    ("rgb(10.6 0 0)", "color", "rgb(10.6 0 0)"),
+    ("rgb(100%, 0%, 0%)", "color", "rgb(100%,0%,0%)"),
+    ("rgb(var(--r) 0 0)", "color", "rgb(var(--r) 0 0)"),
+    ("#FFFF", "color", "#FFFF"),
+    ("oklch(50% .1 20)", "color", "oklch(50% .1 20)"),
+    ("currentColor", "color", "currentColor"),
+    ("url(#ABCDEF)", "fill", "url(#ABCDEF)"),
+  ]);
+}
+
+#[test]
+fn nothing_is_respelled_unless_asked() {
+  let options = default_options();
+
+  assert_eq!(normalize_value("#FFFFFF", "color", &options), "#FFFFFF");
+  assert_eq!(normalize_value("white", "color", &options), "white");
+}
@@ -197,4 +197,47 @@ mod generate_ltr_tests {
     assert_eq!(result.key, "z-index");
     assert_eq!(result.value, "10");
   }
+
+  // ── native logical styles ─────────────────────────────────────
+
+  fn native_options() -> StyleXStateOptions {
+    legacy_logical_options().with_enable_native_logical_styles(true)
+  }
+
+  #[test]
+  fn native_non_standard_logical_property_becomes_standard() {
+    let pair = Pair::new("margin-start", "10px");
+    let result = generate_ltr(&pair, &native_options());
+    assert_eq!(result.key, "margin-inline-start");
+    assert_eq!(result.value, "10px");
+
+    let pair = Pair::new("border-top-end-radius", "4px");
+    assert_eq!(
+      generate_ltr(&pair, &native_options()).key,
+      "border-start-end-radius"
+    );
+  }
+
+  #[test]
+  fn native_standard_logical_property_is_not_polyfilled() {
+    let pair = Pair::new("inset-inline-start", "0");
+    let result = generate_ltr(&pair, &native_options());
+    assert_eq!(result.key, "inset-inline-start");
+  }
+
+  #[test]
+  fn native_float_start_becomes_inline_start() {
+    let pair = Pair::new("float", "start");
+    assert_eq!(generate_ltr(&pair, &native_options()).value, "inline-start");
+
+    let pair = Pair::new("clear", "inline-end");
+    assert_eq!(generate_ltr(&pair, &native_options()).value, "inline-end");
+  }
+
+  #[test]
+  fn native_background_position_still_becomes_physical() {
+    let pair = Pair::new("background-position", "start center");
+    let result = generate_ltr(&pair, &native_options());
+    assert_eq!(result.value, "left center");
+  }
 }
@@ -602,4 +602,45 @@ mod generate_rtl_tests {
       rtl.value
     );
   }
+
+  // ── native logical styles ─────────────────────────────────────
+
+  fn native_options() -> StyleXStateOptions {
+    legacy_logical_options().with_enable_native_logical_styles(true)
+  }
+
+  #[test]
+  fn native_logical_properties_need_no_rtl() {
+    for (key, value) in [
+      ("margin-start", "10px"),
+      ("inset-inline-start", "0"),
+      ("padding-inline-end", "4px"),
+      ("float", "start"),
+      ("clear", "inline-end"),
+    ] {
+      let pair = Pair::new(key, value);
+      assert!(generate_rtl(&pair, &native_options()).is_none(), "{key}");
+    }
+  }
+
+  #[test]
+  fn native_values_without_a_logical_form_still_flip() {
+    let mut options = native_options();
+    options.core.enable_legacy_value_flipping = true;
+
+    let pair = Pair::new("background-position", "start center");
+    assert_eq!(
+      generate_rtl(&pair, &options).map(|rtl| rtl.value.into_owned()),
+      Some("right center".to_string())
+    );
+
+    let pair = Pair::new("cursor", "e-resize");
+    assert_eq!(
+      generate_rtl(&pair, &options).map(|rtl| rtl.value.into_owned()),
+      Some("w-resize".to_string())
+    );
+
+    let pair = Pair::new("text-shadow", "2px 2px 4px #000");
+    assert!(generate_rtl(&pair, &options).is_some());
+  }
 }
@@ -1,3 +1,4 @@
+mod color_normalization_test;
 mod common_test;
 mod generate_ltr_test;
 mod generate_rtl_test;
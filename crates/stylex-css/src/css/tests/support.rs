@@ -45,6 +45,11 @@ pub(super) fn rem_enabled_options() -> StyleXStateOptions {
   StyleXStateOptions::default().with_enable_font_size_px_to_rem(true)
 }
 
+/// Defaults with color normalization switched on.
+pub(super) fn color_normalizing_options() -> StyleXStateOptions {
+  StyleXStateOptions::default().with_enable_color_normalization(true)
+}
+
 /// The text of a rejection, from the result of a `catch_unwind` around a call
 /// expected to reject.
 ///
@@ -59,7 +59,9 @@ use stylex_constants::constants::messages::{
 };
 use stylex_structures::stylex_state_options::StyleXStateOptions;
 
-use super::support::{default_options, panic_message, rem_enabled_options};
+use super::support::{
+  color_normalizing_options, default_options, panic_message, rem_enabled_options,
+};
 use crate::css::common::{MAX_VALUE_NESTING_DEPTH, normalize_css_property_value};
 
 // ---------------------------------------------------------------------------
@@ -105,46 +107,41 @@ enum Answer {
 /// One per path through the passes that differs: the plain longhand every
 /// dimension rule applies to, the duration the timing pass rewrites, the
 /// property name the camel-case pass dashifies, the custom property exempt from
-/// zero canonicalization, and the font size the opt-in pass converts. A sweep
-/// run against only one of them would leave four bodies of code unswept.
+/// zero canonicalization, the font size the opt-in pass converts, and the
+/// color the other opt-in pass respells. A sweep run against only one of them
+/// would leave five bodies of code unswept.
 struct PropertyClass {
   property: &'static str,
-  rem: bool,
+  options: fn() -> StyleXStateOptions,
 }
 
 const PROPERTY_CLASSES: &[PropertyClass] = &[
   PropertyClass {
     property: "width",
-    rem: false,
+    options: default_options,
   },
   PropertyClass {
     property: "transitionDuration",
-    rem: false,
+    options: default_options,
   },
   PropertyClass {
     property: "transitionProperty",
-    rem: false,
+    options: default_options,
   },
   PropertyClass {
     property: "--custom-property",
-    rem: false,
+    options: default_options,
   },
   PropertyClass {
     property: "fontSize",
-    rem: true,
+    options: rem_enabled_options,
+  },
+  PropertyClass {
+    property: "color",
+    options: color_normalizing_options,
   },
 ];
 
-impl PropertyClass {
-  fn options(&self) -> StyleXStateOptions {
-    if self.rem {
-      rem_enabled_options()
-    } else {
-      default_options()
-    }
-  }
-}
-
 /// Normalizes one value and classifies what came back.
 ///
 /// Fails the test — rather than returning — when the call panicked with
@@ -186,7 +183,7 @@ fn sweep<'values>(values: impl IntoIterator<Item = &'values str>) -> BTreeSet<&'
 
   for value in values {
     for class in PROPERTY_CLASSES {
-      if let Answer::Rejected(diagnostic) = answer(class.property, value, &class.options()) {
+      if let Answer::Rejected(diagnostic) = answer(class.property, value, &(class.options)()) {
         seen.insert(diagnostic);
       }
     }
@@ -2,6 +2,7 @@
 
 pub mod css;
 pub mod order;
+pub mod stylesheet;
 pub mod utils;
 pub mod values;
 
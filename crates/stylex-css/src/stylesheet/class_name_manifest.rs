@@ -0,0 +1,134 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+/// The class names a stylesheet has shipped with, by the declaration each is
+/// hashed from, kept across builds so a build that would rename a class can be
+/// caught before CSS cached under the old name stops matching the markup.
+///
+/// A declaration key is the text a class name is hashed from: the property,
+/// value and conditions of an atomic class, or the frames of a `keyframes`
+/// name. Anything else that goes into the name — the `classNamePrefix`, debug
+/// class names, a change to the hash itself — leaves the key as it was, which
+/// is what makes the rename visible. Names hashed from a file path, such as
+/// `defineVars` variables, are not recorded.
+///
+/// Entries are only ever added or renamed, never dropped, so a declaration
+/// that comes back after a deploy without it is still held to its old name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassNameManifest {
+  /// Sorted by key, so the file diffs well under version control.
+  pub class_names: BTreeMap<String, String>,
+}
+
+/// What recording a build's class names found.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassNameChanges {
+  /// Declarations the manifest had under another class name.
+  pub renamed: Vec<RenamedClassName>,
+  /// Class names two different declarations hash to. A stylesheet holding
+  /// both applies whichever rule comes last to every element with the class.
+  pub collisions: Vec<ClassNameCollision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedClassName {
+  pub key: String,
+  pub from: String,
+  pub to: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassNameCollision {
+  pub class_name: String,
+  pub keys: Vec<String>,
+}
+
+impl fmt::Display for RenamedClassName {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "`{}` would be renamed `{}` (declaration `{}`)",
+      self.from, self.to, self.key
+    )
+  }
+}
+
+impl fmt::Display for ClassNameCollision {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "`{}` is the class name of {} declarations: `{}`",
+      self.class_name,
+      self.keys.len(),
+      self.keys.join("`, `")
+    )
+  }
+}
+
+impl ClassNameManifest {
+  /// Records the class names of a build, as declaration key and class name
+  /// pairs from every file, and reports what changed against the manifest as
+  /// it was. A renamed declaration takes its new name in the manifest; under a
+  /// frozen manifest the host refuses the build instead of saving it.
+  pub fn update<'a>(
+    &mut self,
+    class_names: impl IntoIterator<Item = (&'a str, &'a str)>,
+  ) -> ClassNameChanges {
+    let mut renamed: Vec<RenamedClassName> = vec![];
+
+    for (key, class_name) in class_names {
+      match self.class_names.get(key) {
+        Some(known) if known == class_name => {},
+        Some(known) => {
+          if !renamed.iter().any(|rename| rename.key == key) {
+            renamed.push(RenamedClassName {
+              key: key.to_string(),
+              from: known.clone(),
+              to: class_name.to_string(),
+            });
+          }
+          self
+            .class_names
+            .insert(key.to_string(), class_name.to_string());
+        },
+        None => {
+          self
+            .class_names
+            .insert(key.to_string(), class_name.to_string());
+        },
+      }
+    }
+
+    renamed.sort_by(|a, b| a.key.cmp(&b.key));
+
+    ClassNameChanges {
+      renamed,
+      collisions: self.collisions(),
+    }
+  }
+
+  fn collisions(&self) -> Vec<ClassNameCollision> {
+    let mut keys_by_class_name: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+
+    for (key, class_name) in &self.class_names {
+      keys_by_class_name
+        .entry(class_name.as_str())
+        .or_default()
+        .push(key.as_str());
+    }
+
+    keys_by_class_name
+      .into_iter()
+      .filter(|(_, keys)| keys.len() > 1)
+      .map(|(class_name, keys)| ClassNameCollision {
+        class_name: class_name.to_string(),
+        keys: keys.into_iter().map(str::to_string).collect(),
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,57 @@
+use std::cmp::Ordering;
+
+/// Punctuation and symbols in the order the CLDR root collation gives them.
+/// Everything here sorts before every digit and letter.
+const PUNCTUATION_ORDER: &str = "_-,;:!?.'\"()[]{}@*/\\&#%`^+<=>|~$";
+
+/// Sort order of the character classes the root collation distinguishes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CharClass {
+  Whitespace,
+  Punctuation,
+  Digit,
+  Letter,
+  Other,
+}
+
+/// Primary weight of `c`: its class, then its place within it. Case is not
+/// part of the primary weight, so `A` and `a` tie here.
+fn primary_weight(c: char) -> (CharClass, u32) {
+  if c.is_whitespace() {
+    return (CharClass::Whitespace, c as u32);
+  }
+
+  if let Some(position) = PUNCTUATION_ORDER.find(c) {
+    return (CharClass::Punctuation, position as u32);
+  }
+
+  if c.is_ascii_digit() {
+    return (CharClass::Digit, c as u32);
+  }
+
+  if c.is_ascii_alphabetic() {
+    return (CharClass::Letter, c.to_ascii_lowercase() as u32);
+  }
+
+  let lowercase = c.to_lowercase().next().unwrap_or(c);
+
+  (CharClass::Other, lowercase as u32)
+}
+
+/// Compares two strings the way `String.prototype.localeCompare` does in the
+/// root locale, which is what the reference compiler sorts rules with.
+///
+/// This is the subset of the root collation that CSS text exercises:
+/// punctuation before digits before letters, letters compared without case
+/// first, and lowercase before uppercase only when nothing else differs.
+/// Strings the collation considers equal are left `Equal`, as they are in JS.
+pub(crate) fn locale_compare(a: &str, b: &str) -> Ordering {
+  a.chars()
+    .map(primary_weight)
+    .cmp(b.chars().map(primary_weight))
+    .then_with(|| {
+      a.chars()
+        .map(|c| c.is_uppercase())
+        .cmp(b.chars().map(|c| c.is_uppercase()))
+    })
+}
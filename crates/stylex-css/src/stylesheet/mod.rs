@@ -0,0 +1,522 @@
+use std::borrow::Cow;
+
+use log::warn;
+use rustc_hash::{FxHashMap, FxHashSet};
+use stylex_constants::constants::{
+  common::{LOGICAL_FLOAT_END_VAR, LOGICAL_FLOAT_START_VAR},
+  priorities::PSEUDO_ELEMENT_PRIORITY,
+};
+use stylex_types::structures::meta_data::MetaData;
+
+use crate::stylesheet::collation::locale_compare;
+
+pub mod class_name_manifest;
+pub(crate) mod collation;
+pub mod report;
+
+/// Rules whose priorities share a thousand form one bucket, and one `@layer`
+/// when layers are on.
+const PRIORITY_BUCKET_SIZE: f64 = 1000.0;
+
+/// Raises a rule one bucket above the previous one when layers are off.
+const SPECIFICITY_LEVEL: &str = ":not(#\\#)";
+
+/// What the buckets below the pseudo element priority hold, in order. A
+/// pseudo element adds its priority to the property's, so each bucket above
+/// it holds the same kind of property again, under a pseudo element.
+const BUCKET_LAYER_NAMES: [&str; 5] = [
+  "base",
+  "shorthands-of-shorthands",
+  "shorthands-of-longhands",
+  "longhands",
+  "physical-longhands",
+];
+
+/// At-rules that wrap a rule's selector rather than replace it.
+const CONDITIONAL_AT_RULES: [&str; 3] = ["@media", "@supports", "@container"];
+
+const LOGICAL_FLOAT_VARS: &str = ":root, [dir=\"ltr\"] {\n  --stylex-logical-start: left;\n  --stylex-logical-end: right;\n}\n[dir=\"rtl\"] {\n  --stylex-logical-start: right;\n  --stylex-logical-end: left;\n}\n";
+
+/// Where the priority layers sit in the cascade when `useLayers` is on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayersOptions {
+  /// Layers declared ahead of the priority layers.
+  pub before: Vec<String>,
+  /// Layers declared after the priority layers.
+  pub after: Vec<String>,
+  /// Namespace for the priority layers: `prefix.priority1`, and so on.
+  pub prefix: Option<String>,
+  pub naming: LayerNaming,
+}
+
+/// How the priority layers are named.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LayerNaming {
+  /// `priority1`, `priority2`, … numbered over the buckets the stylesheet
+  /// has, as the reference compiler names them. The same name can hold a
+  /// different bucket in a build lacking an earlier one, so a rule's layer is
+  /// only known once every rule is.
+  #[default]
+  Sequential,
+  /// Named for what the bucket holds — `longhands`, or
+  /// `pseudo-elements.longhands` for the same properties under a pseudo
+  /// element — so a rule's layer follows from its priority alone and is the
+  /// same in every build. See `rule_layer`.
+  Bucket,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessStylexRulesOptions {
+  /// Wraps each priority bucket in its own `@layer`. `None` keeps rules
+  /// unlayered and raises their specificity instead.
+  pub use_layers: Option<LayersOptions>,
+  pub enable_ltr_rtl_comments: bool,
+  pub legacy_disable_layers: bool,
+  /// Orders rules of equal priority by class name rather than declaration.
+  pub use_legacy_classnames_sort: bool,
+  /// Without layers, wraps every selector in `:where()` instead of raising
+  /// each bucket with `:not(#\#)`. Every rule then has the same specificity
+  /// and the order of the stylesheet, by priority, decides between them.
+  pub flatten_specificity: bool,
+  /// Scopes the LTR rule and RTL override of a rule with `:dir(ltr)` and
+  /// `:dir(rtl)` on the element itself, rather than the `dir` attribute of
+  /// `html`, so each follows the direction of the nearest `dir` attribute.
+  pub use_dir_pseudo_class: bool,
+}
+
+/// The layer a rule of `priority` is placed in under `LayerNaming::Bucket`,
+/// prefix included, for hosts that place rules in layers themselves.
+///
+/// `None` for a rule left outside every layer — one of priority 0, such as
+/// `@property` or `@keyframes` — and for every rule under
+/// `LayerNaming::Sequential`, where a name depends on the rest of the build.
+pub fn rule_layer(priority: f64, layers: &LayersOptions) -> Option<String> {
+  if layers.naming != LayerNaming::Bucket || priority <= 0.0 {
+    return None;
+  }
+
+  let bucket = (priority / PRIORITY_BUCKET_SIZE).floor() as usize;
+
+  Some(format!(
+    "{}{}",
+    layer_prefix(layers),
+    bucket_layer_name(bucket)
+  ))
+}
+
+fn bucket_layer_name(bucket: usize) -> String {
+  let pseudo_element_bucket = (PSEUDO_ELEMENT_PRIORITY / PRIORITY_BUCKET_SIZE) as usize;
+
+  match BUCKET_LAYER_NAMES.get(bucket) {
+    Some(name) => name.to_string(),
+    None => format!(
+      "pseudo-elements.{}",
+      bucket_layer_name(bucket.saturating_sub(pseudo_element_bucket))
+    ),
+  }
+}
+
+fn layer_prefix(layers: &LayersOptions) -> String {
+  match &layers.prefix {
+    Some(prefix) if !prefix.is_empty() => format!("{}.", prefix),
+    _ => String::new(),
+  }
+}
+
+/// Builds the stylesheet for every rule collected across a build.
+///
+/// Mirrors `processStylexRules` from the reference compiler byte for byte:
+/// `defineConsts` rules are resolved into the rules that reference them and
+/// dropped, the rest are sorted by priority and declaration, bucketed by
+/// priority, deduplicated by class name and emitted with their RTL
+/// overrides.
+pub fn process_stylex_rules(rules: &[MetaData], options: &ProcessStylexRulesOptions) -> String {
+  if rules.is_empty() {
+    return String::new();
+  }
+
+  let constants = resolve_constants(rules);
+
+  let mut sorted_rules: Vec<&MetaData> = rules
+    .iter()
+    .filter(|rule| rule.get_const_key().is_none())
+    .collect();
+
+  sorted_rules.sort_by(|a, b| {
+    let by_priority = a
+      .get_priority()
+      .partial_cmp(b.get_priority())
+      .unwrap_or(std::cmp::Ordering::Equal);
+
+    let by_text = if options.use_legacy_classnames_sort {
+      locale_compare(a.get_class_name(), b.get_class_name())
+    } else {
+      locale_compare(
+        declaration_block(a.get_css()),
+        declaration_block(b.get_css()),
+      )
+    };
+
+    // The collation calls some distinct rules equal; settling those on the
+    // raw text keeps the output independent of the order rules arrived in.
+    by_priority
+      .then(by_text)
+      .then_with(|| a.get_css().cmp(b.get_css()))
+      .then_with(|| a.get_class_name().cmp(b.get_class_name()))
+  });
+
+  let groups = match &options.use_layers {
+    Some(layers) if layers.naming == LayerNaming::Bucket => group_rules(&sorted_rules, |rule| {
+      rule_layer(*rule.get_priority(), layers)
+    }),
+    _ => group_rules(&sorted_rules, |rule| {
+      (rule.get_priority() / PRIORITY_BUCKET_SIZE).floor()
+    }),
+  };
+
+  // The layer each group is wrapped in, if any.
+  let group_layers: Vec<Option<String>> = match &options.use_layers {
+    Some(layers) if layers.naming == LayerNaming::Bucket => groups
+      .iter()
+      .map(|group| rule_layer(*group[0].get_priority(), layers))
+      .collect(),
+    Some(layers) => groups
+      .iter()
+      .enumerate()
+      .map(|(index, group)| {
+        (*group[0].get_priority() > 0.0)
+          .then(|| format!("{}priority{}", layer_prefix(layers), index + 1))
+      })
+      .collect(),
+    None => vec![None; groups.len()],
+  };
+
+  let header = match &options.use_layers {
+    Some(layers) => {
+      let priority_layers: Vec<Cow<str>> = match layers.naming {
+        LayerNaming::Sequential => (1..=groups.len())
+          .map(|level| Cow::Owned(format!("{}priority{level}", layer_prefix(layers))))
+          .collect(),
+        LayerNaming::Bucket => group_layers
+          .iter()
+          .flatten()
+          .map(|name| Cow::Borrowed(name.as_str()))
+          .collect(),
+      };
+
+      let layer_names: Vec<Cow<str>> = layers
+        .before
+        .iter()
+        .map(|name| Cow::Borrowed(name.as_str()))
+        .chain(priority_layers)
+        .chain(layers.after.iter().map(|name| Cow::Borrowed(name.as_str())))
+        .collect();
+
+      format!("\n@layer {};\n", layer_names.join(", "))
+    },
+    None => String::new(),
+  };
+
+  let uses_logical_floats = rules.iter().any(|rule| {
+    std::iter::once(rule.get_css())
+      .chain(rule.get_css_rtl())
+      .any(|css| css.contains(LOGICAL_FLOAT_START_VAR) || css.contains(LOGICAL_FLOAT_END_VAR))
+  });
+
+  let flatten = options.use_layers.is_none() && options.flatten_specificity;
+  let add_specificity = options.use_layers.is_none() && !options.legacy_disable_layers && !flatten;
+  let finish = |css: String| {
+    if flatten {
+      flatten_specificity(&css)
+    } else {
+      css
+    }
+  };
+
+  let collected_css: Vec<String> = groups
+    .iter()
+    .enumerate()
+    .map(|(index, group)| {
+      let mut lines: Vec<String> = Vec::with_capacity(group.len());
+
+      for rule in dedupe_by_class_name(group) {
+        let mut ltr = double_theme_selector(&apply_constants(rule.get_css(), &constants));
+        let mut rtl = rule
+          .get_css_rtl()
+          .map(|rtl| double_theme_selector(&apply_constants(rtl, &constants)));
+
+        if add_specificity {
+          ltr = add_specificity_level(&ltr, index);
+          rtl = rtl.map(|rtl| add_specificity_level(&rtl, index));
+        }
+
+        match rtl {
+          Some(rtl) if options.enable_ltr_rtl_comments => {
+            lines.push(format!("/* @ltr begin */{}/* @ltr end */", finish(ltr)));
+            lines.push(format!("/* @rtl begin */{}/* @rtl end */", finish(rtl)));
+          },
+          Some(rtl) if options.use_dir_pseudo_class && !ltr.starts_with("@keyframes") => {
+            lines.push(finish(add_pseudo_class(&ltr, ":dir(ltr)")));
+            lines.push(finish(add_pseudo_class(&rtl, ":dir(rtl)")));
+          },
+          Some(rtl) => {
+            lines.push(finish(add_ancestor_selector(&ltr, "html:not([dir='rtl'])")));
+            lines.push(finish(add_ancestor_selector(&rtl, "html[dir='rtl']")));
+          },
+          None => lines.push(finish(ltr)),
+        }
+      }
+
+      let css = lines.join("\n");
+
+      match &group_layers[index] {
+        Some(layer) => format!("@layer {layer}{{\n{css}\n}}"),
+        None => css,
+      }
+    })
+    .collect();
+
+  let mut stylesheet = header;
+
+  if uses_logical_floats {
+    stylesheet.push_str(LOGICAL_FLOAT_VARS);
+  }
+
+  stylesheet.push_str(&collected_css.join("\n"));
+
+  stylesheet
+}
+
+/// The part of a rule the default sort compares: its innermost block, so
+/// rules setting the same property end up next to each other whatever
+/// selectors and at-rules wrap them.
+fn declaration_block(css: &str) -> &str {
+  css.rfind('{').map_or(css, |index| &css[index..])
+}
+
+/// Splits already sorted rules into runs sharing a priority bucket, or
+/// whatever else `key` gives them.
+fn group_rules<'a, K: PartialEq>(
+  sorted_rules: &[&'a MetaData],
+  key: impl Fn(&MetaData) -> K,
+) -> Vec<Vec<&'a MetaData>> {
+  let mut groups: Vec<Vec<&MetaData>> = vec![];
+  let mut last_key = None;
+
+  for rule in sorted_rules {
+    let rule_key = key(rule);
+
+    match groups.last_mut() {
+      Some(group) if last_key.as_ref() == Some(&rule_key) => group.push(rule),
+      _ => {
+        last_key = Some(rule_key);
+        groups.push(vec![rule]);
+      },
+    }
+  }
+
+  groups
+}
+
+/// One rule per class name, at the position the name was first seen but with
+/// the text it was last given — the same rule a JS `Map` keyed by class name
+/// would yield.
+fn dedupe_by_class_name<'a>(group: &[&'a MetaData]) -> Vec<&'a MetaData> {
+  let mut positions: FxHashMap<&str, usize> = FxHashMap::default();
+  let mut deduped: Vec<&MetaData> = Vec::with_capacity(group.len());
+
+  for rule in group {
+    match positions.get(rule.get_class_name()) {
+      Some(&position) => deduped[position] = rule,
+      None => {
+        positions.insert(rule.get_class_name(), deduped.len());
+        deduped.push(rule);
+      },
+    }
+  }
+
+  deduped
+}
+
+/// `var(--key)` placeholders of every `defineConsts` rule, each paired with
+/// its fully resolved value.
+fn resolve_constants(rules: &[MetaData]) -> Vec<(String, String)> {
+  let mut placeholders: Vec<String> = vec![];
+  let mut values: FxHashMap<String, &str> = FxHashMap::default();
+
+  for rule in rules {
+    let Some(value) = rule.get_const_value() else {
+      continue;
+    };
+
+    let placeholder = format!("var(--{})", rule.get_class_name());
+
+    // A constant defined twice keeps its first position and last value.
+    if values.insert(placeholder.clone(), value).is_none() {
+      placeholders.push(placeholder);
+    }
+  }
+
+  placeholders
+    .into_iter()
+    .map(|placeholder| {
+      let value = resolve_constant(&placeholder, &values, &mut FxHashSet::default());
+
+      (placeholder, value)
+    })
+    .collect()
+}
+
+fn resolve_constant<'a>(
+  placeholder: &'a str,
+  values: &'a FxHashMap<String, &'a str>,
+  visiting: &mut FxHashSet<&'a str>,
+) -> String {
+  let Some(value) = values.get(placeholder).copied() else {
+    return placeholder.to_string();
+  };
+
+  if !visiting.insert(placeholder) {
+    warn!("Circular constant reference through {}", placeholder);
+    return placeholder.to_string();
+  }
+
+  let mut resolved = String::with_capacity(value.len());
+  let mut rest = value;
+
+  while let Some((start, end)) = find_var_reference(rest) {
+    resolved.push_str(&rest[..start]);
+
+    let reference = &rest[start..end];
+    match values.get_key_value(reference) {
+      Some((key, _)) => resolved.push_str(&resolve_constant(key, values, visiting)),
+      None => resolved.push_str(reference),
+    }
+
+    rest = &rest[end..];
+  }
+
+  resolved.push_str(rest);
+  visiting.remove(placeholder);
+
+  resolved
+}
+
+/// Byte range of the first `var(--name)` in `css` whose name is a plain
+/// identifier, the only shape a constant placeholder takes.
+fn find_var_reference(css: &str) -> Option<(usize, usize)> {
+  let mut offset = 0;
+
+  while let Some(found) = css[offset..].find("var(--") {
+    let start = offset + found;
+    let name_start = start + "var(--".len();
+    let name_len = css[name_start..]
+      .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+      .unwrap_or(css.len() - name_start);
+
+    if name_len > 0 && css[name_start + name_len..].starts_with(')') {
+      return Some((start, name_start + name_len + 1));
+    }
+
+    offset = name_start;
+  }
+
+  None
+}
+
+fn apply_constants(css: &str, constants: &[(String, String)]) -> String {
+  let mut css = css.to_string();
+
+  for (placeholder, value) in constants {
+    if css.contains(placeholder.as_str()) {
+      css = css.replace(placeholder.as_str(), value);
+    }
+  }
+
+  css
+}
+
+/// Repeats the class of a `createTheme` override, `.x, .x:root{`, so the
+/// override beats the `defineVars` defaults whatever order the stylesheets
+/// load in. Any other rule is returned as it is.
+fn double_theme_selector(css: &str) -> String {
+  let Some(root_at) = css.find(":root{") else {
+    return css.to_string();
+  };
+
+  let selectors = &css[..root_at];
+  let selector_start = selectors.rfind('{').map_or(0, |index| index + 1);
+
+  match selectors[selector_start..].split_once(", ") {
+    Some((class, repeated)) if class.starts_with('.') && class == repeated => format!(
+      "{}{class}{class}, {class}{class}{}",
+      &css[..selector_start],
+      &css[root_at..]
+    ),
+    _ => css.to_string(),
+  }
+}
+
+/// Raises `css` by `level` id selectors that match nothing, inserted before
+/// its pseudo element or declaration block. Keyframes have no selector to
+/// raise.
+fn add_specificity_level(css: &str, level: usize) -> String {
+  if css.starts_with("@keyframes") {
+    return css.to_string();
+  }
+
+  add_pseudo_class(css, &SPECIFICITY_LEVEL.repeat(level))
+}
+
+/// Adds `pseudo_class` to the selector of `css`, ahead of its pseudo element
+/// if it has one.
+fn add_pseudo_class(css: &str, pseudo_class: &str) -> String {
+  let insert_at = css.find("::").or_else(|| css.rfind('{')).unwrap_or(0);
+
+  format!("{}{pseudo_class}{}", &css[..insert_at], &css[insert_at..])
+}
+
+/// Wraps the selector of `css` in `:where()`, inside its conditional at-rules.
+/// A pseudo element stays outside, since `:where()` cannot hold one, and a
+/// rule with no selector, such as `@keyframes`, is left as it is.
+fn flatten_specificity(css: &str) -> String {
+  if css.starts_with('@') {
+    let conditional = CONDITIONAL_AT_RULES
+      .iter()
+      .any(|at_rule| css.starts_with(at_rule));
+
+    return match css.find('{').filter(|_| conditional) {
+      Some(split_at) => format!(
+        "{}{}",
+        &css[..=split_at],
+        flatten_specificity(&css[split_at + 1..])
+      ),
+      None => css.to_string(),
+    };
+  }
+
+  let Some(block_start) = css.find('{') else {
+    return css.to_string();
+  };
+
+  let selector = &css[..block_start];
+  let (selector, pseudo_element) = selector
+    .find("::")
+    .map_or((selector, ""), |index| selector.split_at(index));
+
+  format!(":where({selector}){pseudo_element}{}", &css[block_start..])
+}
+
+/// Scopes `css` under `ancestor`, inside its at-rule when it has one.
+fn add_ancestor_selector(css: &str, ancestor: &str) -> String {
+  if !css.starts_with('@') {
+    return format!("{ancestor} {css}");
+  }
+
+  let split_at = css.find('{').map_or(0, |index| index + 1);
+
+  format!("{}{ancestor} {}", &css[..split_at], &css[split_at..])
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,426 @@
+use std::cmp::Reverse;
+
+use indexmap::IndexMap;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use stylex_types::structures::meta_data::MetaData;
+
+/// At-rules that only wrap the rule they guard, so the declarations inside
+/// them are what the rule sets.
+const CONDITIONAL_AT_RULES: [&str; 6] = [
+  "@media",
+  "@supports",
+  "@container",
+  "@layer",
+  "@scope",
+  "@starting-style",
+];
+
+/// What the rules collected across a build add up to, for tracking the size of
+/// the stylesheet over time.
+///
+/// Sizes are of the rules as collected: each distinct class counted once, its
+/// RTL override included, before `process_stylex_rules` adds layers,
+/// specificity and direction selectors. Every list is sorted heaviest first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StylesheetReport {
+  /// Distinct classes in the stylesheet.
+  pub rules: usize,
+  pub bytes: usize,
+  pub rtl: RtlReport,
+  pub properties: Vec<PropertyReport>,
+  pub values: Vec<ValueReport>,
+  pub duplicates: Vec<DuplicateReport>,
+  pub files: Vec<FileReport>,
+}
+
+/// The rules that carry an RTL override, and their share of the stylesheet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RtlReport {
+  pub rules: usize,
+  /// Bytes of the overrides alone.
+  pub bytes: usize,
+  /// `rules` over every rule, between 0 and 1.
+  pub rule_share: f64,
+  /// `bytes` over every byte, between 0 and 1.
+  pub byte_share: f64,
+}
+
+/// One property, or one at-rule such as `@keyframes` that has no property.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyReport {
+  pub property: String,
+  /// Distinct classes setting it: one per value and condition.
+  pub rules: usize,
+  /// Distinct values it is set to.
+  pub values: usize,
+  /// Times a file brought in one of its classes.
+  pub uses: usize,
+  /// Bytes of those classes. One setting several properties, like a
+  /// `defineVars` rule, counts in full toward each.
+  pub bytes: usize,
+}
+
+/// One value of one property, under every condition it is set under.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueReport {
+  pub property: String,
+  pub value: String,
+  pub rules: usize,
+  pub uses: usize,
+  pub bytes: usize,
+}
+
+/// Classes whose rules are the same but for the class name, so each one after
+/// the first repeats CSS the stylesheet already has. They come from files
+/// compiled with different hashing options, such as another
+/// `classNamePrefix`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateReport {
+  /// The rule of the first class, with its class name written `&`.
+  pub css: String,
+  pub class_names: Vec<String>,
+  pub files: Vec<String>,
+  /// Bytes of every class but the first.
+  pub wasted_bytes: usize,
+}
+
+/// What one file brings into the stylesheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileReport {
+  pub file: String,
+  pub rules: usize,
+  pub bytes: usize,
+  /// The rules no other file brings in, which removing this file would drop.
+  pub own_rules: usize,
+  pub own_bytes: usize,
+}
+
+/// A distinct class and the files that brought it in.
+struct CollectedRule<'a> {
+  rule: &'a MetaData,
+  files: Vec<&'a str>,
+}
+
+impl CollectedRule<'_> {
+  fn bytes(&self) -> usize {
+    self.rule.get_css().len() + self.rtl_bytes()
+  }
+
+  fn rtl_bytes(&self) -> usize {
+    self.rule.get_css_rtl().map_or(0, str::len)
+  }
+}
+
+/// Reports on the rules of each file of a build, as the file path and the
+/// rules its transform collected.
+///
+/// A class is counted once however many files bring it in, with the text it
+/// was last given, as `process_stylex_rules` keeps it. `defineConsts` rules
+/// are left out, since they are inlined rather than emitted.
+pub fn stylesheet_report<'a>(
+  files: impl IntoIterator<Item = (&'a str, &'a [MetaData])>,
+) -> StylesheetReport {
+  let mut collected: IndexMap<&str, CollectedRule> = IndexMap::new();
+
+  for (file, rules) in files {
+    for rule in rules.iter().filter(|rule| rule.get_const_key().is_none()) {
+      let entry = collected
+        .entry(rule.get_class_name())
+        .or_insert_with(|| CollectedRule {
+          rule,
+          files: vec![],
+        });
+
+      entry.rule = rule;
+      if !entry.files.contains(&file) {
+        entry.files.push(file);
+      }
+    }
+  }
+
+  let rules: Vec<CollectedRule> = collected.into_values().collect();
+
+  let bytes = rules.iter().map(CollectedRule::bytes).sum();
+  let rtl_rules = rules
+    .iter()
+    .filter(|rule| rule.rule.get_css_rtl().is_some())
+    .count();
+  let rtl_bytes = rules.iter().map(CollectedRule::rtl_bytes).sum();
+
+  StylesheetReport {
+    rules: rules.len(),
+    bytes,
+    rtl: RtlReport {
+      rules: rtl_rules,
+      bytes: rtl_bytes,
+      rule_share: share(rtl_rules, rules.len()),
+      byte_share: share(rtl_bytes, bytes),
+    },
+    properties: property_reports(&rules),
+    values: value_reports(&rules),
+    duplicates: duplicate_reports(&rules),
+    files: file_reports(&rules),
+  }
+}
+
+fn share(part: usize, whole: usize) -> f64 {
+  if whole == 0 {
+    0.0
+  } else {
+    part as f64 / whole as f64
+  }
+}
+
+fn property_reports(rules: &[CollectedRule]) -> Vec<PropertyReport> {
+  let mut properties: IndexMap<&str, (PropertyReport, Vec<&str>)> = IndexMap::new();
+
+  for rule in rules {
+    for (property, value) in declarations(rule.rule.get_css()) {
+      let (report, values) = properties.entry(property).or_insert_with(|| {
+        (
+          PropertyReport {
+            property: property.to_string(),
+            rules: 0,
+            values: 0,
+            uses: 0,
+            bytes: 0,
+          },
+          vec![],
+        )
+      });
+
+      // A rule setting several properties, like a `defineVars` rule, counts
+      // toward each of them once.
+      report.rules += 1;
+      report.uses += rule.files.len();
+      report.bytes += rule.bytes();
+
+      if let Some(value) = value
+        && !values.contains(&value)
+      {
+        values.push(value);
+      }
+    }
+  }
+
+  let mut reports: Vec<PropertyReport> = properties
+    .into_values()
+    .map(|(mut report, values)| {
+      report.values = values.len();
+      report
+    })
+    .collect();
+
+  reports.sort_by(|a, b| {
+    b.bytes
+      .cmp(&a.bytes)
+      .then_with(|| a.property.cmp(&b.property))
+  });
+
+  reports
+}
+
+fn value_reports(rules: &[CollectedRule]) -> Vec<ValueReport> {
+  let mut values: IndexMap<(&str, &str), ValueReport> = IndexMap::new();
+
+  for rule in rules {
+    for (property, value) in declarations(rule.rule.get_css()) {
+      let Some(value) = value else {
+        continue;
+      };
+
+      let report = values
+        .entry((property, value))
+        .or_insert_with(|| ValueReport {
+          property: property.to_string(),
+          value: value.to_string(),
+          rules: 0,
+          uses: 0,
+          bytes: 0,
+        });
+
+      report.rules += 1;
+      report.uses += rule.files.len();
+      report.bytes += rule.bytes();
+    }
+  }
+
+  let mut reports: Vec<ValueReport> = values.into_values().collect();
+
+  reports.sort_by(|a, b| {
+    b.bytes
+      .cmp(&a.bytes)
+      .then_with(|| a.property.cmp(&b.property))
+      .then_with(|| a.value.cmp(&b.value))
+  });
+
+  reports
+}
+
+fn duplicate_reports(rules: &[CollectedRule]) -> Vec<DuplicateReport> {
+  let mut groups: IndexMap<(String, Option<String>), Vec<&CollectedRule>> = IndexMap::new();
+
+  for rule in rules {
+    let selector = format!(".{}", rule.rule.get_class_name());
+    let anonymous = |css: &str| css.replace(&selector, ".&");
+
+    groups
+      .entry((
+        anonymous(rule.rule.get_css()),
+        rule.rule.get_css_rtl().map(anonymous),
+      ))
+      .or_default()
+      .push(rule);
+  }
+
+  let mut reports: Vec<DuplicateReport> = groups
+    .into_iter()
+    .filter(|(_, group)| group.len() > 1)
+    .map(|((css, _), group)| {
+      let mut files: Vec<String> = vec![];
+      for file in group.iter().flat_map(|rule| &rule.files) {
+        if !files.iter().any(|known| known == file) {
+          files.push(file.to_string());
+        }
+      }
+      files.sort();
+
+      DuplicateReport {
+        css,
+        class_names: group
+          .iter()
+          .map(|rule| rule.rule.get_class_name().to_string())
+          .collect(),
+        files,
+        wasted_bytes: group.iter().skip(1).map(|rule| rule.bytes()).sum(),
+      }
+    })
+    .collect();
+
+  reports.sort_by_key(|report| Reverse(report.wasted_bytes));
+
+  reports
+}
+
+fn file_reports(rules: &[CollectedRule]) -> Vec<FileReport> {
+  let mut files: FxHashMap<&str, FileReport> = FxHashMap::default();
+
+  for rule in rules {
+    let own = rule.files.len() == 1;
+
+    for file in &rule.files {
+      let report = files.entry(file).or_insert_with(|| FileReport {
+        file: file.to_string(),
+        rules: 0,
+        bytes: 0,
+        own_rules: 0,
+        own_bytes: 0,
+      });
+
+      report.rules += 1;
+      report.bytes += rule.bytes();
+
+      if own {
+        report.own_rules += 1;
+        report.own_bytes += rule.bytes();
+      }
+    }
+  }
+
+  let mut reports: Vec<FileReport> = files.into_values().collect();
+
+  reports.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.file.cmp(&b.file)));
+
+  reports
+}
+
+/// The properties a rule sets and the value of each. A rule that is an
+/// at-rule of its own, such as `@keyframes` or `@property`, counts as that
+/// at-rule with no value.
+fn declarations(css: &str) -> Vec<(&str, Option<&str>)> {
+  if css.starts_with('@') {
+    let at_rule = css
+      .split(|c: char| c.is_whitespace() || c == '{' || c == '(')
+      .next()
+      .unwrap_or(css);
+
+    if !CONDITIONAL_AT_RULES.contains(&at_rule) {
+      return vec![(at_rule, None)];
+    }
+  }
+
+  let Some(block) = declaration_block(css) else {
+    return vec![];
+  };
+
+  top_level_split(block, ';')
+    .into_iter()
+    .filter_map(|declaration| declaration.split_once(':'))
+    .map(|(property, value)| (property.trim(), Some(value.trim())))
+    .filter(|(property, _)| !property.is_empty())
+    .collect()
+}
+
+/// The inside of the innermost rule's braces, which is where the declarations
+/// are. A brace in a string is text, not a block.
+fn declaration_block(css: &str) -> Option<&str> {
+  let start = structural_chars(css)
+    .filter(|&(_, c, _)| c == '{')
+    .last()
+    .map(|(index, _, _)| index + 1)?;
+  let block = &css[start..];
+  let end = structural_chars(block)
+    .find(|&(_, c, _)| c == '}')
+    .map_or(block.len(), |(index, _, _)| index);
+
+  Some(&block[..end])
+}
+
+/// `css` cut at each `separator` outside strings and parentheses, so the `;` of
+/// a `url(data:…;base64,…)` or a `content: ";"` stays inside its value.
+fn top_level_split(css: &str, separator: char) -> Vec<&str> {
+  let mut parts = Vec::new();
+  let mut from = 0;
+
+  for (index, _, _) in structural_chars(css).filter(|&(_, c, depth)| c == separator && depth == 0) {
+    parts.push(&css[from..index]);
+    from = index + separator.len_utf8();
+  }
+
+  parts.push(&css[from..]);
+  parts
+}
+
+/// The characters of `css` outside strings and escapes, each with its byte
+/// offset and how many parentheses are open around it.
+fn structural_chars(css: &str) -> impl Iterator<Item = (usize, char, usize)> + '_ {
+  let mut quote = None;
+  let mut escaped = false;
+  let mut depth = 0usize;
+
+  css.char_indices().filter_map(move |(index, c)| {
+    if escaped {
+      escaped = false;
+      return None;
+    }
+
+    match (quote, c) {
+      (_, '\\') => escaped = true,
+      (Some(open), c) if c == open => quote = None,
+      (Some(_), _) => {},
+      (None, '"' | '\'') => quote = Some(c),
+      (None, '(') => depth += 1,
+      (None, ')') => depth = depth.saturating_sub(1),
+      (None, c) => return Some((index, c, depth)),
+    }
+
+    None
+  })
+}
@@ -0,0 +1,71 @@
+use crate::stylesheet::class_name_manifest::{
+  ClassNameChanges, ClassNameCollision, ClassNameManifest, RenamedClassName,
+};
+
+const BUILD: [(&str, &str); 2] = [
+  ("<>colorrednull", "x1e2nbdu"),
+  ("<>colorblue:hover", "x17z2mba"),
+];
+
+#[test]
+fn a_first_build_records_every_class_name() {
+  let mut manifest = ClassNameManifest::default();
+
+  assert_eq!(manifest.update(BUILD), ClassNameChanges::default());
+  assert_eq!(manifest.class_names.len(), 2);
+  assert_eq!(manifest.class_names["<>colorrednull"], "x1e2nbdu");
+}
+
+#[test]
+fn a_renamed_declaration_is_reported_and_takes_its_new_name() {
+  let mut manifest = ClassNameManifest::default();
+  manifest.update(BUILD);
+
+  let changes = manifest.update([
+    ("<>colorrednull", "y1e2nbdu"),
+    ("<>colorrednull", "y1e2nbdu"),
+    ("<>colorblue:hover", "x17z2mba"),
+  ]);
+
+  assert_eq!(
+    changes.renamed,
+    [RenamedClassName {
+      key: "<>colorrednull".to_string(),
+      from: "x1e2nbdu".to_string(),
+      to: "y1e2nbdu".to_string(),
+    }]
+  );
+  assert_eq!(
+    changes.renamed[0].to_string(),
+    "`x1e2nbdu` would be renamed `y1e2nbdu` (declaration `<>colorrednull`)"
+  );
+  assert_eq!(manifest.class_names["<>colorrednull"], "y1e2nbdu");
+}
+
+#[test]
+fn declarations_a_build_no_longer_has_are_kept() {
+  let mut manifest = ClassNameManifest::default();
+  manifest.update(BUILD);
+
+  let changes = manifest.update([("<>colorgreennull", "x1prwzq3")]);
+
+  assert_eq!(changes, ClassNameChanges::default());
+  assert_eq!(manifest.class_names.len(), 3);
+}
+
+#[test]
+fn two_declarations_with_one_class_name_collide() {
+  let mut manifest = ClassNameManifest::default();
+  manifest.update(BUILD);
+
+  let changes = manifest.update([("<>colorgreennull", "x1e2nbdu")]);
+
+  assert!(changes.renamed.is_empty());
+  assert_eq!(
+    changes.collisions,
+    [ClassNameCollision {
+      class_name: "x1e2nbdu".to_string(),
+      keys: vec!["<>colorgreennull".to_string(), "<>colorrednull".to_string()],
+    }]
+  );
+}
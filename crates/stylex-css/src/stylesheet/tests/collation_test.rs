@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+
+use crate::stylesheet::collation::locale_compare;
+
+fn sorted(mut values: Vec<&str>) -> Vec<&str> {
+  values.sort_by(|a, b| locale_compare(a, b));
+  values
+}
+
+#[test]
+fn punctuation_sorts_before_digits_and_letters() {
+  assert_eq!(
+    sorted(vec!["a", "1", "{", "-", "_"]),
+    vec!["_", "-", "{", "1", "a"]
+  );
+}
+
+#[test]
+fn dot_sorts_before_at_sign() {
+  assert_eq!(locale_compare(".xPlain1", "@container"), Ordering::Less);
+}
+
+#[test]
+fn letters_compare_without_case_first() {
+  assert_eq!(
+    sorted(vec!["{--small", "{--Blue", "{--colorTokens"]),
+    vec!["{--Blue", "{--colorTokens", "{--small"]
+  );
+}
+
+#[test]
+fn lowercase_wins_only_a_case_tie() {
+  assert_eq!(locale_compare("a", "A"), Ordering::Less);
+  assert_eq!(locale_compare("A", "b"), Ordering::Less);
+}
+
+#[test]
+fn a_prefix_sorts_first() {
+  assert_eq!(locale_compare("{inset:0}", "{inset:0}}"), Ordering::Less);
+  assert_eq!(
+    locale_compare("{display:none}", "{display:none}"),
+    Ordering::Equal
+  );
+}
+
+// Runs of equal priority in the reference `processStylexRules` output for
+// `transform-process-test.js`, in the order `localeCompare` left them.
+
+#[test]
+fn matches_the_reference_order_of_class_names() {
+  let reference = vec![
+    "x13ah0pd", "x14rh7hd", "x18abd1y", "x1kmio9f", "x1skrh0i", "xrkmrrc",
+  ];
+
+  assert_eq!(sorted(reference.iter().rev().copied().collect()), reference);
+  assert_eq!(locale_compare("x1coplze", "x4hn0rr"), Ordering::Less);
+  assert_eq!(locale_compare("xbiwvf9", "xsg933n"), Ordering::Less);
+}
+
+#[test]
+fn matches_the_reference_order_of_declaration_blocks() {
+  let reference = vec![
+    "{animation-name:x35atj5-B}",
+    "{background-color:red}",
+    "{color:var(--x-color)}",
+    "{float:left}",
+    "{outline-color:var(--xkxfyv)}",
+    "{text-shadow:1px 2px 3px 4px red}",
+  ];
+
+  assert_eq!(sorted(reference.iter().rev().copied().collect()), reference);
+  assert_eq!(
+    locale_compare(
+      "{--x19twipt:2px;--xypjos2:4px;--x1ec7iuc:8px;}",
+      "{--xpqh4lw:blue;--x8nt2k2:10px;--xkxfyv:red;}"
+    ),
+    Ordering::Less
+  );
+  assert_eq!(
+    locale_compare(
+      "{--x1ec7iuc:20px;--xypjos2:10px;--x19twipt:5px;}",
+      "{--xpqh4lw:lightblue;}"
+    ),
+    Ordering::Less
+  );
+  assert_eq!(
+    locale_compare(
+      "{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}",
+      "{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}"
+    ),
+    Ordering::Less
+  );
+}
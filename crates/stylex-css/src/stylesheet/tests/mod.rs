@@ -0,0 +1,4 @@
+mod class_name_manifest_test;
+mod collation_test;
+mod process_stylex_rules_test;
+mod report_test;
@@ -0,0 +1,538 @@
+use stylex_types::{
+  enums::data_structures::injectable_style::InjectableStyleKind,
+  structures::{
+    injectable_style::{InjectableConstStyle, InjectableStyle},
+    meta_data::MetaData,
+  },
+};
+
+use crate::stylesheet::{
+  LayerNaming, LayersOptions, ProcessStylexRulesOptions, process_stylex_rules, rule_layer,
+};
+
+fn rule(class_name: &str, ltr: &str, rtl: Option<&str>, priority: f64) -> MetaData {
+  MetaData::new(
+    class_name,
+    InjectableStyleKind::Regular(InjectableStyle {
+      ltr: ltr.to_string(),
+      rtl: rtl.map(str::to_string),
+      priority: Some(priority),
+    }),
+  )
+}
+
+fn constant(const_key: &str, const_value: &str) -> MetaData {
+  MetaData::new(
+    const_key,
+    InjectableStyleKind::Const(InjectableConstStyle {
+      const_key: const_key.to_string(),
+      const_value: const_value.to_string(),
+      ..Default::default()
+    }),
+  )
+}
+
+fn unlayered() -> ProcessStylexRulesOptions {
+  ProcessStylexRulesOptions {
+    legacy_disable_layers: true,
+    ..Default::default()
+  }
+}
+
+fn layered(layers: LayersOptions) -> ProcessStylexRulesOptions {
+  ProcessStylexRulesOptions {
+    use_layers: Some(layers),
+    ..Default::default()
+  }
+}
+
+fn fixture() -> Vec<MetaData> {
+  vec![
+    rule(
+      "x-color",
+      "@property --x-color { syntax: \"*\"; inherits: false;}",
+      None,
+      0.0,
+    ),
+    rule(
+      "xsg933n",
+      ":root, .xsg933n{--blue-xpqh4lw:blue;}",
+      None,
+      0.1,
+    ),
+    rule(
+      "margin-xymmreb",
+      ".margin-xymmreb{margin:10px 20px}",
+      None,
+      1000.0,
+    ),
+    rule(
+      "padding-xss17vw",
+      ".padding-xss17vw{padding:2px}",
+      None,
+      1000.0,
+    ),
+    rule(
+      "borderColor-x1bg2uv5",
+      ".borderColor-x1bg2uv5{border-color:green}",
+      None,
+      2000.0,
+    ),
+    rule(
+      "float-x1kmio9f",
+      ".float-x1kmio9f{float:left}",
+      Some(".float-x1kmio9f{float:right}"),
+      3000.0,
+    ),
+    rule(
+      "backgroundColor-xrkmrrc",
+      ".backgroundColor-xrkmrrc{background-color:red}",
+      None,
+      3000.0,
+    ),
+    rule(
+      "backgroundColor-xbrh7vm",
+      ".backgroundColor-xbrh7vm:hover{background-color:blue}",
+      None,
+      3130.0,
+    ),
+  ]
+}
+
+#[test]
+fn no_rules_produce_an_empty_stylesheet() {
+  assert_eq!(process_stylex_rules(&[], &Default::default()), "");
+}
+
+#[test]
+fn raises_each_bucket_by_one_specificity_level_without_layers() {
+  assert_eq!(
+    process_stylex_rules(&fixture(), &Default::default()),
+    r#"@property --x-color { syntax: "*"; inherits: false;}
+:root, .xsg933n{--blue-xpqh4lw:blue;}
+.margin-xymmreb:not(#\#){margin:10px 20px}
+.padding-xss17vw:not(#\#){padding:2px}
+.borderColor-x1bg2uv5:not(#\#):not(#\#){border-color:green}
+.backgroundColor-xrkmrrc:not(#\#):not(#\#):not(#\#){background-color:red}
+html:not([dir='rtl']) .float-x1kmio9f:not(#\#):not(#\#):not(#\#){float:left}
+html[dir='rtl'] .float-x1kmio9f:not(#\#):not(#\#):not(#\#){float:right}
+.backgroundColor-xbrh7vm:hover:not(#\#):not(#\#):not(#\#){background-color:blue}"#
+  );
+}
+
+#[test]
+fn wraps_buckets_in_priority_layers() {
+  assert_eq!(
+    process_stylex_rules(&fixture(), &layered(LayersOptions::default())),
+    r#"
+@layer priority1, priority2, priority3, priority4;
+@property --x-color { syntax: "*"; inherits: false;}
+:root, .xsg933n{--blue-xpqh4lw:blue;}
+@layer priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:2px}
+}
+@layer priority3{
+.borderColor-x1bg2uv5{border-color:green}
+}
+@layer priority4{
+.backgroundColor-xrkmrrc{background-color:red}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+}"#
+  );
+}
+
+#[test]
+fn places_before_and_after_layers_around_prefixed_priority_layers() {
+  let stylesheet = process_stylex_rules(
+    &fixture(),
+    &layered(LayersOptions {
+      before: vec!["xds.reset".into(), "xds.typography".into()],
+      after: vec!["xds.theme".into()],
+      prefix: Some("xds.base".into()),
+      ..Default::default()
+    }),
+  );
+
+  assert!(stylesheet.starts_with(
+    "\n@layer xds.reset, xds.typography, xds.base.priority1, xds.base.priority2, \
+     xds.base.priority3, xds.base.priority4, xds.theme;\n"
+  ));
+  assert!(stylesheet.contains("@layer xds.base.priority2{\n.margin-xymmreb{margin:10px 20px}"));
+}
+
+#[test]
+fn names_bucket_layers_for_what_they_hold() {
+  assert_eq!(
+    process_stylex_rules(
+      &fixture(),
+      &layered(LayersOptions {
+        prefix: Some("stylex".into()),
+        naming: LayerNaming::Bucket,
+        ..Default::default()
+      })
+    ),
+    r#"
+@layer stylex.base, stylex.shorthands-of-shorthands, stylex.shorthands-of-longhands, stylex.longhands;
+@property --x-color { syntax: "*"; inherits: false;}
+@layer stylex.base{
+:root, .xsg933n{--blue-xpqh4lw:blue;}
+}
+@layer stylex.shorthands-of-shorthands{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:2px}
+}
+@layer stylex.shorthands-of-longhands{
+.borderColor-x1bg2uv5{border-color:green}
+}
+@layer stylex.longhands{
+.backgroundColor-xrkmrrc{background-color:red}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+}"#
+  );
+}
+
+#[test]
+fn a_rule_layer_follows_from_its_priority_alone() {
+  let layers = LayersOptions {
+    naming: LayerNaming::Bucket,
+    ..Default::default()
+  };
+
+  assert_eq!(rule_layer(0.0, &layers), None);
+  assert_eq!(rule_layer(0.1, &layers).as_deref(), Some("base"));
+  assert_eq!(rule_layer(3130.0, &layers).as_deref(), Some("longhands"));
+  assert_eq!(
+    rule_layer(4200.0, &layers).as_deref(),
+    Some("physical-longhands")
+  );
+  assert_eq!(
+    rule_layer(8130.0, &layers).as_deref(),
+    Some("pseudo-elements.longhands")
+  );
+  assert_eq!(rule_layer(3000.0, &LayersOptions::default()), None);
+}
+
+#[test]
+fn flattens_every_selector_with_where_instead_of_raising_specificity() {
+  let mut rules = fixture();
+  rules.push(rule("xB", ".xB::after{inset:0}", None, 6000.0));
+  rules.push(rule(
+    "xM",
+    "@media (min-width: 10px){.xM.xM{color:red}}",
+    None,
+    3200.0,
+  ));
+
+  assert_eq!(
+    process_stylex_rules(
+      &rules,
+      &ProcessStylexRulesOptions {
+        flatten_specificity: true,
+        ..Default::default()
+      }
+    ),
+    r#"@property --x-color { syntax: "*"; inherits: false;}
+:where(:root, .xsg933n){--blue-xpqh4lw:blue;}
+:where(.margin-xymmreb){margin:10px 20px}
+:where(.padding-xss17vw){padding:2px}
+:where(.borderColor-x1bg2uv5){border-color:green}
+:where(.backgroundColor-xrkmrrc){background-color:red}
+:where(html:not([dir='rtl']) .float-x1kmio9f){float:left}
+:where(html[dir='rtl'] .float-x1kmio9f){float:right}
+:where(.backgroundColor-xbrh7vm:hover){background-color:blue}
+@media (min-width: 10px){:where(.xM.xM){color:red}}
+:where(.xB)::after{inset:0}"#
+  );
+}
+
+#[test]
+fn scopes_rtl_overrides_with_the_dir_pseudo_class() {
+  let mut rules = fixture();
+  rules.push(rule(
+    "xC",
+    ".xC::before{cursor:e-resize}",
+    Some(".xC::before{cursor:w-resize}"),
+    8000.0,
+  ));
+
+  assert_eq!(
+    process_stylex_rules(
+      &rules,
+      &ProcessStylexRulesOptions {
+        use_dir_pseudo_class: true,
+        ..Default::default()
+      }
+    ),
+    r#"@property --x-color { syntax: "*"; inherits: false;}
+:root, .xsg933n{--blue-xpqh4lw:blue;}
+.margin-xymmreb:not(#\#){margin:10px 20px}
+.padding-xss17vw:not(#\#){padding:2px}
+.borderColor-x1bg2uv5:not(#\#):not(#\#){border-color:green}
+.backgroundColor-xrkmrrc:not(#\#):not(#\#):not(#\#){background-color:red}
+.float-x1kmio9f:not(#\#):not(#\#):not(#\#):dir(ltr){float:left}
+.float-x1kmio9f:not(#\#):not(#\#):not(#\#):dir(rtl){float:right}
+.backgroundColor-xbrh7vm:hover:not(#\#):not(#\#):not(#\#){background-color:blue}
+.xC:not(#\#):not(#\#):not(#\#):not(#\#):dir(ltr)::before{cursor:e-resize}
+.xC:not(#\#):not(#\#):not(#\#):not(#\#):dir(rtl)::before{cursor:w-resize}"#
+  );
+}
+
+#[test]
+fn repeats_the_class_of_theme_overrides() {
+  let rules = vec![
+    rule(
+      "xsg933n",
+      ":root, .xsg933n{--blue-xpqh4lw:blue;}",
+      None,
+      0.1,
+    ),
+    rule(
+      "x6xqkwy",
+      ".x6xqkwy, .x6xqkwy:root{--blue-xpqh4lw:lightblue;}",
+      None,
+      0.4,
+    ),
+    rule(
+      "x6xqkwy-1lveb7",
+      "@media (prefers-color-scheme: dark){.x6xqkwy-1lveb7, .x6xqkwy-1lveb7:root{--blue-xpqh4lw:navy;}}",
+      None,
+      0.5,
+    ),
+  ];
+
+  assert_eq!(
+    process_stylex_rules(&rules, &unlayered()),
+    ":root, .xsg933n{--blue-xpqh4lw:blue;}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+@media (prefers-color-scheme: dark){.x6xqkwy-1lveb7.x6xqkwy-1lveb7, .x6xqkwy-1lveb7.x6xqkwy-1lveb7:root{--blue-xpqh4lw:navy;}}"
+  );
+}
+
+#[test]
+fn legacy_disable_layers_emits_rules_untouched() {
+  assert_eq!(
+    process_stylex_rules(
+      &fixture(),
+      &ProcessStylexRulesOptions {
+        legacy_disable_layers: true,
+        enable_ltr_rtl_comments: true,
+        ..Default::default()
+      }
+    ),
+    r#"@property --x-color { syntax: "*"; inherits: false;}
+:root, .xsg933n{--blue-xpqh4lw:blue;}
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:2px}
+.borderColor-x1bg2uv5{border-color:green}
+.backgroundColor-xrkmrrc{background-color:red}
+/* @ltr begin */.float-x1kmio9f{float:left}/* @ltr end */
+/* @rtl begin */.float-x1kmio9f{float:right}/* @rtl end */
+.backgroundColor-xbrh7vm:hover{background-color:blue}"#
+  );
+}
+
+#[test]
+fn legacy_classnames_sort_orders_by_class_name() {
+  let rules = vec![
+    rule("xymmreb", ".xymmreb{margin:10px 20px}", None, 1000.0),
+    rule("x1s2izit", ".x1s2izit{padding:2px}", None, 1000.0),
+  ];
+
+  assert_eq!(
+    process_stylex_rules(
+      &rules,
+      &ProcessStylexRulesOptions {
+        use_legacy_classnames_sort: true,
+        ..unlayered()
+      }
+    ),
+    ".x1s2izit{padding:2px}\n.xymmreb{margin:10px 20px}"
+  );
+}
+
+#[test]
+fn sort_is_deterministic_regardless_of_input_order() {
+  let rules = vec![
+    rule(
+      "xMedia1",
+      "@media (min-width: 48rem){.xMedia1{display:none}}",
+      None,
+      6000.0,
+    ),
+    rule(
+      "xContainer1",
+      "@container card (min-width: 31.25rem){.xContainer1{display:flex}}",
+      None,
+      6000.0,
+    ),
+    rule(
+      "xStarting1",
+      "@starting-style{.xStarting1{opacity:0}}",
+      None,
+      6000.0,
+    ),
+    rule(
+      "xVar1",
+      "var(--x10fi87w){.xVar1.xVar1{grid-template-columns:repeat(2,1fr)}}",
+      None,
+      6000.0,
+    ),
+    rule("xPseudo1", ".xPseudo1::before{inset:0}", None, 6000.0),
+    rule(
+      "xMedia2",
+      "@media (min-width: 64rem){.xMedia2{inset:0}}",
+      None,
+      6000.0,
+    ),
+    rule("xPlain1", ".xPlain1{display:none}", None, 6000.0),
+  ];
+
+  let expected = "@container card (min-width: 31.25rem){.xContainer1{display:flex}}
+.xPlain1{display:none}
+@media (min-width: 48rem){.xMedia1{display:none}}
+var(--x10fi87w){.xVar1.xVar1{grid-template-columns:repeat(2,1fr)}}
+.xPseudo1::before{inset:0}
+@media (min-width: 64rem){.xMedia2{inset:0}}
+@starting-style{.xStarting1{opacity:0}}";
+
+  let reversed: Vec<MetaData> = rules.iter().rev().cloned().collect();
+  let shuffled: Vec<MetaData> = [4, 0, 3, 6, 2, 5, 1]
+    .iter()
+    .map(|&index| rules[index].clone())
+    .collect();
+
+  assert_eq!(process_stylex_rules(&rules, &unlayered()), expected);
+  assert_eq!(process_stylex_rules(&reversed, &unlayered()), expected);
+  assert_eq!(process_stylex_rules(&shuffled, &unlayered()), expected);
+}
+
+#[test]
+fn emits_duplicate_rules_once() {
+  let rule_a = rule(
+    "xA",
+    "@media (min-width: 48rem){.xA{display:flex}}",
+    None,
+    6000.0,
+  );
+  let rule_b = rule("xB", ".xB::after{inset:0}", None, 6000.0);
+  let rule_c = rule("xC", "@starting-style{.xC{opacity:0}}", None, 6000.0);
+
+  let expected = "@media (min-width: 48rem){.xA{display:flex}}
+.xB::after{inset:0}
+@starting-style{.xC{opacity:0}}";
+
+  for rules in [
+    vec![
+      rule_a.clone(),
+      rule_b.clone(),
+      rule_b.clone(),
+      rule_c.clone(),
+    ],
+    vec![
+      rule_c.clone(),
+      rule_b.clone(),
+      rule_a.clone(),
+      rule_b.clone(),
+    ],
+    vec![rule_b.clone(), rule_c, rule_b, rule_a],
+  ] {
+    assert_eq!(process_stylex_rules(&rules, &unlayered()), expected);
+  }
+}
+
+#[test]
+fn adds_specificity_before_pseudo_elements_and_skips_keyframes() {
+  let rules = vec![
+    rule(
+      "x35atj5-B",
+      "@keyframes x35atj5-B{0%{opacity:0;}}",
+      None,
+      0.0,
+    ),
+    rule("xB", ".xB::after{inset:0}", None, 1000.0),
+  ];
+
+  assert_eq!(
+    process_stylex_rules(&rules, &Default::default()),
+    "@keyframes x35atj5-B{0%{opacity:0;}}\n.xB:not(#\\#)::after{inset:0}"
+  );
+}
+
+#[test]
+fn scopes_rtl_overrides_inside_at_rules() {
+  let rules = vec![rule(
+    "x1",
+    "@media (min-width: 10px){.x1{margin-left:1px}}",
+    Some("@media (min-width: 10px){.x1{margin-right:1px}}"),
+    3200.0,
+  )];
+
+  assert_eq!(
+    process_stylex_rules(&rules, &unlayered()),
+    "@media (min-width: 10px){html:not([dir='rtl']) .x1{margin-left:1px}}\n\
+     @media (min-width: 10px){html[dir='rtl'] .x1{margin-right:1px}}"
+  );
+}
+
+#[test]
+fn inlines_constants_and_drops_their_rules() {
+  let rules = vec![
+    constant("x1tp0jf2", "var(--x2nvs4r)"),
+    constant("x2nvs4r", "yellow"),
+    constant("xmedia", "@media (max-width: 1000px)"),
+    rule("x1", ".x1{color:var(--x1tp0jf2)}", None, 3000.0),
+    rule(
+      "x2",
+      "var(--xmedia){.x2.x2{color:var(--x2nvs4r)}}",
+      None,
+      3200.0,
+    ),
+  ];
+
+  assert_eq!(
+    process_stylex_rules(&rules, &unlayered()),
+    ".x1{color:yellow}\n@media (max-width: 1000px){.x2.x2{color:yellow}}"
+  );
+}
+
+#[test]
+fn leaves_circular_constants_unresolved() {
+  let rules = vec![
+    constant("xa", "var(--xb)"),
+    constant("xb", "var(--xa)"),
+    rule("x1", ".x1{color:var(--xa)}", None, 3000.0),
+  ];
+
+  assert_eq!(
+    process_stylex_rules(&rules, &unlayered()),
+    ".x1{color:var(--xa)}"
+  );
+}
+
+#[test]
+fn prepends_the_logical_float_polyfill_when_used() {
+  let rules = vec![rule(
+    "xj87blo",
+    ".xj87blo{float:var(--stylex-logical-start)}",
+    None,
+    3000.0,
+  )];
+
+  assert_eq!(
+    process_stylex_rules(&rules, &unlayered()),
+    r#":root, [dir="ltr"] {
+  --stylex-logical-start: left;
+  --stylex-logical-end: right;
+}
+[dir="rtl"] {
+  --stylex-logical-start: right;
+  --stylex-logical-end: left;
+}
+.xj87blo{float:var(--stylex-logical-start)}"#
+  );
+}
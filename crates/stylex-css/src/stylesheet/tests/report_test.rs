@@ -0,0 +1,233 @@
+use stylex_types::{
+  enums::data_structures::injectable_style::InjectableStyleKind,
+  structures::{
+    injectable_style::{InjectableConstStyle, InjectableStyle},
+    meta_data::MetaData,
+  },
+};
+
+use crate::stylesheet::report::{
+  DuplicateReport, FileReport, PropertyReport, ValueReport, stylesheet_report,
+};
+
+fn rule(class_name: &str, ltr: &str, rtl: Option<&str>) -> MetaData {
+  MetaData::new(
+    class_name,
+    InjectableStyleKind::Regular(InjectableStyle {
+      ltr: ltr.to_string(),
+      rtl: rtl.map(str::to_string),
+      priority: Some(3000.0),
+    }),
+  )
+}
+
+fn build() -> Vec<(&'static str, Vec<MetaData>)> {
+  vec![
+    (
+      "src/Button.js",
+      vec![
+        rule("x1e2nbdu", ".x1e2nbdu{color:red}", None),
+        rule(
+          "x1hm9lzh",
+          ".x1hm9lzh{margin-left:4px}",
+          Some(".x1hm9lzh{margin-right:4px}"),
+        ),
+        MetaData::new(
+          "x1cnst",
+          InjectableStyleKind::Const(InjectableConstStyle {
+            const_key: "x1cnst".to_string(),
+            const_value: "8px".to_string(),
+            ..Default::default()
+          }),
+        ),
+      ],
+    ),
+    (
+      "src/Card.js",
+      vec![
+        rule("x1e2nbdu", ".x1e2nbdu{color:red}", None),
+        rule(
+          "x1x8n3ya",
+          "@media (min-width: 800px){.x1x8n3ya.x1x8n3ya{color:red}}",
+          None,
+        ),
+        rule("pkg-x9f3", ".pkg-x9f3{color:red}", None),
+      ],
+    ),
+  ]
+}
+
+fn report() -> crate::stylesheet::report::StylesheetReport {
+  let build = build();
+
+  stylesheet_report(build.iter().map(|(file, rules)| (*file, rules.as_slice())))
+}
+
+#[test]
+fn counts_each_class_once_and_leaves_constants_out() {
+  let report = report();
+
+  assert_eq!(report.rules, 4);
+  assert_eq!(
+    report.bytes,
+    [
+      ".x1e2nbdu{color:red}",
+      ".x1hm9lzh{margin-left:4px}",
+      ".x1hm9lzh{margin-right:4px}",
+      "@media (min-width: 800px){.x1x8n3ya.x1x8n3ya{color:red}}",
+      ".pkg-x9f3{color:red}",
+    ]
+    .iter()
+    .map(|css| css.len())
+    .sum::<usize>()
+  );
+}
+
+#[test]
+fn reports_the_share_of_rtl_overrides() {
+  let report = report();
+
+  assert_eq!(report.rtl.rules, 1);
+  assert_eq!(report.rtl.bytes, ".x1hm9lzh{margin-right:4px}".len());
+  assert_eq!(report.rtl.rule_share, 0.25);
+  assert_eq!(
+    report.rtl.byte_share,
+    report.rtl.bytes as f64 / report.bytes as f64
+  );
+}
+
+#[test]
+fn counts_properties_and_values_through_their_conditions() {
+  let report = report();
+
+  assert_eq!(
+    report.properties,
+    [
+      PropertyReport {
+        property: "color".to_string(),
+        rules: 3,
+        values: 1,
+        uses: 4,
+        bytes: 20 + 56 + 20,
+      },
+      PropertyReport {
+        property: "margin-left".to_string(),
+        rules: 1,
+        values: 1,
+        uses: 1,
+        bytes: 26 + 27,
+      },
+    ]
+  );
+  assert_eq!(
+    report.values[0],
+    ValueReport {
+      property: "color".to_string(),
+      value: "red".to_string(),
+      rules: 3,
+      uses: 4,
+      bytes: 20 + 56 + 20,
+    }
+  );
+}
+
+#[test]
+fn finds_the_same_rule_behind_different_class_names() {
+  assert_eq!(
+    report().duplicates,
+    [DuplicateReport {
+      css: ".&{color:red}".to_string(),
+      class_names: vec!["x1e2nbdu".to_string(), "pkg-x9f3".to_string()],
+      files: vec!["src/Button.js".to_string(), "src/Card.js".to_string()],
+      wasted_bytes: ".pkg-x9f3{color:red}".len(),
+    }]
+  );
+}
+
+#[test]
+fn ranks_files_by_what_they_bring_in() {
+  assert_eq!(
+    report().files,
+    [
+      FileReport {
+        file: "src/Card.js".to_string(),
+        rules: 3,
+        bytes: 20 + 56 + 20,
+        own_rules: 2,
+        own_bytes: 56 + 20,
+      },
+      FileReport {
+        file: "src/Button.js".to_string(),
+        rules: 2,
+        bytes: 20 + 26 + 27,
+        own_rules: 1,
+        own_bytes: 26 + 27,
+      },
+    ]
+  );
+}
+
+#[test]
+fn at_rules_of_their_own_count_as_the_at_rule() {
+  let rules = [
+    rule("xk1", "@keyframes xk1{from{opacity:0}to{opacity:1}}", None),
+    rule(
+      "x-color",
+      "@property --x-color { syntax: \"*\"; inherits: false;}",
+      None,
+    ),
+    rule("xv1", ":root, .xv1{--a-x1:blue;--b-x2:red;}", None),
+  ];
+
+  let properties: Vec<(String, usize)> = stylesheet_report([("a.js", rules.as_slice())])
+    .properties
+    .into_iter()
+    .map(|property| (property.property, property.values))
+    .collect();
+
+  assert_eq!(
+    properties,
+    [
+      ("@property".to_string(), 0),
+      ("@keyframes".to_string(), 0),
+      ("--a-x1".to_string(), 1),
+      ("--b-x2".to_string(), 1),
+    ]
+  );
+}
+
+#[test]
+fn a_semicolon_or_brace_inside_a_value_stays_in_it() {
+  let rules = [
+    rule(
+      "xbg",
+      ".xbg{background-image:url(data:image/svg+xml;base64,PHN2Zz4=)}",
+      None,
+    ),
+    rule("xsemi", ".xsemi::before{content:\";\"}", None),
+    rule("xbrace", ".xbrace::after{content:\"}{\"}", None),
+  ];
+
+  let values: Vec<(String, String)> = stylesheet_report([("a.js", rules.as_slice())])
+    .values
+    .into_iter()
+    .map(|value| (value.property, value.value))
+    .collect();
+
+  assert_eq!(
+    values,
+    [
+      (
+        "background-image".to_string(),
+        "url(data:image/svg+xml;base64,PHN2Zz4=)".to_string()
+      ),
+      ("content".to_string(), "\"}{\"".to_string()),
+      ("content".to_string(), "\";\"".to_string()),
+    ]
+  );
+}
+
+#[test]
+fn an_empty_build_reports_nothing() {
+  assert_eq!(stylesheet_report([]), Default::default());
+}
@@ -0,0 +1,393 @@
+use stylex_css_parser::{
+  css_types::{Angle, BasicShape, Calc, Color, EasingFunction, FilterFunction, Length},
+  properties::{BorderRadiusIndividual, BorderRadiusShorthand, BoxShadow, Transform},
+};
+use stylex_utils::string::dashify;
+
+/// Keywords every property accepts, whatever its grammar.
+const CSS_WIDE_KEYWORDS: &[&str] = &["inherit", "initial", "unset", "revert", "revert-layer"];
+
+const COLOR_FUNCTIONS: &[&str] = &["rgb", "rgba", "hsl", "hsla", "lch", "oklch", "oklab"];
+
+const TRANSFORM_FUNCTIONS: &[&str] = &[
+  "matrix",
+  "matrix3d",
+  "perspective",
+  "rotate",
+  "rotatex",
+  "rotatey",
+  "rotatez",
+  "rotate3d",
+  "scale",
+  "scalex",
+  "scaley",
+  "scalez",
+  "scale3d",
+  "skew",
+  "skewx",
+  "skewy",
+  "translate",
+  "translatex",
+  "translatey",
+  "translatez",
+  "translate3d",
+  "calc",
+];
+
+const FILTER_FUNCTIONS: &[&str] = &[
+  "blur",
+  "brightness",
+  "contrast",
+  "grayscale",
+  "hue-rotate",
+  "invert",
+  "opacity",
+  "saturate",
+  "sepia",
+];
+
+const BASIC_SHAPE_FUNCTIONS: &[&str] = &["inset", "circle", "ellipse", "polygon", "path"];
+
+/// How a value splits into the parts a grammar's parser reads one at a time.
+#[derive(Debug, Clone, Copy)]
+enum Separator {
+  /// The value is read whole.
+  None,
+  /// Up to this many parts separated by whitespace.
+  Whitespace(usize),
+  /// Any number of comma-separated parts.
+  Comma,
+}
+
+/// The syntax a property's value has to match, checked with the typed parsers
+/// of `stylex-css-parser`.
+///
+/// A grammar only judges values it can read in full. A value calling a function
+/// the grammar does not list — `var()`, `color-mix()`, `env()` — is outside what
+/// the parsers model and is accepted unread, so a check can miss a mistake but
+/// never rejects valid CSS it has not heard of.
+#[derive(Debug)]
+pub struct ValueGrammar {
+  expected: &'static str,
+  separator: Separator,
+  keywords: &'static [&'static str],
+  functions: &'static [&'static str],
+  parse: fn(&str) -> bool,
+}
+
+static COLOR: ValueGrammar = ValueGrammar {
+  expected: "<color>",
+  separator: Separator::None,
+  keywords: &[],
+  functions: COLOR_FUNCTIONS,
+  parse: parses_color,
+};
+
+static COLOR_OR_AUTO: ValueGrammar = ValueGrammar {
+  expected: "auto | <color>",
+  separator: Separator::None,
+  keywords: &["auto"],
+  functions: COLOR_FUNCTIONS,
+  parse: parses_color,
+};
+
+static LINE_WIDTH: ValueGrammar = ValueGrammar {
+  expected: "<line-width>",
+  separator: Separator::None,
+  keywords: &["thin", "medium", "thick"],
+  functions: &["calc"],
+  parse: parses_length,
+};
+
+static LINE_WIDTHS: ValueGrammar = ValueGrammar {
+  expected: "<line-width>{1,4}",
+  separator: Separator::Whitespace(4),
+  keywords: &["thin", "medium", "thick"],
+  functions: &["calc"],
+  parse: parses_length,
+};
+
+static LENGTH: ValueGrammar = ValueGrammar {
+  expected: "<length>",
+  separator: Separator::None,
+  keywords: &[],
+  functions: &["calc"],
+  parse: parses_length,
+};
+
+static SPACING: ValueGrammar = ValueGrammar {
+  expected: "normal | <length>",
+  separator: Separator::None,
+  keywords: &["normal"],
+  functions: &["calc"],
+  parse: parses_length,
+};
+
+static TRANSFORM: ValueGrammar = ValueGrammar {
+  expected: "none | <transform-list>",
+  separator: Separator::None,
+  keywords: &["none"],
+  functions: TRANSFORM_FUNCTIONS,
+  parse: parses_transform,
+};
+
+static ROTATE: ValueGrammar = ValueGrammar {
+  expected: "none | [ x | y | z | <number>{3} ]? <angle>",
+  separator: Separator::Whitespace(4),
+  keywords: &["none", "x", "y", "z"],
+  functions: &[],
+  parse: parses_angle_or_number,
+};
+
+static BOX_SHADOW: ValueGrammar = ValueGrammar {
+  expected: "none | <shadow>#",
+  separator: Separator::Comma,
+  keywords: &["none"],
+  functions: COLOR_FUNCTIONS,
+  parse: parses_box_shadow,
+};
+
+static BORDER_RADIUS: ValueGrammar = ValueGrammar {
+  expected: "<length-percentage>{1,4} [ / <length-percentage>{1,4} ]?",
+  separator: Separator::None,
+  keywords: &[],
+  functions: &["calc"],
+  parse: parses_border_radius,
+};
+
+static BORDER_CORNER_RADIUS: ValueGrammar = ValueGrammar {
+  expected: "<length-percentage>{1,2}",
+  separator: Separator::None,
+  keywords: &[],
+  functions: &["calc"],
+  parse: parses_border_corner_radius,
+};
+
+static EASING_FUNCTIONS: ValueGrammar = ValueGrammar {
+  expected: "<easing-function>#",
+  separator: Separator::Comma,
+  keywords: &["linear"],
+  functions: &["cubic-bezier", "steps"],
+  parse: parses_easing_function,
+};
+
+static FILTER: ValueGrammar = ValueGrammar {
+  expected: "none | <filter-function>+",
+  separator: Separator::Whitespace(usize::MAX),
+  keywords: &["none"],
+  functions: FILTER_FUNCTIONS,
+  parse: parses_filter_function,
+};
+
+static BASIC_SHAPE: ValueGrammar = ValueGrammar {
+  expected: "none | <basic-shape> || <geometry-box>",
+  separator: Separator::Whitespace(2),
+  keywords: &[
+    "none",
+    "margin-box",
+    "border-box",
+    "padding-box",
+    "content-box",
+    "fill-box",
+    "stroke-box",
+    "view-box",
+  ],
+  functions: BASIC_SHAPE_FUNCTIONS,
+  parse: parses_basic_shape,
+};
+
+impl ValueGrammar {
+  /// The grammar `property` is checked against, if it has one. Accepts the
+  /// camelCase spelling authors write as well as the CSS one.
+  pub fn for_property(property: &str) -> Option<&'static ValueGrammar> {
+    let grammar = match dashify(property).as_ref() {
+      "color"
+      | "background-color"
+      | "border-top-color"
+      | "border-right-color"
+      | "border-bottom-color"
+      | "border-left-color"
+      | "border-block-start-color"
+      | "border-block-end-color"
+      | "border-inline-start-color"
+      | "border-inline-end-color"
+      | "outline-color"
+      | "column-rule-color"
+      | "text-decoration-color"
+      | "text-emphasis-color"
+      | "flood-color"
+      | "lighting-color"
+      | "stop-color" => &COLOR,
+      "accent-color" | "caret-color" => &COLOR_OR_AUTO,
+      "border-top-width"
+      | "border-right-width"
+      | "border-bottom-width"
+      | "border-left-width"
+      | "border-block-start-width"
+      | "border-block-end-width"
+      | "border-inline-start-width"
+      | "border-inline-end-width"
+      | "outline-width"
+      | "column-rule-width" => &LINE_WIDTH,
+      "border-width" | "border-block-width" | "border-inline-width" => &LINE_WIDTHS,
+      "outline-offset" => &LENGTH,
+      "letter-spacing" | "word-spacing" => &SPACING,
+      "transform" => &TRANSFORM,
+      "rotate" => &ROTATE,
+      "box-shadow" => &BOX_SHADOW,
+      "border-radius" => &BORDER_RADIUS,
+      "border-top-left-radius"
+      | "border-top-right-radius"
+      | "border-bottom-right-radius"
+      | "border-bottom-left-radius"
+      | "border-start-start-radius"
+      | "border-start-end-radius"
+      | "border-end-start-radius"
+      | "border-end-end-radius" => &BORDER_CORNER_RADIUS,
+      "transition-timing-function" | "animation-timing-function" => &EASING_FUNCTIONS,
+      "filter" | "backdrop-filter" => &FILTER,
+      "clip-path" | "shape-outside" => &BASIC_SHAPE,
+      _ => return None,
+    };
+
+    Some(grammar)
+  }
+
+  /// The grammar in value definition syntax, for diagnostics.
+  pub fn expected(&self) -> &'static str {
+    self.expected
+  }
+
+  /// Whether `value` matches the grammar, or lies outside what it can read.
+  pub fn accepts(&self, value: &str) -> bool {
+    let value = value.trim();
+
+    if value.is_empty()
+      || value.contains('!')
+      || is_one_of(value, CSS_WIDE_KEYWORDS)
+      || function_names(value).any(|name| !is_one_of(name, self.functions))
+    {
+      return true;
+    }
+
+    let parts = match self.separator {
+      Separator::None => vec![value],
+      Separator::Whitespace(max) => {
+        let parts = split_top_level(value, char::is_whitespace);
+
+        if parts.len() > max {
+          return false;
+        }
+
+        parts
+      },
+      Separator::Comma => split_top_level(value, |c| c == ','),
+    };
+
+    !parts.is_empty()
+      && parts
+        .into_iter()
+        .all(|part| is_one_of(part, self.keywords) || (self.parse)(part))
+  }
+}
+
+fn is_one_of(value: &str, names: &[&str]) -> bool {
+  names.iter().any(|name| name.eq_ignore_ascii_case(value))
+}
+
+/// Names of the functions `value` calls, nested calls included.
+fn function_names(value: &str) -> impl Iterator<Item = &str> {
+  value.match_indices('(').filter_map(move |(index, _)| {
+    let start = value[..index]
+      .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+      .map_or(0, |position| position + 1);
+
+    (start < index).then(|| &value[start..index])
+  })
+}
+
+/// Splits `value` wherever `is_separator` matches outside parentheses and
+/// quotes, dropping empty parts.
+fn split_top_level(value: &str, is_separator: impl Fn(char) -> bool) -> Vec<&str> {
+  let mut parts = vec![];
+  let mut depth = 0usize;
+  let mut quote = None;
+  let mut start = 0;
+
+  for (index, c) in value.char_indices() {
+    match (quote, c) {
+      (Some(open), _) if c == open => quote = None,
+      (Some(_), _) => {},
+      (None, '"' | '\'') => quote = Some(c),
+      (None, '(') => depth += 1,
+      (None, ')') => depth = depth.saturating_sub(1),
+      (None, _) if depth == 0 && is_separator(c) => {
+        parts.push(&value[start..index]);
+        start = index + c.len_utf8();
+      },
+      _ => {},
+    }
+  }
+
+  parts.push(&value[start..]);
+
+  parts
+    .into_iter()
+    .map(str::trim)
+    .filter(|part| !part.is_empty())
+    .collect()
+}
+
+fn parses_color(value: &str) -> bool {
+  Color::parse().parse_to_end(value).is_ok()
+}
+
+fn parses_length(value: &str) -> bool {
+  Length::parser().parse_to_end(value).is_ok() || Calc::parse().parse_to_end(value).is_ok()
+}
+
+fn parses_transform(value: &str) -> bool {
+  Transform::parser().parse_to_end(value).is_ok()
+}
+
+fn parses_angle_or_number(value: &str) -> bool {
+  Angle::parser().parse_to_end(value).is_ok() || value.parse::<f64>().is_ok()
+}
+
+/// The shadow parser wants its color last and present, where CSS lets a
+/// shadow lead with it or leave it out; both spellings are retried in the
+/// shape the parser reads.
+fn parses_box_shadow(shadow: &str) -> bool {
+  let parses = |shadow: &str| BoxShadow::parser().parse_to_end(shadow).is_ok();
+
+  if parses(shadow) || parses(&format!("{shadow} currentcolor")) {
+    return true;
+  }
+
+  let words = split_top_level(shadow, char::is_whitespace);
+
+  match words.split_first() {
+    Some((first, rest)) if parses_color(first) => parses(&format!("{} {first}", rest.join(" "))),
+    _ => false,
+  }
+}
+
+fn parses_border_radius(value: &str) -> bool {
+  BorderRadiusShorthand::parser().parse_to_end(value).is_ok()
+}
+
+fn parses_border_corner_radius(value: &str) -> bool {
+  BorderRadiusIndividual::parser().parse_to_end(value).is_ok()
+}
+
+fn parses_easing_function(value: &str) -> bool {
+  EasingFunction::parse().parse_to_end(value).is_ok()
+}
+
+fn parses_filter_function(value: &str) -> bool {
+  FilterFunction::parser().parse_to_end(value).is_ok()
+}
+
+fn parses_basic_shape(value: &str) -> bool {
+  BasicShape::parse().parse_to_end(value).is_ok()
+}
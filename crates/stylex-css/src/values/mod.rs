@@ -1,4 +1,5 @@
 pub mod common;
+pub mod grammar;
 pub mod parser;
 #[cfg(test)]
 mod tests;
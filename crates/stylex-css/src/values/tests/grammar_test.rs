@@ -0,0 +1,153 @@
+use crate::values::grammar::ValueGrammar;
+
+fn accepts(property: &str, value: &str) -> bool {
+  match ValueGrammar::for_property(property) {
+    Some(grammar) => grammar.accepts(value),
+    None => panic!("no grammar for {property}"),
+  }
+}
+
+#[test]
+fn properties_without_a_grammar_are_not_checked() {
+  assert!(ValueGrammar::for_property("display").is_none());
+  assert!(ValueGrammar::for_property("--custom").is_none());
+}
+
+#[test]
+fn looks_properties_up_by_either_spelling() {
+  assert!(ValueGrammar::for_property("backgroundColor").is_some());
+  assert!(ValueGrammar::for_property("background-color").is_some());
+}
+
+#[test]
+fn color() {
+  for value in [
+    "red",
+    "currentcolor",
+    "transparent",
+    "#ff00ff80",
+    "rgb(1 2 3 / 50%)",
+    "hsl(120deg 50% 50%)",
+  ] {
+    assert!(accepts("color", value), "{value}");
+  }
+
+  assert!(!accepts("color", "rde"));
+  assert!(!accepts("color", "#ggg"));
+  assert!(!accepts("backgroundColor", "red blue"));
+  assert!(accepts("caretColor", "auto"));
+  assert!(!accepts("color", "auto"));
+}
+
+#[test]
+fn values_outside_the_modelled_syntax_are_accepted() {
+  for value in [
+    "var(--accent)",
+    "color-mix(in srgb, red, blue)",
+    "light-dark(red, blue)",
+    "inherit",
+    "REVERT-LAYER",
+    "red !important",
+    "",
+  ] {
+    assert!(accepts("color", value), "{value}");
+  }
+
+  assert!(accepts("transform", "translateX(var(--x))"));
+  assert!(accepts("filter", "drop-shadow(0 0 2px red)"));
+}
+
+#[test]
+fn line_width() {
+  assert!(accepts("borderTopWidth", "thin"));
+  assert!(accepts("outlineWidth", "2px"));
+  assert!(accepts("outlineWidth", "calc(1px + 1px)"));
+  assert!(!accepts("outlineWidth", "10"));
+  assert!(accepts("borderWidth", "1px thick 0 2px"));
+  assert!(!accepts("borderWidth", "1px 1px 1px 1px 1px"));
+}
+
+#[test]
+fn spacing() {
+  assert!(accepts("letterSpacing", "normal"));
+  assert!(accepts("letterSpacing", "0.1em"));
+  assert!(!accepts("letterSpacing", "wide"));
+}
+
+#[test]
+fn transform() {
+  assert!(accepts("transform", "none"));
+  assert!(accepts("transform", "rotate(10deg)"));
+  assert!(accepts("transform", "translate(-50%, -50%) scale(2)"));
+  assert!(!accepts("transform", "rotate(10)"));
+}
+
+#[test]
+fn rotate() {
+  assert!(accepts("rotate", "none"));
+  assert!(accepts("rotate", "45deg"));
+  assert!(accepts("rotate", "x 45deg"));
+  assert!(accepts("rotate", "1 1 0 45deg"));
+  assert!(!accepts("rotate", "45dag"));
+}
+
+#[test]
+fn box_shadow() {
+  for value in [
+    "none",
+    "0 0 1px red",
+    "1px 1px",
+    "red 0 0 4px",
+    "inset 0 0 0 1px #000",
+    "0 1px 2px rgba(0, 0, 0, 0.2), 0 2px 4px red",
+  ] {
+    assert!(accepts("boxShadow", value), "{value}");
+  }
+
+  assert!(!accepts("boxShadow", "0 0 1px rde"));
+  assert!(!accepts("boxShadow", "big"));
+}
+
+#[test]
+fn border_radius() {
+  assert!(accepts("borderRadius", "4px 8px"));
+  assert!(accepts("borderRadius", "50% / 10%"));
+  assert!(!accepts("borderRadius", "round"));
+  assert!(accepts("borderTopLeftRadius", "4px 8px"));
+  assert!(!accepts("borderTopLeftRadius", "4px 8px 2px"));
+}
+
+#[test]
+fn easing_function() {
+  for value in [
+    "ease",
+    "linear",
+    "ease-in-out, step-start",
+    "cubic-bezier(0.1, 0.7, 1, 0.1)",
+    "steps(4, end)",
+    "linear(0, 0.5 50%, 1)",
+  ] {
+    assert!(accepts("transitionTimingFunction", value), "{value}");
+  }
+
+  assert!(!accepts("animationTimingFunction", "bounce"));
+  assert!(!accepts("transitionTimingFunction", "ease, bounce"));
+}
+
+#[test]
+fn filter() {
+  assert!(accepts("filter", "none"));
+  assert!(accepts("filter", "blur(2px) brightness(0.5)"));
+  assert!(accepts("backdropFilter", "saturate(200%)"));
+  assert!(!accepts("filter", "blur(2)"));
+  assert!(!accepts("filter", "blurry"));
+}
+
+#[test]
+fn basic_shape() {
+  assert!(accepts("clipPath", "circle(50%)"));
+  assert!(accepts("clipPath", "inset(0 round 4px) border-box"));
+  assert!(accepts("clipPath", "url(#mask)"));
+  assert!(accepts("shapeOutside", "polygon(0 0, 100% 0, 50% 100%)"));
+  assert!(!accepts("clipPath", "circle(big)"));
+}
@@ -1,2 +1,3 @@
 mod common_test;
+mod grammar_test;
 mod parser_test;
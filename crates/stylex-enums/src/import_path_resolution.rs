@@ -1,5 +0,0 @@
-#[derive(Debug, PartialEq, Clone)]
-pub enum ImportPathResolution {
-  Unresolved,
-  Resolved { path: String },
-}
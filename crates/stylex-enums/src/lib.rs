@@ -4,7 +4,6 @@ pub mod aliases;
 pub mod core;
 pub mod counter_mode;
 pub mod css_syntax;
-pub mod import_path_resolution;
 pub mod js;
 pub mod misc;
 pub mod property_validation_mode;
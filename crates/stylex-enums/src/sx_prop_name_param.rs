@@ -1,8 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents the `sxPropName` option: either a string name or `false`
 /// (disabled).
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(untagged)]
 pub enum SxPropNameParam {
   /// Disables the `sx` prop feature
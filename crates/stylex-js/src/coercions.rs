@@ -7,6 +7,7 @@
 //! one.
 
 use stylex_utils::number;
+use swc_core::atoms::Wtf8Atom;
 use swc_core::ecma::ast::{
   BigIntValue, BlockStmtOrExpr, Expr, Ident, Lit, ObjectLit, Prop, PropName, PropOrSpread, UnaryOp,
 };
@@ -247,6 +248,45 @@ pub fn is_nullish(expr: &Expr) -> bool {
   }
 }
 
+/// ECMA-262 `IsStrictlyEqual`, over two already-evaluated expressions.
+///
+/// Answers for primitives only. Two objects are equal when they are the same
+/// object, and an evaluated expression carries no identity to compare, so
+/// `None` is the refusal for anything that is not a primitive on both sides —
+/// answering `false` would send a `switch` down the wrong case.
+pub fn strict_equals(left: &Expr, right: &Expr) -> Option<bool> {
+  let primitive = |expr: &Expr| -> Option<Primitive> {
+    Some(match expr {
+      Expr::Lit(Lit::Str(strng)) => Primitive::String(strng.value.clone()),
+      Expr::Lit(Lit::Num(num)) => Primitive::Number(num.value),
+      Expr::Lit(Lit::Bool(bool_lit)) => Primitive::Boolean(bool_lit.value),
+      Expr::Lit(Lit::Null(_)) => Primitive::Null,
+      Expr::Lit(Lit::BigInt(big_int)) => Primitive::BigInt((*big_int.value).clone()),
+      Expr::Ident(ident) => match surviving_global(ident)? {
+        SurvivingGlobal::Undefined => Primitive::Undefined,
+        SurvivingGlobal::NaN => Primitive::Number(f64::NAN),
+        SurvivingGlobal::Infinity => Primitive::Number(f64::INFINITY),
+      },
+      Expr::Unary(unary) if unary.op == UnaryOp::Void => Primitive::Undefined,
+      _ => return None,
+    })
+  };
+
+  // `NaN` is unequal to itself and the two zeroes are equal, which is exactly
+  // what `f64`'s `==` answers, so numbers need no case of their own.
+  Some(primitive(left)? == primitive(right)?)
+}
+
+#[derive(PartialEq)]
+enum Primitive {
+  String(Wtf8Atom),
+  Number(f64),
+  Boolean(bool),
+  BigInt(BigIntValue),
+  Null,
+  Undefined,
+}
+
 /// ECMA-262 `StringToNumber`: the value of the numeric literal a string
 /// spells, or `NaN` if it spells anything else.
 ///
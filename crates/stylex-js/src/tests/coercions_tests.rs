@@ -1035,3 +1035,42 @@ fn a_value_this_crate_cannot_read_is_not_nullish() {
   assert!(!is_nullish(&empty_object_expr()));
   assert!(!is_nullish(&arrow_expr()));
 }
+
+#[test]
+fn strict_equality_compares_primitives_by_value_and_type() {
+  assert_eq!(strict_equals(&str_expr("sm"), &str_expr("sm")), Some(true));
+  assert_eq!(strict_equals(&str_expr("sm"), &str_expr("md")), Some(false));
+  assert_eq!(strict_equals(&num_expr(1.0), &str_expr("1")), Some(false));
+  assert_eq!(strict_equals(&num_expr(0.0), &num_expr(-0.0)), Some(true));
+  assert_eq!(strict_equals(&bool_expr(true), &num_expr(1.0)), Some(false));
+  assert_eq!(
+    strict_equals(&null_expr(), &ident_expr("undefined")),
+    Some(false)
+  );
+  assert_eq!(
+    strict_equals(&ident_expr("undefined"), &ident_expr("undefined")),
+    Some(true)
+  );
+}
+
+#[test]
+fn nan_is_not_strictly_equal_to_itself() {
+  assert_eq!(
+    strict_equals(&ident_expr("NaN"), &ident_expr("NaN")),
+    Some(false)
+  );
+  assert_eq!(
+    strict_equals(&num_expr(f64::NAN), &num_expr(f64::NAN)),
+    Some(false)
+  );
+}
+
+#[test]
+fn objects_have_no_compile_time_identity_to_compare() {
+  assert_eq!(
+    strict_equals(&empty_object_expr(), &empty_object_expr()),
+    None
+  );
+  assert_eq!(strict_equals(&array_expr(vec![]), &num_expr(0.0)), None);
+  assert_eq!(strict_equals(&ident_expr("foo"), &num_expr(0.0)), None);
+}
@@ -0,0 +1,276 @@
+//! Structured diagnostics handed to the callers of a transform.
+//!
+//! A `StyleXError` is rendered into a panic message, which is all a caller
+//! sees of it once the panic is caught. A [`Diagnostic`] keeps its parts apart
+//! instead — code, severity, message, location, suggestion — so a bundler
+//! overlay or a lint reporter can render them, and can be reported without
+//! stopping the transform, as a warning is.
+
+use std::{any::Any, borrow::Cow, cell::RefCell};
+
+use serde::{Deserialize, Serialize};
+use stylex_constants::logger::STYLEX_LOG_PREFIX;
+
+use crate::stylex_error::{StyleXError, format_panic_message, strip_ansi};
+
+/// Stable identifiers for kinds of diagnostic. A reporter can key on these;
+/// messages are free to change wording.
+pub mod codes {
+  /// A StyleX error raised without a more specific code.
+  pub const TRANSFORM_ERROR: &str = "stylex/transform-error";
+  /// A panic that is not a StyleX error, or an unreachable branch: a compiler
+  /// bug rather than a mistake in the source.
+  pub const INTERNAL_ERROR: &str = "stylex/internal-error";
+  /// Syntax the compiler does not support yet.
+  pub const UNSUPPORTED: &str = "stylex/unsupported";
+  /// The file could not be parsed.
+  pub const PARSE_ERROR: &str = "stylex/parse-error";
+  /// A style value that does not match its property's grammar.
+  pub const INVALID_PROPERTY_VALUE: &str = "stylex/invalid-property-value";
+  /// A shorthand given a fallback array, an expression or a value it cannot
+  /// expand.
+  pub const INVALID_SHORTHAND: &str = "stylex/invalid-shorthand";
+  /// A StyleX call that is not assigned to a variable.
+  pub const UNBOUND_CALL: &str = "stylex/unbound-call";
+  /// A `defineVars`, `defineConsts` or `defineMarker` call that is not a named
+  /// export.
+  pub const NON_EXPORTED_DEFINE: &str = "stylex/non-exported-define";
+  /// A StyleX call given the wrong number of arguments.
+  pub const ILLEGAL_ARGUMENT_LENGTH: &str = "stylex/illegal-argument-length";
+  /// An argument that cannot be evaluated at compile time.
+  pub const NON_STATIC_VALUE: &str = "stylex/non-static-value";
+  /// An argument that has to be an object literal of styles and is not.
+  pub const NON_STYLE_OBJECT: &str = "stylex/non-style-object";
+  /// An object spread inside `stylex.create`.
+  pub const NO_OBJECT_SPREADS: &str = "stylex/no-object-spreads";
+  /// A style value that is neither a string, a number nor `null`, or a
+  /// fallback array holding one.
+  pub const ILLEGAL_PROP_VALUE: &str = "stylex/illegal-prop-value";
+  /// A key inside a property's conditions that is neither a pseudo-class, an
+  /// at-rule nor `default`.
+  pub const INVALID_CONDITION: &str = "stylex/invalid-condition";
+  /// The same condition nested inside itself.
+  pub const DUPLICATE_CONDITIONAL: &str = "stylex/duplicate-conditional";
+  /// An `@supports` or `@container` condition that does not parse.
+  pub const INVALID_AT_RULE_CONDITION: &str = "stylex/invalid-at-rule-condition";
+  /// A dynamic style function with a parameter that is not a plain name.
+  pub const INVALID_DYNAMIC_STYLE_PARAM: &str = "stylex/invalid-dynamic-style-param";
+  /// A `keyframes` frame that is not an object.
+  pub const NON_OBJECT_KEYFRAME: &str = "stylex/non-object-keyframe";
+  /// A property that `positionTry` or `viewTransitionClass` does not accept.
+  pub const INVALID_PROPERTY: &str = "stylex/invalid-property";
+  /// A `createTheme` override of something other than a `defineVars` group.
+  pub const INVALID_THEME_OVERRIDE: &str = "stylex/invalid-theme-override";
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+  Error,
+  Warning,
+}
+
+/// A range of source text. Lines and columns count from 1, and the end column
+/// is the one just past the last character, as ESLint reports them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceSpan {
+  pub line: usize,
+  pub column: usize,
+  pub end_line: usize,
+  pub end_column: usize,
+}
+
+/// Another place in the source that explains a diagnostic, such as the call
+/// the offending value belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedSpan {
+  pub message: String,
+  pub file: Option<String>,
+  pub span: SourceSpan,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+  pub code: Cow<'static, str>,
+  pub severity: Severity,
+  /// The message without the `[StyleX]` prefix or the location, led by the
+  /// key path when there is one.
+  pub message: String,
+  pub file: Option<String>,
+  pub span: Option<SourceSpan>,
+  pub suggestion: Option<String>,
+  pub related: Vec<RelatedSpan>,
+}
+
+impl Diagnostic {
+  pub fn from_error(error: &StyleXError, severity: Severity) -> Self {
+    let message = strip_ansi(&error.message);
+    let message = match &error.key_path {
+      Some(key_path) if !key_path.is_empty() => format!("{} > {}", key_path.join(" > "), message),
+      _ => message.into_owned(),
+    };
+
+    Diagnostic {
+      code: error
+        .code
+        .clone()
+        .unwrap_or(Cow::Borrowed(codes::TRANSFORM_ERROR)),
+      severity,
+      message,
+      file: error.file.as_ref().map(|file| file.to_string()),
+      span: error.span,
+      suggestion: error
+        .suggestion
+        .as_ref()
+        .map(|suggestion| suggestion.to_string()),
+      related: error.related.clone(),
+    }
+  }
+
+  /// The error for a panic payload that did not come from a `StyleXError`.
+  fn internal_error(payload: &Box<dyn Any + Send>) -> Self {
+    let message = format_panic_message(payload);
+    let message = message
+      .strip_prefix(STYLEX_LOG_PREFIX)
+      .map_or(message.as_str(), str::trim_start)
+      .to_string();
+
+    Diagnostic {
+      code: Cow::Borrowed(codes::INTERNAL_ERROR),
+      severity: Severity::Error,
+      message,
+      file: None,
+      span: None,
+      suggestion: None,
+      related: vec![],
+    }
+  }
+}
+
+/// Records `error` as a warning of the transform running on this thread. The
+/// caller still logs it; nothing is recorded outside a [`DiagnosticCollector`].
+pub fn report_warning(error: &StyleXError) {
+  DIAGNOSTICS.with(|diagnostics| {
+    if let Some(diagnostics) = diagnostics.borrow_mut().as_mut() {
+      diagnostics.push(Diagnostic::from_error(error, Severity::Warning));
+    }
+  });
+}
+
+/// Records the error a caught panic carried as an error of the transform
+/// running on this thread, for a transform that recovers from it and goes on.
+/// Nothing is recorded outside a [`DiagnosticCollector`].
+pub fn report_panic(payload: &Box<dyn Any + Send>) {
+  let diagnostic = panic_diagnostic(payload);
+
+  DIAGNOSTICS.with(|diagnostics| {
+    if let Some(diagnostics) = diagnostics.borrow_mut().as_mut() {
+      diagnostics.push(diagnostic);
+    }
+  });
+}
+
+/// The error a panic carried: the `StyleXError` raised last when the payload
+/// is its rendering, and an internal error otherwise.
+fn panic_diagnostic(payload: &Box<dyn Any + Send>) -> Diagnostic {
+  let last_panic = LAST_PANIC.with(|last_panic| last_panic.borrow_mut().take());
+  let raised = last_panic.and_then(|(rendered, diagnostic)| {
+    let carried = payload
+      .downcast_ref::<String>()
+      .map(String::as_str)
+      .or_else(|| payload.downcast_ref::<&str>().copied());
+
+    (carried == Some(rendered.as_str())).then_some(diagnostic)
+  });
+
+  raised.unwrap_or_else(|| Diagnostic::internal_error(payload))
+}
+
+/// Remembers the error a StyleX panic is about to carry, with the message it
+/// renders to, so [`DiagnosticCollector::finish_with_panic`] can recover it
+/// from the payload.
+pub(crate) fn record_panic(rendered: &str, error: &StyleXError) {
+  if DIAGNOSTICS.with(|diagnostics| diagnostics.borrow().is_none()) {
+    return;
+  }
+
+  LAST_PANIC.with(|last_panic| {
+    *last_panic.borrow_mut() = Some((
+      rendered.to_string(),
+      Diagnostic::from_error(error, Severity::Error),
+    ));
+  });
+}
+
+/// RAII guard that collects the diagnostics reported on this thread for its
+/// lifetime, one transform's worth.
+///
+/// Create one beside the `catch_unwind` around a transform, and finish it with
+/// the transform's outcome:
+///
+/// ```rust,ignore
+/// let collector = DiagnosticCollector::new();
+/// match std::panic::catch_unwind(|| { /* ... */ }) {
+///   Ok(output) => (output, collector.finish()),
+///   Err(payload) => fail(collector.finish_with_panic(&payload)),
+/// }
+/// ```
+///
+/// A collector created while another is alive collects on its own, and hands
+/// the thread back to the outer one when dropped.
+pub struct DiagnosticCollector {
+  outer: Option<Vec<Diagnostic>>,
+}
+
+impl DiagnosticCollector {
+  pub fn new() -> Self {
+    LAST_PANIC.with(|last_panic| last_panic.borrow_mut().take());
+
+    Self {
+      outer: DIAGNOSTICS.with(|diagnostics| diagnostics.borrow_mut().replace(vec![])),
+    }
+  }
+
+  /// The diagnostics reported so far, in the order they were.
+  pub fn finish(self) -> Vec<Diagnostic> {
+    DIAGNOSTICS
+      .with(|diagnostics| diagnostics.borrow_mut().take())
+      .unwrap_or_default()
+  }
+
+  /// The diagnostics reported so far, followed by the error the transform
+  /// panicked with. That is the `StyleXError` raised last when the payload is
+  /// its rendering, and an internal error otherwise.
+  pub fn finish_with_panic(self, payload: &Box<dyn Any + Send>) -> Vec<Diagnostic> {
+    let raised = panic_diagnostic(payload);
+
+    let mut diagnostics = self.finish();
+    diagnostics.push(raised);
+    diagnostics
+  }
+}
+
+impl Default for DiagnosticCollector {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Drop for DiagnosticCollector {
+  fn drop(&mut self) {
+    let outer = self.outer.take();
+    DIAGNOSTICS.with(|diagnostics| *diagnostics.borrow_mut() = outer);
+  }
+}
+
+thread_local! {
+  static DIAGNOSTICS: RefCell<Option<Vec<Diagnostic>>> = const { RefCell::new(None) };
+  static LAST_PANIC: RefCell<Option<(String, Diagnostic)>> = const { RefCell::new(None) };
+}
+
+#[cfg(test)]
+#[path = "tests/diagnostic_test.rs"]
+mod tests;
@@ -2,5 +2,6 @@
 
 pub mod collection_macros;
 pub mod conversion_macros;
+pub mod diagnostic;
 pub mod panic_macros;
 pub mod stylex_error;
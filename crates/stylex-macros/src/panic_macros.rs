@@ -6,7 +6,10 @@
 use colored::Colorize;
 use std::borrow::Cow;
 
-use crate::stylex_error::StyleXError;
+use crate::{
+  diagnostic::{codes, record_panic},
+  stylex_error::StyleXError,
+};
 
 /// Macro to unwrap a Result or panic with the error message.
 /// This is a cleaner replacement for `.unwrap_or_else(|error| panic!("{}",
@@ -48,11 +51,7 @@ macro_rules! unwrap_or_panic {
 pub fn stylex_err(message: impl Into<Cow<'static, str>>) -> StyleXError {
   StyleXError {
     message: message.into(),
-    file: None,
-    key_path: None,
-    line: None,
-    col: None,
-    source_location: None,
+    ..Default::default()
   }
 }
 
@@ -64,10 +63,7 @@ pub fn stylex_err_with_file(
   StyleXError {
     message: message.into(),
     file: Some(file.into()),
-    key_path: None,
-    line: None,
-    col: None,
-    source_location: None,
+    ..Default::default()
   }
 }
 
@@ -75,6 +71,17 @@ fn format_source_location(caller: &std::panic::Location<'_>) -> Cow<'static, str
   format!("{}:{}", caller.file(), caller.line()).into()
 }
 
+/// Panics with `err` rendered, first recording it for a
+/// [`DiagnosticCollector`](crate::diagnostic::DiagnosticCollector) to recover.
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[track_caller]
+fn raise(err: StyleXError) -> ! {
+  let rendered = err.to_string();
+  record_panic(&rendered, &err);
+
+  panic!("{}", rendered)
+}
+
 // ---------------------------------------------------------------------------
 // Internal diverging functions (called by macros — not for direct use)
 //
@@ -92,7 +99,7 @@ pub fn __stylex_panic(mut err: StyleXError) -> ! {
     err.source_location = Some(format_source_location(caller));
   }
 
-  panic!("{}", err)
+  raise(err)
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -104,8 +111,9 @@ pub fn __stylex_unimplemented(mut err: StyleXError) -> ! {
     err.source_location = Some(format_source_location(caller));
   }
   err.message = format!("{} {}", "[UNIMPLEMENTED]".dimmed().magenta(), err.message).into();
+  err.code.get_or_insert(Cow::Borrowed(codes::UNSUPPORTED));
 
-  panic!("{}", err)
+  raise(err)
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -117,8 +125,9 @@ pub fn __stylex_unreachable(mut err: StyleXError) -> ! {
     err.source_location = Some(format_source_location(caller));
   }
   err.message = format!("{} {}", "[UNREACHABLE]".dimmed().blue(), err.message).into();
+  err.code.get_or_insert(Cow::Borrowed(codes::INTERNAL_ERROR));
 
-  panic!("{}", err)
+  raise(err)
 }
 
 // ---------------------------------------------------------------------------
@@ -7,6 +7,8 @@ use colored::Colorize;
 use std::{borrow::Cow, fmt};
 use stylex_constants::logger::STYLEX_LOG_PREFIX;
 
+use crate::diagnostic::{RelatedSpan, SourceSpan};
+
 /// Structured error for all user-facing StyleX diagnostics.
 ///
 /// `Display` produces:
@@ -15,7 +17,10 @@ use stylex_constants::logger::STYLEX_LOG_PREFIX;
 ///   --> file:line
 /// [Stack trace]: source_location    (whenever source_location is set)
 /// ```
-#[derive(Debug, Clone)]
+///
+/// `code`, `span`, `suggestion` and `related` are not rendered; they are what
+/// a [`Diagnostic`](crate::diagnostic::Diagnostic) built from the error adds.
+#[derive(Debug, Clone, Default)]
 pub struct StyleXError {
   pub message: Cow<'static, str>,
   pub file: Option<Cow<'static, str>>,
@@ -23,6 +28,10 @@ pub struct StyleXError {
   pub line: Option<usize>,
   pub col: Option<usize>,
   pub source_location: Option<Cow<'static, str>>,
+  pub code: Option<Cow<'static, str>>,
+  pub span: Option<SourceSpan>,
+  pub suggestion: Option<Cow<'static, str>>,
+  pub related: Vec<RelatedSpan>,
 }
 
 impl StyleXError {
@@ -47,6 +56,30 @@ impl StyleXError {
     self.source_location = Some(source_location.into());
     self
   }
+
+  /// One of the [`codes`](crate::diagnostic::codes).
+  pub fn with_code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+    self.code = Some(code.into());
+    self
+  }
+
+  /// Sets `line` and `col` to where `span` starts, too.
+  pub fn with_span(mut self, span: SourceSpan) -> Self {
+    self.line = Some(span.line);
+    self.col = Some(span.column);
+    self.span = Some(span);
+    self
+  }
+
+  pub fn with_suggestion(mut self, suggestion: impl Into<Cow<'static, str>>) -> Self {
+    self.suggestion = Some(suggestion.into());
+    self
+  }
+
+  pub fn with_related(mut self, related: RelatedSpan) -> Self {
+    self.related.push(related);
+    self
+  }
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -144,7 +177,7 @@ impl Drop for SuppressPanicStderr {
 // ---------------------------------------------------------------------------
 
 /// Strip ANSI escape sequences from a string.
-fn strip_ansi(s: &str) -> Cow<'_, str> {
+pub(crate) fn strip_ansi(s: &str) -> Cow<'_, str> {
   if !s.as_bytes().contains(&b'\x1B') {
     return Cow::Borrowed(s);
   }
@@ -0,0 +1,159 @@
+//! Tests for Diagnostic conversion and the thread-local DiagnosticCollector.
+
+use std::panic;
+
+use crate::{
+  diagnostic::{
+    Diagnostic, DiagnosticCollector, RelatedSpan, Severity, SourceSpan, codes, report_panic,
+    report_warning,
+  },
+  panic_macros::{__stylex_panic, stylex_err},
+};
+
+fn span(line: usize, column: usize) -> SourceSpan {
+  SourceSpan {
+    line,
+    column,
+    end_line: line,
+    end_column: column + 5,
+  }
+}
+
+#[test]
+fn from_error_keeps_every_part_apart() {
+  let error = stylex_err("\x1b[31mnot a color\x1b[0m")
+    .with_location("src/App.js", 1, 1)
+    .with_key_path(vec!["root".to_string(), "color".to_string()])
+    .with_code(codes::INVALID_PROPERTY_VALUE)
+    .with_span(span(3, 10))
+    .with_suggestion("Use a <color>.")
+    .with_related(RelatedSpan {
+      message: "In this call".to_string(),
+      file: Some("src/App.js".to_string()),
+      span: span(2, 1),
+    });
+
+  let diagnostic = Diagnostic::from_error(&error, Severity::Warning);
+
+  assert_eq!(diagnostic.code, codes::INVALID_PROPERTY_VALUE);
+  assert_eq!(diagnostic.severity, Severity::Warning);
+  assert_eq!(diagnostic.message, "root > color > not a color");
+  assert_eq!(diagnostic.file.as_deref(), Some("src/App.js"));
+  assert_eq!(diagnostic.span, Some(span(3, 10)));
+  assert_eq!(diagnostic.suggestion.as_deref(), Some("Use a <color>."));
+  assert_eq!(diagnostic.related.len(), 1);
+  assert_eq!(error.line, Some(3));
+  assert_eq!(error.col, Some(10));
+}
+
+#[test]
+fn an_error_without_a_code_is_a_transform_error() {
+  let diagnostic = Diagnostic::from_error(&stylex_err("boom"), Severity::Error);
+
+  assert_eq!(diagnostic.code, codes::TRANSFORM_ERROR);
+  assert_eq!(diagnostic.span, None);
+}
+
+#[test]
+fn warnings_are_only_collected_while_a_collector_is_alive() {
+  report_warning(&stylex_err("before"));
+
+  let collector = DiagnosticCollector::new();
+  report_warning(&stylex_err("during"));
+  let diagnostics = collector.finish();
+
+  report_warning(&stylex_err("after"));
+
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0].message, "during");
+  assert_eq!(diagnostics[0].severity, Severity::Warning);
+}
+
+#[test]
+fn an_inner_collector_hands_the_thread_back_to_the_outer_one() {
+  let outer = DiagnosticCollector::new();
+  report_warning(&stylex_err("outer"));
+
+  let inner = DiagnosticCollector::new();
+  report_warning(&stylex_err("inner"));
+  let inner_diagnostics = inner.finish();
+
+  report_warning(&stylex_err("outer again"));
+  let outer_diagnostics = outer.finish();
+
+  let messages = |diagnostics: &[Diagnostic]| {
+    diagnostics
+      .iter()
+      .map(|d| d.message.clone())
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(messages(&inner_diagnostics), ["inner"]);
+  assert_eq!(messages(&outer_diagnostics), ["outer", "outer again"]);
+}
+
+#[test]
+fn finish_with_panic_recovers_the_stylex_error_raised() {
+  let collector = DiagnosticCollector::new();
+  report_warning(&stylex_err("first"));
+
+  let payload = panic::catch_unwind(|| {
+    __stylex_panic(
+      stylex_err("bad value")
+        .with_code(codes::INVALID_PROPERTY_VALUE)
+        .with_span(span(4, 2)),
+    )
+  })
+  .expect_err("panics");
+
+  let diagnostics = collector.finish_with_panic(&payload);
+
+  assert_eq!(diagnostics.len(), 2);
+  assert_eq!(diagnostics[0].message, "first");
+  assert_eq!(diagnostics[1].severity, Severity::Error);
+  assert_eq!(diagnostics[1].code, codes::INVALID_PROPERTY_VALUE);
+  assert_eq!(diagnostics[1].message, "bad value");
+  assert_eq!(diagnostics[1].span, Some(span(4, 2)));
+}
+
+#[test]
+fn finish_with_panic_reports_any_other_panic_as_internal() {
+  let collector = DiagnosticCollector::new();
+
+  // A StyleX error raised and caught inside the transform must not be
+  // mistaken for the panic that escaped it.
+  let _ = panic::catch_unwind(|| __stylex_panic(stylex_err("recovered from")));
+  let payload = panic::catch_unwind(|| panic!("index out of bounds")).expect_err("panics");
+
+  let diagnostics = collector.finish_with_panic(&payload);
+
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0].code, codes::INTERNAL_ERROR);
+  assert_eq!(diagnostics[0].message, "index out of bounds");
+}
+
+#[test]
+fn a_recovered_panic_is_collected_and_collecting_goes_on() {
+  let collector = DiagnosticCollector::new();
+
+  let first =
+    panic::catch_unwind(|| __stylex_panic(stylex_err("first call").with_span(span(1, 1))))
+      .expect_err("panics");
+  report_panic(&first);
+
+  let second = panic::catch_unwind(|| panic!("index out of bounds")).expect_err("panics");
+  report_panic(&second);
+
+  report_warning(&stylex_err("a warning"));
+
+  let diagnostics = collector.finish();
+
+  let messages: Vec<&str> = diagnostics
+    .iter()
+    .map(|diagnostic| diagnostic.message.as_str())
+    .collect();
+  assert_eq!(messages, ["first call", "index out of bounds", "a warning"]);
+  assert_eq!(diagnostics[0].code, codes::TRANSFORM_ERROR);
+  assert_eq!(diagnostics[0].severity, Severity::Error);
+  assert_eq!(diagnostics[0].span, Some(span(1, 1)));
+  assert_eq!(diagnostics[1].code, codes::INTERNAL_ERROR);
+}
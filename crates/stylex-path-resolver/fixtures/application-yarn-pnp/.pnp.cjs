@@ -0,0 +1,86 @@
+#!/usr/bin/env node
+/* eslint-disable */
+// @ts-nocheck
+"use strict";
+
+const RAW_RUNTIME_STATE =
+'{
+  "__info": [
+    "This file is automatically generated. Do not touch it, or risk",
+    "your modules being improperly resolved."
+  ],
+  "dependencyTreeRoots": [
+    {
+      "name": "application-yarn-pnp",
+      "reference": "workspace:."
+    }
+  ],
+  "enableTopLevelFallback": true,
+  "ignorePatternData": "(^(?:\\\\.yarn\\\\/sdks(?:\\\\/(?!\\\\.{1,2}(?:\\\\/|$))(?:(?:(?!(?:^|\\\\/)\\\\.{1,2}(?:\\\\/|$)).)*?)|$))$)",
+  "fallbackExclusionList": [],
+  "fallbackPool": [],
+  "packageRegistryData": [
+    [
+      null,
+      [
+        [
+          null,
+          {
+            "packageLocation": "./",
+            "packageDependencies": [
+              [
+                "@stylexjs/open-props",
+                "npm:0.1.0"
+              ]
+            ],
+            "linkType": "SOFT"
+          }
+        ]
+      ]
+    ],
+    [
+      "@stylexjs/open-props",
+      [
+        [
+          "npm:0.1.0",
+          {
+            "packageLocation": "./.yarn/cache/@stylexjs-open-props-npm-0.1.0-8c1f0e2b7a-3d9a41c6e5.zip/node_modules/@stylexjs/open-props/",
+            "packageDependencies": [
+              [
+                "@stylexjs/open-props",
+                "npm:0.1.0"
+              ]
+            ],
+            "linkType": "HARD"
+          }
+        ]
+      ]
+    ],
+    [
+      "application-yarn-pnp",
+      [
+        [
+          "workspace:.",
+          {
+            "packageLocation": "./",
+            "packageDependencies": [
+              [
+                "@stylexjs/open-props",
+                "npm:0.1.0"
+              ],
+              [
+                "application-yarn-pnp",
+                "workspace:."
+              ]
+            ],
+            "linkType": "SOFT"
+          }
+        ]
+      ]
+    ]
+  ]
+}';
+
+function $$SETUP_STATE(hydrateRuntimeState, basePath) {
+  return hydrateRuntimeState(JSON.parse(RAW_RUNTIME_STATE), {basePath: basePath || __dirname});
+}
@@ -0,0 +1,3 @@
+import { colors } from '@stylexjs/open-props/lib/colors.stylex';
+
+export { colors };
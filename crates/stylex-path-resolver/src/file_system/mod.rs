@@ -3,6 +3,8 @@ use std::{
   path::{Path, PathBuf},
 };
 
+use crate::pnp::path_exists;
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub(crate) fn _check_directory(path: &Path) -> bool {
   match fs::metadata(path) {
@@ -44,7 +46,9 @@ pub(crate) fn _get_directory_path_recursive(path: &Path) -> Option<PathBuf> {
 pub(crate) fn find_closest_path(path: &Path, target_folder_name: &str) -> Option<PathBuf> {
   let node_modules_path: PathBuf = path.join(target_folder_name);
 
-  if node_modules_path.exists() {
+  // Inside a Yarn PnP archive the walk starts in the zip and continues on disk
+  // once it climbs past it.
+  if path_exists(&node_modules_path) {
     return Some(node_modules_path);
   }
 
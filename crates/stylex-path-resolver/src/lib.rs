@@ -2,6 +2,7 @@
 
 mod file_system;
 pub mod package_json;
+pub mod pnp;
 pub mod resolvers;
 pub mod utils;
 
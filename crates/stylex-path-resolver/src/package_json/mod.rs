@@ -1,13 +1,16 @@
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, default::Default, env, fs::read_to_string};
+use std::{collections::HashMap, default::Default, env};
 use stylex_macros::stylex_panic;
 
 use package_json::{PackageDependencies, PackageJsonManager};
 use std::path::{Path, PathBuf};
 
-use crate::file_system::find_closest_path;
+use crate::{
+  file_system::find_closest_path,
+  pnp::{is_pnp_virtual_path, read_to_string},
+};
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -36,7 +39,7 @@ pub fn get_package_json(
       let file_path_string = file.display().to_string();
       let file_path = file_path_string.as_str();
       let data = package_json_seen.get(file_path).cloned().or_else(|| {
-        let data = read_to_string(file_path);
+        let data = read_to_string(&file);
 
         data.ok().map(|package_json_raw| {
           let json = serde_json::from_str::<PackageJsonExtended>(package_json_raw.as_str())
@@ -77,6 +80,18 @@ pub fn get_package_json(
 pub(crate) fn get_package_json_path(path: &Path) -> (Option<PathBuf>, PackageJsonManager) {
   let mut manager = PackageJsonManager::new();
 
+  // The manager only walks the disk, which has no entries for the files of a
+  // package kept in a Yarn PnP archive.
+  if is_pnp_virtual_path(path) {
+    let package_json_path = find_closest_package_json(path);
+
+    if let Some(package_json_path) = &package_json_path {
+      manager.set_file_path(package_json_path);
+    }
+
+    return (package_json_path, manager);
+  }
+
   match manager.locate_closest_from(path) {
     Ok(file) => (Option::Some(file), manager),
     Err(error) => {
@@ -0,0 +1,226 @@
+//! Yarn Plug'n'Play support.
+//!
+//! A PnP install has no `node_modules`: the `.pnp.cjs` runtime (or, with
+//! `pnpEnableInlining: false`, the `.pnp.data.json` beside it) maps every
+//! package locator to a location, and third-party packages stay inside the zip
+//! archives of `.yarn/cache`. A file of such a package has a path like
+//! `.yarn/cache/<archive>.zip/node_modules/<name>/lib/tokens.stylex.js`, which
+//! the filesystem does not know, so both resolving an import into a package
+//! and reading the package's own files go through this module.
+
+use log::debug;
+use once_cell::sync::Lazy;
+use pnp::{
+  Manifest, Resolution,
+  fs::{LruZipCache, VPath, VPathInfo, ZipCache, open_zip_via_read_p},
+};
+use rustc_hash::FxHashMap;
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The runtime Yarn writes at the project root of a PnP install.
+pub const PNP_RUNTIME_FILE: &str = ".pnp.cjs";
+/// The resolution data, written apart from the runtime when inlining is off.
+pub const PNP_DATA_FILE: &str = ".pnp.data.json";
+
+/// Archives kept open at once. A build reads the few packages that ship
+/// themes many times, so a small window avoids reopening them.
+const ZIP_CACHE_SIZE: u64 = 64;
+
+/// Parsed manifests by the directory holding them, `None` for one that failed
+/// to load, so a project's manifest is read once per process.
+static MANIFESTS: Lazy<Mutex<FxHashMap<PathBuf, Option<Arc<Manifest>>>>> =
+  Lazy::new(Default::default);
+
+static ZIP_CACHE: Lazy<LruZipCache<Vec<u8>>> =
+  Lazy::new(|| LruZipCache::new(ZIP_CACHE_SIZE, open_zip_via_read_p));
+
+/// The closest directory above `path` holding a PnP manifest.
+pub fn find_pnp_root(path: &Path) -> Option<PathBuf> {
+  path
+    .ancestors()
+    .find(|dir| dir.join(PNP_RUNTIME_FILE).is_file() || dir.join(PNP_DATA_FILE).is_file())
+    .map(Path::to_path_buf)
+}
+
+/// The PnP manifest of the project `path` belongs to, or `None` outside a PnP
+/// install. A manifest that cannot be read is logged and treated as absent.
+pub(crate) fn find_pnp_manifest(path: &Path) -> Option<Arc<Manifest>> {
+  let pnp_root = find_pnp_root(path)?;
+
+  let mut manifests = MANIFESTS.lock().unwrap_or_else(|error| error.into_inner());
+
+  manifests
+    .entry(pnp_root)
+    .or_insert_with_key(|pnp_root| load_pnp_manifest(pnp_root).map(Arc::new))
+    .clone()
+}
+
+/// Forgets every manifest read so far, so the next resolution in a project
+/// reads its manifest again, as after an install.
+pub fn clear_pnp_manifests() {
+  MANIFESTS
+    .lock()
+    .unwrap_or_else(|error| error.into_inner())
+    .clear();
+}
+
+fn load_pnp_manifest(pnp_root: &Path) -> Option<Manifest> {
+  let data_path = pnp_root.join(PNP_DATA_FILE);
+
+  let manifest = if data_path.is_file() {
+    fs::read_to_string(&data_path)
+      .map_err(|error| error.to_string())
+      .and_then(|data| serde_json::from_str::<Manifest>(&data).map_err(|error| error.to_string()))
+      .map(|mut manifest| {
+        // Package locations are relative to the directory of the manifest,
+        // whichever of the two files carries it.
+        pnp::init_pnp_manifest(&mut manifest, &pnp_root.join(PNP_RUNTIME_FILE));
+        manifest
+      })
+  } else {
+    pnp::load_pnp_manifest(&pnp_root.join(PNP_RUNTIME_FILE)).map_err(|error| error.to_string())
+  };
+
+  manifest
+    .inspect_err(|error| {
+      debug!(
+        "Could not load the PnP manifest of {}: {}",
+        pnp_root.display(),
+        error
+      )
+    })
+    .ok()
+}
+
+/// Resolves a bare import from `source_file_dir` through the PnP manifest of
+/// its project: the locator of the importing package gives the dependency's
+/// location, and the import is then resolved inside that package by
+/// `resolve_in_package`, given the package directory and the specifier to
+/// resolve from it.
+///
+/// Returns `None` outside a PnP install, for an import that is not a bare
+/// specifier, and for a dependency the importing package does not declare.
+pub(crate) fn resolve_pnp_import<F>(
+  import_path: &str,
+  source_file_dir: &Path,
+  resolve_in_package: F,
+) -> Option<PathBuf>
+where
+  F: Fn(&Path, &str) -> Option<PathBuf>,
+{
+  if import_path.starts_with(['.', '/', '#']) {
+    return None;
+  }
+
+  let manifest = find_pnp_manifest(source_file_dir)?;
+
+  // The manifest matches the importing package by a directory path with a
+  // trailing separator.
+  let resolution =
+    pnp::resolve_to_unqualified_via_manifest(&manifest, import_path, &source_file_dir.join(""));
+
+  match resolution {
+    Ok(Resolution::Resolved(package_dir, subpath)) => {
+      // The package's own `exports` first, through a self-reference, then the
+      // subpath as a file of the package.
+      let subpath = subpath.map_or_else(|| ".".to_string(), |subpath| format!("./{subpath}"));
+
+      resolve_in_package(&package_dir, import_path)
+        .or_else(|| resolve_in_package(&package_dir, &subpath))
+    },
+    Ok(Resolution::Skipped) => None,
+    Err(error) => {
+      debug!("PnP could not resolve '{}': {}", import_path, error);
+      None
+    },
+  }
+}
+
+/// Whether `path` exists, looking inside zip archives and through Yarn's
+/// `__virtual__` folders.
+pub fn path_exists(path: &Path) -> bool {
+  if path.exists() {
+    return true;
+  }
+
+  match VPath::from(path) {
+    Ok(VPath::Zip(info)) => ZIP_CACHE
+      .file_type(info.physical_base_path(), &info.zip_path)
+      .is_ok(),
+    Ok(VPath::Virtual(info)) => info.physical_base_path().exists(),
+    Ok(VPath::Native(_)) | Err(_) => false,
+  }
+}
+
+/// Reads `path` as [`std::fs::read_to_string`] does, looking inside zip
+/// archives and through Yarn's `__virtual__` folders.
+///
+/// # Errors
+///
+/// Returns the error of the archive or of the file read.
+pub fn read_to_string(path: &Path) -> std::io::Result<String> {
+  on_disk_or_pnp(
+    path,
+    |path| fs::read_to_string(path),
+    |vpath| match vpath {
+      VPath::Zip(info) => ZIP_CACHE.read_to_string(info.physical_base_path(), &info.zip_path),
+      VPath::Virtual(info) => fs::read_to_string(info.physical_base_path()),
+      VPath::Native(path) => fs::read_to_string(path),
+    },
+  )
+}
+
+/// The metadata of the file on disk holding `path`: the zip archive for a file
+/// inside one, whose modification time then stands for the file's own.
+///
+/// # Errors
+///
+/// Returns the error of [`std::fs::metadata`].
+pub fn disk_metadata(path: &Path) -> std::io::Result<fs::Metadata> {
+  on_disk_or_pnp(
+    path,
+    |path| fs::metadata(path),
+    |vpath| match vpath {
+      VPath::Zip(info) => fs::metadata(info.physical_base_path()),
+      VPath::Virtual(info) => fs::metadata(info.physical_base_path()),
+      VPath::Native(path) => fs::metadata(path),
+    },
+  )
+}
+
+/// Whether `path` only exists through PnP: inside a zip archive or behind a
+/// `__virtual__` folder.
+pub fn is_pnp_virtual_path(path: &Path) -> bool {
+  !path.exists() && matches!(VPath::from(path), Ok(VPath::Zip(_) | VPath::Virtual(_)))
+}
+
+/// Runs `on_disk` on `path`, and `through_pnp` on its PnP reading only when
+/// nothing is there: a real folder may be named `__virtual__` or end in `.zip`.
+fn on_disk_or_pnp<T>(
+  path: &Path,
+  on_disk: impl Fn(&Path) -> std::io::Result<T>,
+  through_pnp: impl Fn(VPath) -> std::io::Result<T>,
+) -> std::io::Result<T> {
+  match on_disk(path) {
+    // Through a zip, the disk reports the archive as a file in the way.
+    Err(error)
+      if matches!(
+        error.kind(),
+        std::io::ErrorKind::NotFound | std::io::ErrorKind::NotADirectory
+      ) =>
+    {
+      match VPath::from(path)? {
+        VPath::Native(_) => Err(error),
+        vpath => through_pnp(vpath),
+      }
+    },
+    result => result,
+  }
+}
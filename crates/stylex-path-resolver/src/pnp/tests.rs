@@ -0,0 +1,144 @@
+//! Tests for Yarn Plug'n'Play manifest discovery, resolution through package
+//! locators, and reads from zip archives.
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use rustc_hash::FxHashMap;
+
+use super::{PNP_DATA_FILE, PNP_RUNTIME_FILE, find_pnp_root, path_exists, read_to_string};
+use crate::{
+  package_json::{PackageJsonExtended, find_closest_package_json_folder, get_package_json},
+  resolvers::resolve_file_path,
+};
+
+const ARCHIVE: &str = ".yarn/cache/@stylexjs-open-props-npm-0.1.0-8c1f0e2b7a-3d9a41c6e5.zip";
+const PACKAGE_DIR: &str = "node_modules/@stylexjs/open-props";
+
+fn fixture_root() -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/application-yarn-pnp")
+}
+
+fn temp_dir(prefix: &str) -> PathBuf {
+  let nanos = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .unwrap()
+    .as_nanos();
+  let dir = std::env::temp_dir().join(format!("stylex-path-resolver-{prefix}-{nanos}"));
+  fs::create_dir_all(&dir).unwrap();
+  dir
+}
+
+fn archived_file(root: &Path, path: &str) -> PathBuf {
+  root.join(ARCHIVE).join(PACKAGE_DIR).join(path)
+}
+
+fn resolve_from_app(root: &Path, import_path: &str) -> std::io::Result<PathBuf> {
+  resolve_file_path(
+    import_path,
+    root.join("src/app.js").to_str().unwrap(),
+    root.to_str().unwrap(),
+    &FxHashMap::default(),
+    None,
+    &mut FxHashMap::<String, PackageJsonExtended>::default(),
+  )
+}
+
+/// The manifest is found from anywhere below the project root, including from
+/// inside an archive.
+#[test]
+fn finds_pnp_root() {
+  let root = fixture_root();
+
+  assert_eq!(find_pnp_root(&root.join("src/app.js")), Some(root.clone()));
+  assert_eq!(
+    find_pnp_root(&archived_file(&root, "lib/colors.stylex.js")),
+    Some(root)
+  );
+  assert_eq!(
+    find_pnp_root(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/application-npm")),
+    None
+  );
+}
+
+/// Files inside an archive exist and read like files on disk.
+#[test]
+fn reads_files_inside_archives() {
+  let root = fixture_root();
+  let theme = archived_file(&root, "lib/colors.stylex.js");
+
+  assert!(path_exists(&theme));
+  assert!(path_exists(&archived_file(&root, "lib")));
+  assert!(!path_exists(&archived_file(&root, "lib/missing.js")));
+  assert!(
+    read_to_string(&theme)
+      .unwrap()
+      .contains("stylex.defineVars")
+  );
+}
+
+/// The package a theme file belongs to is read from the package.json inside
+/// its archive, not from the project's own.
+#[test]
+fn reads_package_json_inside_archives() {
+  let root = fixture_root();
+  let package_dir =
+    find_closest_package_json_folder(&archived_file(&root, "lib/colors.stylex.js")).unwrap();
+
+  assert_eq!(package_dir, archived_file(&root, ""));
+
+  let (package_json, _) = get_package_json(&package_dir, &mut FxHashMap::default());
+
+  assert_eq!(package_json.name.as_deref(), Some("@stylexjs/open-props"));
+}
+
+/// A dependency resolves through its locator to the file inside its archive.
+#[test]
+fn resolves_dependency_through_manifest() {
+  let root = fixture_root();
+
+  assert_eq!(
+    resolve_from_app(&root, "@stylexjs/open-props/lib/colors.stylex").unwrap(),
+    archived_file(&root, "lib/colors.stylex.js")
+  );
+}
+
+/// A package the project does not declare is not resolved.
+#[test]
+fn undeclared_dependency_is_not_found() {
+  let err =
+    resolve_from_app(&fixture_root(), "@stylexjs/undeclared/lib/colors.stylex").unwrap_err();
+
+  assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
+
+/// With inlining off, the manifest is read from `.pnp.data.json`.
+#[test]
+fn resolves_dependency_through_data_file() {
+  let fixture = fixture_root();
+  let root = temp_dir("pnp-data-file");
+
+  let runtime = fs::read_to_string(fixture.join(PNP_RUNTIME_FILE)).unwrap();
+  let data = runtime.split('\'').nth(1).unwrap().replace("\\\\", "\\");
+
+  for path in [ARCHIVE, "package.json", "src/app.js"] {
+    fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+    fs::copy(fixture.join(path), root.join(path)).unwrap();
+  }
+  fs::write(root.join(PNP_DATA_FILE), data).unwrap();
+  fs::write(
+    root.join(PNP_RUNTIME_FILE),
+    "const RAW_RUNTIME_STATE = require('./.pnp.data.json');\n",
+  )
+  .unwrap();
+
+  assert_eq!(
+    resolve_from_app(&root, "@stylexjs/open-props/lib/colors.stylex").unwrap(),
+    archived_file(&root, "lib/colors.stylex.js")
+  );
+
+  fs::remove_dir_all(root).unwrap();
+}
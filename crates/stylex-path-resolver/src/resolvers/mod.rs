@@ -1,7 +1,7 @@
 use fancy_regex::Regex;
 use log::debug;
 use once_cell::sync::Lazy;
-use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_resolver::{ResolveContext, ResolveOptions, Resolver, TsconfigDiscovery};
 use path_clean::PathClean;
 use rustc_hash::FxHashMap;
 use std::{
@@ -12,6 +12,7 @@ use stylex_macros::stylex_panic;
 
 use crate::{
   package_json::{PackageJsonExtended, get_package_json_deps},
+  pnp::resolve_pnp_import,
   utils::{contains_subpath, relative_path},
 };
 
@@ -230,6 +231,47 @@ static RESOLVER: Lazy<Resolver> = Lazy::new(|| {
   Resolver::new(options)
 });
 
+/// The resolver for imports that a project config may map: it applies the
+/// `paths` and `baseUrl` of the `tsconfig.json` that owns the importing file,
+/// following `extends` chains and project references the way `tsc` does.
+///
+/// It shares the cache of [`RESOLVER`], which holds every parsed config, so a
+/// tsconfig is read once however many files it owns.
+static TSCONFIG_RESOLVER: Lazy<Resolver> = Lazy::new(|| {
+  RESOLVER.clone_with_options(ResolveOptions {
+    tsconfig: Some(TsconfigDiscovery::Auto),
+    ..RESOLVER.options().clone()
+  })
+});
+
+/// The resolver for the inside of a package a Yarn PnP install keeps in a zip
+/// archive, which it reads as a directory. Which package an import names is
+/// left to the PnP manifest; see [`resolve_pnp_import`].
+static PNP_RESOLVER: Lazy<Resolver> = Lazy::new(|| {
+  RESOLVER.clone_with_options(ResolveOptions {
+    yarn_pnp: true,
+    ..RESOLVER.options().clone()
+  })
+});
+
+/// Forgets every file, `package.json` and project config the resolvers have
+/// looked at, for a long-lived process whose files changed under it. The next
+/// resolution reads them from disk again.
+///
+/// No resolution may be in flight: one running alongside can return a result
+/// from the half-cleared cache.
+pub fn clear_resolver_cache() {
+  // The resolvers built with `clone_with_options` share this cache, except
+  // the PnP one, which gets a cache of its own.
+  RESOLVER.clear_cache();
+  PNP_RESOLVER.clear_cache();
+}
+
+/// The project configs that can map an import, in the order a directory is
+/// searched for them: a `jsconfig.json` only counts where no `tsconfig.json`
+/// sits beside it, as in the TypeScript language service.
+const PROJECT_CONFIG_FILES: [&str; 2] = ["tsconfig.json", "jsconfig.json"];
+
 pub(crate) fn file_not_found_error(import_path: &str) -> std::io::Error {
   std::io::Error::new(
     std::io::ErrorKind::NotFound,
@@ -238,7 +280,11 @@ pub(crate) fn file_not_found_error(import_path: &str) -> std::io::Error {
 }
 
 /// Resolves an import path to its filesystem location, consulting aliases,
-/// package.json, and `oxc_resolver`.
+/// package.json, the project config of the importing file, the Yarn PnP
+/// manifest, and `oxc_resolver`.
+///
+/// Explicit aliases are tried before the project config, so an alias
+/// overrides a tsconfig `paths` entry for the same specifier.
 ///
 /// Many branches depend on real filesystem state (existence of files with
 /// particular extensions, node_modules layouts, symlinks, etc.) that cannot
@@ -349,6 +395,28 @@ pub fn resolve_file_path(
     }
   }
 
+  // Resolve through the project config of the importing file: tsconfig
+  // `paths`/`baseUrl` and package.json `#imports`
+  if let Some(resolved) = try_resolve_with_project_config(import_path_str, source_file_path) {
+    return Ok(resolved);
+  }
+
+  // Resolve through the Yarn PnP manifest, for installs without node_modules
+  if let Some(resolved) = resolve_pnp_import(
+    import_path_str,
+    source_file_dir,
+    |package_dir, specifier| {
+      PNP_RESOLVER
+        .resolve(package_dir, specifier)
+        .ok()
+        .map(|resolution| resolution.full_path().clean())
+    },
+  ) {
+    debug!("Yarn PnP resolved to: {}", resolved.display());
+
+    return Ok(resolved);
+  }
+
   // Use oxc_resolver for node_modules resolution
   debug!(
     "Resolving import '{}' from directory '{}'",
@@ -501,6 +569,78 @@ fn try_resolve_pnpm_path(resolved_path: &Path) -> PathBuf {
   resolved_path.to_path_buf()
 }
 
+/// Resolves an import through the nearest project config above the importing
+/// file, which maps it by `paths` and `baseUrl`; package.json `#imports` are
+/// read along the way. Returns `None` when the file has no config above it
+/// outside `node_modules`, or the import does not resolve through it.
+///
+/// A `tsconfig.json` is found by [`TSCONFIG_RESOLVER`] itself, which also picks
+/// the referenced project that owns the file. A `jsconfig.json` has no
+/// references to follow and is loaded directly.
+fn try_resolve_with_project_config(import_path: &str, source_file_path: &str) -> Option<PathBuf> {
+  let config_path = nearest_project_config(Path::new(source_file_path))?;
+
+  let resolution = if config_path.ends_with("tsconfig.json") {
+    TSCONFIG_RESOLVER.resolve_file(source_file_path, import_path)
+  } else {
+    let source_file_dir = Path::new(source_file_path).parent()?;
+
+    TSCONFIG_RESOLVER
+      .resolve_tsconfig(&config_path)
+      .and_then(|jsconfig| {
+        TSCONFIG_RESOLVER.resolve_with_context(
+          source_file_dir,
+          import_path,
+          Some(&jsconfig),
+          &mut ResolveContext::default(),
+        )
+      })
+  };
+
+  match resolution {
+    Ok(resolution) => {
+      let resolved_path = resolution.full_path();
+
+      debug!(
+        "{} resolved '{}' to: {}",
+        config_path.display(),
+        import_path,
+        resolved_path.display()
+      );
+
+      Some(try_resolve_pnpm_path(&resolved_path).clean())
+    },
+    Err(err) => {
+      debug!(
+        "{} did not resolve '{}': {}",
+        config_path.display(),
+        import_path,
+        err
+      );
+      None
+    },
+  }
+}
+
+/// The `tsconfig.json` or `jsconfig.json` closest above `source_file_path`.
+/// Files inside `node_modules` are compiled by their package's own rules and
+/// get none.
+fn nearest_project_config(source_file_path: &Path) -> Option<PathBuf> {
+  if source_file_path
+    .components()
+    .any(|component| component.as_os_str() == "node_modules")
+  {
+    return None;
+  }
+
+  source_file_path.ancestors().skip(1).find_map(|dir| {
+    PROJECT_CONFIG_FILES
+      .iter()
+      .map(|file_name| dir.join(file_name))
+      .find(|config_path| config_path.is_file())
+  })
+}
+
 fn try_resolve_as_module(import_path: &Path, base_dir: &Path) -> Option<PathBuf> {
   let import_path = import_path.to_str()?;
   RESOLVER
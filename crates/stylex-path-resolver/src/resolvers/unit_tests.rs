@@ -1,5 +1,6 @@
 //! Unit tests for path resolver internal helpers: `file_not_found_error`
-//! and `possible_aliased_paths`.
+//! and `possible_aliased_paths`, and `resolve_file_path` over fixture and
+//! temporary projects.
 
 use std::{
   fs,
@@ -413,3 +414,252 @@ fn resolve_path_panics_when_strip_prefix_fails() {
   let mut package_json_seen = FxHashMap::<String, PackageJsonExtended>::default();
   let _ = crate::resolvers::resolve_path(processing_file, root_dir, &mut package_json_seen);
 }
+
+/// Writes `files` into a fresh temporary project and returns its root.
+fn temp_project(prefix: &str, files: &[(&str, &str)]) -> PathBuf {
+  let root = temp_dir(prefix);
+
+  for (path, contents) in files {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+  }
+
+  root
+}
+
+/// Resolves `import_path` from `source_file` inside `root`.
+fn resolve_in_project(
+  root: &std::path::Path,
+  source_file: &str,
+  import_path: &str,
+  aliases: &FxHashMap<String, Vec<String>>,
+) -> std::io::Result<PathBuf> {
+  let mut package_json_seen = FxHashMap::<String, PackageJsonExtended>::default();
+
+  resolve_file_path(
+    import_path,
+    root.join(source_file).to_str().unwrap(),
+    root.to_str().unwrap(),
+    aliases,
+    None,
+    &mut package_json_seen,
+  )
+}
+
+const TOKENS_FILE: (&str, &str) = (
+  "src/tokens/colors.stylex.ts",
+  "export const colors = stylex.defineVars({});",
+);
+
+const APP_FILE: (&str, &str) = ("src/app/page.tsx", "export {};");
+
+/// `paths` and `baseUrl` of the nearest tsconfig.json map an import without a
+/// matching StyleX alias.
+#[test]
+fn resolve_file_path_uses_tsconfig_paths() {
+  let root = temp_project(
+    "tsconfig-paths",
+    &[
+      (
+        "tsconfig.json",
+        r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@/tokens/*": ["src/tokens/*"] } } }"#,
+      ),
+      TOKENS_FILE,
+      APP_FILE,
+    ],
+  );
+
+  let resolved = resolve_in_project(
+    &root,
+    APP_FILE.0,
+    "@/tokens/colors.stylex",
+    &FxHashMap::default(),
+  )
+  .unwrap();
+
+  assert_eq!(resolved, root.join(TOKENS_FILE.0));
+
+  fs::remove_dir_all(root).unwrap();
+}
+
+/// `paths` inherited through `extends` apply as if written in the tsconfig.
+#[test]
+fn resolve_file_path_follows_tsconfig_extends() {
+  let root = temp_project(
+    "tsconfig-extends",
+    &[
+      (
+        "config/tsconfig.base.json",
+        r#"{ "compilerOptions": { "paths": { "@tokens/*": ["../src/tokens/*"] } } }"#,
+      ),
+      (
+        "tsconfig.json",
+        r#"{ "extends": "./config/tsconfig.base.json", "include": ["src"] }"#,
+      ),
+      TOKENS_FILE,
+      APP_FILE,
+    ],
+  );
+
+  let resolved = resolve_in_project(
+    &root,
+    APP_FILE.0,
+    "@tokens/colors.stylex",
+    &FxHashMap::default(),
+  )
+  .unwrap();
+
+  assert_eq!(resolved, root.join(TOKENS_FILE.0));
+
+  fs::remove_dir_all(root).unwrap();
+}
+
+/// A solution-style tsconfig.json defers to the referenced project that
+/// includes the importing file.
+#[test]
+fn resolve_file_path_follows_tsconfig_project_references() {
+  let root = temp_project(
+    "tsconfig-references",
+    &[
+      (
+        "tsconfig.json",
+        r#"{ "files": [], "references": [{ "path": "./tsconfig.node.json" }, { "path": "./tsconfig.app.json" }] }"#,
+      ),
+      ("tsconfig.node.json", r#"{ "include": ["vite.config.ts"] }"#),
+      (
+        "tsconfig.app.json",
+        r#"{ "compilerOptions": { "paths": { "~/*": ["./src/*"] } }, "include": ["src"] }"#,
+      ),
+      TOKENS_FILE,
+      APP_FILE,
+    ],
+  );
+
+  let resolved = resolve_in_project(
+    &root,
+    APP_FILE.0,
+    "~/tokens/colors.stylex",
+    &FxHashMap::default(),
+  )
+  .unwrap();
+
+  assert_eq!(resolved, root.join(TOKENS_FILE.0));
+
+  fs::remove_dir_all(root).unwrap();
+}
+
+/// A JavaScript project maps imports through jsconfig.json the same way.
+#[test]
+fn resolve_file_path_uses_jsconfig_paths() {
+  let root = temp_project(
+    "jsconfig-paths",
+    &[
+      (
+        "jsconfig.json",
+        r#"{ "compilerOptions": { "baseUrl": "src" } }"#,
+      ),
+      ("src/tokens/colors.stylex.js", "export const colors = {};"),
+      ("src/app/page.jsx", "export {};"),
+    ],
+  );
+
+  let resolved = resolve_in_project(
+    &root,
+    "src/app/page.jsx",
+    "tokens/colors.stylex",
+    &FxHashMap::default(),
+  )
+  .unwrap();
+
+  assert_eq!(resolved, root.join("src/tokens/colors.stylex.js"));
+
+  fs::remove_dir_all(root).unwrap();
+}
+
+/// package.json `imports` subpath patterns resolve `#` specifiers.
+#[test]
+fn resolve_file_path_uses_package_imports() {
+  let root = temp_project(
+    "package-imports",
+    &[
+      (
+        "package.json",
+        r##"{ "name": "app", "imports": { "#tokens/*": "./src/tokens/*.ts" } }"##,
+      ),
+      TOKENS_FILE,
+      APP_FILE,
+    ],
+  );
+
+  let resolved = resolve_in_project(
+    &root,
+    APP_FILE.0,
+    "#tokens/colors.stylex",
+    &FxHashMap::default(),
+  )
+  .unwrap();
+
+  assert_eq!(resolved, root.join(TOKENS_FILE.0));
+
+  fs::remove_dir_all(root).unwrap();
+}
+
+/// An explicit StyleX alias for the same specifier wins over tsconfig `paths`.
+#[test]
+fn resolve_file_path_prefers_aliases_over_tsconfig_paths() {
+  let root = temp_project(
+    "tsconfig-alias-override",
+    &[
+      (
+        "tsconfig.json",
+        r#"{ "compilerOptions": { "paths": { "@/tokens/*": ["./src/tokens/*"] } } }"#,
+      ),
+      TOKENS_FILE,
+      (
+        "theme/tokens/colors.stylex.ts",
+        "export const colors = stylex.defineVars({});",
+      ),
+      APP_FILE,
+    ],
+  );
+
+  let mut aliases = FxHashMap::<String, Vec<String>>::default();
+  aliases.insert(
+    "@/tokens/*".to_string(),
+    vec![root.join("theme/tokens/*").display().to_string()],
+  );
+
+  let resolved = resolve_in_project(&root, APP_FILE.0, "@/tokens/colors.stylex", &aliases).unwrap();
+
+  assert_eq!(resolved, root.join("theme/tokens/colors.stylex.ts"));
+
+  fs::remove_dir_all(root).unwrap();
+}
+
+/// A tsconfig mapping to a missing file leaves the import unresolved.
+#[test]
+fn resolve_file_path_tsconfig_paths_missing_target_is_not_found() {
+  let root = temp_project(
+    "tsconfig-missing-target",
+    &[
+      (
+        "tsconfig.json",
+        r#"{ "compilerOptions": { "paths": { "@/tokens/*": ["./src/tokens/*"] } } }"#,
+      ),
+      APP_FILE,
+    ],
+  );
+
+  let err = resolve_in_project(
+    &root,
+    APP_FILE.0,
+    "@/tokens/colors.stylex",
+    &FxHashMap::default(),
+  )
+  .unwrap_err();
+
+  assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+
+  fs::remove_dir_all(root).unwrap();
+}
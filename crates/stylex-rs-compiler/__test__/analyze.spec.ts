@@ -0,0 +1,35 @@
+import { expect, test } from 'vitest';
+
+import { analyze } from '../dist/index.js';
+
+const source = `import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: { float: 'inline-start', color: 'red' },
+});
+`;
+
+const options = {
+  unstable_moduleResolution: { type: 'commonJS' as const },
+};
+
+test('analyze returns the rules of the property under the offset', () => {
+  const analysis = analyze('page.tsx', source, source.indexOf('float'), options);
+
+  expect(analysis?.kind).toBe('style');
+  expect(analysis?.style?.namespace).toBe('root');
+  expect(analysis?.style?.property).toBe('float');
+  expect(analysis?.style?.rules).toHaveLength(1);
+  expect(analysis?.style?.rules[0].ltr).toContain('float:left');
+  expect(analysis?.style?.rules[0].rtl).toContain('float:right');
+});
+
+test('analyze returns null for an offset on nothing StyleX compiled', () => {
+  expect(analyze('page.tsx', source, 0, options)).toBeNull();
+});
+
+test('analyze returns null for an excluded file', () => {
+  expect(
+    analyze('page.tsx', source, source.indexOf('float'), { ...options, exclude: ['**/*.tsx'] })
+  ).toBeNull();
+});
@@ -0,0 +1,38 @@
+import { expect, test } from 'vitest';
+
+import { check } from '../dist/index.js';
+
+const source = `import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: { color: 'not-a-color' },
+});
+
+export const empty = stylex.create();
+`;
+
+const options = {
+  propertyValidationMode: 'throw' as const,
+  unstable_moduleResolution: { type: 'commonJS' as const },
+};
+
+test('check reports every failing call instead of throwing at the first', () => {
+  const diagnostics = check('page.tsx', source, options);
+
+  expect(diagnostics.map(({ code, severity }) => [code, severity])).toEqual([
+    ['stylex/invalid-property-value', 'error'],
+    ['stylex/illegal-argument-length', 'error'],
+  ]);
+  expect(diagnostics.map(({ span }) => span?.line)).toEqual([4, 7]);
+});
+
+test('check reports a file that does not parse', () => {
+  const diagnostics = check('page.tsx', 'const a = ;', options);
+
+  expect(diagnostics).toHaveLength(1);
+  expect(diagnostics[0].code).toBe('stylex/parse-error');
+});
+
+test('check returns nothing for an excluded file', () => {
+  expect(check('page.tsx', source, { ...options, exclude: ['**/*.tsx'] })).toEqual([]);
+});
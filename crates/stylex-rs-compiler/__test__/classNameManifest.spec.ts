@@ -0,0 +1,49 @@
+import { expect, test } from 'vitest';
+
+import { transform, updateClassNameManifest } from '../dist/index.js';
+
+const code = `
+  import * as stylex from '@stylexjs/stylex';
+
+  export const styles = stylex.create({
+    root: { color: 'red' },
+  });
+`;
+
+const classNameKeys = (classNamePrefix: string) =>
+  transform('page.tsx', code, {
+    classNamePrefix,
+    emitClassNameKeys: true,
+    unstable_moduleResolution: { type: 'commonJS' },
+  }).classNameKeys ?? {};
+
+test('records the class name of each declaration', () => {
+  const { manifest, renamed, collisions } = updateClassNameManifest([classNameKeys('x')]);
+
+  expect(JSON.parse(manifest).classNames).toEqual({ '<>colorrednull': 'x1e2nbdu' });
+  expect(renamed).toEqual([]);
+  expect(collisions).toEqual([]);
+});
+
+test('reports a declaration whose class name changed', () => {
+  const { manifest } = updateClassNameManifest([classNameKeys('x')]);
+  const { renamed } = updateClassNameManifest([classNameKeys('y')], manifest);
+
+  expect(renamed).toEqual([{ key: '<>colorrednull', from: 'x1e2nbdu', to: 'y1e2nbdu' }]);
+});
+
+test('a frozen manifest refuses a rename', () => {
+  const { manifest } = updateClassNameManifest([classNameKeys('x')]);
+
+  expect(() => updateClassNameManifest([classNameKeys('y')], manifest, true)).toThrow(
+    /frozen, but this build renames 1 class/
+  );
+});
+
+test('leaves classNameKeys out unless asked for', () => {
+  const result = transform('page.tsx', code, {
+    unstable_moduleResolution: { type: 'commonJS' },
+  });
+
+  expect(result.classNameKeys).toBeUndefined();
+});
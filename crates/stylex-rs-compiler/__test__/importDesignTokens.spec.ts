@@ -0,0 +1,52 @@
+import * as path from 'path';
+
+import { expect, test } from 'vitest';
+
+import { importDesignTokens, transform } from '../dist/index.js';
+
+const cwd = process.cwd();
+const filename = path.join(cwd, 'tokens.stylex.ts');
+
+const tokens = JSON.stringify({
+  color: {
+    $type: 'color',
+    accent: { $value: '#0a84ff', $extensions: { mode: { dark: '#64d2ff' } } },
+    link: { $value: '{color.accent}' },
+  },
+});
+
+const options = {
+  dev: false,
+  unstable_moduleResolution: { type: 'commonJS', rootDir: cwd },
+} as const;
+
+test('importDesignTokens generates the module and compiles it', () => {
+  const { source, result } = importDesignTokens(filename, tokens, options);
+
+  expect(source).toContain('export const tokens = stylex.unstable_defineVarsNested({');
+  expect(source).toContain("link: '#0a84ff',");
+  expect(source).toContain('export const darkTheme = stylex.unstable_createThemeNested(tokens, {');
+  expect(result.metadata.stylex).toHaveLength(2);
+});
+
+test('importDesignTokens compiles like the generated module written by hand', () => {
+  const { source, result } = importDesignTokens(filename, tokens, options);
+
+  expect(result.code).toBe(transform(filename, source, options).code);
+});
+
+test('importDesignTokens folds modes named in modeConditions into the variables', () => {
+  const { source } = importDesignTokens(filename, tokens, options, {
+    exportName: 'colors',
+    modeConditions: { dark: '@media (prefers-color-scheme: dark)' },
+  });
+
+  expect(source).toContain("'@media (prefers-color-scheme: dark)': '#64d2ff',");
+  expect(source).not.toContain('darkTheme');
+});
+
+test('importDesignTokens reports an alias to a missing token', () => {
+  expect(() =>
+    importDesignTokens(filename, JSON.stringify({ a: { $value: '{b}' } }), options)
+  ).toThrow('refers to "b", which is not a token');
+});
@@ -2,11 +2,13 @@
 // an absent property and one set to `undefined` as different. `toEqual` does
 // not, so it would accept a compiler result that grew or lost an
 // undefined-valued field. See the note in `normalizeRsOptions.spec.ts`.
+import * as fs from 'fs';
+import * as os from 'os';
 import * as path from 'path';
 
 import { expect, test } from 'vitest';
 
-import { transform, normalizeRsOptions } from '../dist/index.js';
+import { type StyleXDiagnostic, transform, normalizeRsOptions } from '../dist/index.js';
 
 const cwd = process.cwd();
 
@@ -79,6 +81,7 @@ export const styles = {
           3000,
         ],
       ],
+      dependencies: [],
     },
     // Key order matches the serializer's. `sourcesContent` holds the authored
     // text and `mappings` carries columns — both on by default, see
@@ -90,6 +93,7 @@ export const styles = {
       names: [],
       mappings: 'AACI,OAAO,YAAY,mBAAmB;AAEtC,OAAO,MAAM;;;;;;;;EAOV',
     }),
+    diagnostics: [],
   };
 
   expect(result).toStrictEqual(expected);
@@ -106,7 +110,7 @@ test('transform: skips file excluded by include pattern', () => {
   // File doesn't match include — should return code unmodified
   const result = transform(path.join(cwd, 'lib/file.ts'), code, options);
   expect(result.code).toBe(code);
-  expect(result.metadata).toStrictEqual({ stylex: [] });
+  expect(result.metadata).toStrictEqual({ stylex: [], dependencies: [] });
 });
 
 test('transform: skips file matching exclude pattern', () => {
@@ -117,7 +121,7 @@ test('transform: skips file matching exclude pattern', () => {
 
   const result = transform(path.join(cwd, 'src/file.test.tsx'), code, options);
   expect(result.code).toBe(code);
-  expect(result.metadata).toStrictEqual({ stylex: [] });
+  expect(result.metadata).toStrictEqual({ stylex: [], dependencies: [] });
 });
 
 test('transform: processes file matching include and not matching exclude', () => {
@@ -174,7 +178,7 @@ test('transform: exclude takes precedence over include', () => {
 
   const result = transform(path.join(cwd, 'src/internal/Secret.tsx'), code, options);
   expect(result.code, 'excluded file should not be transformed').toBe(code);
-  expect(result.metadata).toStrictEqual({ stylex: [] });
+  expect(result.metadata).toStrictEqual({ stylex: [], dependencies: [] });
 });
 
 // ── transform() edge cases ──────────────────────────────────────────
@@ -190,7 +194,7 @@ test('transform: non-stylex code passes through without metadata', () => {
   });
 
   const result = transform('app.tsx', code, options);
-  expect(result.metadata).toStrictEqual({ stylex: [] });
+  expect(result.metadata).toStrictEqual({ stylex: [], dependencies: [] });
   expect(result.code.length > 0, 'should still have code output').toBeTruthy();
 });
 
@@ -201,7 +205,7 @@ test('transform: empty file returns empty output', () => {
   });
 
   const result = transform('empty.tsx', '', options);
-  expect(result.metadata).toStrictEqual({ stylex: [] });
+  expect(result.metadata).toStrictEqual({ stylex: [], dependencies: [] });
 });
 
 test('transform: filtered file returns exact original code', () => {
@@ -254,7 +258,7 @@ test('transform: regex exclude pattern works', () => {
 
   const result = transform(path.join(cwd, 'src/Button.stories.tsx'), code, options);
   expect(result.code, '.stories file should be excluded').toBe(code);
-  expect(result.metadata).toStrictEqual({ stylex: [] });
+  expect(result.metadata).toStrictEqual({ stylex: [], dependencies: [] });
 });
 
 test('transform: multiple include patterns - match any', () => {
@@ -305,7 +309,7 @@ test('transform: glob pattern with curly braces', () => {
   // .ts and .tsx should pass include filter (then go to native)
   // .js should NOT match include filter
   expect(resultJs.code, '.js should not match include').toBe(code);
-  expect(resultJs.metadata).toStrictEqual({ stylex: [] });
+  expect(resultJs.metadata).toStrictEqual({ stylex: [], dependencies: [] });
 });
 
 // The at-rule priority is deliberately left unrounded; `var_group_priority` in
@@ -335,3 +339,131 @@ test('transform: at-rule priority reaches metadata unrounded', () => {
     0.1, 0.5, 0.6000000000000001,
   ]);
 });
+
+test('transform: reports the theme files and package.json it read', () => {
+  const root = fs.realpathSync(fs.mkdtempSync(path.join(os.tmpdir(), 'stylex-dependencies-')));
+  fs.writeFileSync(path.join(root, 'package.json'), JSON.stringify({ name: 'app' }));
+  fs.writeFileSync(
+    path.join(root, 'tokens.stylex.js'),
+    `import * as stylex from '@stylexjs/stylex';
+    export const colors = stylex.defineVars({ accent: 'red' });`
+  );
+
+  const code = `
+    import * as stylex from '@stylexjs/stylex';
+    import { colors } from './tokens.stylex';
+    export const styles = stylex.create({ root: { color: colors.accent } });
+  `;
+
+  const result = transform(path.join(root, 'page.tsx'), code, {
+    dev: false,
+    unstable_moduleResolution: { type: 'commonJS', rootDir: root },
+  });
+
+  expect(result.metadata.dependencies).toStrictEqual([
+    path.join(root, 'tokens.stylex.js'),
+    path.join(root, 'package.json'),
+  ]);
+});
+
+test('transform: returns warnings as diagnostics', () => {
+  const code = `
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({ root: { color: 'not-a-color' } });
+  `;
+
+  const result = transform(path.join(cwd, 'page.tsx'), code, {
+    dev: false,
+    propertyValidationMode: 'warn',
+    unstable_moduleResolution: { type: 'commonJS', rootDir: cwd },
+  });
+
+  expect(result.diagnostics).toHaveLength(1);
+  expect(result.diagnostics[0]).toMatchObject({
+    code: 'stylex/invalid-property-value',
+    severity: 'warning',
+    message: '"not-a-color" is not a valid value for color. Expected <color>.',
+    file: path.join(cwd, 'page.tsx'),
+    suggestion: 'Use a value matching <color>.',
+  });
+  expect(result.diagnostics[0].span).toBeDefined();
+});
+
+test('transform: throws an error carrying its diagnostics', () => {
+  const code = `
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({ root: { color: 'not-a-color' } });
+  `;
+
+  let thrown: unknown;
+  try {
+    transform(path.join(cwd, 'page.tsx'), code, {
+      dev: false,
+      propertyValidationMode: 'throw',
+      unstable_moduleResolution: { type: 'commonJS', rootDir: cwd },
+    });
+  } catch (error) {
+    thrown = error;
+  }
+
+  expect(thrown).toBeInstanceOf(Error);
+  expect((thrown as Error).message).toContain('is not a valid value for color');
+  expect((thrown as { diagnostics: StyleXDiagnostic[] }).diagnostics).toMatchObject([
+    { code: 'stylex/invalid-property-value', severity: 'error' },
+  ]);
+});
+
+test('transform: reports unused styles when asked to', () => {
+  const code = `import * as stylex from '@stylexjs/stylex';
+const styles = stylex.create({
+  root: { color: 'red' },
+  hidden: { display: 'none' },
+});
+export const App = () => <div {...stylex.props(styles.root)} />;
+`;
+
+  const options = {
+    dev: false,
+    unstable_moduleResolution: { type: 'commonJS', rootDir: cwd },
+  } as const;
+
+  expect(transform(path.join(cwd, 'page.tsx'), code, options).unusedStyles).toBeUndefined();
+
+  const { unusedStyles } = transform(path.join(cwd, 'page.tsx'), code, {
+    ...options,
+    reportUnusedStyles: true,
+  });
+
+  expect(unusedStyles?.namespaces).toMatchObject([
+    { variable: 'styles', namespace: 'hidden', span: { line: 4, column: 3 } },
+  ]);
+  expect(unusedStyles?.tokens).toStrictEqual([]);
+  expect(unusedStyles?.deadClassNames).toStrictEqual(unusedStyles?.namespaces[0].classNames);
+});
+
+test('transform: describes theme exports when asked to', () => {
+  const code = `import * as stylex from '@stylexjs/stylex';
+export const colors = stylex.defineVars({
+  accent: 'blue',
+  background: stylex.types.color('white'),
+});
+`;
+
+  const options = {
+    dev: false,
+    unstable_moduleResolution: { type: 'commonJS', rootDir: cwd },
+  } as const;
+
+  expect(
+    transform(path.join(cwd, 'tokens.stylex.ts'), code, options).declarations
+  ).toBeUndefined();
+
+  const { declarations } = transform(path.join(cwd, 'tokens.stylex.ts'), code, {
+    ...options,
+    emitThemeDeclarations: true,
+  });
+
+  expect(declarations).toContain('export declare const colors: VarGroup<');
+  expect(declarations).toContain('accent: string;');
+  expect(declarations).toContain('background: ReturnType<typeof stylex.types.color>;');
+});
@@ -16,8 +16,11 @@ const defaultResult: StyleXOptions = {
   test: false,
   debug: false,
   enableFontSizePxToRem: false,
+  enableColorNormalization: false,
+  enableAtRuleNormalization: false,
   enableInlinedConditionalMerge: true,
   enableLegacyValueFlipping: false,
+  enableNativeLogicalStyles: false,
   importSources: ['stylex', '@stylexjs/stylex'],
   runtimeInjection: false,
   treeshakeCompensation: false,
@@ -32,6 +35,9 @@ const defaultResult: StyleXOptions = {
   enableMediaQueryOrder: true,
   enableDebugClassNames: false,
   propertyValidationMode: 'silent',
+  reportUnusedStyles: false,
+  emitThemeDeclarations: false,
+  emitClassNameKeys: false,
   include: [],
   exclude: [],
   swcPlugins: [],
@@ -187,6 +193,13 @@ test('normalizeRsOptions: enableLegacyValueFlipping - empty input', () => {
   expect(result.enableLegacyValueFlipping).toBe(false);
 });
 
+test('normalizeRsOptions: enableNativeLogicalStyles default and override', () => {
+  expect(normalizeRsOptions({}).enableNativeLogicalStyles).toBe(false);
+  expect(normalizeRsOptions({ enableNativeLogicalStyles: true }).enableNativeLogicalStyles).toBe(
+    true
+  );
+});
+
 test('normalizeRsOptions: enableLTRRTLComments - true input', () => {
   const result = normalizeRsOptions({ enableLTRRTLComments: true });
   expect(result.enableLTRRTLComments).toBe(true);
@@ -291,6 +304,8 @@ test('normalizeRsOptions: multiple boolean options override defaults correctly',
     test: true,
     debug: true,
     enableFontSizePxToRem: true,
+    enableColorNormalization: true,
+    enableAtRuleNormalization: true,
     enableMinifiedKeys: false,
     enableInlinedConditionalMerge: false,
   });
@@ -298,6 +313,8 @@ test('normalizeRsOptions: multiple boolean options override defaults correctly',
   expect(result.test).toBe(true);
   expect(result.debug).toBe(true);
   expect(result.enableFontSizePxToRem).toBe(true);
+  expect(result.enableColorNormalization).toBe(true);
+  expect(result.enableAtRuleNormalization).toBe(true);
   expect(result.enableMinifiedKeys).toBe(false);
   expect(result.enableInlinedConditionalMerge).toBe(false);
 });
@@ -421,6 +438,21 @@ test('normalizeRsOptions: useRealFileForSource default and override', () => {
   expect(normalizeRsOptions({ useRealFileForSource: false }).useRealFileForSource).toBe(false);
 });
 
+test('normalizeRsOptions: reportUnusedStyles default and override', () => {
+  expect(normalizeRsOptions({}).reportUnusedStyles).toBe(false);
+  expect(normalizeRsOptions({ reportUnusedStyles: true }).reportUnusedStyles).toBe(true);
+});
+
+test('normalizeRsOptions: emitThemeDeclarations default and override', () => {
+  expect(normalizeRsOptions({}).emitThemeDeclarations).toBe(false);
+  expect(normalizeRsOptions({ emitThemeDeclarations: true }).emitThemeDeclarations).toBe(true);
+});
+
+test('normalizeRsOptions: emitClassNameKeys default and override', () => {
+  expect(normalizeRsOptions({}).emitClassNameKeys).toBe(false);
+  expect(normalizeRsOptions({ emitClassNameKeys: true }).emitClassNameKeys).toBe(true);
+});
+
 test('normalizeRsOptions: inlineSourcesContent default and override', () => {
   expect(normalizeRsOptions({}).inlineSourcesContent).toBe(true);
   expect(normalizeRsOptions({ inlineSourcesContent: false }).inlineSourcesContent).toBe(false);
@@ -0,0 +1,109 @@
+import { expect, test } from 'vitest';
+
+import { processStylexRules, transform } from '../dist/index.js';
+
+const fixture = `
+  import * as stylex from '@stylexjs/stylex';
+
+  export const styles = stylex.create({
+    default: {
+      padding: 10,
+      backgroundColor: 'red',
+      float: 'start',
+    },
+  });
+`;
+
+const rules = () =>
+  transform('page.tsx', fixture, {
+    unstable_moduleResolution: { type: 'commonJS' },
+  }).metadata.stylex;
+
+test('returns an empty stylesheet for no rules', () => {
+  expect(processStylexRules([])).toBe('');
+});
+
+test('raises specificity per priority bucket without layers', () => {
+  expect(processStylexRules(rules(), { useLayers: false })).toBe(
+    [
+      '.x7z7khe{padding:10px}',
+      '.xrkmrrc:not(#\\#){background-color:red}',
+      "html:not([dir='rtl']) .xrbpyxo:not(#\\#){float:left}",
+      "html[dir='rtl'] .xrbpyxo:not(#\\#){float:right}",
+    ].join('\n')
+  );
+});
+
+test('accepts a boolean as shorthand for useLayers', () => {
+  expect(processStylexRules(rules(), true)).toBe(
+    processStylexRules(rules(), { useLayers: true })
+  );
+});
+
+test('places the priority layers between before and after', () => {
+  const css = processStylexRules(rules(), {
+    useLayers: { before: ['reset'], after: ['theme'], prefix: 'stylex' },
+  });
+
+  expect(css.startsWith('\n@layer reset, stylex.priority1, stylex.priority2, theme;\n')).toBe(
+    true
+  );
+});
+
+test('names bucket layers for what they hold', () => {
+  const css = processStylexRules(rules(), { useLayers: { naming: 'bucket', prefix: 'stylex' } });
+
+  expect(
+    css.startsWith(
+      '\n@layer stylex.shorthands-of-shorthands, stylex.longhands;\n'
+    )
+  ).toBe(true);
+  expect(css).toContain('@layer stylex.longhands{\n.xrkmrrc{background-color:red}\n');
+});
+
+test('flattens specificity with :where() instead of raising it', () => {
+  expect(processStylexRules(rules(), { flattenSpecificity: true })).toBe(
+    [
+      ':where(.x7z7khe){padding:10px}',
+      ':where(.xrkmrrc){background-color:red}',
+      ":where(html:not([dir='rtl']) .xrbpyxo){float:left}",
+      ":where(html[dir='rtl'] .xrbpyxo){float:right}",
+    ].join('\n')
+  );
+});
+
+test('gives each rule of a transform its bucket layer', () => {
+  const { metadata } = transform('page.tsx', fixture, {
+    unstable_moduleResolution: { type: 'commonJS' },
+    useLayers: { naming: 'bucket', prefix: 'stylex' },
+  });
+
+  expect(metadata.stylex.map(([className, { layer }]) => [className, layer])).toEqual([
+    ['x7z7khe', 'stylex.shorthands-of-shorthands'],
+    ['xrkmrrc', 'stylex.longhands'],
+    ['xrbpyxo', 'stylex.longhands'],
+  ]);
+});
+
+test('scopes RTL overrides with :dir() on the element', () => {
+  expect(processStylexRules(rules(), { useLayers: false, useDirPseudoClass: true })).toBe(
+    [
+      '.x7z7khe{padding:10px}',
+      '.xrkmrrc:not(#\\#){background-color:red}',
+      '.xrbpyxo:not(#\\#):dir(ltr){float:left}',
+      '.xrbpyxo:not(#\\#):dir(rtl){float:right}',
+    ].join('\n')
+  );
+});
+
+test('keeps logical styles native in a single rule', () => {
+  const { metadata } = transform('page.tsx', fixture, {
+    unstable_moduleResolution: { type: 'commonJS' },
+    enableNativeLogicalStyles: true,
+  });
+
+  expect(metadata.stylex.find(([className]) => className === 'xrbpyxo')?.[1]).toEqual({
+    ltr: '.xrbpyxo{float:inline-start}',
+    rtl: null,
+  });
+});
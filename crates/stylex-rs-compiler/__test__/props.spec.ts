@@ -0,0 +1,51 @@
+import { expect, test } from 'vitest';
+
+import { props } from '../dist/index.js';
+
+const base = { $$css: 'Button.js:3', color: 'x1e2nbdu', display: 'x78zum5' };
+const active = { $$css: 'Button.js:7', color: 'x1a2a7pz' };
+
+test('merges compiled styles as stylex.props does', () => {
+  expect(props([base, false, [null, active, undefined]])).toEqual({
+    className: 'x78zum5 x1a2a7pz',
+    'data-style-src': 'Button.js:3; Button.js:7',
+  });
+});
+
+test('lets a later inline style override the compiled ones', () => {
+  const result = props([
+    { $$css: true, color: 'x1e2nbdu', display: 'x78zum5' },
+    { color: 'red', opacity: 0.5 },
+  ]);
+
+  expect(result).toEqual({ className: 'x78zum5', style: { color: 'red', opacity: 0.5 } });
+});
+
+test('leaves out every key when there is nothing to merge', () => {
+  expect(props([null, false, []])).toEqual({});
+});
+
+test('keeps the inline style of a later argument without mixing', () => {
+  const styles = [{ color: 'red' }, { $$css: true, color: 'x1e2nbdu' }];
+
+  expect(props(styles).style).toBeUndefined();
+  expect(props(styles, { disableMix: true }).style).toEqual({ color: 'red' });
+});
+
+test('skips an undefined value of a compiled dynamic style', () => {
+  const dynamic = (color?: string) => [
+    { $$css: true, color: 'x14rh7hd' },
+    { '--x-color': color != null ? color : undefined },
+  ];
+
+  expect(props([dynamic()])).toEqual({ className: 'x14rh7hd' });
+  expect(props([{ '--x-color': 'red' }, dynamic()])).toEqual({ className: 'x14rh7hd' });
+  expect(props([dynamic('red')])).toEqual({
+    className: 'x14rh7hd',
+    style: { '--x-color': 'red' },
+  });
+});
+
+test('rejects an inline value that is not a string, number, boolean, null or undefined', () => {
+  expect(() => props([{ color: {} }])).toThrow(/Unsupported value for style property 'color'/);
+});
@@ -0,0 +1,43 @@
+import { expect, test } from 'vitest';
+
+import { stylesheetReport, transform } from '../dist/index.js';
+
+const compile = (code: string) =>
+  transform('page.tsx', code, {
+    unstable_moduleResolution: { type: 'commonJS' },
+  }).metadata.stylex;
+
+const button = compile(`
+  import * as stylex from '@stylexjs/stylex';
+
+  export const styles = stylex.create({
+    root: { color: 'red', marginInlineStart: 4 },
+  });
+`);
+
+const card = compile(`
+  import * as stylex from '@stylexjs/stylex';
+
+  export const styles = stylex.create({
+    root: { color: 'red', padding: 8 },
+  });
+`);
+
+test('reports each class once, however many files bring it in', () => {
+  const report = JSON.parse(stylesheetReport({ 'Button.tsx': button, 'Card.tsx': card }));
+
+  expect(report.rules).toBe(3);
+  expect(report.properties.find(({ property }) => property === 'color')).toMatchObject({
+    rules: 1,
+    values: 1,
+    uses: 2,
+  });
+  expect(report.files.map(({ file, ownRules }) => [file, ownRules])).toEqual([
+    ['Button.tsx', 1],
+    ['Card.tsx', 1],
+  ]);
+});
+
+test('reports an empty build as empty', () => {
+  expect(JSON.parse(stylesheetReport({}))).toMatchObject({ rules: 0, bytes: 0, files: [] });
+});
@@ -0,0 +1,86 @@
+import { expect, test } from 'vitest';
+
+import { transform, transformAsync, transformBatch } from '../dist/index.js';
+
+const fixture = (color: string) => `
+  import * as stylex from '@stylexjs/stylex';
+
+  export const styles = stylex.create({
+    default: {
+      color: '${color}',
+    },
+  });
+`;
+
+const options = {
+  unstable_moduleResolution: { type: 'commonJS' as const },
+};
+
+test('transformAsync resolves to what transform returns', async () => {
+  const result = await transformAsync('page.tsx', fixture('red'), options);
+
+  expect(result).toEqual(transform('page.tsx', fixture('red'), options));
+});
+
+test('transformAsync rejects a function debugFilePath', async () => {
+  await expect(
+    transformAsync('page.tsx', fixture('red'), {
+      ...options,
+      debugFilePath: (filePath: string) => filePath,
+    })
+  ).rejects.toThrow('debugFilePath');
+});
+
+test('transformBatch returns one result per file, in order', () => {
+  const results = transformBatch(
+    [
+      { filename: 'a.tsx', code: fixture('red') },
+      { filename: 'b.tsx', code: fixture('blue') },
+    ],
+    options
+  );
+
+  expect(results.map(({ filename }) => filename)).toEqual(['a.tsx', 'b.tsx']);
+  expect(results[0].result).toEqual(transform('a.tsx', fixture('red'), options));
+  expect(results[1].result).toEqual(transform('b.tsx', fixture('blue'), options));
+});
+
+test('transformBatch reports a failing file without aborting the rest', () => {
+  const results = transformBatch(
+    [
+      { filename: 'broken.tsx', code: 'export const = ;' },
+      { filename: 'ok.tsx', code: fixture('red') },
+    ],
+    options
+  );
+
+  expect(results[0].result).toBeUndefined();
+  expect(results[0].error).toBeTruthy();
+  expect(results[0].diagnostics?.map(({ code }) => code)).toEqual(['stylex/parse-error']);
+  expect(results[1].error).toBeUndefined();
+  expect(results[1].result?.metadata.stylex).toHaveLength(1);
+});
+
+test('transformBatch leaves excluded files untouched', () => {
+  const code = fixture('red');
+  const [result] = transformBatch([{ filename: 'vendor/a.tsx', code }], {
+    ...options,
+    exclude: ['vendor/**'],
+  });
+
+  expect(result.result).toEqual({
+    code,
+    metadata: { stylex: [], dependencies: [] },
+    map: undefined,
+    diagnostics: [],
+  });
+});
+
+test('transformBatch rejects env functions', () => {
+  expect(() =>
+    transformBatch([{ filename: 'a.tsx', code: fixture('red') }], {
+      ...options,
+      env: { tokens: () => 'red' },
+    })
+  ).toThrow('env.tokens');
+});
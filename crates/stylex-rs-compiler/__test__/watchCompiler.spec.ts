@@ -0,0 +1,87 @@
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+
+import { expect, test } from 'vitest';
+
+import { StyleXCompiler, transform } from '../dist/index.js';
+
+const fixture = (color: string) => `
+  import * as stylex from '@stylexjs/stylex';
+
+  export const styles = stylex.create({
+    default: {
+      color: '${color}',
+      display: 'flex',
+    },
+  });
+`;
+
+function project() {
+  const root = fs.realpathSync(fs.mkdtempSync(path.join(os.tmpdir(), 'stylex-watch-')));
+  fs.writeFileSync(path.join(root, 'package.json'), JSON.stringify({ name: 'app' }));
+
+  const options = {
+    unstable_moduleResolution: { type: 'commonJS', rootDir: root },
+  } as const;
+
+  return { root, options };
+}
+
+test('StyleXCompiler returns what transform returns, with every rule added', () => {
+  const { root, options } = project();
+  const filename = path.join(root, 'page.tsx');
+  const compiler = new StyleXCompiler(options);
+
+  const { result, added, removed } = compiler.transform(filename, fixture('red'));
+
+  expect(result).toEqual(transform(filename, fixture('red'), options));
+  expect(added).toEqual(result.metadata.stylex);
+  expect(removed).toEqual([]);
+});
+
+test('StyleXCompiler reports only the rules an edit changed', () => {
+  const { root, options } = project();
+  const filename = path.join(root, 'page.tsx');
+  const compiler = new StyleXCompiler(options);
+
+  const red = compiler.transform(filename, fixture('red'));
+  const blue = compiler.transform(filename, fixture('blue'));
+  const display = red.result.metadata.stylex.find(rule => rule[1].ltr.includes('display'));
+
+  expect(blue.added).toHaveLength(1);
+  expect(blue.removed).toHaveLength(1);
+  expect(blue.added).not.toContainEqual(display);
+  expect(blue.removed).not.toContainEqual(display);
+  expect(compiler.transform(filename, fixture('blue'))).toMatchObject({ added: [], removed: [] });
+});
+
+test('StyleXCompiler.invalidate compiles changed files again from disk', () => {
+  const { root, options } = project();
+  const filename = path.join(root, 'page.tsx');
+  const compiler = new StyleXCompiler(options);
+
+  fs.writeFileSync(filename, fixture('red'));
+  compiler.transform(filename, fixture('red'));
+
+  fs.writeFileSync(filename, fixture('blue'));
+  const [delta, ...rest] = compiler.invalidate([filename]);
+
+  expect(rest).toEqual([]);
+  expect(delta.filename).toBe(filename);
+  expect(delta.error).toBeUndefined();
+  expect(delta.added).toHaveLength(1);
+  expect(delta.removed).toHaveLength(1);
+
+  fs.rmSync(filename);
+  const [deleted] = compiler.invalidate([filename]);
+
+  expect(deleted.added).toEqual([]);
+  expect(deleted.removed).toHaveLength(2);
+});
+
+test('StyleXCompiler refuses swcPlugins', () => {
+  expect(
+    () => new StyleXCompiler({ swcPlugins: [['@swc/plugin-emotion', {}]] })
+  ).toThrow('swcPlugins');
+});
@@ -1,12 +1,14 @@
 use log::warn;
 use napi::{
-  Env, Error, JsValue, NapiRaw, Unknown,
-  bindgen_prelude::{FromNapiValue, ToNapiValue},
+  Env, Error, JsValue, NapiRaw, Unknown, ValueType,
+  bindgen_prelude::{FromNapiValue, Null, Object, ToNapiValue},
   sys::{napi_env, napi_value},
 };
 use napi_derive::napi;
+use stylex_constants::constants::common::COMPILED_KEY;
 use stylex_regex::regex::NPM_NAME_REGEX;
 use stylex_structures::named_import_source::NamedImportSource;
+use stylex_styleq::{RuntimeStyleValue, StyleMap, StyleqInput};
 
 #[napi(object)]
 #[derive(Debug, Clone)]
@@ -17,7 +19,7 @@ pub struct StyleXModuleResolution {
 }
 
 #[napi(string_enum)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, serde::Serialize)]
 pub enum SourceMaps {
   True,
   False,
@@ -213,6 +215,153 @@ impl ToNapiValue for SxPropNameUnion {
   }
 }
 
+/// Layer placement accepted by `useLayers` in `processStylexRules`.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct StyleXLayersOptions {
+  pub before: Option<Vec<String>>,
+  pub after: Option<Vec<String>>,
+  pub prefix: Option<String>,
+  /// `'sequential'` (the default) numbers the layers `priority1`,
+  /// `priority2`, … over the buckets a build has; `'bucket'` names each for
+  /// what it holds, such as `longhands`, the same in every build.
+  #[napi(ts_type = "'sequential' | 'bucket'")]
+  pub naming: Option<LayerNaming>,
+}
+
+#[napi(string_enum)]
+#[derive(Debug, Clone, Copy)]
+pub enum LayerNaming {
+  #[napi(value = "sequential")]
+  Sequential,
+  #[napi(value = "bucket")]
+  Bucket,
+}
+
+/// Represents the `useLayers` option: a boolean switch, or an object placing
+/// the priority layers among the caller's own.
+#[derive(Debug, Clone)]
+pub enum UseLayersUnion {
+  Enabled(bool),
+  Layers(StyleXLayersOptions),
+}
+
+impl FromNapiValue for UseLayersUnion {
+  unsafe fn from_napi_value(env: napi_env, value: napi::sys::napi_value) -> Result<Self, Error> {
+    // Try to parse as boolean first
+    if let Ok(bool_value) = unsafe { bool::from_napi_value(env, value) } {
+      return Ok(UseLayersUnion::Enabled(bool_value));
+    }
+
+    let layers = unsafe { StyleXLayersOptions::from_napi_value(env, value) }?;
+
+    Ok(UseLayersUnion::Layers(layers))
+  }
+}
+
+impl ToNapiValue for UseLayersUnion {
+  unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value, Error> {
+    match value {
+      UseLayersUnion::Enabled(b) => unsafe { bool::to_napi_value(env, b) },
+      UseLayersUnion::Layers(layers) => unsafe { StyleXLayersOptions::to_napi_value(env, layers) },
+    }
+  }
+}
+
+/// One argument of `props`, read the way the runtime `styleq` reads it: an
+/// array is walked into, a style object is merged, and `null`, `undefined`
+/// and booleans are skipped.
+#[derive(Debug, Clone)]
+pub struct StyleXPropsArgument(pub StyleqInput<RuntimeStyleValue>);
+
+impl FromNapiValue for StyleXPropsArgument {
+  unsafe fn from_napi_value(env: napi_env, value: napi::sys::napi_value) -> Result<Self, Error> {
+    let js_unknown = unsafe { Unknown::from_napi_value(env, value) }?;
+
+    if js_unknown.get_type()? != ValueType::Object {
+      return Ok(StyleXPropsArgument(StyleqInput::False));
+    }
+
+    if js_unknown.is_array()? {
+      let length = unsafe { js_unknown.cast::<napi::JsObject>() }?.get_array_length()?;
+      let array = unsafe { napi::bindgen_prelude::Array::from_napi_value(env, value) }?;
+
+      let styles = (0..length)
+        .map(|index| {
+          array
+            .get::<StyleXPropsArgument>(index)
+            .map(|style| style.map_or(StyleqInput::False, |style| style.0))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+      return Ok(StyleXPropsArgument(StyleqInput::Nested(styles)));
+    }
+
+    let object = unsafe { Object::from_napi_value(env, value) }?;
+    let mut style = StyleMap::new();
+
+    // `Object::keys` lists properties in the order `for...in` visits them,
+    // which is the order the merge has to see them in.
+    for prop in Object::keys(&object)? {
+      let Some(value) = object.get::<Unknown>(&prop)? else {
+        continue;
+      };
+
+      let value = match value.get_type()? {
+        ValueType::String => {
+          RuntimeStyleValue::String(unsafe { String::from_napi_value(env, value.raw()) }?)
+        },
+        ValueType::Number => {
+          RuntimeStyleValue::Number(unsafe { f64::from_napi_value(env, value.raw()) }?)
+        },
+        ValueType::Boolean => {
+          RuntimeStyleValue::Bool(unsafe { bool::from_napi_value(env, value.raw()) }?)
+        },
+        // A `$$css` of `null` marks nothing as compiled, the same as none.
+        ValueType::Null | ValueType::Undefined if prop == COMPILED_KEY => continue,
+        // The runtime reads `undefined` as it reads `null`: no value, though
+        // the property still hides one an earlier argument gives it. A
+        // compiled dynamic style answers `undefined` for a missing argument.
+        ValueType::Null | ValueType::Undefined => RuntimeStyleValue::Null,
+        value_type => {
+          return Err(Error::from_reason(format!(
+            "[StyleX] Unsupported value for style property '{}': expected a string, number, \
+             boolean, null or undefined, got {}",
+            prop, value_type
+          )));
+        },
+      };
+
+      style.insert(prop, value);
+    }
+
+    Ok(StyleXPropsArgument(StyleqInput::Style(style)))
+  }
+}
+
+/// The inline `style` of a `props` result, kept in the order the merge built
+/// it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleXInlineStyle(pub StyleMap<RuntimeStyleValue>);
+
+impl ToNapiValue for StyleXInlineStyle {
+  unsafe fn to_napi_value(env: napi_env, value: Self) -> Result<napi_value, Error> {
+    let js_env = Env::from_raw(env);
+    let mut style = Object::new(&js_env)?;
+
+    for (prop, value) in value.0 {
+      match value {
+        RuntimeStyleValue::String(value) => style.set(prop, value)?,
+        RuntimeStyleValue::Number(value) => style.set(prop, value)?,
+        RuntimeStyleValue::Bool(value) => style.set(prop, value)?,
+        RuntimeStyleValue::Null => style.set(prop, Null)?,
+      }
+    }
+
+    unsafe { Object::to_napi_value(env, style) }
+  }
+}
+
 #[cfg(test)]
 #[path = "../tests/enums_tests.rs"]
 mod tests;
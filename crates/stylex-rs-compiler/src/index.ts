@@ -8,15 +8,50 @@ import nativeBinding from '../dist/transform';
 
 export type {
   ImportSourceInput,
+  StyleXAnalysis,
+  StyleXAnalyzedRule,
+  StyleXBatchFile,
+  StyleXBatchResult,
+  StyleXClassNameCollision,
+  StyleXClassNameManifestUpdate,
+  StyleXDesignTokenImport,
+  StyleXDesignTokenOptions,
+  StyleXDiagnostic,
+  StyleXLayersOptions,
   StyleXMetadata,
   StyleXModuleResolution,
+  StyleXProps,
+  StyleXPropsOptions,
+  StyleXRelatedSpan,
+  StyleXRenamedClassName,
+  StyleXRuleDelta,
+  StyleXRuleStyle,
+  StyleXSourceSpan,
+  StyleXStyleAnalysis,
+  StyleXTokenAnalysis,
   StyleXTransformResult,
+  StyleXUnusedNamespace,
+  StyleXUnusedStyles,
+  StyleXUnusedToken,
+  StyleXWatchResult,
 } from '../dist/transform';
 
 import type {
   SourceMaps as NativeSourceMaps,
+  StyleXAnalysis,
+  StyleXBatchFile,
+  StyleXBatchResult,
+  StyleXClassNameManifestUpdate,
+  StyleXDesignTokenImport,
+  StyleXDesignTokenOptions,
+  StyleXDiagnostic,
   StyleXOptions as NativeStyleXOptions,
+  StyleXProps,
+  StyleXPropsOptions,
+  StyleXRuleDelta,
+  StyleXRuleStyle,
   StyleXTransformResult,
+  StyleXWatchResult,
 } from '../dist/transform';
 
 // const enums are erased by TypeScript — provide runtime values
@@ -49,20 +84,27 @@ export interface StyleXOptions extends NativeStyleXOptions {
   swcPlugins?: Array<[string, Record<string, unknown>]>;
 }
 
-export type UseLayersType =
-  | boolean
-  | {
-      before?: ReadonlyArray<string>;
-      after?: ReadonlyArray<string>;
-      prefix?: string;
-    };
+export type UseLayersType = NonNullable<StyleXOptions['useLayers']>;
 
+/** The stylesheet options a bundler plugin passes on to `processStylexRules`. */
 export type TransformedOptions = Partial<
-  Pick<StyleXOptions, 'legacyDisableLayers' | 'enableLTRRTLComments'> & {
-    useLayers: UseLayersType;
-  }
+  Pick<
+    StyleXOptions,
+    | 'legacyDisableLayers'
+    | 'enableLTRRTLComments'
+    | 'useLayers'
+    | 'flattenSpecificity'
+    | 'useDirPseudoClass'
+  >
 >;
 
+/** A collected `[className, style, priority]` rule, as found in `metadata.stylex`. */
+export type StyleXRule = [string, StyleXRuleStyle, number];
+
+export type ProcessStylexRulesOptions = TransformedOptions & {
+  useLegacyClassnamesSort?: boolean;
+};
+
 /**
  * Default values for StyleX options.
  * Every field that has a sensible default is listed here.
@@ -72,12 +114,15 @@ const defaultOptions: Partial<StyleXOptions> = {
   test: false,
   debug: false,
   enableFontSizePxToRem: false,
+  enableColorNormalization: false,
+  enableAtRuleNormalization: false,
   runtimeInjection: false,
   treeshakeCompensation: false,
   enableInlinedConditionalMerge: true,
   enableLogicalStylesPolyfill: false,
   enableMinifiedKeys: true,
   enableLegacyValueFlipping: false,
+  enableNativeLogicalStyles: false,
   enableLTRRTLComments: false,
   legacyDisableLayers: false,
   useRealFileForSource: true,
@@ -86,6 +131,9 @@ const defaultOptions: Partial<StyleXOptions> = {
   enableMediaQueryOrder: true,
   enableDebugClassNames: false,
   propertyValidationMode: 'silent',
+  reportUnusedStyles: false,
+  emitThemeDeclarations: false,
+  emitClassNameKeys: false,
   styleResolution: 'property-specificity',
   importSources: ['stylex', '@stylexjs/stylex'],
 };
@@ -170,6 +218,74 @@ function matchPattern(filePath: string, pattern: string | RegExp): boolean {
 
 // ── transform ───────────────────────────────────────────────────────
 
+/** Source handed to the native transform once the TS-side steps have run. */
+interface NativeInput {
+  code: string;
+  inputSourceMap: string | undefined;
+}
+
+/**
+ * Run `options.swcPlugins` over `code`, if any. Returns the code and the map
+ * the native transform should chain from.
+ */
+function applySwcPlugins(
+  filename: string,
+  code: string,
+  inputSourceMap: string | undefined,
+  options: StyleXOptions
+): NativeInput {
+  if (!options.swcPlugins?.length) {
+    return { code, inputSourceMap };
+  }
+
+  // oxlint-disable-next-line typescript/no-require-imports
+  const swc = require('@swc/core');
+
+  // Always request an external map: it chains `inputSourceMap` and the
+  // plugins' own edits, so the positions handed to the native transform
+  // keep describing the code it actually receives. The native transform
+  // owns emission of the final map per `options.sourceMap` (incl. inline).
+  // The intermediate map becomes the native transform's `orig`, and a
+  // chained map keeps whatever `sourcesContent` it arrives with — so it has
+  // to carry the authored text from here.
+  const result = swc.transformSync(code, {
+    filename,
+    sourceMaps: true,
+    inlineSourcesContent: options.inlineSourcesContent ?? true,
+    inputSourceMap,
+    jsc: {
+      parser: { syntax: 'typescript', tsx: true },
+      target: 'es2022',
+      experimental: { plugins: options.swcPlugins },
+    },
+  });
+
+  return { code: result.code, inputSourceMap: result.map || inputSourceMap };
+}
+
+/** Strip TS-only fields before passing options to the native binding. */
+function toNativeOptions(options: StyleXOptions): NativeStyleXOptions {
+  const {
+    swcPlugins: _swcPlugins,
+    include: _include,
+    exclude: _exclude,
+    inputSourceMap: _inputSourceMap,
+    ...nativeOptions
+  } = options;
+
+  return nativeOptions;
+}
+
+/** The result for a file the include/exclude patterns leave alone. */
+function untransformed(code: string): StyleXTransformResult {
+  return {
+    code,
+    metadata: { stylex: [], dependencies: [] },
+    map: undefined,
+    diagnostics: [],
+  };
+}
+
 /**
  * Transform source code with StyleX. When `options.swcPlugins` is set,
  * SWC plugins are applied first, then the native StyleX transform runs.
@@ -181,54 +297,275 @@ export function transform(
 ): StyleXTransformResult {
   // Apply include/exclude filter before transforming
   if (!shouldTransformFile(filename, options.include, options.exclude)) {
-    return {
-      code,
-      metadata: { stylex: [] },
-      map: undefined,
-    };
+    return untransformed(code);
   }
 
-  let transformedCode = code;
-  let inputSourceMap = options.inputSourceMap;
-
-  if (options.swcPlugins?.length) {
-    // oxlint-disable-next-line typescript/no-require-imports
-    const swc = require('@swc/core');
-
-    // Always request an external map: it chains `inputSourceMap` and the
-    // plugins' own edits, so the positions handed to the native transform
-    // keep describing the code it actually receives. The native transform
-    // owns emission of the final map per `options.sourceMap` (incl. inline).
-    // The intermediate map becomes the native transform's `orig`, and a
-    // chained map keeps whatever `sourcesContent` it arrives with — so it has
-    // to carry the authored text from here.
-    const result = swc.transformSync(transformedCode, {
-      filename,
-      sourceMaps: true,
-      inlineSourcesContent: options.inlineSourcesContent ?? true,
-      inputSourceMap,
-      jsc: {
-        parser: { syntax: 'typescript', tsx: true },
-        target: 'es2022',
-        experimental: { plugins: options.swcPlugins },
-      },
+  const input = applySwcPlugins(filename, code, options.inputSourceMap, options);
+
+  return nativeBinding.transform(filename, input.code, {
+    ...toNativeOptions(options),
+    inputSourceMap: input.inputSourceMap,
+  });
+}
+
+// ── transformAsync ──────────────────────────────────────────────────
+
+/**
+ * `transform` without blocking the event loop: the native compile runs on the
+ * libuv threadpool. `env` functions and a function `debugFilePath` cannot be
+ * called off the JS thread and are rejected; use `transform` for those.
+ */
+export function transformAsync(
+  filename: string,
+  code: string,
+  options: StyleXOptions
+): Promise<StyleXTransformResult> {
+  if (!shouldTransformFile(filename, options.include, options.exclude)) {
+    return Promise.resolve(untransformed(code));
+  }
+
+  try {
+    const input = applySwcPlugins(filename, code, options.inputSourceMap, options);
+
+    return nativeBinding.transformAsync(filename, input.code, {
+      ...toNativeOptions(options),
+      inputSourceMap: input.inputSourceMap,
     });
-    transformedCode = result.code;
-    if (result.map) {
-      inputSourceMap = result.map;
-    }
+  } catch (error) {
+    return Promise.reject(error);
   }
+}
 
-  // Strip TS-only fields before passing to native transform
-  const {
-    swcPlugins: _swcPlugins,
-    include: _include,
-    exclude: _exclude,
-    ...nativeOptions
-  } = options;
+// ── transformBatch ──────────────────────────────────────────────────
 
-  return nativeBinding.transform(filename, transformedCode, {
-    ...nativeOptions,
-    inputSourceMap,
+/**
+ * Transform many files that share `options` in one native call, compiled in
+ * parallel. Each file gets its own `result` or `error`, in the order given;
+ * files outside the include/exclude patterns come back untouched.
+ * `inputSourceMap` is per file. The JS-callable options `transformAsync`
+ * rejects are rejected here too.
+ */
+export function transformBatch(
+  files: ReadonlyArray<StyleXBatchFile>,
+  options: StyleXOptions
+): StyleXBatchResult[] {
+  const results: Array<StyleXBatchResult | undefined> = [];
+  const pending: Array<{ index: number; file: StyleXBatchFile }> = [];
+
+  files.forEach((file, index) => {
+    if (!shouldTransformFile(file.filename, options.include, options.exclude)) {
+      results[index] = { filename: file.filename, result: untransformed(file.code) };
+      return;
+    }
+
+    try {
+      const input = applySwcPlugins(file.filename, file.code, file.inputSourceMap, options);
+
+      pending.push({ index, file: { filename: file.filename, ...input } });
+    } catch (error) {
+      results[index] = { filename: file.filename, error: String(error) };
+    }
   });
+
+  if (pending.length > 0) {
+    const native = nativeBinding.transformBatch(
+      pending.map(({ file }) => file),
+      toNativeOptions(options)
+    );
+
+    native.forEach((result, i) => {
+      results[pending[i].index] = result;
+    });
+  }
+
+  return results as StyleXBatchResult[];
+}
+
+// ── analyze ─────────────────────────────────────────────────────────
+
+/**
+ * What the code at `offset` compiles to, for an editor's hover and
+ * go-to-definition: the atomic rules of the `stylex.create` key or
+ * `styles.key` read there, or the value of the `vars.token` read there and
+ * where the token is declared. `offset` is a UTF-8 byte offset into `code`.
+ * `null` when the offset is on neither, or the file is outside the
+ * include/exclude patterns. `swcPlugins` are not applied, since they would
+ * move the offset.
+ */
+export function analyze(
+  filename: string,
+  code: string,
+  offset: number,
+  options: StyleXOptions
+): StyleXAnalysis | null {
+  if (!shouldTransformFile(filename, options.include, options.exclude)) {
+    return null;
+  }
+
+  return nativeBinding.analyze(filename, code, offset, toNativeOptions(options));
+}
+
+// ── check ───────────────────────────────────────────────────────────
+
+/**
+ * Every problem StyleX finds in a file, for a lint host: one error for each
+ * StyleX call that fails validation, with its code and span, and any warning.
+ * Where `transform` throws at the first failing call, `check` reports it and
+ * goes on. A file that cannot be compiled at all reports why as its last
+ * diagnostic rather than throwing. `[]` for a file outside the
+ * include/exclude patterns. `swcPlugins` are not applied, since they would
+ * move the spans.
+ */
+export function check(
+  filename: string,
+  code: string,
+  options: StyleXOptions
+): StyleXDiagnostic[] {
+  if (!shouldTransformFile(filename, options.include, options.exclude)) {
+    return [];
+  }
+
+  return nativeBinding.check(filename, code, toNativeOptions(options));
+}
+
+// ── importDesignTokens ──────────────────────────────────────────────
+
+/**
+ * Import a W3C Design Tokens (DTCG) file as the `.stylex.ts` module at
+ * `filename`. `source` is the generated module, to write beside the rest of
+ * the code; `result` is what compiling it gives, the same variables and rules
+ * as the module written by hand. Modes become themes unless `modeConditions`
+ * names a condition for them. The JS-callable options `transformAsync`
+ * rejects are rejected here too.
+ */
+export function importDesignTokens(
+  filename: string,
+  tokens: string,
+  options: StyleXOptions,
+  tokenOptions?: StyleXDesignTokenOptions
+): StyleXDesignTokenImport {
+  return nativeBinding.importDesignTokens(
+    filename,
+    tokens,
+    toNativeOptions(options),
+    tokenOptions
+  );
+}
+
+// ── processStylexRules ──────────────────────────────────────────────
+
+/**
+ * Build the final stylesheet from the rules collected across a build. Native
+ * drop-in for `@stylexjs/babel-plugin`'s `processStylexRules`, including its
+ * shorthand of a bare boolean for `useLayers`.
+ */
+export function processStylexRules(
+  rules: ReadonlyArray<StyleXRule>,
+  options?: ProcessStylexRulesOptions | boolean
+): string {
+  const config = typeof options === 'boolean' ? { useLayers: options } : (options ?? {});
+
+  return nativeBinding.processStylexRules(rules, config);
+}
+
+// ── stylesheetReport ────────────────────────────────────────────────
+
+/**
+ * Report, as JSON to track in CI, what the rules of each file add up to in
+ * the stylesheet: its size and RTL share, the properties and values weighing
+ * most, the same rule emitted under different class names, and the files
+ * bringing in the most. `rules` maps each file to the `metadata.stylex` rules
+ * its transform returned.
+ */
+export function stylesheetReport(
+  rules: Readonly<Record<string, ReadonlyArray<StyleXRule>>>
+): string {
+  return nativeBinding.stylesheetReport(rules);
+}
+
+// ── updateClassNameManifest ─────────────────────────────────────────
+
+/**
+ * Record the class names of a build in a class-name manifest, by the
+ * declaration each is hashed from, and report the classes it renames and the
+ * class names two declarations share. `classNameKeys` are the `classNameKeys`
+ * of every file transformed with `emitClassNameKeys`; `previous` is the
+ * manifest the last build saved. Under `frozen`, a rename throws instead.
+ */
+export function updateClassNameManifest(
+  classNameKeys: ReadonlyArray<Readonly<Record<string, string>>>,
+  previous?: string,
+  frozen?: boolean
+): StyleXClassNameManifestUpdate {
+  return nativeBinding.updateClassNameManifest(classNameKeys, previous, frozen);
+}
+
+// ── props ───────────────────────────────────────────────────────────
+
+/**
+ * What `stylex.props` takes: compiled styles, inline styles, `null`,
+ * `undefined`, booleans, and nested arrays of them.
+ */
+export type StyleXPropsStyle =
+  | Readonly<Record<string, string | number | boolean | null | undefined>>
+  | ReadonlyArray<StyleXPropsStyle>
+  | boolean
+  | null
+  | undefined;
+
+/**
+ * Merge compiled style objects into `className`, `style` and
+ * `data-style-src` exactly as `stylex.props(...styles)` does at runtime, for
+ * server rendering without the JS runtime. Compiled styles are cached across
+ * calls, as they are by the runtime.
+ */
+export function props(
+  styles: ReadonlyArray<StyleXPropsStyle>,
+  options?: StyleXPropsOptions
+): StyleXProps {
+  return nativeBinding.props(styles, options);
+}
+
+// ── StyleXCompiler ──────────────────────────────────────────────────
+
+/**
+ * A compiler kept for the life of a dev server. The options are converted
+ * once, and the `package.json` files, import resolutions and parsed theme
+ * files of one compile are reused by the next. Each `transform` reports the
+ * rules the file `added` to and `removed` from the stylesheet since its last
+ * compile, for HMR to patch the stylesheet with. `invalidate` takes the
+ * absolute paths that changed on disk and compiles again, from disk, every
+ * file compiled so far that is among them or depends on one of them.
+ *
+ * Files outside the include/exclude patterns come back untouched and are not
+ * tracked. `swcPlugins` are rejected, since `invalidate` could not apply them
+ * to the files it reads from disk, and so are the JS-callable options
+ * `transformAsync` rejects.
+ */
+export class StyleXCompiler {
+  readonly #options: StyleXOptions;
+  readonly #native: InstanceType<typeof nativeBinding.StyleXCompiler>;
+
+  constructor(options: StyleXOptions) {
+    if (options.swcPlugins?.length) {
+      throw new TypeError(
+        '[StyleX] StyleXCompiler does not run swcPlugins. Use transform() for options with swcPlugins.'
+      );
+    }
+
+    this.#options = options;
+    this.#native = new nativeBinding.StyleXCompiler(toNativeOptions(options));
+  }
+
+  transform(filename: string, code: string, inputSourceMap?: string): StyleXWatchResult {
+    if (!shouldTransformFile(filename, this.#options.include, this.#options.exclude)) {
+      return { result: untransformed(code), added: [], removed: [] };
+    }
+
+    return this.#native.transform(filename, code, inputSourceMap);
+  }
+
+  invalidate(paths: ReadonlyArray<string>): StyleXRuleDelta[] {
+    return this.#native.invalidate([...paths]);
+  }
 }
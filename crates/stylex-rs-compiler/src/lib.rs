@@ -3,38 +3,63 @@
 mod enums;
 mod structs;
 mod utils;
+use indexmap::IndexMap;
 use log::{info, warn};
-use napi::{Env, Result};
+use napi::{
+  Env, JsError, JsValue, Result, Task,
+  bindgen_prelude::{AsyncTask, JsObjectValue},
+};
 use std::{
+  borrow::Cow,
+  collections::HashMap,
   env, panic,
   path::{Component, Path, PathBuf},
-  sync::Arc,
+  sync::{Arc, LazyLock},
+};
+use structs::{
+  StyleXAnalysis, StyleXBatchFile, StyleXBatchResult, StyleXClassNameManifestUpdate,
+  StyleXDesignTokenImport, StyleXDesignTokenOptions, StyleXDiagnostic, StyleXMetadata,
+  StyleXOptions, StyleXProcessRulesOptions, StyleXProps, StyleXPropsOptions, StyleXRule,
+  StyleXRuleDelta, StyleXTransformResult, StyleXWatchResult, layers_options, rule_to_metadata,
+};
+use stylex_css::stylesheet::{
+  LayersOptions, class_name_manifest::ClassNameManifest, process_stylex_rules as build_stylesheet,
+  report::stylesheet_report as build_stylesheet_report,
 };
-use structs::{StyleXMetadata, StyleXOptions, StyleXTransformResult};
 use stylex_logs::initializer::initialize as initialize_logger;
-use stylex_macros::stylex_error::{SuppressPanicStderr, format_panic_message};
-use swc_compiler_base::{PrintArgs, SourceMapsConfig, print};
+use stylex_macros::{
+  diagnostic::{Diagnostic, DiagnosticCollector, Severity, codes},
+  stylex_error::{SuppressPanicStderr, format_panic_message},
+};
+use swc_compiler_base::{PrintArgs, SourceMapsConfig};
 
 use stylex_structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams};
-use stylex_transform::StyleXTransform;
-use swc_ecma_parser::{Parser, StringInput, Syntax, TsSyntax, lexer::Lexer};
-
-use swc_core::{
-  common::{FileName, GLOBALS, Globals, Mark, SourceMap, comments::SingleThreadedComments},
-  ecma::{
-    ast::EsVersion,
-    transforms::{
-      base::{fixer::fixer, hygiene::hygiene, resolver},
-      typescript::strip as typescript_strip,
-    },
-    visit::visit_mut_pass,
+use stylex_styleq::{RuntimeStyleValue, Styleq, StyleqInput, StyleqOptions, create_styleq};
+use stylex_transform::{
+  StyleXTransform,
+  compile::{parse_source, print_program, transform_program},
+  shared::{
+    structures::session_cache::SessionCache, utils::log::build_code_frame_error::source_span_in,
   },
 };
+use stylex_types::structures::{
+  analysis::Analysis, meta_data::MetaData, unused_styles::UnusedStyles,
+};
+
+use swc_core::common::{
+  FileName, GLOBALS, Globals, SourceMap, Spanned, comments::SingleThreadedComments,
+};
 
 use napi_derive::napi;
-use utils::extract_stylex_metadata;
+use utils::{
+  cache::{TransformCache, cache_key},
+  extract_stylex_metadata,
+  fn_parser::DebugFilePath,
+  shared_options::{SharedOptions, run_in_parallel},
+  watch::WatchCompiler,
+};
 
-use crate::enums::SourceMaps;
+use crate::enums::{SourceMaps, StyleXPropsArgument};
 
 fn source_maps_config(source_map: Option<&SourceMaps>) -> SourceMapsConfig {
   match source_map {
@@ -151,6 +176,98 @@ fn backfill_source_contents(
   }
 }
 
+/// A transformed file, before any of it has been handed to JS.
+pub struct TransformedFile {
+  code: String,
+  map: Option<String>,
+  metadata: Vec<MetaData>,
+  /// Theme, consts and `package.json` files the transform read while
+  /// resolving the file's imports.
+  dependencies: Vec<String>,
+  /// Warnings reported while compiling.
+  diagnostics: Vec<Diagnostic>,
+  unused_styles: Option<UnusedStyles>,
+  /// The `.d.ts` text describing the file's theme exports.
+  declarations: Option<String>,
+  /// The declaration each class name is hashed from, when asked for.
+  class_name_keys: Option<IndexMap<String, String>>,
+  /// What the code at the requested analysis offset compiles to.
+  analysis: Option<Analysis>,
+}
+
+/// A transform that failed: the error, and the diagnostics reported up to and
+/// including it.
+#[derive(Debug)]
+pub struct TransformFailure {
+  error: napi::Error,
+  diagnostics: Vec<Diagnostic>,
+}
+
+impl From<napi::Error> for TransformFailure {
+  /// A failure outside the transform proper, such as printing the output.
+  fn from(error: napi::Error) -> Self {
+    let message = error
+      .reason
+      .strip_prefix("[StyleX]")
+      .map_or(error.reason.as_str(), str::trim_start)
+      .to_string();
+
+    TransformFailure {
+      diagnostics: vec![Diagnostic {
+        code: Cow::Borrowed(codes::INTERNAL_ERROR),
+        severity: Severity::Error,
+        message,
+        file: None,
+        span: None,
+        suggestion: None,
+        related: vec![],
+      }],
+      error,
+    }
+  }
+}
+
+impl TransformFailure {
+  /// The error to throw: a JS `Error` with the same message, carrying the
+  /// diagnostics as its `diagnostics` property. Falls back to the plain error
+  /// should the property not be set.
+  fn into_js_error(self, env: Env) -> napi::Error {
+    let reason = self.error.reason.clone();
+    let diagnostics: Vec<StyleXDiagnostic> = self.diagnostics.into_iter().map(Into::into).collect();
+
+    JsError::from(self.error)
+      .into_unknown(env)
+      .coerce_to_object()
+      .and_then(|mut error| {
+        error.set_named_property("diagnostics", diagnostics)?;
+        Ok(napi::Error::from(error.to_unknown()))
+      })
+      .unwrap_or_else(|_| napi::Error::from_reason(reason))
+  }
+}
+
+/// The options that shape the emitted code and map of one file, as opposed to
+/// the compile itself.
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OutputOptions {
+  source_map: Option<SourceMaps>,
+  inline_sources_content: Option<bool>,
+  emit_source_map_columns: Option<bool>,
+  input_source_map: Option<String>,
+}
+
+impl OutputOptions {
+  fn take_from(options: &mut StyleXOptions) -> Self {
+    OutputOptions {
+      source_map: options.source_map.take(),
+      inline_sources_content: options.inline_sources_content.take(),
+      emit_source_map_columns: options.emit_source_map_columns.take(),
+      input_source_map: options.input_source_map.take(),
+    }
+  }
+}
+
 #[napi]
 pub fn transform(
   env: Env,
@@ -179,14 +296,429 @@ pub fn transform(
     .map(|unknown_ref| {
       let parse_result = unknown_ref
         .get_value(&env)
-        .and_then(|value| utils::fn_parser::parse_debug_file_path(&env, value));
+        .and_then(|value| utils::fn_parser::read_debug_file_path(&env, value));
       // Always unref to prevent leak, regardless of parse success/failure
       let _ = unknown_ref.unref(&env);
       parse_result
     })
     .transpose()?;
 
+  // A function debugFilePath can answer differently on every call, so its
+  // results cannot be cached.
+  let cache = options
+    .cache_directory
+    .take()
+    .filter(|_| !matches!(parsed_debug_file_path, Some(DebugFilePath::Function(_))))
+    .map(TransformCache::new);
+
+  let fixed_debug_file_path = match &parsed_debug_file_path {
+    Some(DebugFilePath::Fixed(path)) => Some(path.clone()),
+    _ => None,
+  };
+
+  let output_options = OutputOptions::take_from(&mut options);
+  let layers = layers_options(options.use_layers.take());
+
+  let mut config: StyleXOptionsParams = options.try_into()?;
+
+  // Set the parsed env and debugFilePath on the config
+  config.env = parsed_env;
+  config.debug_file_path = parsed_debug_file_path.map(DebugFilePath::into_js_function);
+
+  let cached = cache.and_then(|cache| {
+    let key = cache_key(
+      &filename,
+      &code,
+      &config,
+      fixed_debug_file_path.as_deref(),
+      &output_options,
+    )?;
+
+    Some((cache, key))
+  });
+
+  let compile = || {
+    transform_source(
+      filename,
+      code,
+      output_options,
+      &mut config,
+      CompileMode::Transform,
+      Some(&env),
+      None,
+    )
+  };
+
+  let transformed = match cached {
+    Some((cache, key)) => cache.get_or_insert_with(&key, compile),
+    None => compile(),
+  };
+
+  match transformed {
+    Ok(file) => into_transform_result(env, file, layers.as_ref()),
+    Err(failure) => Err(failure.into_js_error(env)),
+  }
+}
+
+/// Transforms many files that share one set of options, in parallel.
+///
+/// The options are converted once for the whole batch rather than once per
+/// file. Each file is compiled on a Rust thread with a state of its own, and
+/// reports its own result or error: one file failing does not abort the rest.
+/// Results come back in the order the files were given.
+///
+/// Takes only the options [`SharedOptions`] can share between threads.
+/// `inputSourceMap` belongs to one file and is given per file here.
+#[napi(js_name = "transformBatch")]
+pub fn transform_batch(
+  env: Env,
+  files: Vec<StyleXBatchFile>,
+  options: StyleXOptions,
+) -> Result<Vec<StyleXBatchResult>> {
+  initialize_logger();
+
+  if options.input_source_map.is_some() {
+    return Err(napi::Error::from_reason(
+      "[StyleX] transformBatch() takes inputSourceMap per file, not in the shared options.",
+    ));
+  }
+
+  info!("Transforming a batch of {} source files", files.len());
+
+  let shared = SharedOptions::new(&env, options)?;
+
+  let outcomes = run_in_parallel(files, |file| {
+    let outcome = shared.transform(file.filename.clone(), file.code, file.input_source_map);
+
+    (file.filename, outcome)
+  });
+
+  outcomes
+    .into_iter()
+    .map(|(filename, outcome)| {
+      let (result, error, diagnostics) = match outcome {
+        Ok(file) => match into_transform_result(env, file, shared.layers()) {
+          Ok(result) => (Some(result), None, None),
+          Err(error) => (None, Some(error.reason), None),
+        },
+        Err(failure) => (
+          None,
+          Some(failure.error.reason),
+          Some(failure.diagnostics.into_iter().map(Into::into).collect()),
+        ),
+      };
+
+      Ok(StyleXBatchResult {
+        filename,
+        result,
+        error,
+        diagnostics,
+      })
+    })
+    .collect()
+}
+
+/// `transform` on the libuv threadpool, leaving the JS thread free while the
+/// file compiles, with the options of [`SharedOptions`].
+#[napi(js_name = "transformAsync")]
+pub fn transform_async(
+  env: Env,
+  filename: String,
+  code: String,
+  mut options: StyleXOptions,
+) -> Result<AsyncTask<TransformTask>> {
+  initialize_logger();
+
+  info!("Transforming source file off the JS thread: {}", filename);
+
+  let input_source_map = options.input_source_map.take();
+  let shared = SharedOptions::new(&env, options)?;
+
+  Ok(AsyncTask::new(TransformTask {
+    filename,
+    code,
+    input_source_map,
+    shared,
+  }))
+}
+
+/// What the code at `offset`, a byte offset into `code`, compiles to: the
+/// atomic rules of the `stylex.create` key or `styles.key` read there, or what
+/// the `vars.token` read there compiles to and where the token is declared.
+/// `null` when the offset is on neither.
+///
+/// The file is compiled in full, so the answer is the one a build would give,
+/// with the options of [`SharedOptions`].
+#[napi]
+pub fn analyze(
+  env: Env,
+  filename: String,
+  code: String,
+  offset: u32,
+  options: StyleXOptions,
+) -> Result<Option<StyleXAnalysis>> {
+  initialize_logger();
+
+  info!("Analyzing source file at offset {}: {}", offset, filename);
+
+  let shared = SharedOptions::new(&env, options)?;
+
+  match shared.analyze(filename, code, offset) {
+    Ok(analysis) => Ok(analysis.map(Into::into)),
+    Err(failure) => Err(failure.into_js_error(env)),
+  }
+}
+
+/// Every problem StyleX finds in a file, without compiling it: one error
+/// diagnostic for each StyleX call that fails validation, with its code and
+/// span, and any warning. Where `transform` throws at the first failing call,
+/// a check reports that call and goes on to the next, so a lint host can show
+/// them all at once.
+///
+/// Never throws for the file itself: one that cannot be compiled at all, such
+/// as one that does not parse, reports why as its last diagnostic. Takes the
+/// options of [`SharedOptions`].
+#[napi]
+pub fn check(
+  env: Env,
+  filename: String,
+  code: String,
+  options: StyleXOptions,
+) -> Result<Vec<StyleXDiagnostic>> {
+  initialize_logger();
+
+  info!("Checking source file: {}", filename);
+
+  let shared = SharedOptions::new(&env, options)?;
+
+  Ok(
+    shared
+      .check(filename, code)
+      .into_iter()
+      .map(Into::into)
+      .collect(),
+  )
+}
+
+/// Imports a W3C Design Tokens (DTCG) file as the `.stylex.ts` module at
+/// `filename`: its groups as one `unstable_defineVarsNested` export, its modes
+/// as `unstable_createThemeNested` exports or as conditions, with aliases and
+/// `$type`s resolved.
+///
+/// The generated source is compiled like any other file, with the options of
+/// [`SharedOptions`], so the variables and rules are the ones the same module
+/// written by hand would give.
+#[napi(js_name = "importDesignTokens")]
+pub fn import_design_tokens(
+  env: Env,
+  filename: String,
+  tokens: String,
+  options: StyleXOptions,
+  token_options: Option<StyleXDesignTokenOptions>,
+) -> Result<StyleXDesignTokenImport> {
+  initialize_logger();
+
+  info!("Importing design tokens as: {}", filename);
+
+  let shared = SharedOptions::new(&env, options)?;
+  let source = shared.design_tokens_source(&tokens, token_options.unwrap_or_default())?;
+
+  match shared.transform(filename, source.clone(), None) {
+    Ok(file) => Ok(StyleXDesignTokenImport {
+      source,
+      result: into_transform_result(env, file, shared.layers())?,
+    }),
+    Err(failure) => Err(failure.into_js_error(env)),
+  }
+}
+
+/// A compiler kept for the life of a dev server. The options are converted
+/// once, and the `package.json` files, import resolutions and parsed theme
+/// files of one compile are reused by the next, so an edit only pays for the
+/// file edited.
+///
+/// Each compile reports the rules the file added to and removed from the
+/// stylesheet since its previous one, for a host to patch the stylesheet with
+/// rather than build it again. When files change on disk, `invalidate` drops
+/// what they made stale and compiles again every file that read them.
+///
+/// Every compile uses the options of [`SharedOptions`]. Paths are matched as
+/// given, so give `invalidate` the absolute paths files were compiled under and
+/// `metadata.dependencies` lists.
+#[napi(js_name = "StyleXCompiler")]
+pub struct StyleXCompiler {
+  compiler: WatchCompiler,
+}
+
+#[napi]
+impl StyleXCompiler {
+  #[napi(constructor)]
+  pub fn new(env: Env, options: StyleXOptions) -> Result<Self> {
+    initialize_logger();
+
+    if options.input_source_map.is_some() {
+      return Err(napi::Error::from_reason(
+        "[StyleX] StyleXCompiler takes inputSourceMap per file, not in its options.",
+      ));
+    }
+
+    Ok(StyleXCompiler {
+      compiler: WatchCompiler::new(SharedOptions::new(&env, options)?),
+    })
+  }
+
+  /// Compiles `code` as `filename`, as `transform` would, with the rules that
+  /// changed since the file's previous compile. Throws as `transform` does; a
+  /// file that fails keeps the rules it had.
+  #[napi]
+  pub fn transform(
+    &self,
+    env: Env,
+    filename: String,
+    code: String,
+    input_source_map: Option<String>,
+  ) -> Result<StyleXWatchResult> {
+    info!("Transforming source file in a watch session: {}", filename);
+
+    match self.compiler.transform(filename, code, input_source_map) {
+      Ok((file, delta)) => {
+        let layers = self.compiler.shared().layers();
+
+        Ok(StyleXWatchResult {
+          added: extract_stylex_metadata(env, &delta.added, layers)?,
+          removed: extract_stylex_metadata(env, &delta.removed, layers)?,
+          result: into_transform_result(env, file, layers)?,
+        })
+      },
+      Err(failure) => Err(failure.into_js_error(env)),
+    }
+  }
+
+  /// Tells the compiler `paths` changed on disk, then compiles again, from
+  /// disk, every file it compiled that is among them or depends on one of
+  /// them. A file that was deleted reports every rule it had as removed.
+  ///
+  /// Clears the resolver caches the process shares, so waits for any
+  /// `transformAsync` or `transformBatch` compile running to finish first.
+  #[napi]
+  pub fn invalidate(&self, env: Env, paths: Vec<String>) -> Result<Vec<StyleXRuleDelta>> {
+    info!("Invalidating {} paths in a watch session", paths.len());
+
+    let layers = self.compiler.shared().layers();
+
+    self
+      .compiler
+      .invalidate(&paths)
+      .into_iter()
+      .map(|(filename, recompiled)| match recompiled {
+        Ok(delta) => Ok(StyleXRuleDelta {
+          filename,
+          added: extract_stylex_metadata(env, &delta.added, layers)?,
+          removed: extract_stylex_metadata(env, &delta.removed, layers)?,
+          error: None,
+          diagnostics: None,
+        }),
+        Err(failure) => Ok(StyleXRuleDelta {
+          filename,
+          added: vec![],
+          removed: vec![],
+          error: Some(failure.error.reason),
+          diagnostics: Some(failure.diagnostics.into_iter().map(Into::into).collect()),
+        }),
+      })
+      .collect()
+  }
+}
+
+pub struct TransformTask {
+  filename: String,
+  code: String,
+  input_source_map: Option<String>,
+  shared: SharedOptions,
+}
+
+impl Task for TransformTask {
+  /// A failure is only turned into the JS error it rejects with once back on
+  /// the JS thread, where its diagnostics can be attached.
+  type Output = std::result::Result<TransformedFile, TransformFailure>;
+  type JsValue = StyleXTransformResult;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    Ok(self.shared.transform(
+      std::mem::take(&mut self.filename),
+      std::mem::take(&mut self.code),
+      self.input_source_map.take(),
+    ))
+  }
+
+  fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    match output {
+      Ok(file) => into_transform_result(env, file, self.shared.layers()),
+      Err(failure) => Err(failure.into_js_error(env)),
+    }
+  }
+}
+
+fn into_transform_result(
+  env: Env,
+  file: TransformedFile,
+  layers: Option<&LayersOptions>,
+) -> Result<StyleXTransformResult> {
+  Ok(StyleXTransformResult {
+    code: file.code,
+    metadata: StyleXMetadata {
+      stylex: extract_stylex_metadata(env, &file.metadata, layers)?,
+      dependencies: file.dependencies,
+    },
+    map: file.map,
+    diagnostics: file.diagnostics.into_iter().map(Into::into).collect(),
+    unused_styles: file.unused_styles.map(Into::into),
+    declarations: file.declarations,
+    class_name_keys: file
+      .class_name_keys
+      .map(|class_name_keys| class_name_keys.into_iter().collect()),
+  })
+}
+
+/// What a compile is for, besides the compiled code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum CompileMode {
+  /// Nothing else: the first StyleX error fails the file.
+  #[default]
+  Transform,
+  /// An analysis of the code at this byte offset into the source as well.
+  Analyze(u32),
+  /// Every error in the file: a StyleX call that fails is reported and left
+  /// as written, and the compile goes on. The compiled code is not wanted.
+  Check,
+}
+
+/// Compiles one file with an already converted config.
+///
+/// `napi_env` is the JS thread's env when the caller is on it, which is what
+/// lets `env` functions and a function `debugFilePath` call back into JS.
+/// Without it the config must hold neither.
+///
+/// `mode` says what the compile is for besides the compiled code.
+///
+/// `session_cache` is the resolutions a long-lived compiler keeps between
+/// compiles, when the file is compiled by one.
+///
+/// Every diagnostic reported while compiling comes back with the outcome, on
+/// the file or on the failure. Those that name no file are given `filename`.
+pub(crate) fn transform_source(
+  filename: String,
+  code: String,
+  output_options: OutputOptions,
+  config: &mut StyleXOptionsParams,
+  mode: CompileMode,
+  napi_env: Option<&Env>,
+  session_cache: Option<&Arc<SessionCache>>,
+) -> std::result::Result<TransformedFile, TransformFailure> {
   let _suppress = SuppressPanicStderr::new();
+  let collector = DiagnosticCollector::new();
+  let diagnostics_file = filename.clone();
+
   let result = panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
     let cm: Arc<SourceMap> = Default::default();
     let file_path = PathBuf::from(filename);
@@ -194,22 +726,24 @@ pub fn transform(
 
     let fm = cm.new_source_file(filename.clone().into(), code);
 
-    let cwd = env::current_dir()?;
+    let cwd = env::current_dir().map_err(napi::Error::from)?;
 
     let plugin_pass = PluginPass {
       cwd: Some(cwd),
       filename: filename.clone(),
     };
 
-    let source_map = source_maps_config(options.source_map.as_ref());
+    let source_map = source_maps_config(output_options.source_map.as_ref());
     let should_chain_input_source_map =
-      !matches!(options.source_map.as_ref(), Some(SourceMaps::False));
-    let inline_sources_content = resolve_inline_sources_content(options.inline_sources_content);
-    let emit_source_map_columns = resolve_emit_source_map_columns(options.emit_source_map_columns);
+      !matches!(output_options.source_map.as_ref(), Some(SourceMaps::False));
+    let inline_sources_content =
+      resolve_inline_sources_content(output_options.inline_sources_content);
+    let emit_source_map_columns =
+      resolve_emit_source_map_columns(output_options.emit_source_map_columns);
 
     // Parse the incoming source map (if any) once: it feeds both the debug
     // source-map annotations and the chaining of the emitted map.
-    let input_source_map = options.input_source_map.take().and_then(|json| {
+    let input_source_map = output_options.input_source_map.and_then(|json| {
       match swc_sourcemap::SourceMap::from_slice(json.as_bytes()) {
         Ok(mut map) => {
           // Chaining returns the input map as-is, so `inline_sources_content`
@@ -248,67 +782,71 @@ pub fn transform(
     let is_chaining = should_chain_input_source_map && input_source_map.is_some();
     let emit_source_map_columns = emit_source_map_columns || is_chaining;
 
-    let mut config: StyleXOptionsParams = options.try_into()?;
-
-    // Set the parsed env and debugFilePath on the config
-    config.env = parsed_env;
-    config.debug_file_path = parsed_debug_file_path;
-
-    // Collect comments while lexing and hand the same store to the printer.
-    // Without it the emitted code loses every comment — including the ones
-    // bundlers act on: `/* webpackChunkName: "…" */` on dynamic imports and
-    // `/* #__PURE__ */` annotations that minifiers need to drop dead calls.
+    // Collect comments while lexing and hand the same store to the printer,
+    // which `print_program` keeps them with.
     let comments = SingleThreadedComments::default();
 
-    let mut parser = Parser::new_from(Lexer::new(
-      Syntax::Typescript(TsSyntax {
-        tsx: true,
-        ..Default::default()
-      }),
-      EsVersion::latest(),
-      StringInput::from(&*fm),
-      Some(&comments),
-    ));
-
-    let program = match parser.parse_program() {
+    let program = match parse_source(&fm, Some(&comments)) {
       Ok(program) => program,
       Err(err) => {
         let error_message = format!("Failed to parse file `{}`: {:?}", filename, err);
-        return Err(napi::Error::from_reason(error_message));
+
+        return Err(TransformFailure {
+          diagnostics: vec![Diagnostic {
+            code: Cow::Borrowed(codes::PARSE_ERROR),
+            severity: Severity::Error,
+            message: err.kind().msg().into_owned(),
+            file: None,
+            span: source_span_in(&fm, err.span()),
+            suggestion: None,
+            related: vec![],
+          }],
+          error: napi::Error::from_reason(error_message),
+        });
       },
     };
 
     let globals = Globals::default();
     GLOBALS.set(&globals, || {
-      // Set the NAPI env in thread-local storage so env functions can call back to JS
-      utils::fn_parser::with_napi_env(&env, || {
-        let unresolved_mark = Mark::new();
-        let top_level_mark = Mark::new();
-
+      let compile = || {
         // The same store the lexer filled and the printer will read. The
         // alternative, `PluginCommentsProxy`, only forwards to a wasm plugin
         // host — outside `wasm32` every one of its methods is a no-op, so a
         // transform holding it can neither read an existing annotation nor
         // attach a new one, and does so silently.
         let mut stylex: StyleXTransform<&SingleThreadedComments> =
-          StyleXTransform::new(&comments, plugin_pass, &mut config);
+          StyleXTransform::new(&comments, plugin_pass, config);
 
         // Give the transform exact access to the parsed input so span-based
         // position lookups need no re-parsing, and to the input source map so
         // debug annotations point at the original authored file.
         stylex.state.set_input_source_file(fm.clone());
+        match mode {
+          CompileMode::Transform => {},
+          CompileMode::Analyze(offset) => stylex.state.set_analysis_offset(offset),
+          CompileMode::Check => stylex.state.set_check_only(),
+        }
         if let Some(ref input_source_map) = input_source_map {
           stylex.state.set_input_source_map(input_source_map.clone());
         }
+        if let Some(session_cache) = session_cache {
+          stylex.state.set_session_cache(Arc::clone(session_cache));
+        }
 
-        let program = program
-          .apply(resolver(unresolved_mark, top_level_mark, true))
-          .apply(typescript_strip(unresolved_mark, top_level_mark))
-          .apply(&mut visit_mut_pass(&mut stylex))
-          .apply(hygiene())
-          .apply(&mut fixer(None));
+        let program = transform_program(program, &mut stylex);
 
-        let stylex_metadata = extract_stylex_metadata(env, &stylex)?;
+        let metadata: Vec<MetaData> = stylex
+          .state
+          .metadata()
+          .values()
+          .flatten()
+          .cloned()
+          .collect();
+        let dependencies = stylex.state.dependencies();
+        let unused_styles = stylex.state.unused_styles().cloned();
+        let declarations = stylex.state.theme_declarations();
+        let class_name_keys = stylex.state.class_name_keys().cloned();
+        let analysis = stylex.state.analysis().cloned();
         drop(stylex);
 
         // StateManager shared this map during transformation and has just been
@@ -322,14 +860,14 @@ pub fn transform(
           None
         };
 
-        let transformed_code = print(
+        let transformed_code = print_program(
           cm,
           &program,
+          &comments,
           PrintArgs {
             source_map,
             inline_sources_content,
             emit_source_map_columns,
-            comments: Some(&comments),
             // Chain the emitted map onto the input map so it resolves all the
             // way back to the original authored file.
             orig: original_source_map,
@@ -337,39 +875,197 @@ pub fn transform(
           },
         );
 
-        let result = match transformed_code {
-          Ok(output) => output,
-          Err(e) => {
-            return Err(napi::Error::from_reason(format!(
-              "[StyleX] Failed to print transformed code: {}",
-              e
-            )));
-          },
-        };
-
-        let js_result = StyleXTransformResult {
-          code: result.code,
-          metadata: StyleXMetadata {
-            stylex: stylex_metadata,
-          },
-          map: result.map,
-        };
+        match transformed_code {
+          Ok(output) => Ok(TransformedFile {
+            code: output.code,
+            map: output.map,
+            metadata,
+            dependencies,
+            diagnostics: vec![],
+            unused_styles,
+            declarations,
+            class_name_keys,
+            analysis,
+          }),
+          Err(e) => Err(
+            napi::Error::from_reason(format!("[StyleX] Failed to print transformed code: {}", e))
+              .into(),
+          ),
+        }
+      };
 
-        Ok(js_result)
-      })
+      // Set the NAPI env in thread-local storage so env functions can call back
+      // to JS
+      match napi_env {
+        Some(napi_env) => utils::fn_parser::with_napi_env(napi_env, compile),
+        None => compile(),
+      }
     })
   }));
 
-  match result {
-    Ok(res) => res,
-    Err(error) => {
-      let error_msg = format_panic_message(&error);
+  let with_file = |mut diagnostics: Vec<Diagnostic>| {
+    for diagnostic in &mut diagnostics {
+      diagnostic
+        .file
+        .get_or_insert_with(|| diagnostics_file.clone());
+    }
+    diagnostics
+  };
 
-      Err(napi::Error::from_reason(error_msg))
+  match result {
+    Ok(Ok(mut file)) => {
+      file.diagnostics = with_file(collector.finish());
+      Ok(file)
     },
+    Ok(Err(mut failure)) => {
+      let mut diagnostics = collector.finish();
+      diagnostics.append(&mut failure.diagnostics);
+      failure.diagnostics = with_file(diagnostics);
+      Err(failure)
+    },
+    Err(error) => Err(TransformFailure {
+      error: napi::Error::from_reason(format_panic_message(&error)),
+      diagnostics: with_file(collector.finish_with_panic(&error)),
+    }),
   }
 }
 
+/// Builds the final stylesheet from the rules collected across every
+/// transformed file, as `@stylexjs/babel-plugin`'s `processStylexRules` does.
+#[napi(js_name = "processStylexRules")]
+pub fn process_stylex_rules(
+  #[napi(ts_arg_type = "ReadonlyArray<[string, StyleXRuleStyle, number]>")] rules: Vec<StyleXRule>,
+  options: Option<StyleXProcessRulesOptions>,
+) -> String {
+  initialize_logger();
+
+  let rules: Vec<_> = rules.into_iter().map(rule_to_metadata).collect();
+
+  build_stylesheet(&rules, &options.unwrap_or_default().into())
+}
+
+/// Reports, as JSON, what the rules of each file add up to in the stylesheet:
+/// its size and RTL share, the properties and values that weigh most, the
+/// same rule emitted under different class names, and the files bringing in
+/// the most. `rules` maps each file to the rules its transform collected, the
+/// shape bundler plugins keep them in.
+#[napi(js_name = "stylesheetReport")]
+pub fn stylesheet_report(
+  #[napi(ts_arg_type = "Record<string, ReadonlyArray<[string, StyleXRuleStyle, number]>>")]
+  rules: HashMap<String, Vec<StyleXRule>>,
+) -> Result<String> {
+  initialize_logger();
+
+  // A JS object has no order a HashMap keeps; sorting by file makes the report
+  // the same whatever order the files were compiled in.
+  let mut files: Vec<(String, Vec<MetaData>)> = rules
+    .into_iter()
+    .map(|(file, rules)| (file, rules.into_iter().map(rule_to_metadata).collect()))
+    .collect();
+  files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+  let report = build_stylesheet_report(
+    files
+      .iter()
+      .map(|(file, rules)| (file.as_str(), rules.as_slice())),
+  );
+
+  serde_json::to_string(&report).map_err(|error| napi::Error::from_reason(error.to_string()))
+}
+
+/// Records the class names of a build in a class-name manifest, and reports
+/// the declarations it renames and the class names two declarations share.
+///
+/// `class_name_keys` are the `classNameKeys` of every transformed file, and
+/// `previous` the manifest JSON the last build saved, if any. Under `frozen`, a
+/// build that renames a class fails instead, so CSS cached under the old names
+/// keeps matching the markup.
+#[napi(js_name = "updateClassNameManifest")]
+pub fn update_class_name_manifest(
+  #[napi(ts_arg_type = "ReadonlyArray<Record<string, string>>")] class_name_keys: Vec<
+    HashMap<String, String>,
+  >,
+  previous: Option<String>,
+  frozen: Option<bool>,
+) -> Result<StyleXClassNameManifestUpdate> {
+  initialize_logger();
+
+  let mut manifest: ClassNameManifest = match previous {
+    Some(json) => serde_json::from_str(&json).map_err(|error| {
+      napi::Error::from_reason(format!("[StyleX] Invalid class-name manifest: {}", error))
+    })?,
+    None => ClassNameManifest::default(),
+  };
+
+  let changes = manifest.update(
+    class_name_keys
+      .iter()
+      .flatten()
+      .map(|(key, class_name)| (key.as_str(), class_name.as_str())),
+  );
+
+  if frozen.unwrap_or(false) && !changes.renamed.is_empty() {
+    let renames: Vec<String> = changes
+      .renamed
+      .iter()
+      .map(|renamed| format!("  {}", renamed))
+      .collect();
+
+    return Err(napi::Error::from_reason(format!(
+      "[StyleX] The class-name manifest is frozen, but this build renames {} class(es):\n{}",
+      changes.renamed.len(),
+      renames.join("\n")
+    )));
+  }
+
+  let json = serde_json::to_string_pretty(&manifest)
+    .map_err(|error| napi::Error::from_reason(error.to_string()))?;
+
+  Ok(StyleXClassNameManifestUpdate::new(json, changes))
+}
+
+/// One merger per combination of `props` options, kept for the life of the
+/// process so compiled styles merged once are cache hits on every later
+/// render, as they are for the runtime's module-level `styleq`.
+static PROPS_MERGERS: LazyLock<[Styleq<RuntimeStyleValue>; 4]> = LazyLock::new(|| {
+  [(false, false), (false, true), (true, false), (true, true)].map(
+    |(disable_mix, dedupe_class_name_chunks)| {
+      create_styleq(StyleqOptions {
+        disable_mix,
+        dedupe_class_name_chunks,
+        ..Default::default()
+      })
+    },
+  )
+});
+
+fn merge_props(
+  styles: &[StyleqInput<RuntimeStyleValue>],
+  options: StyleXPropsOptions,
+) -> StyleXProps {
+  let disable_mix = options.disable_mix.unwrap_or(false);
+  let dedupe_class_name_chunks = options.dedupe_class_name_chunks.unwrap_or(false);
+
+  PROPS_MERGERS[usize::from(disable_mix) * 2 + usize::from(dedupe_class_name_chunks)]
+    .props(styles)
+    .into()
+}
+
+/// Merges compiled style objects into `className`, `style` and
+/// `data-style-src` exactly as `stylex.props` does at runtime, for rendering
+/// on the server without the JS runtime. `styles` holds what `stylex.props`
+/// would be called with: compiled styles, inline styles, `null`, booleans and
+/// nested arrays of them.
+#[napi]
+pub fn props(
+  #[napi(ts_arg_type = "ReadonlyArray<unknown>")] styles: Vec<StyleXPropsArgument>,
+  options: Option<StyleXPropsOptions>,
+) -> StyleXProps {
+  let styles: Vec<_> = styles.into_iter().map(|style| style.0).collect();
+
+  merge_props(&styles, options.unwrap_or_default())
+}
+
 #[cfg(test)]
 #[path = "tests/lib_tests.rs"]
 mod tests;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use napi::JsObject;
 use napi_derive::napi;
 use rustc_hash::FxHashMap;
@@ -10,9 +12,25 @@ use stylex_structures::{
   stylex_options::{ModuleResolution, ModuleResolutionKind, StyleXOptionsParams},
 };
 
+use stylex_css::stylesheet::{
+  LayerNaming as StylesheetLayerNaming, LayersOptions, ProcessStylexRulesOptions,
+  class_name_manifest::{ClassNameChanges, ClassNameCollision, RenamedClassName},
+};
+use stylex_macros::diagnostic::{Diagnostic, Severity, SourceSpan};
+use stylex_styleq::{RuntimeStyleValue, StyleqProps};
+use stylex_types::{
+  enums::data_structures::injectable_style::InjectableStyleKind,
+  structures::{
+    analysis::{Analysis, AnalyzedRule, StyleAnalysis, TokenAnalysis},
+    injectable_style::{InjectableConstStyle, InjectableStyle},
+    meta_data::MetaData,
+    unused_styles::{UnusedNamespace, UnusedStyles, UnusedToken},
+  },
+};
+
 use crate::enums::{
-  ImportSourceUnion, PropertyValidationMode, RuntimeInjectionUnion, SourceMaps,
-  StyleXModuleResolution, SxPropNameUnion,
+  ImportSourceUnion, LayerNaming, PropertyValidationMode, RuntimeInjectionUnion, SourceMaps,
+  StyleXInlineStyle, StyleXModuleResolution, SxPropNameUnion, UseLayersUnion,
 };
 
 #[napi(object)]
@@ -20,6 +38,8 @@ pub struct StyleXOptions {
   #[napi(ts_type = "'application-order' | 'property-specificity' | 'legacy-expand-shorthands'")]
   pub style_resolution: Option<String>,
   pub enable_font_size_px_to_rem: Option<bool>,
+  pub enable_color_normalization: Option<bool>,
+  pub enable_at_rule_normalization: Option<bool>,
   #[napi(ts_type = "boolean | string")]
   pub runtime_injection: Option<RuntimeInjectionUnion>,
   pub class_name_prefix: Option<String>,
@@ -32,9 +52,23 @@ pub struct StyleXOptions {
   pub enable_media_query_order: Option<bool>,
   pub enable_logical_styles_polyfill: Option<bool>,
   pub enable_legacy_value_flipping: Option<bool>,
+  pub enable_native_logical_styles: Option<bool>,
   #[napi(js_name = "enableLTRRTLComments")]
   pub enable_ltr_rtl_comments: Option<bool>,
   pub legacy_disable_layers: Option<bool>,
+  /// The stylesheet's layers, as `processStylexRules` takes them. Read by the
+  /// transform only to set the `layer` of each `metadata.stylex` rule, which
+  /// it can under `naming: 'bucket'`.
+  #[napi(
+    ts_type = "boolean | { before?: ReadonlyArray<string>; after?: ReadonlyArray<string>; prefix?: string; naming?: 'sequential' | 'bucket' }"
+  )]
+  pub use_layers: Option<UseLayersUnion>,
+  /// Flatten the stylesheet's specificity with `:where()` when it has no
+  /// layers, as `processStylexRules` takes it. Not read by the transform.
+  pub flatten_specificity: Option<bool>,
+  /// Scope RTL overrides with `:dir()` rather than the `dir` of `html`, as
+  /// `processStylexRules` takes it. Not read by the transform.
+  pub use_dir_pseudo_class: Option<bool>,
   pub dev: Option<bool>,
   pub test: Option<bool>,
   pub debug: Option<bool>,
@@ -77,12 +111,28 @@ pub struct StyleXOptions {
   /// `false` to disable.
   #[napi(ts_type = "string | false")]
   pub sx_prop_name: Option<SxPropNameUnion>,
+  /// Directory to keep transform results in between builds. A file whose
+  /// source, options and imported theme files are unchanged is read back from
+  /// it instead of being compiled again. Not used when `env` holds functions
+  /// or `debugFilePath` is one.
+  pub cache_directory: Option<String>,
+  /// Report the `stylex.create` namespaces and `defineVars` tokens the file
+  /// never reads as the result's `unusedStyles`.
+  pub report_unused_styles: Option<bool>,
+  /// Describe the file's `defineVars` and `defineConsts` exports as a
+  /// TypeScript declaration file, returned as the result's `declarations`.
+  pub emit_theme_declarations: Option<bool>,
+  pub emit_class_name_keys: Option<bool>,
 }
 
 #[napi(object)]
 pub struct StyleXMetadata {
-  #[napi(ts_type = "([string, { ltr: string; rtl?: null | string }, number])[]")]
+  #[napi(ts_type = "([string, { ltr: string; rtl?: null | string; layer?: string }, number])[]")]
   pub stylex: Vec<JsObject>,
+  /// Absolute paths of the files the output depends on besides the source:
+  /// every theme or consts file an import resolved to, and every
+  /// `package.json` read to resolve them. Bundlers should watch them.
+  pub dependencies: Vec<String>,
 }
 
 #[napi(object)]
@@ -90,6 +140,239 @@ pub struct StyleXTransformResult {
   pub code: String,
   pub metadata: StyleXMetadata,
   pub map: Option<String>,
+  /// Warnings the transform reported without failing, such as invalid values
+  /// under `propertyValidationMode: 'warn'`.
+  pub diagnostics: Vec<StyleXDiagnostic>,
+  /// Set when `reportUnusedStyles` is on.
+  pub unused_styles: Option<StyleXUnusedStyles>,
+  /// The `.d.ts` text describing the file's `defineVars` and `defineConsts`
+  /// exports. Set when `emitThemeDeclarations` is on and the file has any.
+  pub declarations: Option<String>,
+  /// The declaration each class name of the file is hashed from, to the name,
+  /// for `updateClassNameManifest`. Set when `emitClassNameKeys` is on.
+  #[napi(ts_type = "Record<string, string>")]
+  pub class_name_keys: Option<HashMap<String, String>>,
+}
+
+/// A build's class names recorded in a class-name manifest.
+#[napi(object)]
+pub struct StyleXClassNameManifestUpdate {
+  /// The manifest JSON to save for the next build.
+  pub manifest: String,
+  /// Declarations the manifest had under another class name.
+  pub renamed: Vec<StyleXRenamedClassName>,
+  /// Class names two different declarations hash to.
+  pub collisions: Vec<StyleXClassNameCollision>,
+}
+
+/// How `props` merges, as the options of the runtime `styleq`.
+#[napi(object)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StyleXPropsOptions {
+  /// Let a later inline style override an earlier one as a whole, instead of
+  /// property by property against the compiled styles.
+  pub disable_mix: Option<bool>,
+  /// Leave out a compiled class-name chunk already in the result.
+  pub dedupe_class_name_chunks: Option<bool>,
+}
+
+/// What `stylex.props` returns: each key is set only when it carries
+/// something.
+#[napi(object, object_from_js = false)]
+pub struct StyleXProps {
+  pub class_name: Option<String>,
+  #[napi(ts_type = "Record<string, string | number | boolean | null>")]
+  pub style: Option<StyleXInlineStyle>,
+  #[napi(js_name = "data-style-src")]
+  pub data_style_src: Option<String>,
+}
+
+#[napi(object)]
+pub struct StyleXRenamedClassName {
+  pub key: String,
+  pub from: String,
+  pub to: String,
+}
+
+#[napi(object)]
+pub struct StyleXClassNameCollision {
+  pub class_name: String,
+  pub keys: Vec<String>,
+}
+
+/// What the file declares with `stylex.create` and `defineVars` and never
+/// reads. Exported `stylex.create` results are not reported, since they may
+/// be read by other files.
+#[napi(object)]
+pub struct StyleXUnusedStyles {
+  /// Namespaces never passed to `props`, `attrs` or `sx`.
+  pub namespaces: Vec<StyleXUnusedNamespace>,
+  /// `defineVars` tokens never read in the file.
+  pub tokens: Vec<StyleXUnusedToken>,
+  /// Class names only the unused namespaces generate.
+  pub dead_class_names: Vec<String>,
+}
+
+#[napi(object)]
+pub struct StyleXUnusedNamespace {
+  pub variable: String,
+  pub namespace: String,
+  pub span: Option<StyleXSourceSpan>,
+  pub class_names: Vec<String>,
+}
+
+#[napi(object)]
+pub struct StyleXUnusedToken {
+  pub variable: String,
+  pub token: String,
+  pub span: Option<StyleXSourceSpan>,
+}
+
+/// What the code at an offset compiles to. `style` is set when `kind` is
+/// `'style'`, and `token` when it is `'token'`.
+#[napi(object)]
+pub struct StyleXAnalysis {
+  #[napi(ts_type = "'style' | 'token'")]
+  pub kind: String,
+  pub style: Option<StyleXStyleAnalysis>,
+  pub token: Option<StyleXTokenAnalysis>,
+}
+
+/// A `stylex.create` namespace, a property inside one, or a `styles.namespace`
+/// read.
+#[napi(object)]
+pub struct StyleXStyleAnalysis {
+  pub variable: String,
+  pub namespace: String,
+  /// The property under the offset, when it is inside one.
+  pub property: Option<String>,
+  pub span: Option<StyleXSourceSpan>,
+  /// The atomic rules the namespace, or only the property, compiles to.
+  pub rules: Vec<StyleXAnalyzedRule>,
+}
+
+#[napi(object)]
+pub struct StyleXAnalyzedRule {
+  pub property: String,
+  pub class_name: String,
+  pub ltr: String,
+  pub rtl: Option<String>,
+  pub priority: f64,
+}
+
+/// A `vars.token` read, or a token's key in a `defineVars` call.
+#[napi(object)]
+pub struct StyleXTokenAnalysis {
+  pub variable: String,
+  pub token: String,
+  pub span: Option<StyleXSourceSpan>,
+  /// What the read compiles to, such as `var(--x1a2b3c)`.
+  pub value: Option<String>,
+  /// The file declaring the token.
+  pub file: Option<String>,
+  /// Where the token is declared in `file`, for go-to-definition.
+  pub declaration: Option<StyleXSourceSpan>,
+  /// The token's value as written in its declaration.
+  pub declared_value: Option<String>,
+}
+
+/// A problem the transform found in a file. A failed transform throws an
+/// error carrying these as its `diagnostics`, the one it failed on last.
+#[napi(object)]
+pub struct StyleXDiagnostic {
+  /// Stable identifier of the kind of problem, such as
+  /// `stylex/invalid-property-value`.
+  pub code: String,
+  #[napi(ts_type = "'error' | 'warning'")]
+  pub severity: String,
+  pub message: String,
+  pub file: Option<String>,
+  /// Where the offending code is, when it could be found.
+  pub span: Option<StyleXSourceSpan>,
+  pub suggestion: Option<String>,
+  /// Other places that explain the problem.
+  pub related: Vec<StyleXRelatedSpan>,
+}
+
+/// Lines and columns count from 1; `endColumn` is just past the last
+/// character.
+#[napi(object)]
+pub struct StyleXSourceSpan {
+  pub line: u32,
+  pub column: u32,
+  pub end_line: u32,
+  pub end_column: u32,
+}
+
+#[napi(object)]
+pub struct StyleXRelatedSpan {
+  pub message: String,
+  pub file: Option<String>,
+  pub span: StyleXSourceSpan,
+}
+
+/// One file of a `transformBatch` call.
+#[napi(object)]
+pub struct StyleXBatchFile {
+  pub filename: String,
+  pub code: String,
+  pub input_source_map: Option<String>,
+}
+
+/// The outcome for one file of a `transformBatch` call: `result` when it
+/// compiled, `error` and its `diagnostics` when it did not.
+#[napi(object)]
+pub struct StyleXBatchResult {
+  pub filename: String,
+  pub result: Option<StyleXTransformResult>,
+  pub error: Option<String>,
+  pub diagnostics: Option<Vec<StyleXDiagnostic>>,
+}
+
+/// How `importDesignTokens` turns a design tokens file into a module.
+#[napi(object)]
+#[derive(Default)]
+pub struct StyleXDesignTokenOptions {
+  /// The name the variables are exported under. Defaults to `tokens`.
+  pub export_name: Option<String>,
+  /// Modes compiled into conditions of the variables instead of themes, such
+  /// as `{ dark: '@media (prefers-color-scheme: dark)' }`.
+  #[napi(ts_type = "Record<string, string>")]
+  pub mode_conditions: Option<FxHashMap<String, String>>,
+}
+
+/// A design tokens file imported as a `.stylex.ts` module: the module's
+/// source, and the result of compiling it.
+#[napi(object)]
+pub struct StyleXDesignTokenImport {
+  pub source: String,
+  pub result: StyleXTransformResult,
+}
+
+/// The outcome of a `StyleXCompiler` compile: the result `transform` would
+/// give, and the rules the file added to and removed from the stylesheet
+/// since its previous compile.
+#[napi(object)]
+pub struct StyleXWatchResult {
+  pub result: StyleXTransformResult,
+  #[napi(ts_type = "([string, { ltr: string; rtl?: null | string; layer?: string }, number])[]")]
+  pub added: Vec<JsObject>,
+  #[napi(ts_type = "([string, { ltr: string; rtl?: null | string; layer?: string }, number])[]")]
+  pub removed: Vec<JsObject>,
+}
+
+/// The outcome for one file a `StyleXCompiler.invalidate` call compiled
+/// again: the rules it added and removed, or `error` and its `diagnostics`
+/// when it no longer compiles, in which case its rules are left as they were.
+#[napi(object)]
+pub struct StyleXRuleDelta {
+  pub filename: String,
+  #[napi(ts_type = "([string, { ltr: string; rtl?: null | string; layer?: string }, number])[]")]
+  pub added: Vec<JsObject>,
+  #[napi(ts_type = "([string, { ltr: string; rtl?: null | string; layer?: string }, number])[]")]
+  pub removed: Vec<JsObject>,
+  pub error: Option<String>,
+  pub diagnostics: Option<Vec<StyleXDiagnostic>>,
 }
 
 impl TryFrom<StyleXOptions> for StyleXOptionsParams {
@@ -151,6 +434,8 @@ impl TryFrom<StyleXOptions> for StyleXOptionsParams {
     Ok(StyleXOptionsParams {
       style_resolution,
       enable_font_size_px_to_rem: val.enable_font_size_px_to_rem,
+      enable_color_normalization: val.enable_color_normalization,
+      enable_at_rule_normalization: val.enable_at_rule_normalization,
       runtime_injection,
       class_name_prefix: val.class_name_prefix,
       defined_stylex_css_variables: val.defined_stylex_css_variables,
@@ -160,8 +445,12 @@ impl TryFrom<StyleXOptions> for StyleXOptionsParams {
       enable_media_query_order: val.enable_media_query_order,
       enable_logical_styles_polyfill: val.enable_logical_styles_polyfill,
       enable_legacy_value_flipping: val.enable_legacy_value_flipping,
+      enable_native_logical_styles: val.enable_native_logical_styles,
       enable_ltr_rtl_comments: val.enable_ltr_rtl_comments,
       use_real_file_for_source: val.use_real_file_for_source,
+      report_unused_styles: val.report_unused_styles,
+      emit_theme_declarations: val.emit_theme_declarations,
+      emit_class_name_keys: val.emit_class_name_keys,
       dev: val.dev,
       test: val.test,
       debug: val.debug.or(val.dev),
@@ -175,11 +464,270 @@ impl TryFrom<StyleXOptions> for StyleXOptionsParams {
       sx_prop_name,
       property_validation_mode,
       env: None, // Parsed separately via parse_env_object since it needs napi::Env
-      debug_file_path: None, // Parsed separately via parse_debug_file_path since it needs napi::Env
+      debug_file_path: None, // Parsed separately via read_debug_file_path since it needs napi::Env
     })
   }
 }
 
+/// The style half of a collected `[className, style, priority]` rule.
+#[napi(object)]
+pub struct StyleXRuleStyle {
+  pub ltr: String,
+  #[napi(ts_type = "null | string")]
+  pub rtl: Option<String>,
+  pub const_key: Option<String>,
+  pub const_val: Option<String>,
+}
+
+/// A rule as collected from `metadata.stylex`.
+pub type StyleXRule = (String, StyleXRuleStyle, f64);
+
+#[napi(object)]
+#[derive(Default)]
+pub struct StyleXProcessRulesOptions {
+  #[napi(
+    ts_type = "boolean | { before?: ReadonlyArray<string>; after?: ReadonlyArray<string>; prefix?: string; naming?: 'sequential' | 'bucket' }"
+  )]
+  pub use_layers: Option<UseLayersUnion>,
+  #[napi(js_name = "enableLTRRTLComments")]
+  pub enable_ltr_rtl_comments: Option<bool>,
+  pub legacy_disable_layers: Option<bool>,
+  pub use_legacy_classnames_sort: Option<bool>,
+  pub flatten_specificity: Option<bool>,
+  pub use_dir_pseudo_class: Option<bool>,
+}
+
+/// The layers `useLayers` asks for, or `None` when it leaves them off.
+pub(crate) fn layers_options(use_layers: Option<UseLayersUnion>) -> Option<LayersOptions> {
+  match use_layers {
+    Some(UseLayersUnion::Enabled(true)) => Some(LayersOptions::default()),
+    Some(UseLayersUnion::Enabled(false)) | None => None,
+    Some(UseLayersUnion::Layers(layers)) => Some(LayersOptions {
+      before: layers.before.unwrap_or_default(),
+      after: layers.after.unwrap_or_default(),
+      prefix: layers.prefix,
+      naming: match layers.naming {
+        Some(LayerNaming::Bucket) => StylesheetLayerNaming::Bucket,
+        Some(LayerNaming::Sequential) | None => StylesheetLayerNaming::Sequential,
+      },
+    }),
+  }
+}
+
+impl From<StyleXProcessRulesOptions> for ProcessStylexRulesOptions {
+  fn from(val: StyleXProcessRulesOptions) -> Self {
+    ProcessStylexRulesOptions {
+      use_layers: layers_options(val.use_layers),
+      enable_ltr_rtl_comments: val.enable_ltr_rtl_comments.unwrap_or(false),
+      legacy_disable_layers: val.legacy_disable_layers.unwrap_or(false),
+      use_legacy_classnames_sort: val.use_legacy_classnames_sort.unwrap_or(false),
+      flatten_specificity: val.flatten_specificity.unwrap_or(false),
+      use_dir_pseudo_class: val.use_dir_pseudo_class.unwrap_or(false),
+    }
+  }
+}
+
+impl From<Diagnostic> for StyleXDiagnostic {
+  fn from(diagnostic: Diagnostic) -> Self {
+    StyleXDiagnostic {
+      code: diagnostic.code.into_owned(),
+      severity: match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+      }
+      .to_string(),
+      message: diagnostic.message,
+      file: diagnostic.file,
+      span: diagnostic.span.map(Into::into),
+      suggestion: diagnostic.suggestion,
+      related: diagnostic
+        .related
+        .into_iter()
+        .map(|related| StyleXRelatedSpan {
+          message: related.message,
+          file: related.file,
+          span: related.span.into(),
+        })
+        .collect(),
+    }
+  }
+}
+
+impl From<SourceSpan> for StyleXSourceSpan {
+  fn from(span: SourceSpan) -> Self {
+    let to_u32 = |position: usize| u32::try_from(position).unwrap_or(u32::MAX);
+
+    StyleXSourceSpan {
+      line: to_u32(span.line),
+      column: to_u32(span.column),
+      end_line: to_u32(span.end_line),
+      end_column: to_u32(span.end_column),
+    }
+  }
+}
+
+impl From<RenamedClassName> for StyleXRenamedClassName {
+  fn from(renamed: RenamedClassName) -> Self {
+    StyleXRenamedClassName {
+      key: renamed.key,
+      from: renamed.from,
+      to: renamed.to,
+    }
+  }
+}
+
+impl From<ClassNameCollision> for StyleXClassNameCollision {
+  fn from(collision: ClassNameCollision) -> Self {
+    StyleXClassNameCollision {
+      class_name: collision.class_name,
+      keys: collision.keys,
+    }
+  }
+}
+
+impl StyleXClassNameManifestUpdate {
+  pub(crate) fn new(manifest: String, changes: ClassNameChanges) -> Self {
+    StyleXClassNameManifestUpdate {
+      manifest,
+      renamed: changes.renamed.into_iter().map(Into::into).collect(),
+      collisions: changes.collisions.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+impl From<StyleqProps<RuntimeStyleValue>> for StyleXProps {
+  fn from(props: StyleqProps<RuntimeStyleValue>) -> Self {
+    StyleXProps {
+      class_name: props.class_name,
+      style: props.style.map(StyleXInlineStyle),
+      data_style_src: props.data_style_src,
+    }
+  }
+}
+
+impl From<UnusedStyles> for StyleXUnusedStyles {
+  fn from(unused: UnusedStyles) -> Self {
+    StyleXUnusedStyles {
+      namespaces: unused
+        .namespaces
+        .into_iter()
+        .map(
+          |UnusedNamespace {
+             variable,
+             namespace,
+             span,
+             class_names,
+           }| StyleXUnusedNamespace {
+            variable,
+            namespace,
+            span: span.map(Into::into),
+            class_names,
+          },
+        )
+        .collect(),
+      tokens: unused
+        .tokens
+        .into_iter()
+        .map(
+          |UnusedToken {
+             variable,
+             token,
+             span,
+           }| StyleXUnusedToken {
+            variable,
+            token,
+            span: span.map(Into::into),
+          },
+        )
+        .collect(),
+      dead_class_names: unused.dead_class_names,
+    }
+  }
+}
+
+impl From<Analysis> for StyleXAnalysis {
+  fn from(analysis: Analysis) -> Self {
+    match analysis {
+      Analysis::Style(StyleAnalysis {
+        variable,
+        namespace,
+        property,
+        span,
+        rules,
+      }) => StyleXAnalysis {
+        kind: "style".to_string(),
+        style: Some(StyleXStyleAnalysis {
+          variable,
+          namespace,
+          property,
+          span: span.map(Into::into),
+          rules: rules
+            .into_iter()
+            .map(
+              |AnalyzedRule {
+                 property,
+                 class_name,
+                 ltr,
+                 rtl,
+                 priority,
+               }| StyleXAnalyzedRule {
+                property,
+                class_name,
+                ltr,
+                rtl,
+                priority,
+              },
+            )
+            .collect(),
+        }),
+        token: None,
+      },
+      Analysis::Token(TokenAnalysis {
+        variable,
+        token,
+        span,
+        value,
+        file,
+        declaration,
+        declared_value,
+      }) => StyleXAnalysis {
+        kind: "token".to_string(),
+        style: None,
+        token: Some(StyleXTokenAnalysis {
+          variable,
+          token,
+          span: span.map(Into::into),
+          value,
+          file,
+          declaration: declaration.map(Into::into),
+          declared_value,
+        }),
+      },
+    }
+  }
+}
+
+/// Rebuilds the metadata a rule was serialized from. A rule carrying both a
+/// `constKey` and a `constVal` is a `defineConsts` entry.
+pub(crate) fn rule_to_metadata((class_name, style, priority): StyleXRule) -> MetaData {
+  let injectable_style = match (style.const_key, style.const_val) {
+    (Some(const_key), Some(const_value)) => InjectableStyleKind::Const(InjectableConstStyle {
+      ltr: style.ltr,
+      rtl: style.rtl,
+      priority: Some(priority),
+      const_key,
+      const_value,
+    }),
+    _ => InjectableStyleKind::Regular(InjectableStyle {
+      ltr: style.ltr,
+      rtl: style.rtl,
+      priority: Some(priority),
+    }),
+  };
+
+  MetaData::new(class_name, injectable_style)
+}
+
 #[cfg(test)]
 #[path = "../tests/structs_tests.rs"]
 mod tests;
@@ -0,0 +1,284 @@
+// Tests for the on-disk transform cache and its keys.
+// Source: crates/stylex-rs-compiler/src/utils/cache.rs
+
+use std::{
+  cell::Cell,
+  time::{Duration, UNIX_EPOCH},
+};
+
+use indexmap::IndexMap;
+use rustc_hash::FxHashMap;
+use stylex_macros::{
+  diagnostic::{Severity, codes},
+  panic_macros::stylex_err,
+};
+use stylex_structures::stylex_env::{EnvEntry, JSFunction};
+
+use super::*;
+
+fn unique_temp_dir(prefix: &str) -> PathBuf {
+  let unique = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or_default();
+  std::env::temp_dir().join(format!("{prefix}-{unique}"))
+}
+
+fn transformed(code: &str, dependencies: &[&Path]) -> TransformedFile {
+  TransformedFile {
+    code: code.to_string(),
+    map: None,
+    metadata: vec![],
+    dependencies: dependencies
+      .iter()
+      .map(|path| path.to_string_lossy().into_owned())
+      .collect(),
+    diagnostics: vec![],
+    unused_styles: None,
+    declarations: None,
+    class_name_keys: None,
+    analysis: None,
+  }
+}
+
+fn key_of(config: &StyleXOptionsParams) -> Option<String> {
+  cache_key(
+    "/src/app.js",
+    "const a = 1;",
+    config,
+    None,
+    &OutputOptions::default(),
+  )
+}
+
+/// Writes `contents` to `path` with an mtime well before now, as if it was
+/// saved before the compile that reads it started.
+fn write_old_file(path: &Path, contents: &str) {
+  fs::write(path, contents).expect("write file");
+
+  let file = fs::File::options()
+    .write(true)
+    .open(path)
+    .expect("open file");
+  file
+    .set_modified(SystemTime::now() - Duration::from_secs(60))
+    .expect("set mtime");
+}
+
+#[test]
+fn cache_key_ignores_the_order_of_hash_map_options() {
+  let aliases = |names: &[&str]| {
+    let mut aliases = FxHashMap::default();
+    for name in names {
+      aliases.insert(format!("@{name}/*"), vec![format!("./{name}/*")]);
+    }
+    aliases
+  };
+
+  let first = StyleXOptionsParams {
+    aliases: Some(aliases(&["a", "b", "c", "d", "e", "f"])),
+    ..Default::default()
+  };
+  let second = StyleXOptionsParams {
+    aliases: Some(aliases(&["f", "e", "d", "c", "b", "a"])),
+    ..Default::default()
+  };
+
+  assert_eq!(key_of(&first), key_of(&second));
+}
+
+#[test]
+fn cache_key_changes_with_the_source_options_and_output() {
+  let config = StyleXOptionsParams::default();
+  let base = key_of(&config);
+
+  let other_code = cache_key(
+    "/src/app.js",
+    "const a = 2;",
+    &config,
+    None,
+    &OutputOptions::default(),
+  );
+  let other_file = cache_key(
+    "/src/other.js",
+    "const a = 1;",
+    &config,
+    None,
+    &OutputOptions::default(),
+  );
+  let other_options = key_of(&StyleXOptionsParams {
+    class_name_prefix: Some("y".to_string()),
+    ..Default::default()
+  });
+  let other_debug_file_path = cache_key(
+    "/src/app.js",
+    "const a = 1;",
+    &config,
+    Some("app.js"),
+    &OutputOptions::default(),
+  );
+  let other_output = cache_key(
+    "/src/app.js",
+    "const a = 1;",
+    &config,
+    None,
+    &OutputOptions {
+      inline_sources_content: Some(false),
+      ..Default::default()
+    },
+  );
+
+  for other in [
+    other_code,
+    other_file,
+    other_options,
+    other_debug_file_path,
+    other_output,
+  ] {
+    assert!(other.is_some());
+    assert_ne!(other, base);
+  }
+}
+
+#[test]
+fn cache_key_refuses_env_functions() {
+  let mut env = IndexMap::new();
+  env.insert(
+    "color".to_string(),
+    EnvEntry::Function(JSFunction::new(|_| {
+      stylex_ast::ast::convertors::create_string_expr("red")
+    })),
+  );
+
+  let config = StyleXOptionsParams {
+    env: Some(env),
+    ..Default::default()
+  };
+
+  assert_eq!(key_of(&config), None);
+}
+
+#[test]
+fn cache_key_covers_env_values_but_not_their_order() {
+  let config = |entries: &[(&str, &str)]| StyleXOptionsParams {
+    env: Some(
+      entries
+        .iter()
+        .map(|(name, value)| {
+          (
+            name.to_string(),
+            EnvEntry::Expr(stylex_ast::ast::convertors::create_string_expr(value)),
+          )
+        })
+        .collect(),
+    ),
+    ..Default::default()
+  };
+
+  let first = key_of(&config(&[("accent", "red"), ("radius", "4px")]));
+
+  assert!(first.is_some());
+  assert_eq!(
+    first,
+    key_of(&config(&[("radius", "4px"), ("accent", "red")]))
+  );
+  assert_ne!(
+    first,
+    key_of(&config(&[("accent", "blue"), ("radius", "4px")]))
+  );
+}
+
+#[test]
+fn returns_what_was_stored() {
+  let cache = TransformCache::new(unique_temp_dir("stylex-cache-round-trip"));
+  let warning = Diagnostic::from_error(
+    &stylex_err("Cannot use fallbacks for shorthands.").with_code(codes::INVALID_SHORTHAND),
+    Severity::Warning,
+  );
+
+  let first = cache
+    .get_or_insert_with("key", || {
+      Ok(TransformedFile {
+        diagnostics: vec![warning.clone()],
+        ..transformed("compiled", &[])
+      })
+    })
+    .expect("transform succeeds");
+  let second = cache
+    .get_or_insert_with("key", || panic!("should have been cached"))
+    .expect("cache hit");
+
+  assert_eq!(first.code, "compiled");
+  assert_eq!(second.code, "compiled");
+  assert_eq!(second.diagnostics, vec![warning]);
+}
+
+#[test]
+fn does_not_store_a_failed_transform() {
+  let cache = TransformCache::new(unique_temp_dir("stylex-cache-failure"));
+
+  let result = cache.get_or_insert_with("key", || Err(napi::Error::from_reason("boom").into()));
+  assert!(result.is_err());
+
+  let calls = Cell::new(0);
+  cache
+    .get_or_insert_with("key", || {
+      calls.set(calls.get() + 1);
+      Ok(transformed("compiled", &[]))
+    })
+    .expect("transform succeeds");
+
+  assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn a_changed_dependency_invalidates_the_entry() {
+  let directory = unique_temp_dir("stylex-cache-dependency");
+  fs::create_dir_all(&directory).expect("create dir");
+
+  let theme = directory.join("tokens.stylex.js");
+  write_old_file(&theme, "export const vars = {};");
+
+  let cache = TransformCache::new(directory.join("cache"));
+  cache
+    .get_or_insert_with("key", || Ok(transformed("before", &[&theme])))
+    .expect("transform succeeds");
+
+  let hit = cache.get("key").expect("dependency unchanged");
+  assert_eq!(hit.dependencies, vec![theme.to_string_lossy().into_owned()]);
+
+  write_old_file(&theme, "export const vars = { changed: true };");
+
+  assert!(cache.get("key").is_none());
+}
+
+#[test]
+fn a_dependency_modified_during_the_compile_is_not_cached() {
+  let directory = unique_temp_dir("stylex-cache-racing-dependency");
+  fs::create_dir_all(&directory).expect("create dir");
+
+  let theme = directory.join("tokens.stylex.js");
+  let cache = TransformCache::new(directory.join("cache"));
+
+  cache
+    .get_or_insert_with("key", || {
+      fs::write(&theme, "export const vars = {};").expect("write file");
+      Ok(transformed("compiled", &[&theme]))
+    })
+    .expect("transform succeeds");
+
+  assert!(cache.get("key").is_none());
+}
+
+#[test]
+fn an_unreadable_entry_is_a_miss() {
+  let directory = unique_temp_dir("stylex-cache-corrupt");
+  fs::create_dir_all(&directory).expect("create dir");
+  // What a writer killed mid-write would leave, were entries not renamed
+  // into place.
+  fs::write(directory.join("key.json"), r#"{"code":"const a"#).expect("write file");
+
+  let cache = TransformCache::new(directory);
+
+  assert!(cache.get("key").is_none());
+}
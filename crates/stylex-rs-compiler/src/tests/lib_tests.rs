@@ -1,3 +1,6 @@
+use stylex_enums::property_validation_mode::PropertyValidationMode;
+use stylex_structures::stylex_options::ModuleResolution;
+
 use super::*;
 
 #[test]
@@ -237,3 +240,706 @@ fn backfill_handles_a_map_with_no_sources() {
 
   assert!(contents_of(&map).is_empty());
 }
+
+const INVALID_COLOR: &str = r#"import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: { color: 'not-a-color' },
+});
+"#;
+
+/// Compiles `code` as `App.js` in a directory of its own. Code frames are
+/// read from the file on disk, so it is written there too.
+fn transform_with(
+  code: &str,
+  config: StyleXOptionsParams,
+) -> (
+  String,
+  std::result::Result<TransformedFile, TransformFailure>,
+) {
+  transform_in_directory("App.js", code, &[], CompileMode::Transform, config)
+}
+
+/// Writes `code` to `name`, and each of `files` beside it, in a fresh
+/// directory and compiles `name` there.
+fn transform_in_directory(
+  name: &str,
+  code: &str,
+  files: &[(&str, &str)],
+  mode: CompileMode,
+  mut config: StyleXOptionsParams,
+) -> (
+  String,
+  std::result::Result<TransformedFile, TransformFailure>,
+) {
+  let unique = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or_default();
+  let directory = std::env::temp_dir().join(format!("stylex-diagnostics-{unique}"));
+  std::fs::create_dir_all(&directory).expect("create dir");
+
+  for (name, contents) in files {
+    std::fs::write(directory.join(name), contents).expect("write file");
+  }
+
+  let filename = directory.join(name).to_string_lossy().into_owned();
+  std::fs::write(&filename, code).expect("write file");
+
+  if let Some(resolution) = config.unstable_module_resolution.as_mut() {
+    resolution.root_dir = Some(directory.to_string_lossy().into_owned());
+  }
+
+  let outcome = transform_source(
+    filename.clone(),
+    code.to_string(),
+    OutputOptions::default(),
+    &mut config,
+    mode,
+    None,
+    None,
+  );
+
+  (filename, outcome)
+}
+
+fn validating(property_validation_mode: PropertyValidationMode) -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    property_validation_mode: Some(property_validation_mode),
+    ..Default::default()
+  }
+}
+
+#[test]
+fn a_warning_is_returned_with_the_compiled_file() {
+  let (filename, outcome) = transform_with(INVALID_COLOR, validating(PropertyValidationMode::Warn));
+  let file = outcome.expect("a warning does not fail the transform");
+
+  assert_eq!(file.diagnostics.len(), 1);
+
+  let warning = &file.diagnostics[0];
+  assert_eq!(warning.code, codes::INVALID_PROPERTY_VALUE);
+  assert_eq!(warning.severity, Severity::Warning);
+  assert_eq!(
+    warning.message,
+    r#""not-a-color" is not a valid value for color. Expected <color>."#
+  );
+  assert_eq!(warning.file.as_deref(), Some(filename.as_str()));
+  assert_eq!(
+    warning.suggestion.as_deref(),
+    Some("Use a value matching <color>.")
+  );
+
+  // Debug builds frame the reprinted module rather than the file, so only the
+  // extent of the span is the same in every build.
+  let span = warning.span.expect("the value is located");
+  assert_eq!(span.line, span.end_line);
+  assert_eq!(span.end_column - span.column, "'not-a-color'".len());
+}
+
+#[test]
+fn the_error_a_transform_fails_on_is_its_last_diagnostic() {
+  let (_, Err(failure)) = transform_with(INVALID_COLOR, validating(PropertyValidationMode::Throw))
+  else {
+    panic!("an invalid value fails the transform");
+  };
+
+  assert!(
+    failure
+      .error
+      .reason
+      .contains("is not a valid value for color")
+  );
+  assert_eq!(failure.diagnostics.len(), 1);
+
+  let error = &failure.diagnostics[0];
+  assert_eq!(error.code, codes::INVALID_PROPERTY_VALUE);
+  assert_eq!(error.severity, Severity::Error);
+  assert!(error.span.is_some());
+}
+
+#[test]
+fn a_file_that_does_not_parse_reports_where() {
+  let (filename, Err(failure)) =
+    transform_with("const a = ;\n", validating(PropertyValidationMode::Silent))
+  else {
+    panic!("the file does not parse");
+  };
+
+  assert_eq!(failure.diagnostics.len(), 1);
+
+  let error = &failure.diagnostics[0];
+  assert_eq!(error.code, codes::PARSE_ERROR);
+  assert_eq!(error.file.as_deref(), Some(filename.as_str()));
+  assert_eq!(
+    error.span.map(|span| (span.line, span.column)),
+    Some((1, 11))
+  );
+}
+
+#[test]
+fn a_check_reports_every_failing_call_where_it_is_written() {
+  let code = r#"import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: { color: 'not-a-color' },
+});
+
+export const empty = stylex.create();
+
+export const valid = stylex.create({
+  root: { color: 'red' },
+});
+"#;
+
+  let (_, outcome) = transform_in_directory(
+    "App.js",
+    code,
+    &[],
+    CompileMode::Check,
+    validating(PropertyValidationMode::Throw),
+  );
+  let file = outcome.expect("a check goes on past a failing call");
+
+  let reported: Vec<_> = file
+    .diagnostics
+    .iter()
+    .map(|diagnostic| {
+      (
+        diagnostic.code.as_ref(),
+        diagnostic.severity,
+        diagnostic.span.map(|span| span.line),
+      )
+    })
+    .collect();
+
+  assert_eq!(
+    reported,
+    [
+      (codes::INVALID_PROPERTY_VALUE, Severity::Error, Some(4)),
+      (codes::ILLEGAL_ARGUMENT_LENGTH, Severity::Error, Some(7)),
+    ]
+  );
+}
+
+#[test]
+fn a_check_of_a_file_that_does_not_parse_reports_where() {
+  let (_, outcome) = transform_in_directory(
+    "App.js",
+    "const a = ;\n",
+    &[],
+    CompileMode::Check,
+    StyleXOptionsParams::default(),
+  );
+  let Err(failure) = outcome else {
+    panic!("the file does not parse");
+  };
+
+  assert_eq!(failure.diagnostics.len(), 1);
+  assert_eq!(failure.diagnostics[0].code, codes::PARSE_ERROR);
+}
+
+#[test]
+fn unused_styles_are_reported_where_they_are_written() {
+  let code = r#"import * as stylex from '@stylexjs/stylex';
+
+const styles = stylex.create({
+  root: { color: 'red' },
+  hidden: { display: 'none' },
+});
+
+export const App = () => <div {...stylex.props(styles.root)} />;
+"#;
+
+  let (_, outcome) = transform_with(
+    code,
+    StyleXOptionsParams {
+      report_unused_styles: Some(true),
+      ..Default::default()
+    },
+  );
+  let file = outcome.expect("the file compiles");
+  let unused = file.unused_styles.expect("the report is on");
+
+  assert_eq!(unused.namespaces.len(), 1);
+
+  let hidden = &unused.namespaces[0];
+  assert_eq!(hidden.namespace, "hidden");
+  assert_eq!(
+    hidden
+      .span
+      .map(|span| (span.line, span.column, span.end_column)),
+    Some((5, 3, 9))
+  );
+  assert_eq!(unused.dead_class_names, hidden.class_names);
+}
+
+#[test]
+fn unused_styles_are_only_reported_when_asked_for() {
+  let (_, outcome) = transform_with(INVALID_COLOR, StyleXOptionsParams::default());
+
+  assert!(outcome.expect("the file compiles").unused_styles.is_none());
+}
+
+// ── Analysis ────────────────────────────────────────────────────────
+
+const ANALYZED: &str = r#"import * as stylex from '@stylexjs/stylex';
+
+export const vars = stylex.defineVars({
+  accent: 'blue',
+});
+
+const styles = stylex.create({
+  root: { float: 'inline-start', color: vars.accent },
+});
+
+export const App = () => <div {...stylex.props(styles.root)} />;
+"#;
+
+/// Analyzes `code`, written to `name`, at the first occurrence of `marker`, in
+/// a package resolved the CommonJS way so `defineVars` can hash its tokens.
+fn analyze_at(name: &str, code: &str, files: &[(&str, &str)], marker: &str) -> Option<Analysis> {
+  let offset = code.find(marker).expect("the marker is in the code") as u32;
+  let files = [&[("package.json", r#"{ "name": "app" }"#)], files].concat();
+  let config = StyleXOptionsParams {
+    unstable_module_resolution: Some(ModuleResolution::common_js(None)),
+    ..Default::default()
+  };
+  let (_, outcome) =
+    transform_in_directory(name, code, &files, CompileMode::Analyze(offset), config);
+
+  outcome.expect("the file compiles").analysis
+}
+
+#[test]
+fn a_property_key_is_analyzed_to_its_own_rules() {
+  let Some(Analysis::Style(style)) = analyze_at("App.stylex.js", ANALYZED, &[], "float") else {
+    panic!("expected a style analysis");
+  };
+
+  assert_eq!(style.variable, "styles");
+  assert_eq!(style.namespace, "root");
+  assert_eq!(style.property.as_deref(), Some("float"));
+  assert_eq!(
+    style
+      .span
+      .map(|span| (span.line, span.column, span.end_column)),
+    Some((8, 11, 16))
+  );
+  assert_eq!(style.rules.len(), 1);
+
+  let rule = &style.rules[0];
+  assert_eq!(rule.property, "float");
+  assert!(rule.ltr.contains("float:left"), "{}", rule.ltr);
+  assert!(
+    rule
+      .rtl
+      .as_deref()
+      .is_some_and(|rtl| rtl.contains("float:right")),
+    "{:?}",
+    rule.rtl
+  );
+  assert_eq!(rule.priority, 3000.0);
+}
+
+#[test]
+fn a_namespace_key_and_a_read_of_it_are_analyzed_to_every_rule() {
+  let key = analyze_at("App.stylex.js", ANALYZED, &[], "root:");
+  let read = analyze_at("App.stylex.js", ANALYZED, &[], "root)");
+
+  for analysis in [key, read] {
+    let Some(Analysis::Style(style)) = analysis else {
+      panic!("expected a style analysis");
+    };
+
+    assert_eq!(style.namespace, "root");
+    assert_eq!(style.property, None);
+
+    let mut properties = style
+      .rules
+      .iter()
+      .map(|rule| rule.property.as_str())
+      .collect::<Vec<_>>();
+    properties.sort_unstable();
+    assert_eq!(properties, ["color", "float"]);
+
+    let color = style
+      .rules
+      .iter()
+      .find(|rule| rule.property == "color")
+      .expect("a color rule");
+    assert!(color.ltr.contains("color:var(--"), "{}", color.ltr);
+    assert_eq!(color.rtl, None);
+  }
+}
+
+#[test]
+fn a_local_token_read_resolves_to_its_declaration() {
+  let Some(Analysis::Token(token)) = analyze_at("App.stylex.js", ANALYZED, &[], "accent }") else {
+    panic!("expected a token analysis");
+  };
+
+  assert_eq!(token.variable, "vars");
+  assert_eq!(token.token, "accent");
+  assert!(
+    token
+      .value
+      .as_deref()
+      .is_some_and(|value| value.starts_with("var(--")),
+    "{:?}",
+    token.value
+  );
+  assert!(
+    token
+      .file
+      .is_some_and(|file| file.ends_with("App.stylex.js"))
+  );
+  assert_eq!(
+    token
+      .declaration
+      .map(|span| (span.line, span.column, span.end_column)),
+    Some((4, 3, 9))
+  );
+  assert_eq!(token.declared_value.as_deref(), Some("'blue'"));
+}
+
+#[test]
+fn an_imported_token_read_resolves_to_the_file_declaring_it() {
+  let code = r#"import * as stylex from '@stylexjs/stylex';
+import { tokens } from './tokens.stylex.js';
+
+const styles = stylex.create({
+  root: { color: tokens.accent },
+});
+
+export const App = () => <div {...stylex.props(styles.root)} />;
+"#;
+  let declaring = r#"import * as stylex from '@stylexjs/stylex';
+
+export const tokens = stylex.defineVars({
+  background: 'white',
+  accent: 'blue',
+});
+"#;
+
+  let Some(Analysis::Token(token)) =
+    analyze_at("App.js", code, &[("tokens.stylex.js", declaring)], "accent")
+  else {
+    panic!("expected a token analysis");
+  };
+
+  assert_eq!(token.variable, "tokens");
+  assert_eq!(token.token, "accent");
+  assert!(
+    token
+      .value
+      .as_deref()
+      .is_some_and(|value| value.starts_with("var(--")),
+    "{:?}",
+    token.value
+  );
+  assert!(
+    token
+      .file
+      .as_deref()
+      .is_some_and(|file| file.ends_with("tokens.stylex.js")),
+    "{:?}",
+    token.file
+  );
+  assert_eq!(
+    token
+      .declaration
+      .map(|span| (span.line, span.column, span.end_column)),
+    Some((5, 3, 9))
+  );
+  assert_eq!(token.declared_value.as_deref(), Some("'blue'"));
+}
+
+#[test]
+fn an_offset_on_nothing_stylex_compiled_is_not_analyzed() {
+  assert_eq!(analyze_at("App.stylex.js", ANALYZED, &[], "App"), None);
+}
+
+#[test]
+fn a_transform_does_not_analyze() {
+  let (_, outcome) = transform_with(INVALID_COLOR, StyleXOptionsParams::default());
+
+  assert!(outcome.expect("the file compiles").analysis.is_none());
+}
+
+#[test]
+fn a_read_of_an_import_that_is_not_a_theme_file_is_not_analyzed() {
+  let code = r#"import * as stylex from '@stylexjs/stylex';
+import { helpers } from './helpers.js';
+
+const styles = stylex.create({
+  root: { color: 'red' },
+});
+
+export const App = () => <div {...stylex.props(styles.root)}>{helpers.label}</div>;
+"#;
+
+  assert_eq!(
+    analyze_at(
+      "App.js",
+      code,
+      &[("helpers.js", "export const helpers = { label: 'hi' };")],
+      "label"
+    ),
+    None
+  );
+}
+
+#[test]
+fn theme_declarations_are_returned_with_the_compiled_file() {
+  let code = r#"import * as stylex from '@stylexjs/stylex';
+
+export const colors = stylex.defineVars({ accent: 'blue' });
+"#;
+
+  let (_, outcome) = transform_in_directory(
+    "tokens.stylex.js",
+    code,
+    &[("package.json", r#"{ "name": "app" }"#)],
+    CompileMode::Transform,
+    StyleXOptionsParams {
+      unstable_module_resolution: Some(ModuleResolution::common_js(None)),
+      emit_theme_declarations: Some(true),
+      ..Default::default()
+    },
+  );
+  let declarations = outcome
+    .expect("the file compiles")
+    .declarations
+    .expect("the file exports vars");
+
+  assert!(
+    declarations.contains("export declare const colors: VarGroup<"),
+    "{declarations}"
+  );
+}
+
+#[test]
+fn imported_design_tokens_compile_to_variables_and_themes() {
+  let source = utils::shared_options::design_tokens_source(
+    &StyleXOptionsParams::default(),
+    r##"{
+      "color": {
+        "$type": "color",
+        "accent": { "$value": "#0a84ff", "$extensions": { "mode": { "dark": "#64d2ff" } } }
+      }
+    }"##,
+    StyleXDesignTokenOptions::default(),
+  )
+  .expect("valid tokens");
+
+  let (_, outcome) = transform_in_directory(
+    "tokens.stylex.ts",
+    &source,
+    &[("package.json", r#"{ "name": "app" }"#)],
+    CompileMode::Transform,
+    StyleXOptionsParams {
+      unstable_module_resolution: Some(ModuleResolution::common_js(None)),
+      ..Default::default()
+    },
+  );
+  let file = outcome.expect("the generated module compiles");
+
+  assert!(
+    file.code.contains("export const tokens = {"),
+    "{}",
+    file.code
+  );
+  assert!(
+    file.code.contains("export const darkTheme = {"),
+    "{}",
+    file.code
+  );
+  assert_eq!(file.metadata.len(), 2);
+}
+
+fn report_rule(class_name: &str, ltr: &str) -> StyleXRule {
+  (
+    class_name.to_string(),
+    structs::StyleXRuleStyle {
+      ltr: ltr.to_string(),
+      rtl: None,
+      const_key: None,
+      const_val: None,
+    },
+    3000.0,
+  )
+}
+
+#[test]
+fn the_stylesheet_report_is_json_in_file_order() {
+  let rules = HashMap::from([
+    (
+      "src/Card.js".to_string(),
+      vec![report_rule("pkg-x9f3", ".pkg-x9f3{color:red}")],
+    ),
+    (
+      "src/Button.js".to_string(),
+      vec![report_rule("x1e2nbdu", ".x1e2nbdu{color:red}")],
+    ),
+  ]);
+
+  let report: serde_json::Value =
+    serde_json::from_str(&stylesheet_report(rules).expect("a report")).expect("valid JSON");
+
+  assert_eq!(report["rules"], 2);
+  assert_eq!(
+    report["duplicates"][0]["classNames"],
+    serde_json::json!(["x1e2nbdu", "pkg-x9f3"])
+  );
+  assert_eq!(report["files"][0]["file"], "src/Button.js");
+}
+
+#[test]
+fn the_class_name_manifest_reports_renames_unless_frozen() {
+  let build = |class_name: &str| {
+    vec![HashMap::from([(
+      "<>colorrednull".to_string(),
+      class_name.to_string(),
+    )])]
+  };
+
+  let first = update_class_name_manifest(build("x1e2nbdu"), None, None).expect("a manifest");
+  assert!(first.renamed.is_empty());
+
+  let renamed = update_class_name_manifest(build("y1e2nbdu"), Some(first.manifest.clone()), None)
+    .expect("an updated manifest");
+  assert_eq!(renamed.renamed.len(), 1);
+  assert_eq!(renamed.renamed[0].from, "x1e2nbdu");
+  assert!(renamed.manifest.contains("y1e2nbdu"));
+
+  let frozen = update_class_name_manifest(build("y1e2nbdu"), Some(first.manifest), Some(true))
+    .err()
+    .expect("a frozen manifest refuses the rename");
+  assert!(frozen.reason.contains("renames 1 class(es)"));
+}
+
+#[test]
+fn an_invalid_class_name_manifest_is_an_error() {
+  let error = update_class_name_manifest(vec![], Some("not json".to_string()), None)
+    .err()
+    .expect("invalid JSON is an error");
+
+  assert!(error.reason.contains("Invalid class-name manifest"));
+}
+
+fn compiled_style(entries: &[(&str, &str)]) -> StyleXPropsArgument {
+  let mut style = stylex_styleq::StyleMap::new();
+  style.insert(
+    "$$css".to_string(),
+    RuntimeStyleValue::string("Button.js:3"),
+  );
+
+  for (prop, class_name) in entries {
+    style.insert(prop.to_string(), RuntimeStyleValue::string(*class_name));
+  }
+
+  StyleXPropsArgument(StyleqInput::Style(style))
+}
+
+#[test]
+fn props_merges_as_the_runtime_does() {
+  let result = props(
+    vec![
+      compiled_style(&[("color", "x1e2nbdu"), ("display", "x78zum5")]),
+      StyleXPropsArgument(StyleqInput::False),
+      StyleXPropsArgument(StyleqInput::Nested(vec![
+        compiled_style(&[("color", "x1a2a7pz")]).0,
+        StyleqInput::Style(
+          [("width".to_string(), RuntimeStyleValue::Number(12.5))]
+            .into_iter()
+            .collect(),
+        ),
+      ])),
+    ],
+    None,
+  );
+
+  assert_eq!(result.class_name.as_deref(), Some("x78zum5 x1a2a7pz"));
+  assert_eq!(
+    result
+      .style
+      .map(|style| style.0.into_iter().collect::<Vec<_>>()),
+    Some(vec![("width".to_string(), RuntimeStyleValue::Number(12.5))])
+  );
+  assert_eq!(
+    result.data_style_src.as_deref(),
+    Some("Button.js:3; Button.js:3")
+  );
+}
+
+#[test]
+fn props_leaves_out_what_is_empty() {
+  let result = props(vec![StyleXPropsArgument(StyleqInput::Null)], None);
+
+  assert!(result.class_name.is_none());
+  assert!(result.style.is_none());
+  assert!(result.data_style_src.is_none());
+}
+
+#[test]
+fn props_skips_an_absent_dynamic_value() {
+  let dynamic = |value: RuntimeStyleValue| {
+    StyleXPropsArgument(StyleqInput::Nested(vec![
+      compiled_style(&[("color", "x14rh7hd")]).0,
+      StyleqInput::Style([("--x-color".to_string(), value)].into_iter().collect()),
+    ]))
+  };
+
+  let result = props(
+    vec![
+      StyleXPropsArgument(StyleqInput::Style(
+        [("--x-color".to_string(), RuntimeStyleValue::string("red"))]
+          .into_iter()
+          .collect(),
+      )),
+      dynamic(RuntimeStyleValue::Null),
+    ],
+    None,
+  );
+
+  assert_eq!(result.class_name.as_deref(), Some("x14rh7hd"));
+  assert!(result.style.is_none());
+
+  let result = props(vec![dynamic(RuntimeStyleValue::string("red"))], None);
+
+  assert_eq!(
+    result
+      .style
+      .map(|style| style.0.into_iter().collect::<Vec<_>>()),
+    Some(vec![(
+      "--x-color".to_string(),
+      RuntimeStyleValue::string("red")
+    )])
+  );
+}
+
+#[test]
+fn props_options_pick_the_merger() {
+  let styles = vec![
+    StyleXPropsArgument(StyleqInput::Style(
+      [("color".to_string(), RuntimeStyleValue::string("red"))]
+        .into_iter()
+        .collect(),
+    )),
+    compiled_style(&[("color", "x1e2nbdu")]),
+  ];
+
+  assert!(props(styles.clone(), None).style.is_none());
+  assert!(
+    props(
+      styles,
+      Some(StyleXPropsOptions {
+        disable_mix: Some(true),
+        dedupe_class_name_chunks: None,
+      }),
+    )
+    .style
+    .is_some()
+  );
+}
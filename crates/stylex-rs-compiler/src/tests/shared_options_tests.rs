@@ -0,0 +1,166 @@
+// Tests for the options shared by files compiled off the JS thread.
+// Source: crates/stylex-rs-compiler/src/utils/shared_options.rs
+
+use std::{collections::HashSet, sync::Mutex, thread};
+
+use stylex_structures::named_import_source::NamedImportSource;
+
+use super::*;
+
+#[test]
+fn run_in_parallel_handles_no_items() {
+  let results: Vec<u32> = run_in_parallel(Vec::<u32>::new(), |item| item);
+
+  assert!(results.is_empty());
+}
+
+#[test]
+fn run_in_parallel_keeps_the_order_of_the_items() {
+  let items: Vec<usize> = (0..200).collect();
+
+  let results = run_in_parallel(items, |item| item * 2);
+
+  assert_eq!(results, (0..200).map(|item| item * 2).collect::<Vec<_>>());
+}
+
+#[test]
+fn run_in_parallel_maps_every_item_exactly_once() {
+  let seen = Mutex::new(Vec::new());
+
+  run_in_parallel((0..64).collect(), |item: u32| {
+    if let Ok(mut seen) = seen.lock() {
+      seen.push(item);
+    }
+  });
+
+  let seen = seen.into_inner().unwrap_or_default();
+  assert_eq!(seen.len(), 64);
+  assert_eq!(seen.into_iter().collect::<HashSet<_>>().len(), 64);
+}
+
+#[test]
+fn run_in_parallel_uses_no_more_threads_than_items() {
+  let threads = Mutex::new(HashSet::new());
+
+  run_in_parallel(vec![(); 1], |_| {
+    if let Ok(mut threads) = threads.lock() {
+      threads.insert(thread::current().id());
+    }
+  });
+
+  let threads = threads.into_inner().unwrap_or_default();
+  assert_eq!(
+    threads.into_iter().collect::<Vec<_>>(),
+    vec![thread::current().id()]
+  );
+}
+
+#[test]
+fn shared_options_can_be_shared_between_threads() {
+  fn assert_send_sync<T: Send + Sync>() {}
+
+  assert_send_sync::<SharedOptions>();
+}
+
+#[test]
+fn plain_config_gives_back_the_params_it_was_made_from() {
+  let params = StyleXOptionsParams {
+    class_name_prefix: Some("acme".to_string()),
+    dev: Some(true),
+    import_sources: Some(vec![ImportSources::Regular("@acme/stylex".to_string())]),
+    ..Default::default()
+  };
+  let env = [("brand".to_string(), Expr::from("blue"))]
+    .into_iter()
+    .collect();
+
+  let round_tripped = PlainConfig::new(params.clone(), Some(env)).to_params();
+
+  assert_eq!(
+    format!(
+      "{:?}",
+      StyleXOptionsParams {
+        env: None,
+        ..round_tripped.clone()
+      }
+    ),
+    format!("{params:?}"),
+  );
+  assert!(matches!(
+    round_tripped.env.as_ref().and_then(|env| env.get("brand")),
+    Some(EnvEntry::Expr(_))
+  ));
+}
+
+const TOKENS: &str = r#"{ "accent": { "$type": "color", "$value": "blue" } }"#;
+
+#[test]
+fn design_tokens_import_stylex_from_the_first_package_of_the_import_sources() {
+  let config = StyleXOptionsParams {
+    import_sources: Some(vec![
+      ImportSources::Named(NamedImportSource {
+        r#as: "css".to_string(),
+        from: "react-strict-dom".to_string(),
+      }),
+      ImportSources::Regular("@acme/stylex".to_string()),
+    ]),
+    ..Default::default()
+  };
+
+  let source = design_tokens_source(&config, TOKENS, StyleXDesignTokenOptions::default())
+    .expect("valid tokens");
+
+  assert!(
+    source.starts_with("import * as stylex from '@acme/stylex';"),
+    "{source}"
+  );
+}
+
+#[test]
+fn design_tokens_need_a_package_to_import_stylex_from() {
+  let config = StyleXOptionsParams {
+    import_sources: Some(vec![ImportSources::Named(NamedImportSource {
+      r#as: "css".to_string(),
+      from: "react-strict-dom".to_string(),
+    })]),
+    ..Default::default()
+  };
+
+  let error = design_tokens_source(&config, TOKENS, StyleXDesignTokenOptions::default())
+    .expect_err("no package to import from");
+
+  assert!(error.reason.contains("need a package in importSources"));
+}
+
+#[test]
+fn design_token_options_name_the_export_and_fold_modes_into_conditions() {
+  let source = design_tokens_source(
+    &StyleXOptionsParams::default(),
+    r#"{
+      "accent": {
+        "$type": "color",
+        "$value": "blue",
+        "$extensions": { "mode": { "dark": "skyblue" } }
+      }
+    }"#,
+    StyleXDesignTokenOptions {
+      export_name: Some("colors".to_string()),
+      mode_conditions: Some(
+        [(
+          "dark".to_string(),
+          "@media (prefers-color-scheme: dark)".to_string(),
+        )]
+        .into_iter()
+        .collect(),
+      ),
+    },
+  )
+  .expect("valid tokens");
+
+  assert!(source.contains("export const colors ="), "{source}");
+  assert!(
+    source.contains("'@media (prefers-color-scheme: dark)': 'skyblue'"),
+    "{source}"
+  );
+  assert!(!source.contains("Theme"), "{source}");
+}
@@ -4,6 +4,8 @@ fn empty_options() -> StyleXOptions {
   StyleXOptions {
     style_resolution: None,
     enable_font_size_px_to_rem: None,
+    enable_color_normalization: None,
+    enable_at_rule_normalization: None,
     runtime_injection: None,
     class_name_prefix: None,
     defined_stylex_css_variables: None,
@@ -13,8 +15,12 @@ fn empty_options() -> StyleXOptions {
     enable_media_query_order: None,
     enable_logical_styles_polyfill: None,
     enable_legacy_value_flipping: None,
+    enable_native_logical_styles: None,
     enable_ltr_rtl_comments: None,
     legacy_disable_layers: None,
+    use_layers: None,
+    flatten_specificity: None,
+    use_dir_pseudo_class: None,
     dev: None,
     test: None,
     debug: None,
@@ -34,6 +40,10 @@ fn empty_options() -> StyleXOptions {
     env: None,
     debug_file_path: None,
     sx_prop_name: None,
+    cache_directory: None,
+    report_unused_styles: None,
+    emit_theme_declarations: None,
+    emit_class_name_keys: None,
   }
 }
 
@@ -157,6 +167,7 @@ fn try_from_maps_all_boolean_options() {
     enable_media_query_order: Some(false),
     enable_logical_styles_polyfill: Some(true),
     enable_legacy_value_flipping: Some(true),
+    enable_native_logical_styles: Some(true),
     enable_ltr_rtl_comments: Some(true),
     dev: Some(true),
     test: Some(true),
@@ -175,6 +186,7 @@ fn try_from_maps_all_boolean_options() {
   assert_eq!(parsed.enable_media_query_order, Some(false));
   assert_eq!(parsed.enable_logical_styles_polyfill, Some(true));
   assert_eq!(parsed.enable_legacy_value_flipping, Some(true));
+  assert_eq!(parsed.enable_native_logical_styles, Some(true));
   assert_eq!(parsed.enable_ltr_rtl_comments, Some(true));
   assert_eq!(parsed.dev, Some(true));
   assert_eq!(parsed.test, Some(true));
@@ -359,3 +371,93 @@ fn try_from_all_style_resolutions() {
     );
   }
 }
+
+fn rule_style(ltr: &str) -> StyleXRuleStyle {
+  StyleXRuleStyle {
+    ltr: ltr.to_string(),
+    rtl: None,
+    const_key: None,
+    const_val: None,
+  }
+}
+
+#[test]
+fn process_rules_options_default_to_unlayered() {
+  let options = ProcessStylexRulesOptions::from(StyleXProcessRulesOptions::default());
+
+  assert_eq!(options, ProcessStylexRulesOptions::default());
+}
+
+#[test]
+fn process_rules_options_map_use_layers() {
+  let enabled = ProcessStylexRulesOptions::from(StyleXProcessRulesOptions {
+    use_layers: Some(UseLayersUnion::Enabled(true)),
+    ..Default::default()
+  });
+  assert_eq!(enabled.use_layers, Some(LayersOptions::default()));
+
+  let disabled = ProcessStylexRulesOptions::from(StyleXProcessRulesOptions {
+    use_layers: Some(UseLayersUnion::Enabled(false)),
+    ..Default::default()
+  });
+  assert_eq!(disabled.use_layers, None);
+
+  let placed = ProcessStylexRulesOptions::from(StyleXProcessRulesOptions {
+    use_layers: Some(UseLayersUnion::Layers(crate::enums::StyleXLayersOptions {
+      before: Some(vec!["reset".to_string()]),
+      after: None,
+      prefix: Some("stylex".to_string()),
+      naming: Some(crate::enums::LayerNaming::Bucket),
+    })),
+    enable_ltr_rtl_comments: Some(true),
+    flatten_specificity: Some(true),
+    use_dir_pseudo_class: Some(true),
+    ..Default::default()
+  });
+  assert_eq!(
+    placed.use_layers,
+    Some(LayersOptions {
+      before: vec!["reset".to_string()],
+      after: vec![],
+      prefix: Some("stylex".to_string()),
+      naming: StylesheetLayerNaming::Bucket,
+    })
+  );
+  assert!(placed.enable_ltr_rtl_comments);
+  assert!(placed.flatten_specificity);
+  assert!(placed.use_dir_pseudo_class);
+}
+
+#[test]
+fn rule_to_metadata_keeps_regular_rules() {
+  let metadata = rule_to_metadata((
+    "xrkmrrc".to_string(),
+    StyleXRuleStyle {
+      rtl: Some(".xrkmrrc{float:right}".to_string()),
+      ..rule_style(".xrkmrrc{float:left}")
+    },
+    3000.0,
+  ));
+
+  assert_eq!(metadata.get_class_name(), "xrkmrrc");
+  assert_eq!(metadata.get_css(), ".xrkmrrc{float:left}");
+  assert_eq!(metadata.get_css_rtl(), Some(".xrkmrrc{float:right}"));
+  assert_eq!(*metadata.get_priority(), 3000.0);
+  assert_eq!(metadata.get_const_key(), None);
+}
+
+#[test]
+fn rule_to_metadata_recognizes_constants() {
+  let metadata = rule_to_metadata((
+    "x1tp0jf2".to_string(),
+    StyleXRuleStyle {
+      const_key: Some("x1tp0jf2".to_string()),
+      const_val: Some("yellow".to_string()),
+      ..rule_style("")
+    },
+    0.0,
+  ));
+
+  assert_eq!(metadata.get_const_key(), Some("x1tp0jf2"));
+  assert_eq!(metadata.get_const_value(), Some("yellow"));
+}
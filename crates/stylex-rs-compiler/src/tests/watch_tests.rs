@@ -0,0 +1,245 @@
+// Tests for the compiler kept warm across the edits of a dev server.
+// Source: crates/stylex-rs-compiler/src/utils/watch.rs
+
+use std::path::PathBuf;
+
+use stylex_structures::stylex_options::{ModuleResolution, StyleXOptionsParams};
+
+use super::*;
+
+const TOKENS: &str = r#"import * as stylex from '@stylexjs/stylex';
+
+export const tokens = stylex.defineVars({
+  accent: 'blue',
+});
+"#;
+
+const APP: &str = r#"import * as stylex from '@stylexjs/stylex';
+import { tokens } from './tokens.stylex.js';
+
+const styles = stylex.create({
+  root: { color: tokens.accent },
+});
+
+export const App = () => <div {...stylex.props(styles.root)} />;
+"#;
+
+/// A project directory of its own, with a `package.json`, and a compiler
+/// resolving imports from it.
+fn project(name: &str) -> (PathBuf, WatchCompiler) {
+  let unique = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or_default();
+  let directory = std::env::temp_dir().join(format!("stylex-watch-{name}-{unique}"));
+  std::fs::create_dir_all(&directory).expect("create dir");
+  std::fs::write(directory.join("package.json"), r#"{ "name": "app" }"#).expect("write file");
+
+  let config = StyleXOptionsParams {
+    unstable_module_resolution: Some(ModuleResolution::common_js(Some(
+      directory.to_string_lossy().into_owned(),
+    ))),
+    ..Default::default()
+  };
+
+  (
+    directory,
+    WatchCompiler::new(SharedOptions::from_config(config)),
+  )
+}
+
+fn styles(color: &str) -> String {
+  format!(
+    r#"import * as stylex from '@stylexjs/stylex';
+
+const styles = stylex.create({{
+  root: {{ color: '{color}', display: 'flex' }},
+}});
+
+export const App = () => <div {{...stylex.props(styles.root)}} />;
+"#
+  )
+}
+
+fn write(directory: &Path, name: &str, code: &str) -> String {
+  let path = directory.join(name);
+  std::fs::write(&path, code).expect("write file");
+
+  path.to_string_lossy().into_owned()
+}
+
+fn class_names(rules: &[MetaData]) -> Vec<&str> {
+  rules.iter().map(|rule| rule.get_class_name()).collect()
+}
+
+#[test]
+fn the_first_compile_adds_every_rule_and_an_unchanged_one_nothing() {
+  let (directory, compiler) = project("first");
+  let filename = write(&directory, "App.js", &styles("red"));
+
+  let (file, delta) = compiler
+    .transform(filename.clone(), styles("red"), None)
+    .expect("the file compiles");
+
+  assert_eq!(delta.added, file.metadata);
+  assert_eq!(delta.added.len(), 2);
+  assert!(delta.removed.is_empty());
+
+  let (_, delta) = compiler
+    .transform(filename, styles("red"), None)
+    .expect("the file compiles");
+
+  assert_eq!(delta, RuleDelta::default());
+}
+
+#[test]
+fn an_edit_reports_only_the_rules_it_changed() {
+  let (directory, compiler) = project("edit");
+  let filename = write(&directory, "App.js", &styles("red"));
+
+  let (red, _) = compiler
+    .transform(filename.clone(), styles("red"), None)
+    .expect("the file compiles");
+  let (blue, delta) = compiler
+    .transform(filename, styles("blue"), None)
+    .expect("the file compiles");
+
+  let display = red
+    .metadata
+    .iter()
+    .find(|rule| blue.metadata.contains(rule))
+    .expect("display is unchanged");
+
+  assert_eq!(delta.added.len(), 1);
+  assert_eq!(delta.removed.len(), 1);
+  assert!(!delta.added.contains(display));
+  assert!(!delta.removed.contains(display));
+  assert_ne!(class_names(&delta.added), class_names(&delta.removed));
+}
+
+#[test]
+fn a_failed_compile_keeps_the_rules_the_file_had() {
+  let (directory, compiler) = project("failure");
+  let filename = write(&directory, "App.js", &styles("red"));
+
+  compiler
+    .transform(filename.clone(), styles("red"), None)
+    .expect("the file compiles");
+
+  assert!(
+    compiler
+      .transform(filename.clone(), "const = ;".to_string(), None)
+      .is_err()
+  );
+
+  let (_, delta) = compiler
+    .transform(filename, styles("red"), None)
+    .expect("the file compiles");
+
+  assert_eq!(delta, RuleDelta::default());
+}
+
+#[test]
+fn invalidating_a_file_compiles_it_again_from_disk() {
+  let (directory, compiler) = project("invalidate");
+  let filename = write(&directory, "App.js", &styles("red"));
+  let untouched = write(&directory, "Other.js", &styles("green"));
+
+  compiler
+    .transform(filename.clone(), styles("red"), None)
+    .expect("the file compiles");
+  compiler
+    .transform(untouched, styles("green"), None)
+    .expect("the file compiles");
+
+  write(&directory, "App.js", &styles("blue"));
+  let outcomes = compiler.invalidate(std::slice::from_ref(&filename));
+
+  assert_eq!(outcomes.len(), 1);
+  let (recompiled, outcome) = &outcomes[0];
+  let delta = outcome.as_ref().expect("the file compiles");
+
+  assert_eq!(recompiled, &filename);
+  assert_eq!((delta.added.len(), delta.removed.len()), (1, 1));
+}
+
+#[test]
+fn a_deleted_file_removes_every_rule_it_had() {
+  let (directory, compiler) = project("deleted");
+  let filename = write(&directory, "App.js", &styles("red"));
+
+  let (file, _) = compiler
+    .transform(filename.clone(), styles("red"), None)
+    .expect("the file compiles");
+
+  std::fs::remove_file(&filename).expect("remove file");
+  let outcomes = compiler.invalidate(std::slice::from_ref(&filename));
+
+  assert_eq!(outcomes.len(), 1);
+  let delta = outcomes[0].1.as_ref().expect("a deletion is not a failure");
+
+  assert!(delta.added.is_empty());
+  assert_eq!(delta.removed, file.metadata);
+  assert!(compiler.files().is_empty());
+}
+
+#[test]
+fn a_rule_another_file_still_has_is_not_removed() {
+  let (directory, compiler) = project("shared");
+  let filename = write(&directory, "App.js", &styles("red"));
+  let other = write(&directory, "Other.js", &styles("red"));
+
+  let (red, _) = compiler
+    .transform(filename.clone(), styles("red"), None)
+    .expect("the file compiles");
+  compiler
+    .transform(other.clone(), styles("red"), None)
+    .expect("the file compiles");
+
+  let (blue, delta) = compiler
+    .transform(filename, styles("blue"), None)
+    .expect("the file compiles");
+
+  assert_eq!(delta.added.len(), 1);
+  assert!(delta.removed.is_empty());
+
+  std::fs::remove_file(&other).expect("remove file");
+  let outcomes = compiler.invalidate(std::slice::from_ref(&other));
+  let delta = outcomes[0].1.as_ref().expect("a deletion is not a failure");
+
+  let red_only: Vec<MetaData> = red
+    .metadata
+    .into_iter()
+    .filter(|rule| !blue.metadata.contains(rule))
+    .collect();
+
+  assert_eq!(delta.removed, red_only);
+}
+
+#[test]
+fn invalidating_a_theme_compiles_the_files_importing_it() {
+  let (directory, compiler) = project("theme");
+  let tokens = write(&directory, "tokens.stylex.js", TOKENS);
+  let filename = write(&directory, "App.js", APP);
+
+  let (file, _) = compiler
+    .transform(filename.clone(), APP.to_string(), None)
+    .expect("the file compiles");
+
+  assert!(
+    file.dependencies.contains(&tokens),
+    "{:?}",
+    file.dependencies
+  );
+
+  write(
+    &directory,
+    "tokens.stylex.js",
+    &TOKENS.replace("accent: 'blue'", "accent: 'red'"),
+  );
+  let outcomes = compiler.invalidate(std::slice::from_ref(&tokens));
+
+  assert_eq!(outcomes.len(), 1);
+  assert_eq!(outcomes[0].0, filename);
+  assert!(outcomes[0].1.is_ok());
+}
@@ -0,0 +1,287 @@
+use std::{
+  collections::BTreeMap,
+  env, fs, io,
+  path::{Path, PathBuf},
+  process,
+  sync::atomic::{AtomicUsize, Ordering},
+  time::{Duration, SystemTime},
+};
+
+use indexmap::IndexMap;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use stylex_macros::diagnostic::Diagnostic;
+use stylex_structures::stylex_options::StyleXOptionsParams;
+use stylex_types::structures::{meta_data::MetaData, unused_styles::UnusedStyles};
+use swc_core::ecma::codegen::to_code;
+
+use crate::{OutputOptions, TransformFailure, TransformedFile};
+
+/// Tells apart the temporary files of concurrent writers in one process.
+static WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// How far a file's mtime can lag behind the clock. Filesystems stamp writes
+/// with a coarse clock, down to two seconds on FAT, so a file written during a
+/// compile can look older than the compile's start.
+const MTIME_GRANULARITY: Duration = Duration::from_secs(2);
+
+/// Transform results kept on disk, so a file compiled by an earlier build, or
+/// another process, is not compiled again.
+///
+/// An entry is found by [`cache_key`], which covers everything the file's own
+/// compile reads. The theme, consts and `package.json` files it depends on
+/// are not known until it is compiled, so each entry records their content
+/// hashes instead, and is a miss once any of them differs.
+pub(crate) struct TransformCache {
+  directory: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+  code: String,
+  map: Option<String>,
+  metadata: Vec<MetaData>,
+  dependencies: Vec<CachedDependency>,
+  diagnostics: Vec<Diagnostic>,
+  unused_styles: Option<UnusedStyles>,
+  declarations: Option<String>,
+  class_name_keys: Option<IndexMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedDependency {
+  path: String,
+  hash: String,
+}
+
+impl TransformCache {
+  pub(crate) fn new(directory: impl Into<PathBuf>) -> Self {
+    TransformCache {
+      directory: directory.into(),
+    }
+  }
+
+  /// The entry for `key`, or what `transform` returns, which is then stored
+  /// along with the warnings it reported.
+  /// A failed transform is not stored, nor is any cache error reported: the
+  /// worst a broken cache does is make the build compile again.
+  pub(crate) fn get_or_insert_with(
+    &self,
+    key: &str,
+    transform: impl FnOnce() -> Result<TransformedFile, TransformFailure>,
+  ) -> Result<TransformedFile, TransformFailure> {
+    if let Some(file) = self.get(key) {
+      debug!("Transform cache hit: {}", key);
+      return Ok(file);
+    }
+
+    let started = SystemTime::now();
+    let file = transform()?;
+
+    self.insert(key, &file, started);
+
+    Ok(file)
+  }
+
+  /// The entry for `key`, provided every dependency it recorded still has the
+  /// content it had when the entry was written.
+  pub(crate) fn get(&self, key: &str) -> Option<TransformedFile> {
+    let path = self.entry_path(key);
+    let bytes = fs::read(&path).ok()?;
+
+    let entry = match serde_json::from_slice::<CacheEntry>(&bytes) {
+      Ok(entry) => entry,
+      Err(error) => {
+        debug!(
+          "Ignoring unreadable cache entry {}: {}",
+          path.display(),
+          error
+        );
+        return None;
+      },
+    };
+
+    if let Some(changed) = entry
+      .dependencies
+      .iter()
+      .find(|dependency| file_hash(Path::new(&dependency.path)).as_ref() != Some(&dependency.hash))
+    {
+      debug!("Cache entry {} is stale: {} changed", key, changed.path);
+      return None;
+    }
+
+    Some(TransformedFile {
+      code: entry.code,
+      map: entry.map,
+      metadata: entry.metadata,
+      dependencies: entry
+        .dependencies
+        .into_iter()
+        .map(|dependency| dependency.path)
+        .collect(),
+      diagnostics: entry.diagnostics,
+      unused_styles: entry.unused_styles,
+      declarations: entry.declarations,
+      class_name_keys: entry.class_name_keys,
+      // Only `analyze` asks for one, and it never reads the cache.
+      analysis: None,
+    })
+  }
+
+  /// Stores `file` under `key`, unless one of its dependencies may have been
+  /// modified after `started`: the compile may have read it before the
+  /// change, and the hash taken now would vouch for output it does not match.
+  fn insert(&self, key: &str, file: &TransformedFile, started: SystemTime) {
+    let started = started
+      .checked_sub(MTIME_GRANULARITY)
+      .unwrap_or(SystemTime::UNIX_EPOCH);
+    let mut dependencies = Vec::with_capacity(file.dependencies.len());
+
+    for dependency in &file.dependencies {
+      let path = Path::new(dependency);
+
+      let modified = fs::metadata(path).and_then(|metadata| metadata.modified());
+      if modified.is_ok_and(|modified| modified >= started) {
+        debug!(
+          "Not caching {}: {} changed while compiling",
+          key, dependency
+        );
+        return;
+      }
+
+      let Some(hash) = file_hash(path) else {
+        debug!("Not caching {}: cannot read {}", key, dependency);
+        return;
+      };
+
+      dependencies.push(CachedDependency {
+        path: dependency.clone(),
+        hash,
+      });
+    }
+
+    let entry = CacheEntry {
+      code: file.code.clone(),
+      map: file.map.clone(),
+      metadata: file.metadata.clone(),
+      dependencies,
+      diagnostics: file.diagnostics.clone(),
+      unused_styles: file.unused_styles.clone(),
+      declarations: file.declarations.clone(),
+      class_name_keys: file.class_name_keys.clone(),
+    };
+
+    if let Err(error) = self.write_entry(key, &entry) {
+      warn!(
+        "[StyleX] Failed to write to the transform cache in {}: {}",
+        self.directory.display(),
+        error
+      );
+    }
+  }
+
+  /// Writes to a temporary file first and renames it into place, so a reader
+  /// never sees half an entry, whatever other process is writing the same one.
+  fn write_entry(&self, key: &str, entry: &CacheEntry) -> io::Result<()> {
+    fs::create_dir_all(&self.directory)?;
+
+    let json = serde_json::to_vec(entry).map_err(io::Error::other)?;
+    let temp_path = self.directory.join(format!(
+      "{}.{}-{}.tmp",
+      key,
+      process::id(),
+      WRITE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    fs::write(&temp_path, json)?;
+    fs::rename(&temp_path, self.entry_path(key)).inspect_err(|_| {
+      let _ = fs::remove_file(&temp_path);
+    })
+  }
+
+  fn entry_path(&self, key: &str) -> PathBuf {
+    self.directory.join(format!("{}.json", key))
+  }
+}
+
+/// Everything a compile reads besides the files it depends on. Its JSON is
+/// what [`cache_key`] hashes, so every field serializes the same way in every
+/// run: hash maps are sorted and `env` values are printed as JS.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheKey<'a> {
+  version: &'static str,
+  cwd: Option<PathBuf>,
+  filename: &'a str,
+  config: StyleXOptionsParams,
+  aliases: Option<BTreeMap<String, Vec<String>>>,
+  defined_stylex_css_variables: Option<BTreeMap<String, String>>,
+  env: Option<BTreeMap<&'a str, String>>,
+  debug_file_path: Option<&'a str>,
+  output_options: &'a OutputOptions,
+  code: &'a str,
+}
+
+/// Identifies the compile of `code` as `filename` under `config`, or `None`
+/// when the config cannot be keyed because its `env` holds functions, whose
+/// results are only known once they are called.
+///
+/// The key covers the crate version, the working directory, the file and its
+/// source, the output options, and the config with its hash maps sorted, so
+/// the same options always produce the same key. A `debugFilePath` is keyed by
+/// its fixed value, passed as `debug_file_path`; the caller must not cache
+/// when it is a function.
+pub(crate) fn cache_key(
+  filename: &str,
+  code: &str,
+  config: &StyleXOptionsParams,
+  debug_file_path: Option<&str>,
+  output_options: &OutputOptions,
+) -> Option<String> {
+  let env = match &config.env {
+    Some(env) => Some(
+      env
+        .iter()
+        .map(|(name, entry)| Some((name.as_str(), to_code(entry.as_expr()?))))
+        .collect::<Option<BTreeMap<_, _>>>()?,
+    ),
+    None => None,
+  };
+
+  let mut normalized = config.clone();
+  let key = CacheKey {
+    version: env!("CARGO_PKG_VERSION"),
+    cwd: env::current_dir().ok(),
+    filename,
+    aliases: normalized
+      .aliases
+      .take()
+      .map(|aliases| aliases.into_iter().collect()),
+    defined_stylex_css_variables: normalized
+      .defined_stylex_css_variables
+      .take()
+      .map(|variables| variables.into_iter().collect()),
+    config: normalized,
+    env,
+    debug_file_path,
+    output_options,
+    code,
+  };
+
+  let json = serde_json::to_vec(&key).ok()?;
+
+  Some(format!("{:x}", md5::compute(json)))
+}
+
+/// Hex digest of the file at `path`, or `None` when it cannot be read.
+fn file_hash(path: &Path) -> Option<String> {
+  fs::read(path)
+    .ok()
+    .map(|bytes| format!("{:x}", md5::compute(bytes)))
+}
+
+#[cfg(test)]
+#[path = "../tests/cache_tests.rs"]
+mod tests;
@@ -255,12 +255,33 @@ fn expr_to_napi_value(raw_env: napi::sys::napi_env, expr: &Expr) -> napi::sys::n
   result
 }
 
-/// Parses a JS string or function into a `JSFunction` for use as
-/// `debugFilePath`.
-pub(crate) fn parse_debug_file_path(
+/// `debugFilePath` as read from JS, before it is turned into a `JSFunction`.
+pub(crate) enum DebugFilePath {
+  /// A fixed path, which any thread can produce.
+  Fixed(String),
+  /// A JS function, which only the JS thread can call.
+  Function(JSFunction),
+}
+
+impl DebugFilePath {
+  pub(crate) fn into_js_function(self) -> JSFunction {
+    match self {
+      DebugFilePath::Fixed(path) => fixed_debug_file_path(path),
+      DebugFilePath::Function(f) => f,
+    }
+  }
+}
+
+/// A `debugFilePath` that answers `path` whatever file it is asked about.
+pub(crate) fn fixed_debug_file_path(path: String) -> JSFunction {
+  JSFunction::new(move |_args| create_string_expr(&path))
+}
+
+/// Reads a JS string or function as a `debugFilePath`.
+pub(crate) fn read_debug_file_path(
   env: &napi::Env,
   unknown_val: Unknown,
-) -> napi::Result<JSFunction> {
+) -> napi::Result<DebugFilePath> {
   let raw_val = unknown_val.raw();
   let raw_env = env.raw();
 
@@ -284,11 +305,12 @@ pub(crate) fn parse_debug_file_path(
           &mut written,
         );
       }
-      let s = utf8_string_from_written_buffer(buf, written);
-      Ok(JSFunction::new(move |_args| create_string_expr(&s)))
+      Ok(DebugFilePath::Fixed(utf8_string_from_written_buffer(
+        buf, written,
+      )))
     },
     DebugFilePathKind::Function => parse_env_function(env, raw_val).and_then(|entry| match entry {
-      EnvEntry::Function(f) => Ok(f),
+      EnvEntry::Function(f) => Ok(DebugFilePath::Function(f)),
       _ => Err(napi::Error::from_reason(
         "Expected function from parse_env_function",
       )),
@@ -1,9 +1,11 @@
 #![allow(deprecated)]
 
 use napi::{Env, Error, JsObject};
-use stylex_transform::StyleXTransform;
-use stylex_types::enums::data_structures::injectable_style::InjectableStyleBaseKind;
-use swc_core::common::comments::Comments;
+use stylex_css::stylesheet::{LayersOptions, rule_layer};
+use stylex_types::{
+  enums::data_structures::injectable_style::InjectableStyleBaseKind,
+  structures::meta_data::MetaData,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 struct MetadataStyleParts<'a> {
@@ -30,39 +32,44 @@ fn metadata_style_parts(style: &InjectableStyleBaseKind) -> MetadataStyleParts<'
   }
 }
 
-/// Extracts StyleX metadata from the transformation state.
+/// Converts the metadata a transform collected into its JS shape,
+/// `[className, { ltr, rtl, constKey?, constVal?, layer? }, priority]`.
 ///
-/// Generic over the comment store: only `state` is read, so which store the
-/// transform carries is none of this function's business.
-pub(crate) fn extract_stylex_metadata<C: Comments>(
+/// `layer` is set when `layers` names the layer of a rule from its priority
+/// alone, so a host placing rules itself gets the cascade
+/// `processStylexRules` would.
+pub(crate) fn extract_stylex_metadata(
   env: Env,
-  stylex: &StyleXTransform<C>,
+  metadata: &[MetaData],
+  layers: Option<&LayersOptions>,
 ) -> Result<Vec<JsObject>, Error> {
-  let mut stylex_metadata = Vec::with_capacity(stylex.state.metadata().len());
+  let mut stylex_metadata = Vec::with_capacity(metadata.len());
 
-  for value in stylex.state.metadata().values() {
-    for meta in value {
-      let mut metadata_value = env.create_array_with_length(3)?;
+  for meta in metadata {
+    let mut metadata_value = env.create_array_with_length(3)?;
 
-      metadata_value.set_element(0, env.create_string(meta.get_class_name())?)?;
+    metadata_value.set_element(0, env.create_string(meta.get_class_name())?)?;
 
-      let mut style_value = env.create_object()?;
-      let parts = metadata_style_parts(meta.get_style());
+    let mut style_value = env.create_object()?;
+    let parts = metadata_style_parts(meta.get_style());
 
-      set_metadata_ltr_and_rtl(
-        env,
-        &mut style_value,
-        parts.ltr,
-        parts.rtl,
-        parts.const_key,
-        parts.const_value,
-      )?;
+    set_metadata_ltr_and_rtl(
+      env,
+      &mut style_value,
+      parts.ltr,
+      parts.rtl,
+      parts.const_key,
+      parts.const_value,
+    )?;
 
-      metadata_value.set_element(1, style_value)?;
-      metadata_value.set_element(2, env.create_double(*meta.get_priority())?)?;
-
-      stylex_metadata.push(metadata_value);
+    if let Some(layer) = layers.and_then(|layers| rule_layer(*meta.get_priority(), layers)) {
+      style_value.set_named_property("layer", layer)?;
     }
+
+    metadata_value.set_element(1, style_value)?;
+    metadata_value.set_element(2, env.create_double(*meta.get_priority())?)?;
+
+    stylex_metadata.push(metadata_value);
   }
 
   Ok(stylex_metadata)
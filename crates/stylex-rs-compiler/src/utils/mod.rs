@@ -1,4 +1,7 @@
+pub(crate) mod cache;
 pub(crate) mod fn_parser;
 mod metadata;
+pub(crate) mod shared_options;
+pub(crate) mod watch;
 
 pub(crate) use metadata::extract_stylex_metadata;
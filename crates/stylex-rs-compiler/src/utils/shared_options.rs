@@ -0,0 +1,554 @@
+use std::{
+  num::NonZeroUsize,
+  sync::{Arc, Mutex, mpsc},
+  thread,
+};
+
+use indexmap::IndexMap;
+use napi::Env;
+use rustc_hash::FxHashMap;
+use stylex_css::stylesheet::LayersOptions;
+use stylex_enums::{
+  property_validation_mode::PropertyValidationMode, style_resolution::StyleResolution,
+  sx_prop_name_param::SxPropNameParam,
+};
+use stylex_macros::diagnostic::Diagnostic;
+use stylex_structures::{
+  named_import_source::{ImportSources, RuntimeInjection},
+  stylex_env::EnvEntry,
+  stylex_options::{ModuleResolution, StyleXOptionsParams},
+};
+use stylex_transform::shared::{
+  structures::session_cache::SessionCache,
+  utils::core::design_tokens::{DesignTokenOptions, design_tokens_to_stylex_source},
+};
+use stylex_types::structures::analysis::Analysis;
+use swc_core::ecma::ast::Expr;
+
+use crate::{
+  CompileMode, OutputOptions, TransformFailure, TransformedFile,
+  enums::SourceMaps,
+  structs::{StyleXDesignTokenOptions, StyleXOptions, layers_options},
+  transform_source,
+  utils::{
+    cache::{TransformCache, cache_key},
+    fn_parser::{DebugFilePath, fixed_debug_file_path, parse_env_object, read_debug_file_path},
+  },
+};
+
+/// Options converted once and shared by every file compiled off the JS thread.
+///
+/// Work that has to call back into JS cannot leave the JS thread, so every API
+/// that compiles with these options refuses `env` functions and a function
+/// `debugFilePath`; `transform` takes both. A string `debugFilePath` is kept as
+/// a string and wrapped again for each file.
+pub(crate) struct SharedOptions {
+  config: PlainConfig,
+  debug_file_path: Option<String>,
+  source_map: Option<SourceMaps>,
+  inline_sources_content: Option<bool>,
+  emit_source_map_columns: Option<bool>,
+  cache: Option<TransformCache>,
+  /// Only read to set the `layer` of each rule in a result's metadata.
+  layers: Option<LayersOptions>,
+}
+
+impl SharedOptions {
+  pub(crate) fn new(env: &Env, mut options: StyleXOptions) -> napi::Result<Self> {
+    let parsed_env = options
+      .env
+      .take()
+      .map(|ref env_obj| parse_env_object(env, env_obj))
+      .transpose()?;
+
+    let env_exprs = parsed_env
+      .map(|parsed_env| {
+        parsed_env
+          .into_iter()
+          .map(|(name, entry)| match entry {
+            EnvEntry::Expr(expr) => Ok((name, expr)),
+            EnvEntry::Function(_) => Err(napi::Error::from_reason(format!(
+              "[StyleX] env.{} is a function, which can only be called on the JS thread. Use transform() for options with env functions.",
+              name
+            ))),
+          })
+          .collect::<napi::Result<IndexMap<_, _>>>()
+      })
+      .transpose()?;
+
+    // The UnknownRef must be explicitly unref'd after extracting its value, as
+    // in `transform`.
+    let debug_file_path = options
+      .debug_file_path
+      .take()
+      .map(|unknown_ref| {
+        let read_result = unknown_ref
+          .get_value(env)
+          .and_then(|value| read_debug_file_path(env, value));
+        let _ = unknown_ref.unref(env);
+        read_result
+      })
+      .transpose()?
+      .map(|debug_file_path| match debug_file_path {
+        DebugFilePath::Fixed(path) => Ok(path),
+        DebugFilePath::Function(_) => Err(napi::Error::from_reason(
+          "[StyleX] A debugFilePath function can only be called on the JS thread. Use transform() or pass a string.",
+        )),
+      })
+      .transpose()?;
+
+    let source_map = options.source_map.take();
+    let inline_sources_content = options.inline_sources_content.take();
+    let emit_source_map_columns = options.emit_source_map_columns.take();
+    let cache = options.cache_directory.take().map(TransformCache::new);
+    let layers = layers_options(options.use_layers.take());
+
+    let config = PlainConfig::new(options.try_into()?, env_exprs);
+
+    Ok(SharedOptions {
+      config,
+      debug_file_path,
+      source_map,
+      inline_sources_content,
+      emit_source_map_columns,
+      cache,
+      layers,
+    })
+  }
+
+  /// Options for tests, which have no `Env` to convert [`StyleXOptions`] with.
+  #[cfg(test)]
+  pub(crate) fn from_config(config: StyleXOptionsParams) -> Self {
+    let env = config.env.as_ref().map(|env| {
+      env
+        .iter()
+        .map(|(name, entry)| {
+          let expr = entry.as_expr().expect("test options have no env functions");
+          (name.clone(), expr.clone())
+        })
+        .collect()
+    });
+
+    SharedOptions {
+      config: PlainConfig::new(config, env),
+      debug_file_path: None,
+      source_map: None,
+      inline_sources_content: None,
+      emit_source_map_columns: None,
+      cache: None,
+      layers: None,
+    }
+  }
+
+  pub(crate) fn layers(&self) -> Option<&LayersOptions> {
+    self.layers.as_ref()
+  }
+
+  /// Compiles one file with a copy of the shared config, so no two files share
+  /// a state.
+  pub(crate) fn transform(
+    &self,
+    filename: String,
+    code: String,
+    input_source_map: Option<String>,
+  ) -> Result<TransformedFile, TransformFailure> {
+    self.transform_in_session(filename, code, input_source_map, None)
+  }
+
+  /// [`SharedOptions::transform`], reusing and adding to the resolutions of
+  /// `session_cache` when there is one.
+  pub(crate) fn transform_in_session(
+    &self,
+    filename: String,
+    code: String,
+    input_source_map: Option<String>,
+    session_cache: Option<&Arc<SessionCache>>,
+  ) -> Result<TransformedFile, TransformFailure> {
+    let mut config = self.config.to_params();
+    config.debug_file_path = self.debug_file_path.clone().map(fixed_debug_file_path);
+
+    let output_options = OutputOptions {
+      source_map: self.source_map,
+      inline_sources_content: self.inline_sources_content,
+      emit_source_map_columns: self.emit_source_map_columns,
+      input_source_map,
+    };
+
+    let cached = self.cache.as_ref().and_then(|cache| {
+      let key = cache_key(
+        &filename,
+        &code,
+        &config,
+        self.debug_file_path.as_deref(),
+        &output_options,
+      )?;
+
+      Some((cache, key))
+    });
+
+    let compile = || {
+      transform_source(
+        filename,
+        code,
+        output_options,
+        &mut config,
+        CompileMode::Transform,
+        None,
+        session_cache,
+      )
+    };
+
+    match cached {
+      Some((cache, key)) => cache.get_or_insert_with(&key, compile),
+      None => compile(),
+    }
+  }
+
+  /// Compiles one file for what the code at `offset` compiles to.
+  ///
+  /// The cache is neither read nor written: an editor asks about one offset at
+  /// a time, of code that is usually unsaved, so an entry would be stale before
+  /// it could be hit.
+  pub(crate) fn analyze(
+    &self,
+    filename: String,
+    code: String,
+    offset: u32,
+  ) -> Result<Option<Analysis>, TransformFailure> {
+    let mut config = self.config.to_params();
+    config.debug_file_path = self.debug_file_path.clone().map(fixed_debug_file_path);
+
+    let output_options = OutputOptions {
+      source_map: Some(SourceMaps::False),
+      ..Default::default()
+    };
+
+    transform_source(
+      filename,
+      code,
+      output_options,
+      &mut config,
+      CompileMode::Analyze(offset),
+      None,
+      None,
+    )
+    .map(|file| file.analysis)
+  }
+
+  /// Compiles one file for its diagnostics alone: every StyleX call that
+  /// fails, and any warning. A file that cannot be compiled at all, such as
+  /// one that does not parse, reports why as its last diagnostic.
+  ///
+  /// Like an analysis, a check is never cached.
+  pub(crate) fn check(&self, filename: String, code: String) -> Vec<Diagnostic> {
+    let mut config = self.config.to_params();
+    config.debug_file_path = self.debug_file_path.clone().map(fixed_debug_file_path);
+
+    let output_options = OutputOptions {
+      source_map: Some(SourceMaps::False),
+      ..Default::default()
+    };
+
+    match transform_source(
+      filename,
+      code,
+      output_options,
+      &mut config,
+      CompileMode::Check,
+      None,
+      None,
+    ) {
+      Ok(file) => file.diagnostics,
+      Err(failure) => failure.diagnostics,
+    }
+  }
+
+  /// The `.stylex.ts` source declaring the tokens of a design tokens file,
+  /// for these options.
+  pub(crate) fn design_tokens_source(
+    &self,
+    tokens: &str,
+    token_options: StyleXDesignTokenOptions,
+  ) -> napi::Result<String> {
+    design_tokens_source(&self.config.to_params(), tokens, token_options)
+  }
+}
+
+/// The [`StyleXOptionsParams`] of [`SharedOptions`] as plain data, which,
+/// unlike the params, can be sent to and shared between threads.
+///
+/// It has every option but the two that can hold a JS function: `env` is kept
+/// as the expressions of its entries, and `debugFilePath` is left to
+/// [`SharedOptions`]. Each file gets params of its own back from it, on the
+/// thread that compiles it.
+#[derive(Clone)]
+struct PlainConfig {
+  style_resolution: Option<StyleResolution>,
+  property_validation_mode: Option<PropertyValidationMode>,
+  enable_font_size_px_to_rem: Option<bool>,
+  enable_color_normalization: Option<bool>,
+  enable_at_rule_normalization: Option<bool>,
+  runtime_injection: Option<RuntimeInjection>,
+  class_name_prefix: Option<String>,
+  defined_stylex_css_variables: Option<FxHashMap<String, String>>,
+  import_sources: Option<Vec<ImportSources>>,
+  treeshake_compensation: Option<bool>,
+  enable_inlined_conditional_merge: Option<bool>,
+  enable_media_query_order: Option<bool>,
+  enable_logical_styles_polyfill: Option<bool>,
+  enable_legacy_value_flipping: Option<bool>,
+  enable_native_logical_styles: Option<bool>,
+  enable_ltr_rtl_comments: Option<bool>,
+  use_real_file_for_source: Option<bool>,
+  report_unused_styles: Option<bool>,
+  emit_theme_declarations: Option<bool>,
+  emit_class_name_keys: Option<bool>,
+  dev: Option<bool>,
+  test: Option<bool>,
+  debug: Option<bool>,
+  enable_debug_class_names: Option<bool>,
+  enable_debug_data_prop: Option<bool>,
+  enable_dev_class_names: Option<bool>,
+  enable_minified_keys: Option<bool>,
+  inject_stylex_side_effects: Option<bool>,
+  aliases: Option<FxHashMap<String, Vec<String>>>,
+  unstable_module_resolution: Option<ModuleResolution>,
+  sx_prop_name: Option<SxPropNameParam>,
+  env: Option<IndexMap<String, Expr>>,
+}
+
+impl PlainConfig {
+  fn new(params: StyleXOptionsParams, env: Option<IndexMap<String, Expr>>) -> Self {
+    let StyleXOptionsParams {
+      style_resolution,
+      property_validation_mode,
+      enable_font_size_px_to_rem,
+      enable_color_normalization,
+      enable_at_rule_normalization,
+      runtime_injection,
+      class_name_prefix,
+      defined_stylex_css_variables,
+      import_sources,
+      treeshake_compensation,
+      enable_inlined_conditional_merge,
+      enable_media_query_order,
+      enable_logical_styles_polyfill,
+      enable_legacy_value_flipping,
+      enable_native_logical_styles,
+      enable_ltr_rtl_comments,
+      use_real_file_for_source,
+      report_unused_styles,
+      emit_theme_declarations,
+      emit_class_name_keys,
+      dev,
+      test,
+      debug,
+      enable_debug_class_names,
+      enable_debug_data_prop,
+      enable_dev_class_names,
+      enable_minified_keys,
+      inject_stylex_side_effects,
+      aliases,
+      unstable_module_resolution,
+      sx_prop_name,
+      env: _,
+      debug_file_path: _,
+    } = params;
+
+    PlainConfig {
+      style_resolution,
+      property_validation_mode,
+      enable_font_size_px_to_rem,
+      enable_color_normalization,
+      enable_at_rule_normalization,
+      runtime_injection,
+      class_name_prefix,
+      defined_stylex_css_variables,
+      import_sources,
+      treeshake_compensation,
+      enable_inlined_conditional_merge,
+      enable_media_query_order,
+      enable_logical_styles_polyfill,
+      enable_legacy_value_flipping,
+      enable_native_logical_styles,
+      enable_ltr_rtl_comments,
+      use_real_file_for_source,
+      report_unused_styles,
+      emit_theme_declarations,
+      emit_class_name_keys,
+      dev,
+      test,
+      debug,
+      enable_debug_class_names,
+      enable_debug_data_prop,
+      enable_dev_class_names,
+      enable_minified_keys,
+      inject_stylex_side_effects,
+      aliases,
+      unstable_module_resolution,
+      sx_prop_name,
+      env,
+    }
+  }
+
+  fn to_params(&self) -> StyleXOptionsParams {
+    let PlainConfig {
+      style_resolution,
+      property_validation_mode,
+      enable_font_size_px_to_rem,
+      enable_color_normalization,
+      enable_at_rule_normalization,
+      runtime_injection,
+      class_name_prefix,
+      defined_stylex_css_variables,
+      import_sources,
+      treeshake_compensation,
+      enable_inlined_conditional_merge,
+      enable_media_query_order,
+      enable_logical_styles_polyfill,
+      enable_legacy_value_flipping,
+      enable_native_logical_styles,
+      enable_ltr_rtl_comments,
+      use_real_file_for_source,
+      report_unused_styles,
+      emit_theme_declarations,
+      emit_class_name_keys,
+      dev,
+      test,
+      debug,
+      enable_debug_class_names,
+      enable_debug_data_prop,
+      enable_dev_class_names,
+      enable_minified_keys,
+      inject_stylex_side_effects,
+      aliases,
+      unstable_module_resolution,
+      sx_prop_name,
+      env,
+    } = self.clone();
+
+    StyleXOptionsParams {
+      style_resolution,
+      property_validation_mode,
+      enable_font_size_px_to_rem,
+      enable_color_normalization,
+      enable_at_rule_normalization,
+      runtime_injection,
+      class_name_prefix,
+      defined_stylex_css_variables,
+      import_sources,
+      treeshake_compensation,
+      enable_inlined_conditional_merge,
+      enable_media_query_order,
+      enable_logical_styles_polyfill,
+      enable_legacy_value_flipping,
+      enable_native_logical_styles,
+      enable_ltr_rtl_comments,
+      use_real_file_for_source,
+      report_unused_styles,
+      emit_theme_declarations,
+      emit_class_name_keys,
+      dev,
+      test,
+      debug,
+      enable_debug_class_names,
+      enable_debug_data_prop,
+      enable_dev_class_names,
+      enable_minified_keys,
+      inject_stylex_side_effects,
+      aliases,
+      unstable_module_resolution,
+      sx_prop_name,
+      env: env.map(|env| {
+        env
+          .into_iter()
+          .map(|(name, expr)| (name, EnvEntry::Expr(expr)))
+          .collect()
+      }),
+      debug_file_path: None,
+    }
+  }
+}
+
+/// The `.stylex.ts` source declaring the tokens of a design tokens file.
+///
+/// The module imports `stylex` from the first package of `importSources`, so
+/// that compiling it with the same options recognises its calls.
+pub(crate) fn design_tokens_source(
+  config: &StyleXOptionsParams,
+  tokens: &str,
+  token_options: StyleXDesignTokenOptions,
+) -> napi::Result<String> {
+  let defaults = DesignTokenOptions::default();
+
+  let import_source = match &config.import_sources {
+    None => defaults.import_source,
+    Some(import_sources) => import_sources
+      .iter()
+      .find_map(|import_source| match import_source {
+        ImportSources::Regular(package) => Some(package.clone()),
+        ImportSources::Named(_) => None,
+      })
+      .ok_or_else(|| {
+        napi::Error::from_reason(
+          "[StyleX] Imported design tokens need a package in importSources to import stylex from.",
+        )
+      })?,
+  };
+
+  let options = DesignTokenOptions {
+    import_source,
+    export_name: token_options.export_name.unwrap_or(defaults.export_name),
+    mode_conditions: token_options
+      .mode_conditions
+      .unwrap_or_default()
+      .into_iter()
+      .collect(),
+  };
+
+  design_tokens_to_stylex_source(tokens, &options)
+    .map_err(|error| napi::Error::from_reason(format!("[StyleX] {}", error)))
+}
+
+/// Maps `items` through `f` on as many threads as the machine has cores,
+/// keeping the results in the order of the items.
+pub(crate) fn run_in_parallel<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+  T: Send,
+  R: Send,
+  F: Fn(T) -> R + Sync,
+{
+  let thread_count = thread::available_parallelism()
+    .map_or(1, NonZeroUsize::get)
+    .min(items.len());
+
+  if thread_count <= 1 {
+    return items.into_iter().map(f).collect();
+  }
+
+  let queue = Mutex::new(items.into_iter().enumerate());
+  let (sender, receiver) = mpsc::channel();
+
+  thread::scope(|scope| {
+    for _ in 0..thread_count {
+      let (queue, f, sender) = (&queue, &f, sender.clone());
+
+      scope.spawn(move || {
+        while let Some((index, item)) = queue.lock().ok().and_then(|mut queue| queue.next()) {
+          let _ = sender.send((index, f(item)));
+        }
+      });
+    }
+  });
+
+  drop(sender);
+
+  let mut results: Vec<(usize, R)> = receiver.into_iter().collect();
+  results.sort_unstable_by_key(|(index, _)| *index);
+
+  results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+#[path = "../tests/shared_options_tests.rs"]
+mod tests;
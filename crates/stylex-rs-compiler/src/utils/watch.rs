@@ -0,0 +1,194 @@
+use std::{
+  fs, io,
+  path::Path,
+  sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use indexmap::IndexMap;
+use stylex_transform::shared::structures::session_cache::SessionCache;
+use stylex_types::structures::meta_data::MetaData;
+
+use crate::{
+  TransformFailure, TransformedFile,
+  utils::shared_options::{SharedOptions, run_in_parallel},
+};
+
+/// What the stylesheet gained and lost from one file between two of its
+/// compiles.
+///
+/// A rule the file no longer has is removed only once no other file compiled
+/// by the same compiler still has it.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct RuleDelta {
+  pub(crate) added: Vec<MetaData>,
+  pub(crate) removed: Vec<MetaData>,
+}
+
+impl RuleDelta {
+  fn between(previous: &[MetaData], current: &[MetaData]) -> Self {
+    RuleDelta {
+      added: current
+        .iter()
+        .filter(|rule| !previous.contains(rule))
+        .cloned()
+        .collect(),
+      removed: previous
+        .iter()
+        .filter(|rule| !current.contains(rule))
+        .cloned()
+        .collect(),
+    }
+  }
+
+  /// Keeps only the removals none of `files` still has.
+  fn unused_by(mut self, files: &IndexMap<String, CompiledFile>) -> Self {
+    self
+      .removed
+      .retain(|rule| !files.values().any(|file| file.rules.contains(rule)));
+
+    self
+  }
+}
+
+/// What a watch compiler remembers of a file since its last compile.
+#[derive(Debug, Default)]
+struct CompiledFile {
+  rules: Vec<MetaData>,
+  dependencies: Vec<String>,
+}
+
+/// The outcome of compiling one file again after an invalidation.
+pub(crate) type Recompiled = Result<RuleDelta, TransformFailure>;
+
+/// A compiler kept for the life of a dev server: the options are converted
+/// once, and the `package.json` reads, import resolutions and parsed theme
+/// files of one compile serve the next, until `invalidate` is told the files
+/// behind them changed.
+///
+/// Each file's rules are remembered, so every compile reports what it added to
+/// and removed from the stylesheet rather than the whole of it.
+pub(crate) struct WatchCompiler {
+  shared: SharedOptions,
+  session_cache: Arc<SessionCache>,
+  /// By filename, in the order they were first compiled.
+  files: Mutex<IndexMap<String, CompiledFile>>,
+}
+
+impl WatchCompiler {
+  pub(crate) fn new(shared: SharedOptions) -> Self {
+    WatchCompiler {
+      shared,
+      session_cache: Arc::default(),
+      files: Mutex::default(),
+    }
+  }
+
+  pub(crate) fn shared(&self) -> &SharedOptions {
+    &self.shared
+  }
+
+  /// Compiles `code` as `filename`, and reports how its rules differ from
+  /// those of its previous compile. A file that fails to compile keeps the
+  /// rules it had.
+  pub(crate) fn transform(
+    &self,
+    filename: String,
+    code: String,
+    input_source_map: Option<String>,
+  ) -> Result<(TransformedFile, RuleDelta), TransformFailure> {
+    let file = self.shared.transform_in_session(
+      filename.clone(),
+      code,
+      input_source_map,
+      Some(&self.session_cache),
+    )?;
+    let delta = self.record(filename, &file);
+
+    Ok((file, delta))
+  }
+
+  /// Drops what `paths` changing makes stale, then compiles again, from disk,
+  /// every file among `paths` or depending on one of them. Returns the outcome
+  /// for each such file, in the order they were first compiled.
+  ///
+  /// A file that no longer exists is forgotten, and reported as removing every
+  /// rule it had that no other file has.
+  pub(crate) fn invalidate(&self, paths: &[String]) -> Vec<(String, Recompiled)> {
+    let changed: Vec<&Path> = paths.iter().map(Path::new).collect();
+
+    self.session_cache.invalidate(&changed);
+
+    let affected: Vec<String> = self
+      .files()
+      .iter()
+      .filter(|(filename, file)| {
+        paths.contains(filename)
+          || file
+            .dependencies
+            .iter()
+            .any(|dependency| paths.contains(dependency))
+      })
+      .map(|(filename, _)| filename.clone())
+      .collect();
+
+    let outcomes = run_in_parallel(affected, |filename| {
+      let outcome = match fs::read_to_string(&filename) {
+        Ok(code) => self
+          .shared
+          .transform_in_session(filename.clone(), code, None, Some(&self.session_cache))
+          .map(Some),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(napi::Error::from(error).into()),
+      };
+
+      (filename, outcome)
+    });
+
+    outcomes
+      .into_iter()
+      .map(|(filename, outcome)| {
+        let recompiled = outcome.map(|file| match file {
+          Some(file) => self.record(filename.clone(), &file),
+          None => self.forget(&filename),
+        });
+
+        (filename, recompiled)
+      })
+      .collect()
+  }
+
+  /// Remembers the rules and dependencies of `file`, returning how the rules
+  /// differ from those remembered before.
+  fn record(&self, filename: String, file: &TransformedFile) -> RuleDelta {
+    let mut files = self.files();
+    let compiled = files.entry(filename).or_default();
+    let delta = RuleDelta::between(&compiled.rules, &file.metadata);
+
+    compiled.rules = file.metadata.clone();
+    compiled.dependencies = file.dependencies.clone();
+
+    delta.unused_by(&files)
+  }
+
+  fn forget(&self, filename: &str) -> RuleDelta {
+    let mut files = self.files();
+    let rules = files
+      .shift_remove(filename)
+      .map(|file| file.rules)
+      .unwrap_or_default();
+
+    RuleDelta {
+      added: vec![],
+      removed: rules,
+    }
+    .unused_by(&files)
+  }
+
+  fn files(&self) -> MutexGuard<'_, IndexMap<String, CompiledFile>> {
+    self.files.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+#[cfg(test)]
+#[path = "../tests/watch_tests.rs"]
+mod tests;
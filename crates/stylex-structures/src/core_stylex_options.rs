@@ -30,11 +30,31 @@ pub struct CoreStyleXOptions {
   pub enable_media_query_order: bool,
   pub enable_logical_styles_polyfill: bool,
   pub enable_legacy_value_flipping: bool,
+  /// Keeps logical properties and `float`/`clear` values logical, spelled as
+  /// the standard ones browsers implement, rather than turning them into a
+  /// physical LTR rule and RTL override. Takes precedence over
+  /// `enable_logical_styles_polyfill`.
+  pub enable_native_logical_styles: bool,
   #[allow(dead_code)]
   pub enable_ltr_rtl_comments: bool,
   pub enable_minified_keys: bool,
   pub enable_font_size_px_to_rem: bool,
+  /// Respells colors in their shortest form, so equivalent colors share a
+  /// class.
+  pub enable_color_normalization: bool,
+  /// Respells `@supports` and `@container` conditions canonically, so
+  /// equivalent conditions share a class.
+  pub enable_at_rule_normalization: bool,
   pub use_real_file_for_source: bool,
+  /// Reports the `stylex.create` namespaces and `defineVars` tokens a file
+  /// never reads, alongside the transform output.
+  pub report_unused_styles: bool,
+  /// Describes the file's `defineVars` and `defineConsts` exports as a
+  /// TypeScript declaration file, alongside the transform output.
+  pub emit_theme_declarations: bool,
+  /// Records the declaration each class name is hashed from, alongside the
+  /// transform output, for a class-name manifest to compare across builds.
+  pub emit_class_name_keys: bool,
   pub class_name_prefix: String,
   pub style_resolution: StyleResolution,
   /// Configured StyleX import sources in fixed order
@@ -67,10 +87,16 @@ impl Default for CoreStyleXOptions {
       enable_media_query_order: true,
       enable_logical_styles_polyfill: false,
       enable_legacy_value_flipping: false,
+      enable_native_logical_styles: false,
       enable_ltr_rtl_comments: false,
       enable_minified_keys: true,
       enable_font_size_px_to_rem: false,
+      enable_color_normalization: false,
+      enable_at_rule_normalization: false,
       use_real_file_for_source: true,
+      report_unused_styles: false,
+      emit_theme_declarations: false,
+      emit_class_name_keys: false,
       class_name_prefix: "x".to_string(),
       style_resolution: StyleResolution::PropertySpecificity,
       import_sources: IndexSet::new(),
@@ -147,6 +173,11 @@ impl CoreStyleXOptions {
     self
   }
 
+  pub fn with_enable_native_logical_styles(mut self, enabled: bool) -> Self {
+    self.enable_native_logical_styles = enabled;
+    self
+  }
+
   pub fn with_enable_ltr_rtl_comments(mut self, enabled: bool) -> Self {
     self.enable_ltr_rtl_comments = enabled;
     self
@@ -162,11 +193,36 @@ impl CoreStyleXOptions {
     self
   }
 
+  pub fn with_enable_color_normalization(mut self, enabled: bool) -> Self {
+    self.enable_color_normalization = enabled;
+    self
+  }
+
+  pub fn with_enable_at_rule_normalization(mut self, enabled: bool) -> Self {
+    self.enable_at_rule_normalization = enabled;
+    self
+  }
+
   pub fn with_use_real_file_for_source(mut self, enabled: bool) -> Self {
     self.use_real_file_for_source = enabled;
     self
   }
 
+  pub fn with_report_unused_styles(mut self, enabled: bool) -> Self {
+    self.report_unused_styles = enabled;
+    self
+  }
+
+  pub fn with_emit_theme_declarations(mut self, enabled: bool) -> Self {
+    self.emit_theme_declarations = enabled;
+    self
+  }
+
+  pub fn with_emit_class_name_keys(mut self, enabled: bool) -> Self {
+    self.emit_class_name_keys = enabled;
+    self
+  }
+
   pub fn with_class_name_prefix(mut self, prefix: impl Into<String>) -> Self {
     self.class_name_prefix = prefix.into();
     self
@@ -254,6 +310,20 @@ impl CoreStyleXOptions {
     self
   }
 
+  pub fn maybe_enable_color_normalization(mut self, enabled: Option<bool>) -> Self {
+    if let Some(v) = enabled {
+      self.enable_color_normalization = v;
+    }
+    self
+  }
+
+  pub fn maybe_enable_at_rule_normalization(mut self, enabled: Option<bool>) -> Self {
+    if let Some(v) = enabled {
+      self.enable_at_rule_normalization = v;
+    }
+    self
+  }
+
   pub fn maybe_class_name_prefix(mut self, prefix: Option<String>) -> Self {
     if let Some(v) = prefix {
       self.class_name_prefix = v;
@@ -331,6 +401,13 @@ impl CoreStyleXOptions {
     self
   }
 
+  pub fn maybe_enable_native_logical_styles(mut self, enabled: Option<bool>) -> Self {
+    if let Some(v) = enabled {
+      self.enable_native_logical_styles = v;
+    }
+    self
+  }
+
   pub fn maybe_enable_ltr_rtl_comments(mut self, enabled: Option<bool>) -> Self {
     if let Some(v) = enabled {
       self.enable_ltr_rtl_comments = v;
@@ -344,6 +421,27 @@ impl CoreStyleXOptions {
     }
     self
   }
+
+  pub fn maybe_report_unused_styles(mut self, enabled: Option<bool>) -> Self {
+    if let Some(v) = enabled {
+      self.report_unused_styles = v;
+    }
+    self
+  }
+
+  pub fn maybe_emit_theme_declarations(mut self, enabled: Option<bool>) -> Self {
+    if let Some(v) = enabled {
+      self.emit_theme_declarations = v;
+    }
+    self
+  }
+
+  pub fn maybe_emit_class_name_keys(mut self, enabled: Option<bool>) -> Self {
+    if let Some(v) = enabled {
+      self.emit_class_name_keys = v;
+    }
+    self
+  }
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@ use std::ops::{Deref, DerefMut};
 
 use indexmap::IndexMap;
 use rustc_hash::FxHashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use stylex_constants::constants::common::DEFAULT_INJECT_PATH;
 use stylex_enums::{
@@ -16,12 +16,14 @@ use crate::{
   stylex_env::{EnvEntry, JSFunction},
 };
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct StyleXOptionsParams {
   pub style_resolution: Option<StyleResolution>,
   pub property_validation_mode: Option<PropertyValidationMode>,
   pub enable_font_size_px_to_rem: Option<bool>,
+  pub enable_color_normalization: Option<bool>,
+  pub enable_at_rule_normalization: Option<bool>,
   pub runtime_injection: Option<RuntimeInjection>,
   pub class_name_prefix: Option<String>,
   pub defined_stylex_css_variables: Option<FxHashMap<String, String>>,
@@ -31,8 +33,12 @@ pub struct StyleXOptionsParams {
   pub enable_media_query_order: Option<bool>,
   pub enable_logical_styles_polyfill: Option<bool>,
   pub enable_legacy_value_flipping: Option<bool>,
+  pub enable_native_logical_styles: Option<bool>,
   pub enable_ltr_rtl_comments: Option<bool>,
   pub use_real_file_for_source: Option<bool>,
+  pub report_unused_styles: Option<bool>,
+  pub emit_theme_declarations: Option<bool>,
+  pub emit_class_name_keys: Option<bool>,
   pub dev: Option<bool>,
   pub test: Option<bool>,
   pub debug: Option<bool>,
@@ -57,6 +63,8 @@ impl Default for StyleXOptionsParams {
       style_resolution: Some(StyleResolution::PropertySpecificity),
       property_validation_mode: Some(PropertyValidationMode::Silent),
       enable_font_size_px_to_rem: Some(false),
+      enable_color_normalization: Some(false),
+      enable_at_rule_normalization: Some(false),
       runtime_injection: Some(RuntimeInjection::Boolean(false)),
       class_name_prefix: Some("x".to_string()),
       defined_stylex_css_variables: Some(FxHashMap::default()),
@@ -67,6 +75,7 @@ impl Default for StyleXOptionsParams {
       enable_logical_styles_polyfill: Some(false),
       enable_ltr_rtl_comments: Some(false),
       enable_legacy_value_flipping: Some(false),
+      enable_native_logical_styles: Some(false),
       dev: Some(false),
       test: Some(false),
       debug: None,
@@ -76,6 +85,9 @@ impl Default for StyleXOptionsParams {
       enable_minified_keys: Some(true),
       inject_stylex_side_effects: Some(false),
       use_real_file_for_source: Some(true),
+      report_unused_styles: Some(false),
+      emit_theme_declarations: Some(false),
+      emit_class_name_keys: Some(false),
       aliases: None,
       unstable_module_resolution: None,
       sx_prop_name: None,
@@ -85,7 +97,7 @@ impl Default for StyleXOptionsParams {
   }
 }
 
-#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub enum ModuleResolutionKind {
   Haste,
@@ -94,7 +106,7 @@ pub enum ModuleResolutionKind {
   CrossFileParsing,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all(deserialize = "camelCase", serialize = "PascalCase"))]
 pub struct ModuleResolution {
   #[serde(rename = "type")]
@@ -271,6 +283,16 @@ impl StyleXOptions {
     self
   }
 
+  pub fn with_enable_color_normalization(mut self, enabled: bool) -> Self {
+    self.core.enable_color_normalization = enabled;
+    self
+  }
+
+  pub fn with_enable_at_rule_normalization(mut self, enabled: bool) -> Self {
+    self.core.enable_at_rule_normalization = enabled;
+    self
+  }
+
   pub fn with_enable_logical_styles_polyfill(mut self, enabled: bool) -> Self {
     self.core.enable_logical_styles_polyfill = enabled;
     self
@@ -338,6 +360,8 @@ impl From<StyleXOptionsParams> for StyleXOptions {
       .maybe_style_resolution(options.style_resolution)
       .maybe_property_validation_mode(options.property_validation_mode)
       .maybe_enable_font_size_px_to_rem(options.enable_font_size_px_to_rem)
+      .maybe_enable_color_normalization(options.enable_color_normalization)
+      .maybe_enable_at_rule_normalization(options.enable_at_rule_normalization)
       .maybe_class_name_prefix(options.class_name_prefix)
       .maybe_dev(options.dev)
       .maybe_test(options.test)
@@ -351,8 +375,12 @@ impl From<StyleXOptionsParams> for StyleXOptions {
       .maybe_enable_media_query_order(options.enable_media_query_order)
       .maybe_enable_logical_styles_polyfill(options.enable_logical_styles_polyfill)
       .maybe_enable_legacy_value_flipping(options.enable_legacy_value_flipping)
+      .maybe_enable_native_logical_styles(options.enable_native_logical_styles)
       .maybe_enable_ltr_rtl_comments(options.enable_ltr_rtl_comments)
-      .maybe_use_real_file_for_source(options.use_real_file_for_source);
+      .maybe_use_real_file_for_source(options.use_real_file_for_source)
+      .maybe_report_unused_styles(options.report_unused_styles)
+      .maybe_emit_theme_declarations(options.emit_theme_declarations)
+      .maybe_emit_class_name_keys(options.emit_class_name_keys);
 
     StyleXOptions {
       core,
@@ -77,11 +77,26 @@ impl StyleXStateOptions {
     self
   }
 
+  pub fn with_enable_color_normalization(mut self, enabled: bool) -> Self {
+    self.core.enable_color_normalization = enabled;
+    self
+  }
+
+  pub fn with_enable_at_rule_normalization(mut self, enabled: bool) -> Self {
+    self.core.enable_at_rule_normalization = enabled;
+    self
+  }
+
   pub fn with_enable_logical_styles_polyfill(mut self, enabled: bool) -> Self {
     self.core.enable_logical_styles_polyfill = enabled;
     self
   }
 
+  pub fn with_enable_native_logical_styles(mut self, enabled: bool) -> Self {
+    self.core.enable_native_logical_styles = enabled;
+    self
+  }
+
   pub fn with_enable_minified_keys(mut self, enabled: bool) -> Self {
     self.core.enable_minified_keys = enabled;
     self
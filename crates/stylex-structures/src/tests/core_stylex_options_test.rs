@@ -47,10 +47,16 @@ fn core_stylex_options_direct_setters_cover_all_fields() {
     .with_enable_media_query_order(false)
     .with_enable_logical_styles_polyfill(true)
     .with_enable_legacy_value_flipping(true)
+    .with_enable_native_logical_styles(true)
     .with_enable_ltr_rtl_comments(true)
     .with_enable_minified_keys(false)
     .with_enable_font_size_px_to_rem(true)
+    .with_enable_color_normalization(true)
+    .with_enable_at_rule_normalization(true)
     .with_use_real_file_for_source(false)
+    .with_report_unused_styles(true)
+    .with_emit_theme_declarations(true)
+    .with_emit_class_name_keys(true)
     .with_class_name_prefix("pref")
     .with_style_resolution(StyleResolution::ApplicationOrder)
     .with_import_sources(import_sources.clone())
@@ -73,10 +79,16 @@ fn core_stylex_options_direct_setters_cover_all_fields() {
   assert!(!opts.enable_media_query_order);
   assert!(opts.enable_logical_styles_polyfill);
   assert!(opts.enable_legacy_value_flipping);
+  assert!(opts.enable_native_logical_styles);
   assert!(opts.enable_ltr_rtl_comments);
   assert!(!opts.enable_minified_keys);
   assert!(opts.enable_font_size_px_to_rem);
+  assert!(opts.enable_color_normalization);
+  assert!(opts.enable_at_rule_normalization);
   assert!(!opts.use_real_file_for_source);
+  assert!(opts.report_unused_styles);
+  assert!(opts.emit_theme_declarations);
+  assert!(opts.emit_class_name_keys);
   assert_eq!(opts.class_name_prefix, "pref");
   assert_eq!(opts.style_resolution, StyleResolution::ApplicationOrder);
   assert_eq!(opts.import_sources.len(), import_sources.len());
@@ -102,6 +114,8 @@ fn core_stylex_options_optional_setters_skip_none_values() {
     .maybe_style_resolution(None)
     .maybe_property_validation_mode(None)
     .maybe_enable_font_size_px_to_rem(None)
+    .maybe_enable_color_normalization(None)
+    .maybe_enable_at_rule_normalization(None)
     .maybe_class_name_prefix(None)
     .maybe_enable_debug_class_names(None)
     .maybe_enable_debug_data_prop(None)
@@ -113,8 +127,12 @@ fn core_stylex_options_optional_setters_skip_none_values() {
     .maybe_enable_media_query_order(None)
     .maybe_enable_logical_styles_polyfill(None)
     .maybe_enable_legacy_value_flipping(None)
+    .maybe_enable_native_logical_styles(None)
     .maybe_enable_ltr_rtl_comments(None)
-    .maybe_use_real_file_for_source(None);
+    .maybe_use_real_file_for_source(None)
+    .maybe_report_unused_styles(None)
+    .maybe_emit_theme_declarations(None)
+    .maybe_emit_class_name_keys(None);
 
   assert_eq!(opts.dev, original.dev);
   assert_eq!(opts.test, original.test);
@@ -127,6 +145,14 @@ fn core_stylex_options_optional_setters_skip_none_values() {
     opts.enable_font_size_px_to_rem,
     original.enable_font_size_px_to_rem
   );
+  assert_eq!(
+    opts.enable_color_normalization,
+    original.enable_color_normalization
+  );
+  assert_eq!(
+    opts.enable_at_rule_normalization,
+    original.enable_at_rule_normalization
+  );
   assert_eq!(opts.class_name_prefix, original.class_name_prefix);
   assert_eq!(
     opts.enable_debug_class_names,
@@ -156,6 +182,10 @@ fn core_stylex_options_optional_setters_skip_none_values() {
     opts.enable_legacy_value_flipping,
     original.enable_legacy_value_flipping
   );
+  assert_eq!(
+    opts.enable_native_logical_styles,
+    original.enable_native_logical_styles
+  );
   assert_eq!(
     opts.enable_ltr_rtl_comments,
     original.enable_ltr_rtl_comments
@@ -164,6 +194,12 @@ fn core_stylex_options_optional_setters_skip_none_values() {
     opts.use_real_file_for_source,
     original.use_real_file_for_source
   );
+  assert_eq!(opts.report_unused_styles, original.report_unused_styles);
+  assert_eq!(
+    opts.emit_theme_declarations,
+    original.emit_theme_declarations
+  );
+  assert_eq!(opts.emit_class_name_keys, original.emit_class_name_keys);
 }
 
 /// Optional `maybe_*` setters should apply values only when `Some`.
@@ -175,6 +211,8 @@ fn core_stylex_options_optional_setters_apply_some_values() {
     .maybe_style_resolution(Some(StyleResolution::ApplicationOrder))
     .maybe_property_validation_mode(Some(PropertyValidationMode::Throw))
     .maybe_enable_font_size_px_to_rem(Some(true))
+    .maybe_enable_color_normalization(Some(true))
+    .maybe_enable_at_rule_normalization(Some(true))
     .maybe_class_name_prefix(Some("abc".to_string()))
     .maybe_enable_debug_class_names(Some(true))
     .maybe_enable_debug_data_prop(Some(false))
@@ -186,14 +224,20 @@ fn core_stylex_options_optional_setters_apply_some_values() {
     .maybe_enable_media_query_order(Some(false))
     .maybe_enable_logical_styles_polyfill(Some(true))
     .maybe_enable_legacy_value_flipping(Some(true))
+    .maybe_enable_native_logical_styles(Some(true))
     .maybe_enable_ltr_rtl_comments(Some(true))
-    .maybe_use_real_file_for_source(Some(false));
+    .maybe_use_real_file_for_source(Some(false))
+    .maybe_report_unused_styles(Some(true))
+    .maybe_emit_theme_declarations(Some(true))
+    .maybe_emit_class_name_keys(Some(true));
 
   assert!(opts.dev);
   assert!(opts.test);
   assert_eq!(opts.style_resolution, StyleResolution::ApplicationOrder);
   assert_eq!(opts.property_validation_mode, PropertyValidationMode::Throw);
   assert!(opts.enable_font_size_px_to_rem);
+  assert!(opts.enable_color_normalization);
+  assert!(opts.enable_at_rule_normalization);
   assert_eq!(opts.class_name_prefix, "abc");
   assert!(opts.enable_debug_class_names);
   assert!(!opts.enable_debug_data_prop);
@@ -205,6 +249,10 @@ fn core_stylex_options_optional_setters_apply_some_values() {
   assert!(!opts.enable_media_query_order);
   assert!(opts.enable_logical_styles_polyfill);
   assert!(opts.enable_legacy_value_flipping);
+  assert!(opts.enable_native_logical_styles);
   assert!(opts.enable_ltr_rtl_comments);
   assert!(!opts.use_real_file_for_source);
+  assert!(opts.report_unused_styles);
+  assert!(opts.emit_theme_declarations);
+  assert!(opts.emit_class_name_keys);
 }
@@ -24,6 +24,8 @@ fn stylex_options_builders_update_supported_fields() {
     .with_enable_debug_data_prop(false)
     .with_enable_dev_class_names(true)
     .with_enable_font_size_px_to_rem(true)
+    .with_enable_color_normalization(true)
+    .with_enable_at_rule_normalization(true)
     .with_enable_logical_styles_polyfill(true)
     .with_enable_minified_keys(false)
     .with_runtime_injection(RuntimeInjection::Regular("/custom/inject".to_string()))
@@ -38,6 +40,8 @@ fn stylex_options_builders_update_supported_fields() {
   assert!(!opts.enable_debug_data_prop);
   assert!(opts.enable_dev_class_names);
   assert!(opts.enable_font_size_px_to_rem);
+  assert!(opts.enable_color_normalization);
+  assert!(opts.enable_at_rule_normalization);
   assert!(opts.enable_logical_styles_polyfill);
   assert!(!opts.enable_minified_keys);
   assert!(matches!(
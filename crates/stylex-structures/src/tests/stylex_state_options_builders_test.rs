@@ -21,6 +21,8 @@ fn stylex_state_options_builders_update_all_supported_fields() {
     .with_enable_debug_data_prop(false)
     .with_enable_dev_class_names(true)
     .with_enable_font_size_px_to_rem(true)
+    .with_enable_color_normalization(true)
+    .with_enable_at_rule_normalization(true)
     .with_enable_logical_styles_polyfill(true)
     .with_enable_minified_keys(false)
     .with_unstable_module_resolution(
@@ -40,6 +42,8 @@ fn stylex_state_options_builders_update_all_supported_fields() {
   assert!(!state.enable_debug_data_prop);
   assert!(state.enable_dev_class_names);
   assert!(state.enable_font_size_px_to_rem);
+  assert!(state.enable_color_normalization);
+  assert!(state.enable_at_rule_normalization);
   assert!(state.enable_logical_styles_polyfill);
   assert!(!state.enable_minified_keys);
   assert!(matches!(
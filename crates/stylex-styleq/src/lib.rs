@@ -16,7 +16,8 @@
 mod styleq;
 mod types;
 
-pub use styleq::{Styleq, create_styleq, styleq};
+pub use styleq::{Styleq, create_styleq, props, styleq};
 pub use types::{
-  StyleMap, StyleValue, StyleqArgument, StyleqInput, StyleqOptions, StyleqResult, StyleqValue,
+  RuntimeStyleValue, StyleMap, StyleValue, StyleqArgument, StyleqInput, StyleqOptions, StyleqProps,
+  StyleqResult, StyleqValue,
 };
@@ -10,7 +10,9 @@ use log::{debug, error};
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use stylex_constants::constants::common::COMPILED_KEY;
 
-use crate::{StyleMap, StyleqArgument, StyleqInput, StyleqOptions, StyleqResult, StyleqValue};
+use crate::{
+  StyleMap, StyleqArgument, StyleqInput, StyleqOptions, StyleqProps, StyleqResult, StyleqValue,
+};
 
 // JS-parity: styleq/src/styleq.js — `compiledStyleCache` (a Map keyed by
 // either the source array reference or a structural hash). Order is never
@@ -68,6 +70,12 @@ pub fn styleq<V: StyleqValue>(styles: &[StyleqInput<V>]) -> StyleqResult<V> {
   create_styleq(StyleqOptions::default()).styleq(styles)
 }
 
+// JS-parity: @stylexjs/stylex `props` — `styleq` with the empty parts of its
+// result left out.
+pub fn props<V: StyleqValue>(styles: &[StyleqInput<V>]) -> StyleqProps<V> {
+  create_styleq(StyleqOptions::default()).props(styles)
+}
+
 impl<V: StyleqValue> Styleq<V> {
   pub fn styleq<A>(&self, arguments: &[A]) -> StyleqResult<V>
   where
@@ -144,6 +152,14 @@ impl<V: StyleqValue> Styleq<V> {
     }
   }
 
+  /// Runs the merge and shapes its result as `stylex.props` does.
+  pub fn props<A>(&self, arguments: &[A]) -> StyleqProps<V>
+  where
+    A: StyleqArgument<V>,
+  {
+    self.styleq(arguments).into()
+  }
+
   fn process_compiled_style(
     &self,
     style: &StyleMap<V>,
@@ -1,4 +1,9 @@
-use std::{fmt::Debug, hash::Hash, rc::Rc, sync::Arc};
+use std::{
+  fmt::Debug,
+  hash::{Hash, Hasher},
+  rc::Rc,
+  sync::Arc,
+};
 
 use indexmap::IndexMap;
 
@@ -113,6 +118,25 @@ pub struct StyleqResult<V: StyleqValue> {
   pub data_style_src: String,
 }
 
+/// What `stylex.props` returns for a merge: each key is present only when it
+/// carries something, so the result spreads onto an element as it is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyleqProps<V: StyleqValue> {
+  pub class_name: Option<String>,
+  pub style: Option<StyleMap<V>>,
+  pub data_style_src: Option<String>,
+}
+
+impl<V: StyleqValue> From<StyleqResult<V>> for StyleqProps<V> {
+  fn from(result: StyleqResult<V>) -> Self {
+    Self {
+      class_name: Some(result.class_name).filter(|class_name| !class_name.is_empty()),
+      style: result.inline_style.filter(|style| !style.is_empty()),
+      data_style_src: Some(result.data_style_src).filter(|src| !src.is_empty()),
+    }
+  }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum StyleValue {
   String(String),
@@ -146,3 +170,51 @@ impl StyleqValue for StyleValue {
     matches!(self, StyleValue::Bool(true))
   }
 }
+
+/// A value as the runtime sees it at render time: a class name, `null` or the
+/// `$$css` marker in a compiled style object, and anything an inline style may
+/// hold otherwise. Unlike [`StyleValue`], numbers keep their fraction, so an
+/// inline `opacity: 0.5` comes back out unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuntimeStyleValue {
+  String(String),
+  Number(f64),
+  Bool(bool),
+  Null,
+}
+
+impl RuntimeStyleValue {
+  pub fn string(value: impl Into<String>) -> Self {
+    Self::String(value.into())
+  }
+}
+
+impl Hash for RuntimeStyleValue {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    std::mem::discriminant(self).hash(state);
+
+    match self {
+      RuntimeStyleValue::String(value) => value.hash(state),
+      RuntimeStyleValue::Number(value) => value.to_bits().hash(state),
+      RuntimeStyleValue::Bool(value) => value.hash(state),
+      RuntimeStyleValue::Null => {},
+    }
+  }
+}
+
+impl StyleqValue for RuntimeStyleValue {
+  fn as_class_name(&self) -> Option<&str> {
+    match self {
+      RuntimeStyleValue::String(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  fn is_null(&self) -> bool {
+    matches!(self, RuntimeStyleValue::Null)
+  }
+
+  fn is_true_bool(&self) -> bool {
+    matches!(self, RuntimeStyleValue::Bool(true))
+  }
+}
@@ -0,0 +1,137 @@
+use stylex_constants::constants::common::COMPILED_KEY;
+use stylex_styleq::{
+  RuntimeStyleValue, StyleMap, StyleqInput, StyleqOptions, StyleqProps, StyleqValue, create_styleq,
+  props,
+};
+
+fn compiled(marker: RuntimeStyleValue, entries: &[(&str, &str)]) -> StyleqInput<RuntimeStyleValue> {
+  let mut style = StyleMap::new();
+  style.insert(COMPILED_KEY.to_string(), marker);
+
+  for (key, value) in entries {
+    style.insert((*key).to_string(), RuntimeStyleValue::string(*value));
+  }
+
+  StyleqInput::Style(style)
+}
+
+fn inline(entries: &[(&str, RuntimeStyleValue)]) -> StyleqInput<RuntimeStyleValue> {
+  StyleqInput::Style(
+    entries
+      .iter()
+      .map(|(key, value)| ((*key).to_string(), value.clone()))
+      .collect(),
+  )
+}
+
+#[test]
+fn props_leaves_out_empty_parts() {
+  let result = props::<RuntimeStyleValue>(&[
+    StyleqInput::Null,
+    StyleqInput::False,
+    StyleqInput::Nested(vec![]),
+  ]);
+
+  assert_eq!(
+    result,
+    StyleqProps {
+      class_name: None,
+      style: None,
+      data_style_src: None,
+    }
+  );
+}
+
+#[test]
+fn props_merges_compiled_styles_with_debug_sources() {
+  let base = compiled(
+    RuntimeStyleValue::string("app.js:4"),
+    &[("color", "x1e2nbdu"), ("display", "x78zum5")],
+  );
+  let active = compiled(
+    RuntimeStyleValue::string("app.js:8"),
+    &[("color", "x1a2a7pz")],
+  );
+
+  let result = props(&[base, StyleqInput::Nested(vec![StyleqInput::False, active])]);
+
+  assert_eq!(result.class_name.as_deref(), Some("x78zum5 x1a2a7pz"));
+  assert_eq!(result.style, None);
+  assert_eq!(result.data_style_src.as_deref(), Some("app.js:4; app.js:8"));
+}
+
+#[test]
+fn props_keeps_inline_values_as_given() {
+  let result = props(&[
+    compiled(RuntimeStyleValue::Bool(true), &[("opacity", "x1hc1fzr")]),
+    inline(&[
+      ("opacity", RuntimeStyleValue::Number(0.5)),
+      ("width", RuntimeStyleValue::Number(12.5)),
+      ("color", RuntimeStyleValue::Null),
+    ]),
+  ]);
+
+  assert_eq!(result.class_name, None);
+  assert_eq!(
+    result.style.unwrap().into_iter().collect::<Vec<_>>(),
+    vec![
+      ("opacity".to_string(), RuntimeStyleValue::Number(0.5)),
+      ("width".to_string(), RuntimeStyleValue::Number(12.5)),
+    ]
+  );
+  assert_eq!(result.data_style_src, None);
+}
+
+#[test]
+fn props_follows_options_and_reuses_the_cache() {
+  let styleq = create_styleq(StyleqOptions {
+    dedupe_class_name_chunks: true,
+    ..Default::default()
+  });
+  let styles = [
+    compiled(RuntimeStyleValue::Bool(true), &[("display", "x78zum5")]),
+    compiled(
+      RuntimeStyleValue::Bool(true),
+      &[("color", "x1e2nbdu x1a2a7pz")],
+    ),
+  ];
+
+  let first = styleq.props(&styles);
+  let second = styleq.props(&styles);
+
+  assert_eq!(
+    first.class_name.as_deref(),
+    Some("x78zum5 x1e2nbdu x1a2a7pz")
+  );
+  assert_eq!(first, second);
+
+  // A later compiled `color` hides the inline one unless mixing is off.
+  let styles = [
+    inline(&[("color", RuntimeStyleValue::string("red"))]),
+    compiled(RuntimeStyleValue::Bool(true), &[("color", "x1e2nbdu")]),
+  ];
+  let unmixed = create_styleq(StyleqOptions {
+    disable_mix: true,
+    ..Default::default()
+  });
+
+  assert_eq!(styleq.props(&styles).style, None);
+  assert_eq!(
+    unmixed.props(&styles).style.unwrap().get("color"),
+    Some(&RuntimeStyleValue::string("red"))
+  );
+}
+
+#[test]
+fn runtime_style_value_trait_methods_cover_all_value_kinds() {
+  assert_eq!(
+    RuntimeStyleValue::string("x1abc").as_class_name(),
+    Some("x1abc")
+  );
+  assert_eq!(RuntimeStyleValue::Number(1.0).as_class_name(), None);
+  assert!(RuntimeStyleValue::Null.is_null());
+  assert!(!RuntimeStyleValue::Bool(false).is_null());
+  assert!(RuntimeStyleValue::Bool(true).is_true_bool());
+  assert!(!RuntimeStyleValue::Bool(false).is_true_bool());
+  assert!(!RuntimeStyleValue::Number(1.0).is_true_bool());
+}
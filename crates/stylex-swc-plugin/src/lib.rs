@@ -0,0 +1,160 @@
+//! The StyleX transform as an SWC Wasm plugin, for builds that already run
+//! SWC — `@swc/core` with a `.swcrc`, Next.js `experimental.swcPlugins`,
+//! Rspack's `builtin:swc-loader` — and would otherwise parse every file a
+//! second time through the NAPI compiler.
+//!
+//! A plugin returns nothing but the program, so the rules a file collects
+//! travel in the output itself: a leading comment carrying the metadata
+//! tuples the NAPI `transform` returns as `metadata.stylex`, between
+//! [`METADATA_COMMENT_START`] and [`METADATA_COMMENT_END`].
+
+mod options;
+
+use std::path::PathBuf;
+
+use serde_json::{Value, json};
+use stylex_macros::stylex_panic;
+use stylex_structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams};
+use stylex_transform::StyleXTransform;
+use stylex_types::structures::meta_data::MetaData;
+use swc_core::{
+  common::{
+    FileName, Spanned,
+    comments::{Comment, CommentKind, Comments},
+  },
+  ecma::{
+    ast::{Pass, Program},
+    visit::VisitMutWith,
+  },
+  plugin::{
+    metadata::{TransformPluginMetadataContextKind, TransformPluginProgramMetadata},
+    plugin_transform,
+  },
+};
+
+pub use crate::options::parse_plugin_options;
+
+/// Opens the comment a transformed file carries its rules in.
+pub const METADATA_COMMENT_START: &str = "__stylex_metadata_start__";
+/// Closes the comment a transformed file carries its rules in.
+pub const METADATA_COMMENT_END: &str = "__stylex_metadata_end__";
+
+/// Runs the StyleX transform over a program and attaches the rules it
+/// collected as a leading comment, through whichever comments store the host
+/// printing the program holds.
+///
+/// Every program gets a state of its own, as every file does in the NAPI
+/// binding; `config` is only read.
+pub struct StyleXPlugin<C: Comments + Clone> {
+  comments: C,
+  plugin_pass: PluginPass,
+  config: StyleXOptionsParams,
+}
+
+impl<C: Comments + Clone> StyleXPlugin<C> {
+  pub fn new(comments: C, plugin_pass: PluginPass, config: StyleXOptionsParams) -> Self {
+    Self {
+      comments,
+      plugin_pass,
+      config,
+    }
+  }
+}
+
+impl<C: Comments + Clone> Pass for StyleXPlugin<C> {
+  fn process(&mut self, program: &mut Program) {
+    let mut stylex = StyleXTransform::new(
+      self.comments.clone(),
+      self.plugin_pass.clone(),
+      &mut self.config,
+    );
+
+    program.visit_mut_with(&mut stylex);
+
+    let metadata: Vec<&MetaData> = stylex.state.metadata().values().flatten().collect();
+
+    if let Some(comment) = metadata_comment(&metadata) {
+      self.comments.add_leading(
+        program.span().lo,
+        Comment {
+          kind: CommentKind::Block,
+          span: Default::default(),
+          text: comment.into(),
+        },
+      );
+    }
+  }
+}
+
+/// The text of the metadata comment, or `None` for a file without rules.
+///
+/// Each rule is `[className, { ltr, rtl, constKey?, constVal? }, priority]`,
+/// the shape `processStylexRules` takes. `*/` inside a rule is written `*\/`,
+/// which JSON reads back as the same string, so a rule cannot end the comment.
+pub fn metadata_comment(metadata: &[&MetaData]) -> Option<String> {
+  if metadata.is_empty() {
+    return None;
+  }
+
+  let rules: Vec<Value> = metadata.iter().map(|meta| metadata_tuple(meta)).collect();
+  let json = Value::Array(rules).to_string().replace("*/", "*\\/");
+
+  Some(format!(
+    "{}{}{}",
+    METADATA_COMMENT_START, json, METADATA_COMMENT_END
+  ))
+}
+
+fn metadata_tuple(meta: &MetaData) -> Value {
+  let mut style = json!({
+    "ltr": meta.get_css(),
+    "rtl": meta.get_css_rtl(),
+  });
+
+  if let (Some(const_key), Some(const_value)) = (meta.get_const_key(), meta.get_const_value()) {
+    style["constKey"] = const_key.into();
+    style["constVal"] = const_value.into();
+  }
+
+  let priority = *meta.get_priority();
+  let priority = if priority.fract() == 0.0 {
+    Value::from(priority as i64)
+  } else {
+    Value::from(priority)
+  };
+
+  json!([meta.get_class_name(), style, priority])
+}
+
+/// The plugin entry SWC calls for every file. Options are the JSON object
+/// beside the plugin in the SWC config; see [`parse_plugin_options`].
+#[plugin_transform]
+pub fn process_transform(
+  mut program: Program,
+  metadata: TransformPluginProgramMetadata,
+) -> Program {
+  let config = parse_plugin_options(&metadata.get_transform_plugin_config().unwrap_or_default())
+    .unwrap_or_else(|error| stylex_panic!("Invalid StyleX plugin options: {}", error));
+
+  let plugin_pass = PluginPass {
+    cwd: metadata
+      .get_context(&TransformPluginMetadataContextKind::Cwd)
+      .map(PathBuf::from),
+    filename: metadata
+      .get_context(&TransformPluginMetadataContextKind::Filename)
+      .map_or(FileName::Anon, |filename| {
+        FileName::Real(PathBuf::from(filename))
+      }),
+  };
+
+  // Without the proxy the host has no comments to print, and a transform
+  // given a no-op store could neither read `@stylex` annotations nor leave
+  // the metadata comment; the host always passes one.
+  let Some(comments) = metadata.comments else {
+    stylex_panic!("The SWC host passed the StyleX plugin no comments");
+  };
+
+  StyleXPlugin::new(comments, plugin_pass, config).process(&mut program);
+
+  program
+}
@@ -0,0 +1,73 @@
+use indexmap::IndexMap;
+use serde_json::Value;
+use stylex_ast::ast::{
+  convertors::{create_bool_expr, create_null_expr, create_number_expr, create_string_expr},
+  factories::{
+    create_array_expression, create_expr_or_spread, create_object_expression,
+    create_str_key_value_prop,
+  },
+};
+use stylex_structures::{stylex_env::EnvEntry, stylex_options::StyleXOptionsParams};
+use swc_core::ecma::ast::Expr;
+
+/// Reads the options an SWC config gives the plugin, the JSON object written
+/// beside it in `jsc.experimental.plugins`.
+///
+/// They are spelled as the `rsOptions` of the bundler plugins. `env` is the
+/// exception that JSON forces: its values are read as static values, each
+/// compiled to the expression a `stylex.env` read is replaced with, and there
+/// is no way to pass an `env` function. A config with no options, which SWC
+/// hands over as an empty string or `null`, gets the defaults.
+pub fn parse_plugin_options(json: &str) -> Result<StyleXOptionsParams, String> {
+  if json.trim().is_empty() {
+    return Ok(StyleXOptionsParams::default());
+  }
+
+  let value: Value = serde_json::from_str(json).map_err(|error| error.to_string())?;
+
+  if value.is_null() {
+    return Ok(StyleXOptionsParams::default());
+  }
+
+  let env = match value.get("env") {
+    None | Some(Value::Null) => None,
+    Some(Value::Object(entries)) => Some(
+      entries
+        .iter()
+        .map(|(key, value)| (key.clone(), EnvEntry::Expr(json_to_expr(value))))
+        .collect::<IndexMap<_, _>>(),
+    ),
+    Some(_) => return Err("`env` must be an object of static values".to_string()),
+  };
+
+  let mut options: StyleXOptionsParams =
+    serde_json::from_value(value).map_err(|error| error.to_string())?;
+  options.env = env;
+
+  Ok(options)
+}
+
+fn json_to_expr(value: &Value) -> Expr {
+  match value {
+    Value::Null => create_null_expr(),
+    Value::Bool(value) => create_bool_expr(*value),
+    Value::Number(value) => create_number_expr(value.as_f64().unwrap_or(f64::NAN)),
+    Value::String(value) => create_string_expr(value),
+    Value::Array(values) => create_array_expression(
+      values
+        .iter()
+        .map(|value| Some(create_expr_or_spread(json_to_expr(value))))
+        .collect(),
+    ),
+    Value::Object(entries) => create_object_expression(
+      entries
+        .iter()
+        .map(|(key, value)| create_str_key_value_prop(key, json_to_expr(value)))
+        .collect(),
+    ),
+  }
+}
+
+#[cfg(test)]
+#[path = "tests/options_tests.rs"]
+mod tests;
@@ -0,0 +1,85 @@
+// Tests for reading the plugin's options from the SWC config.
+// Source: crates/stylex-swc-plugin/src/options.rs
+
+use swc_core::ecma::ast::{Lit, PropOrSpread};
+
+use super::*;
+
+fn env_entry(options: &StyleXOptionsParams, key: &str) -> Expr {
+  options
+    .env
+    .as_ref()
+    .and_then(|env| env.get(key))
+    .and_then(EnvEntry::as_expr)
+    .cloned()
+    .unwrap_or_else(|| panic!("env entry `{key}`"))
+}
+
+#[test]
+fn no_options_are_the_defaults() {
+  for json in ["", "  ", "null"] {
+    let options = parse_plugin_options(json).expect("valid options");
+
+    assert_eq!(options.class_name_prefix.as_deref(), Some("x"));
+    assert!(options.env.is_none());
+  }
+}
+
+#[test]
+fn options_are_spelled_as_the_bundler_plugins_spell_them() {
+  let options = parse_plugin_options(
+    r#"{"dev":true,"classNamePrefix":"app","unstable_moduleResolution":{"type":"commonJS"}}"#,
+  )
+  .expect("valid options");
+
+  assert_eq!(options.dev, Some(true));
+  assert_eq!(options.class_name_prefix.as_deref(), Some("app"));
+  assert!(options.unstable_module_resolution.is_some());
+}
+
+#[test]
+fn env_values_become_expressions() {
+  let options = parse_plugin_options(
+    r#"{"env":{"brand":"red","scale":1.5,"compact":false,"theme":{"--accent":"blue"},"sizes":[4,8]}}"#,
+  )
+  .expect("valid options");
+
+  assert!(matches!(
+    env_entry(&options, "brand"),
+    Expr::Lit(Lit::Str(value)) if value.value == "red"
+  ));
+  assert!(matches!(
+    env_entry(&options, "scale"),
+    Expr::Lit(Lit::Num(value)) if value.value == 1.5
+  ));
+  assert!(matches!(
+    env_entry(&options, "compact"),
+    Expr::Lit(Lit::Bool(value)) if !value.value
+  ));
+
+  let Expr::Object(theme) = env_entry(&options, "theme") else {
+    panic!("`theme` is an object");
+  };
+  assert!(matches!(
+    &theme.props[..],
+    [PropOrSpread::Prop(prop)] if prop.as_key_value().is_some()
+  ));
+
+  let Expr::Array(sizes) = env_entry(&options, "sizes") else {
+    panic!("`sizes` is an array");
+  };
+  assert_eq!(sizes.elems.len(), 2);
+}
+
+#[test]
+fn env_must_be_an_object() {
+  let error = parse_plugin_options(r#"{"env":"production"}"#).expect_err("a string env is refused");
+
+  assert!(error.contains("`env` must be an object"));
+}
+
+#[test]
+fn invalid_json_is_an_error() {
+  assert!(parse_plugin_options("{dev: true}").is_err());
+  assert!(parse_plugin_options(r#"{"dev":"yes"}"#).is_err());
+}
@@ -0,0 +1,15 @@
+import * as stylex from '@stylexjs/stylex';
+
+const styles = stylex.create({
+  root: {
+    color: 'red',
+    marginInlineStart: 8,
+    ':hover': {
+      color: 'blue',
+    },
+  },
+});
+
+export function Button() {
+  return <button {...stylex.props(styles.root)} />;
+}
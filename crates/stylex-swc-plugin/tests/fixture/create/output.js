@@ -0,0 +1,4 @@
+/*__stylex_metadata_start__[["x1e2nbdu",{"ltr":".x1e2nbdu{color:red}","rtl":null},3000],["x13fj5qh",{"ltr":".x13fj5qh{margin-inline-start:8px}","rtl":null},3000],["x17z2mba",{"ltr":".x17z2mba:hover{color:blue}","rtl":null},3130]]__stylex_metadata_end__*/ import * as stylex from '@stylexjs/stylex';
+export function Button() {
+    return <button className="x1e2nbdu x13fj5qh x17z2mba"/>;
+}
@@ -0,0 +1,8 @@
+import * as stylex from '@stylexjs/stylex';
+
+export const styles = stylex.create({
+  root: {
+    color: stylex.env.brand,
+    padding: stylex.env.spacing.small,
+  },
+});
@@ -0,0 +1,8 @@
+/*__stylex_metadata_start__[["app1tfn4g9",{"ltr":".app1tfn4g9{color:#123456}","rtl":null},3000],["appfawy5m",{"ltr":".appfawy5m{padding:4px}","rtl":null},1000]]__stylex_metadata_end__*/ import * as stylex from '@stylexjs/stylex';
+export const styles = {
+    root: {
+        kMwMTN: "app1tfn4g9",
+        kmVPX3: "appfawy5m",
+        $$css: true
+    }
+};
@@ -0,0 +1,2 @@
+// A file without StyleX is printed as it came, with no metadata comment.
+export const answer = 42;
@@ -0,0 +1,40 @@
+use std::{fs, path::PathBuf};
+
+use stylex_structures::plugin_pass::PluginPass;
+use stylex_swc_plugin::{StyleXPlugin, parse_plugin_options};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test_fixture,
+  },
+};
+
+/// Each fixture is an `input.js`, the `output.js` the plugin prints for it,
+/// and optionally the `options.json` an SWC config would give the plugin.
+#[testing::fixture("tests/fixture/**/input.js")]
+fn fixture(input: PathBuf) {
+  let dir = input.parent().unwrap();
+  let options = fs::read_to_string(dir.join("options.json")).unwrap_or_default();
+  let config = parse_plugin_options(&options).expect("valid fixture options");
+
+  test_fixture(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    &|tr| {
+      StyleXPlugin::new(
+        tr.comments.clone(),
+        PluginPass {
+          cwd: None,
+          filename: FileName::Real(input.clone()),
+        },
+        config.clone(),
+      )
+    },
+    &input,
+    &dir.join("output.js"),
+    Default::default(),
+  );
+}
@@ -0,0 +1,86 @@
+//! The steps every compile of a source file takes around [`StyleXTransform`]:
+//! parsing it, running the transform with the passes it depends on, and
+//! printing the result. The NAPI binding and the CLI differ only in what they
+//! read off the state in between and how they print.
+
+use std::sync::Arc;
+
+use swc_compiler_base::{PrintArgs, TransformOutput, print};
+use swc_core::{
+  common::{
+    Mark, SourceFile, SourceMap,
+    comments::{Comments, SingleThreadedComments},
+  },
+  ecma::{
+    ast::{EsVersion, Program},
+    transforms::{
+      base::{fixer::fixer, hygiene::hygiene, resolver},
+      typescript::strip as typescript_strip,
+    },
+    visit::visit_mut_pass,
+  },
+};
+use swc_ecma_parser::{Parser, StringInput, Syntax, TsSyntax, error::Error, lexer::Lexer};
+
+use crate::{StyleXTransform, shared::structures::session_cache::lock_process_caches};
+
+/// Parses `fm` the way every StyleX source is parsed, as TypeScript with JSX,
+/// collecting its comments into `comments` when given.
+pub fn parse_source(fm: &SourceFile, comments: Option<&dyn Comments>) -> Result<Program, Error> {
+  let mut parser = Parser::new_from(Lexer::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    EsVersion::latest(),
+    StringInput::from(fm),
+    comments,
+  ));
+
+  parser.parse_program()
+}
+
+/// Runs `stylex` over `program`, after the scope resolution and TypeScript
+/// stripping it expects and before the hygiene and parenthesis fixes its output
+/// needs.
+///
+/// Must be called inside `GLOBALS.set`, as the marks it creates are.
+pub fn transform_program<C>(program: Program, stylex: &mut StyleXTransform<C>) -> Program
+where
+  C: Comments,
+{
+  // The resolutions and theme files read below come from caches the whole
+  // process shares; a session invalidating them waits for this compile.
+  let _caches = lock_process_caches();
+
+  let unresolved_mark = Mark::new();
+  let top_level_mark = Mark::new();
+
+  program
+    .apply(resolver(unresolved_mark, top_level_mark, true))
+    .apply(typescript_strip(unresolved_mark, top_level_mark))
+    .apply(&mut visit_mut_pass(stylex))
+    .apply(hygiene())
+    .apply(&mut fixer(None))
+}
+
+/// Prints `program` with `args`, keeping the comments it was parsed with.
+///
+/// Without them the emitted code loses every comment — including the ones
+/// bundlers act on: `/* webpackChunkName: "…" */` on dynamic imports and
+/// `/* #__PURE__ */` annotations that minifiers need to drop dead calls.
+pub fn print_program(
+  cm: Arc<SourceMap>,
+  program: &Program,
+  comments: &SingleThreadedComments,
+  args: PrintArgs,
+) -> anyhow::Result<TransformOutput> {
+  print(
+    cm,
+    program,
+    PrintArgs {
+      comments: Some(comments),
+      ..args
+    },
+  )
+}
@@ -6,6 +6,7 @@ pub(crate) mod null_pre_rule;
 pub(crate) mod pre_rule;
 pub(crate) mod pre_rule_set;
 pub(crate) mod seen_value;
+pub mod session_cache;
 pub mod state;
 pub mod state_manager;
 #[cfg(test)]
@@ -0,0 +1,216 @@
+use std::{
+  path::Path,
+  sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard},
+};
+
+use rustc_hash::FxHashMap;
+use stylex_path_resolver::{
+  package_json::PackageJsonExtended, pnp::clear_pnp_manifests, resolvers::clear_resolver_cache,
+};
+
+use crate::shared::utils::core::cross_file_parsing::forget_parsed_modules;
+
+/// Files whose change can move what any import resolves to, or which package
+/// a file belongs to.
+const PROJECT_FILES: [&str; 5] = [
+  "package.json",
+  "tsconfig.json",
+  "jsconfig.json",
+  ".pnp.cjs",
+  ".pnp.data.json",
+];
+
+/// Held shared by every compile and exclusively by [`SessionCache::invalidate`],
+/// since the parsed theme files and resolver caches it clears are the
+/// process's, not the session's: a compile running on another thread would
+/// otherwise see them emptied halfway through.
+static PROCESS_CACHES: RwLock<()> = RwLock::new(());
+
+/// Keeps [`SessionCache::invalidate`] from clearing the process-wide caches
+/// until the guard is dropped.
+pub(crate) fn lock_process_caches() -> RwLockReadGuard<'static, ()> {
+  PROCESS_CACHES
+    .read()
+    .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// What a long-lived host keeps warm from one compile to the next: the
+/// `package.json` files read, what each theme import resolved to and the
+/// canonical path of each theme file. Without it every compile starts from an
+/// empty `package_json_seen` and resolves every import again.
+///
+/// Each answer is kept with the `package.json` files read to reach it, and a
+/// compile that reuses the answer is given those files as if it had read them,
+/// so its dependencies are the ones a cold compile reports.
+///
+/// Nothing here expires on its own. The host reports changed files through
+/// [`SessionCache::invalidate`], which also drops the parsed theme files and
+/// the resolver caches the process keeps, once no compile is running.
+#[derive(Debug, Default)]
+pub struct SessionCache {
+  entries: Mutex<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+  package_jsons: FxHashMap<String, PackageJsonExtended>,
+  /// By importing file and import path; `None` for an import that did not
+  /// resolve.
+  imports: FxHashMap<(String, String), Cached<Option<String>>>,
+  canonical_paths: FxHashMap<String, Cached<String>>,
+}
+
+#[derive(Debug)]
+struct Cached<T> {
+  value: T,
+  package_jsons: Vec<String>,
+}
+
+impl SessionCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// What `import_path` resolved to from `filename`, or `resolve`'s answer,
+  /// which is then kept.
+  pub(crate) fn resolve_import(
+    &self,
+    filename: &str,
+    import_path: &str,
+    package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
+    resolve: impl FnOnce(&mut FxHashMap<String, PackageJsonExtended>) -> Option<String>,
+  ) -> Option<String> {
+    let key = (filename.to_string(), import_path.to_string());
+
+    self.get_or_compute(
+      |entries| &mut entries.imports,
+      key,
+      package_json_seen,
+      resolve,
+    )
+  }
+
+  /// The canonical path of `file_path`, or `compute`'s answer, which is then
+  /// kept.
+  pub(crate) fn canonical_path(
+    &self,
+    file_path: &str,
+    package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
+    compute: impl FnOnce(&mut FxHashMap<String, PackageJsonExtended>) -> String,
+  ) -> String {
+    self.get_or_compute(
+      |entries| &mut entries.canonical_paths,
+      file_path.to_string(),
+      package_json_seen,
+      compute,
+    )
+  }
+
+  /// Forgets whatever `paths` changing can make stale: for a `package.json`,
+  /// a project config or a PnP manifest, every resolution; for any other
+  /// file, the imports that resolved to it and those that resolved to
+  /// nothing, which a new file can answer. The parsed exports of the files,
+  /// and of every theme file that read one, go as well.
+  ///
+  /// Waits for the compiles running on other threads to finish.
+  pub fn invalidate(&self, paths: &[&Path]) {
+    let _caches = PROCESS_CACHES
+      .write()
+      .unwrap_or_else(PoisonError::into_inner);
+
+    forget_parsed_modules(paths);
+
+    let is_project_file = |path: &&Path| {
+      path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| PROJECT_FILES.contains(&name))
+    };
+
+    {
+      let mut entries = self.entries();
+
+      if paths.iter().any(is_project_file) {
+        *entries = Entries::default();
+      } else {
+        entries.imports.retain(|_, cached| {
+          cached
+            .value
+            .as_deref()
+            .is_some_and(|resolved| !paths.contains(&Path::new(resolved)))
+        });
+        entries
+          .canonical_paths
+          .retain(|file_path, _| !paths.contains(&Path::new(file_path.as_str())));
+      }
+    }
+
+    // The resolvers remember which files exist, so an import that now names
+    // a new file, or one that went away, is looked up again.
+    clear_resolver_cache();
+
+    if paths.iter().any(is_project_file) {
+      clear_pnp_manifests();
+    }
+  }
+
+  fn get_or_compute<K, T>(
+    &self,
+    select: impl Fn(&mut Entries) -> &mut FxHashMap<K, Cached<T>>,
+    key: K,
+    package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
+    compute: impl FnOnce(&mut FxHashMap<String, PackageJsonExtended>) -> T,
+  ) -> T
+  where
+    K: Eq + std::hash::Hash,
+    T: Clone,
+  {
+    {
+      let mut entries = self.entries();
+
+      if let Some(cached) = select(&mut entries).get(&key) {
+        let value = cached.value.clone();
+        let package_jsons = cached.package_jsons.clone();
+
+        for path in package_jsons {
+          if let Some(package_json) = entries.package_jsons.get(&path) {
+            package_json_seen
+              .entry(path)
+              .or_insert_with(|| package_json.clone());
+          }
+        }
+
+        return value;
+      }
+    }
+
+    // Computed on a map of its own, without the lock held, so that exactly
+    // the `package.json` files this answer needs are recorded with it.
+    let mut read = FxHashMap::default();
+    let value = compute(&mut read);
+
+    let mut entries = self.entries();
+    let package_jsons = read.keys().cloned().collect();
+
+    for (path, package_json) in read {
+      entries
+        .package_jsons
+        .insert(path.clone(), package_json.clone());
+      package_json_seen.entry(path).or_insert(package_json);
+    }
+
+    select(&mut entries).insert(
+      key,
+      Cached {
+        value: value.clone(),
+        package_jsons,
+      },
+    );
+
+    value
+  }
+
+  fn entries(&self) -> MutexGuard<'_, Entries> {
+    self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
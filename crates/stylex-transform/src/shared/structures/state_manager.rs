@@ -1,6 +1,6 @@
 use rustc_hash::{FxHashMap, FxHashSet};
-use std::{option::Option, path::Path, rc::Rc, sync::Arc};
-use stylex_macros::{stylex_panic, stylex_unimplemented};
+use std::{cell::RefCell, option::Option, path::Path, rc::Rc, sync::Arc};
+use stylex_macros::stylex_panic;
 
 use indexmap::{IndexMap, IndexSet};
 use log::debug;
@@ -14,20 +14,24 @@ use swc_core::{
   common::{DUMMY_SP, EqIgnoreSpan, FileName, SourceFile, Span, SyntaxContext},
   ecma::{
     ast::{
-      CallExpr, Callee, Decl, Expr, ExprStmt, Id, Ident, ImportDecl, ImportDefaultSpecifier,
-      ImportNamedSpecifier, ImportPhase, ImportSpecifier, JSXAttrOrSpread, Lit, MemberExpr,
-      MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem, NamedExport, Pat, Program,
-      PropName, Stmt, Str, VarDecl, VarDeclKind, VarDeclarator,
+      CallExpr, Callee, Decl, Expr, ExprStmt, FnDecl, Id, Ident, ImportDecl,
+      ImportDefaultSpecifier, ImportNamedSpecifier, ImportPhase, ImportSpecifier, JSXAttrOrSpread,
+      Lit, MemberExpr, MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem, NamedExport,
+      Pat, Program, PropName, Stmt, Str, VarDecl, VarDeclKind, VarDeclarator,
     },
     visit::{Visit, VisitMut, VisitMutWith, VisitWith},
   },
 };
 
 use crate::shared::{
-  structures::types::InjectableStylesMap,
+  structures::{functions::FunctionMap, types::InjectableStylesMap},
   utils::{
     ast::{convertors::create_number_expr, helpers::namespace_name_from_member_prop},
     common::{extract_filename_from_path, extract_filename_with_ext_from_path, extract_path},
+    core::{
+      cross_file_parsing::resolve_imported_export,
+      theme_declarations::{ThemeDeclaration, render_theme_declarations},
+    },
     validators::{is_attrs_call, is_props_call},
   },
 };
@@ -49,7 +53,6 @@ use stylex_constants::constants::{
 use stylex_enums::{
   core::TransformationCycle,
   counter_mode::CounterMode,
-  import_path_resolution::ImportPathResolution,
   style_vars_to_keep::{NonNullProp, NonNullProps},
   top_level_expression::TopLevelExpressionKind,
 };
@@ -61,6 +64,7 @@ use stylex_utils::hash::{stable_hash_unspanned, stable_hash_unspanned_call};
 
 use super::{
   seen_value::SeenValue,
+  session_cache::SessionCache,
   types::{InjectImportIdents, SeenModuleSource, StylesObjectMap},
 };
 use stylex_structures::{
@@ -70,7 +74,9 @@ use stylex_structures::{
   stylex_state_options::StyleXStateOptions,
   uid_generator::UidGenerator,
 };
-use stylex_types::structures::meta_data::MetaData;
+use stylex_types::structures::{
+  analysis::Analysis, meta_data::MetaData, unused_styles::UnusedStyles,
+};
 
 // LOCK: Rc<T> by design. SWC visitors are sequential per file; cross-file
 // parallelism is provided by the host (Node worker pool calls into the
@@ -125,6 +131,16 @@ pub(crate) struct PendingInsertion {
   pub(crate) item: ModuleItem,
 }
 
+/// A theme or consts import, resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ThemeImport {
+  /// The path theme names are hashed from, as
+  /// [`StateManager::get_canonical_file_path`] gives it.
+  pub(crate) canonical_path: String,
+  /// The file on disk; `None` under Haste, which resolves names, not files.
+  pub(crate) file_path: Option<String>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum ImportKind {
   Props,
@@ -300,6 +316,9 @@ impl CacheState {
 pub(crate) struct DeclarationState {
   class_name_declarations: Vec<Ident>,
   function_name_declarations: Vec<Ident>,
+  /// Function declarations at the top of the module, which the evaluator can
+  /// call wherever they are used, since a declaration is hoisted.
+  top_level_functions: Vec<FnDecl>,
 }
 
 impl DeclarationState {
@@ -323,6 +342,23 @@ impl DeclarationState {
     }
   }
 
+  fn add_top_level_function(&mut self, fn_decl: &FnDecl) {
+    if !self
+      .top_level_functions
+      .iter()
+      .any(|existing| existing.ident.eq_ignore_span(&fn_decl.ident))
+    {
+      self.top_level_functions.push(fn_decl.clone());
+    }
+  }
+
+  fn top_level_function(&self, ident: &Ident) -> Option<&FnDecl> {
+    self
+      .top_level_functions
+      .iter()
+      .find(|fn_decl| fn_decl.ident.eq_ignore_span(ident))
+  }
+
   fn class_name_declarations(&self) -> &[Ident] {
     &self.class_name_declarations
   }
@@ -374,6 +410,40 @@ pub struct StateManager {
   /// transformed the code.
   pub(crate) input_source_map: Option<Arc<swc_sourcemap::SourceMap>>,
 
+  /// Absolute paths of the theme and consts files this module's imports
+  /// resolved to, plus the ones those files import when cross-file parsing
+  /// reads them, and of every `package.json` read to resolve or hash them.
+  /// The output of the transform is only valid while none of them changes.
+  /// Filled through `&self` lookups, hence the `RefCell`.
+  pub(crate) dependencies: RefCell<IndexSet<String>>,
+
+  /// Filled once the module is finalized, when `report_unused_styles` is on.
+  pub(crate) unused_styles: Option<UnusedStyles>,
+
+  /// The `defineVars` and `defineConsts` exports of the module by name,
+  /// recorded when `emit_theme_declarations` is on.
+  pub(crate) theme_declarations: IndexMap<String, ThemeDeclaration>,
+
+  /// The declaration each atomic class and keyframes name of the module is
+  /// hashed from, to the name, recorded when `emit_class_name_keys` is on.
+  pub(crate) class_name_keys: IndexMap<String, String>,
+
+  /// Resolutions a long-lived host keeps from one compile to the next, when
+  /// it provides them.
+  pub(crate) session_cache: Option<Arc<SessionCache>>,
+
+  /// The byte offset into the input source file to analyze, set by a host
+  /// asking what the code there compiles to.
+  pub(crate) analysis_offset: Option<u32>,
+  /// Filled once the module is finalized, when `analysis_offset` is set and
+  /// points at something the analysis recognises.
+  pub(crate) analysis: Option<Analysis>,
+
+  /// Set by a host that only wants the module's errors: a StyleX call that
+  /// fails is reported as a diagnostic and left as written, and the walk goes
+  /// on to the next one.
+  pub(crate) check_only: bool,
+
   // Imports
   pub(crate) imports: ImportState,
   pub(crate) export_id: Option<String>,
@@ -426,6 +496,13 @@ pub struct StateManager {
   pub(crate) pattern_bound_top_level_calls: FxHashSet<Span>,
   pub(crate) call_expressions: CallExpressionState,
   pub(crate) seen: FxHashMap<u64, Rc<SeenValue>>,
+  /// How many calls to module functions the evaluator is inside of, so a
+  /// helper that recurses without end deopts rather than overflowing the stack.
+  pub(crate) function_call_depth: usize,
+  /// What the names a module function closes over mean while the evaluator is
+  /// inside one: the bindings the outermost call was made with, before any call
+  /// bound its parameters. `None` outside every call.
+  pub(crate) module_functions: Option<Rc<FunctionMap>>,
   pub(crate) cache: CacheState,
   /// Maps a JSX spread expression to the JSX attributes that replace it.
   ///
@@ -514,6 +591,14 @@ impl StateManager {
       plugin_pass: PluginPass::default(),
       input_source_file: None,
       input_source_map: None,
+      dependencies: RefCell::default(),
+      unused_styles: None,
+      theme_declarations: IndexMap::new(),
+      class_name_keys: IndexMap::new(),
+      session_cache: None,
+      analysis_offset: None,
+      check_only: false,
+      analysis: None,
       imports: ImportState::default(),
       existing_import_sources: vec![],
       bound_names: FxHashSet::default(),
@@ -529,6 +614,8 @@ impl StateManager {
       export_id: None,
 
       seen: FxHashMap::default(),
+      function_call_depth: 0,
+      module_functions: None,
       cache: CacheState::default(),
       module_source: ModuleSourceState::default(),
 
@@ -563,6 +650,69 @@ impl StateManager {
     self.injection.metadata()
   }
 
+  /// Absolute paths of the files the transformed module depends on, in the
+  /// order they were first resolved.
+  pub fn dependencies(&self) -> Vec<String> {
+    self.dependencies.borrow().iter().cloned().collect()
+  }
+
+  /// What the module declares with `stylex.create` and `defineVars` and never
+  /// reads, or `None` unless `report_unused_styles` is on.
+  pub fn unused_styles(&self) -> Option<&UnusedStyles> {
+    self.unused_styles.as_ref()
+  }
+
+  /// The `.d.ts` text describing the module's `defineVars` and
+  /// `defineConsts` exports, or `None` unless `emit_theme_declarations` is on
+  /// and the module has any.
+  pub fn theme_declarations(&self) -> Option<String> {
+    render_theme_declarations(self.theme_declarations.values())
+  }
+
+  /// The declaration each class name of the module is hashed from, to the
+  /// name, or `None` unless `emit_class_name_keys` is on.
+  pub fn class_name_keys(&self) -> Option<&IndexMap<String, String>> {
+    self
+      .options
+      .emit_class_name_keys
+      .then_some(&self.class_name_keys)
+  }
+
+  /// Records that `class_name` is hashed from `key`, when asked to.
+  pub(crate) fn record_class_name_key(&mut self, key: &str, class_name: &str) {
+    if self.options.emit_class_name_keys {
+      self
+        .class_name_keys
+        .insert(key.to_string(), class_name.to_string());
+    }
+  }
+
+  /// What the code at the analysis offset compiles to, if an offset was set
+  /// and the code there is a style key or a token read.
+  pub fn analysis(&self) -> Option<&Analysis> {
+    self.analysis.as_ref()
+  }
+
+  pub(crate) fn add_dependency(&self, file_path: &str) {
+    if !self.dependencies.borrow().contains(file_path) {
+      self.dependencies.borrow_mut().insert(file_path.to_string());
+    }
+  }
+
+  /// Records every `package.json` read into `package_json_seen`, in path
+  /// order, since the map's own order is arbitrary.
+  fn add_package_json_dependencies(
+    &self,
+    package_json_seen: &FxHashMap<String, PackageJsonExtended>,
+  ) {
+    let mut package_json_paths: Vec<&String> = package_json_seen.keys().collect();
+    package_json_paths.sort_unstable();
+
+    for package_json_path in package_json_paths {
+      self.add_dependency(package_json_path);
+    }
+  }
+
   pub fn add_call_expression(&mut self, call_expr: &CallExpr) {
     self.call_expressions.add_call_expression(call_expr);
   }
@@ -587,6 +737,15 @@ impl StateManager {
     self.declarations_state.add_function_name_declaration(ident);
   }
 
+  pub(crate) fn add_top_level_function(&mut self, fn_decl: &FnDecl) {
+    self.declarations_state.add_top_level_function(fn_decl);
+  }
+
+  /// The top-level function declaration `ident` names, if the module has one.
+  pub(crate) fn top_level_function(&self, ident: &Ident) -> Option<&FnDecl> {
+    self.declarations_state.top_level_function(ident)
+  }
+
   pub(crate) fn class_name_declarations(&self) -> &[Ident] {
     self.declarations_state.class_name_declarations()
   }
@@ -916,6 +1075,25 @@ impl StateManager {
     self.input_source_file = Some(source_file);
   }
 
+  /// Reuses the package.json reads and import resolutions of earlier compiles
+  /// kept in `session_cache`, and keeps this compile's there.
+  pub fn set_session_cache(&mut self, session_cache: Arc<SessionCache>) {
+    self.session_cache = Some(session_cache);
+  }
+
+  /// Asks for an analysis of the code at `offset`, a byte offset into the
+  /// input source file, which must be provided as well.
+  pub fn set_analysis_offset(&mut self, offset: u32) {
+    self.analysis_offset = Some(offset);
+  }
+
+  /// Asks for every error in the module rather than its compiled code. See
+  /// [`DiagnosticCollector`](stylex_macros::diagnostic::DiagnosticCollector)
+  /// for where they are collected.
+  pub fn set_check_only(&mut self) {
+    self.check_only = true;
+  }
+
   /// Provides the source map of the compiler's input code, enabling positions
   /// to be mapped back to the original authored file.
   pub fn set_input_source_map(&mut self, source_map: Arc<swc_sourcemap::SourceMap>) {
@@ -958,7 +1136,11 @@ impl StateManager {
         extract_filename_with_ext_from_path(&filename).map(|s| s.to_string())
       },
       CheckModuleResolution::CommonJs { .. } | CheckModuleResolution::CrossFileParsing { .. } => {
-        Some(self.get_canonical_file_path(filename, package_json_seen))
+        let canonical_file_path = self.get_canonical_file_path(filename, package_json_seen);
+
+        self.add_package_json_dependencies(package_json_seen);
+
+        Some(canonical_file_path)
       },
     }
   }
@@ -992,6 +1174,21 @@ impl StateManager {
     &self,
     file_path: &str,
     package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
+  ) -> String {
+    match &self.session_cache {
+      Some(session_cache) => {
+        session_cache.canonical_path(file_path, package_json_seen, |package_json_seen| {
+          self.compute_canonical_file_path(file_path, package_json_seen)
+        })
+      },
+      None => self.compute_canonical_file_path(file_path, package_json_seen),
+    }
+  }
+
+  fn compute_canonical_file_path(
+    &self,
+    file_path: &str,
+    package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
   ) -> String {
     if let Some(pkg_info) = StateManager::get_package_name_and_path(file_path, package_json_seen) {
       let (package_name, package_dir) = pkg_info;
@@ -1030,15 +1227,17 @@ impl StateManager {
     format!("_unknown_path_:{}", file_name)
   }
 
-  pub(crate) fn import_path_resolver(
+  /// What a theme or consts import resolves to, or `None` for an import of
+  /// any other file or one that does not resolve.
+  pub(crate) fn resolve_theme_import(
     &self,
     import_path: &str,
     package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
-  ) -> ImportPathResolution {
+  ) -> Option<ThemeImport> {
     let source_file_path = self.get_filename();
 
     if source_file_path.is_empty() {
-      return ImportPathResolution::Unresolved;
+      return None;
     }
 
     let theme_file_extension = self
@@ -1056,51 +1255,116 @@ impl StateManager {
       matches_file_suffix(TRANSFORMED_VARS_FILE_EXTENSION, import_path);
 
     if !is_theme_file && !is_valid_transformed_vars_file && !is_consts_only_file {
-      return ImportPathResolution::Unresolved;
+      return None;
     }
 
     match &self.options.unstable_module_resolution {
-      CheckModuleResolution::CommonJs { .. } => {
-        let filename = self.get_filename();
+      CheckModuleResolution::CommonJs { .. } | CheckModuleResolution::CrossFileParsing { .. } => {
+        let resolved_file_path = self.resolve_import_file_path(import_path, package_json_seen);
 
-        let (_, root_dir) = StateManager::get_package_name_and_path(filename, package_json_seen)
-          .unwrap_or_else(|| stylex_panic!("Cannot get package name and path for: {}", filename));
+        // Recorded even when nothing resolved: a package.json edit can make the
+        // import resolve next time.
+        self.add_package_json_dependencies(package_json_seen);
 
-        let aliases = self.options.aliases.as_ref().cloned().unwrap_or_default();
+        let resolved_file_path = resolved_file_path?;
+        let canonical_path = self.get_canonical_file_path(&resolved_file_path, package_json_seen);
 
-        let resolved_file_path = match file_path_resolver(
-          import_path,
-          source_file_path,
-          &root_dir,
-          &aliases,
-          self.options.unstable_module_resolution.root_dir(),
-          package_json_seen,
-        ) {
-          Ok(resolved_file_path) => resolved_file_path,
-          Err(err) => {
-            debug!("Could not resolve import path {}: {}", import_path, err);
-            return ImportPathResolution::Unresolved;
-          },
-        };
+        self.add_package_json_dependencies(package_json_seen);
 
-        debug!("Resolved import path: {}", resolved_file_path);
+        Some(ThemeImport {
+          canonical_path,
+          file_path: Some(resolved_file_path),
+        })
+      },
+      CheckModuleResolution::Haste { .. } => Some(ThemeImport {
+        canonical_path: add_file_extension(import_path, source_file_path),
+        file_path: None,
+      }),
+    }
+  }
+
+  /// Absolute path of the file `import_path` names, resolved from the current
+  /// file through its package root, the configured aliases and `rootDir`.
+  pub(crate) fn resolve_import_file_path(
+    &self,
+    import_path: &str,
+    package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
+  ) -> Option<String> {
+    let resolved_file_path = match &self.session_cache {
+      Some(session_cache) => session_cache.resolve_import(
+        self.get_filename(),
+        import_path,
+        package_json_seen,
+        |package_json_seen| self.compute_import_file_path(import_path, package_json_seen),
+      ),
+      None => self.compute_import_file_path(import_path, package_json_seen),
+    };
 
-        let resolved_file_path =
-          self.get_canonical_file_path(&resolved_file_path, package_json_seen);
+    if let Some(resolved_file_path) = &resolved_file_path {
+      self.add_dependency(resolved_file_path);
+    }
 
-        ImportPathResolution::Resolved {
-          path: resolved_file_path,
-        }
-      },
-      CheckModuleResolution::Haste { .. } => ImportPathResolution::Resolved {
-        path: add_file_extension(import_path, source_file_path),
+    resolved_file_path
+  }
+
+  fn compute_import_file_path(
+    &self,
+    import_path: &str,
+    package_json_seen: &mut FxHashMap<String, PackageJsonExtended>,
+  ) -> Option<String> {
+    let filename = self.get_filename();
+
+    let (_, root_dir) = StateManager::get_package_name_and_path(filename, package_json_seen)
+      .unwrap_or_else(|| stylex_panic!("Cannot get package name and path for: {}", filename));
+
+    let aliases = self.options.aliases.as_ref().cloned().unwrap_or_default();
+
+    match file_path_resolver(
+      import_path,
+      filename,
+      &root_dir,
+      &aliases,
+      self.options.unstable_module_resolution.root_dir(),
+      package_json_seen,
+    ) {
+      Ok(resolved_file_path) => {
+        debug!("Resolved import path: {}", resolved_file_path);
+
+        Some(resolved_file_path)
       },
-      CheckModuleResolution::CrossFileParsing { .. } => {
-        stylex_unimplemented!("This module resolution strategy is not yet supported.")
+      Err(err) => {
+        debug!("Could not resolve import path {}: {}", import_path, err);
+        None
       },
     }
   }
 
+  /// The statically known value of `export_name` in the theme or consts file
+  /// at `file_path`, read by parsing and transforming that file.
+  ///
+  /// Only the `cross-file-parsing` module resolution reads imported files;
+  /// every other strategy, and any export that is not a literal once
+  /// transformed, answers `None` and is referenced by hash instead.
+  pub(crate) fn resolve_cross_file_export(
+    &self,
+    file_path: &str,
+    export_name: &str,
+  ) -> Option<Expr> {
+    if !matches!(
+      self.options.unstable_module_resolution,
+      CheckModuleResolution::CrossFileParsing { .. }
+    ) {
+      return None;
+    }
+
+    resolve_imported_export(
+      Path::new(file_path),
+      export_name,
+      &self.options,
+      |dependency| self.add_dependency(dependency),
+    )
+  }
+
   pub(crate) fn find_top_level_expr(
     &self,
     call: &CallExpr,
@@ -1538,29 +1802,39 @@ impl Visit for CollectIdentsVisitor {
 /// The graph is consumed by [`compute_live_set`] to compute reachability
 /// from `roots` and decide which declarators survive the sweep.
 pub(crate) fn build_decl_use_graph(module: &Module, state: &mut StateManager) {
+  add_decl_uses(module, &mut state.decl_uses, &mut state.roots);
+}
+
+/// The walk behind [`build_decl_use_graph`], into any graph: the unused-style
+/// report builds its own over the module as written.
+pub(crate) fn add_decl_uses(
+  module: &Module,
+  decl_uses: &mut FxHashMap<DeclId, FxHashSet<DeclId>>,
+  roots: &mut FxHashSet<DeclId>,
+) {
   for item in &module.body {
     match item {
       ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
         for decl in &var_decl.decls {
-          collect_decl_uses(state, decl);
+          collect_decl_uses(decl_uses, roots, decl);
         }
       },
       ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => match &export_decl.decl {
         Decl::Var(var_decl) => {
           for decl in &var_decl.decls {
-            collect_decl_uses(state, decl);
+            collect_decl_uses(decl_uses, roots, decl);
           }
         },
         other_decl => {
           let mut visitor = CollectIdentsVisitor::default();
           other_decl.visit_with(&mut visitor);
-          state.roots.extend(visitor.idents);
+          roots.extend(visitor.idents);
         },
       },
       _ => {
         let mut visitor = CollectIdentsVisitor::default();
         item.visit_with(&mut visitor);
-        state.roots.extend(visitor.idents);
+        roots.extend(visitor.idents);
       },
     }
   }
@@ -1572,19 +1846,27 @@ pub(crate) fn build_decl_use_graph(module: &Module, state: &mut StateManager) {
 /// reference graph. The sweep keeps every declarator whose `DeclId` is
 /// either in the returned set or absent from `state.decl_uses` entirely
 /// (the "not-in-graph ⇒ keep by default" fallback).
+pub(crate) fn compute_live_set(state: &StateManager) -> FxHashSet<DeclId> {
+  reachable_decls(&state.roots, &state.decl_uses)
+}
+
+/// Every `DeclId` reachable from `roots` over `decl_uses`.
 ///
 /// Iterative breadth-first traversal with a worklist; cycles and
 /// self-references terminate naturally because already-marked nodes are
 /// not revisited.
-pub(crate) fn compute_live_set(state: &StateManager) -> FxHashSet<DeclId> {
+pub(crate) fn reachable_decls(
+  roots: &FxHashSet<DeclId>,
+  decl_uses: &FxHashMap<DeclId, FxHashSet<DeclId>>,
+) -> FxHashSet<DeclId> {
   let mut live: FxHashSet<DeclId> = FxHashSet::default();
-  let mut worklist: Vec<DeclId> = state.roots.iter().cloned().collect();
+  let mut worklist: Vec<DeclId> = roots.iter().cloned().collect();
 
   while let Some(node) = worklist.pop() {
     if !live.insert(node.clone()) {
       continue;
     }
-    if let Some(targets) = state.decl_uses.get(&node) {
+    if let Some(targets) = decl_uses.get(&node) {
       for target in targets {
         worklist.push(target.clone());
       }
@@ -1594,7 +1876,11 @@ pub(crate) fn compute_live_set(state: &StateManager) -> FxHashSet<DeclId> {
   live
 }
 
-fn collect_decl_uses(state: &mut StateManager, decl: &VarDeclarator) {
+fn collect_decl_uses(
+  decl_uses: &mut FxHashMap<DeclId, FxHashSet<DeclId>>,
+  roots: &mut FxHashSet<DeclId>,
+  decl: &VarDeclarator,
+) {
   let mut visitor = CollectIdentsVisitor::default();
   if let Some(init) = &decl.init {
     init.visit_with(&mut visitor);
@@ -1602,17 +1888,13 @@ fn collect_decl_uses(state: &mut StateManager, decl: &VarDeclarator) {
 
   if let Pat::Ident(bind_ident) = &decl.name {
     let decl_id: DeclId = bind_ident.id.to_id();
-    state
-      .decl_uses
-      .entry(decl_id)
-      .or_default()
-      .extend(visitor.idents);
+    decl_uses.entry(decl_id).or_default().extend(visitor.idents);
   } else {
     // Non-`Pat::Ident` declarators (destructuring, etc.) are not tracked
     // by the graph; they fall through to the sweep's "absent ⇒ keep"
     // fallback. Treat their referenced idents as roots so anything they
     // depend on is preserved.
-    state.roots.extend(visitor.idents);
+    roots.extend(visitor.idents);
   }
 }
 
@@ -1687,7 +1969,7 @@ fn is_stylex_consumer_call(call: &CallExpr, state: &StateManager) -> bool {
       .is_some_and(|ident| state.is_regular_stylex_import(&ident.sym))
 }
 
-fn member_namespace_name(member_prop: &MemberProp) -> Option<NonNullProp> {
+pub(crate) fn member_namespace_name(member_prop: &MemberProp) -> Option<NonNullProp> {
   if let Some(namespace_name) = namespace_name_from_member_prop(member_prop) {
     Some(NonNullProp::Atom(namespace_name))
   } else if member_prop.is_computed() {
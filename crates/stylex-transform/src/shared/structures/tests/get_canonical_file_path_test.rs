@@ -98,4 +98,24 @@ mod get_canonical_file_path {
       "@stylexswc/transform:src/shared/structures/tests/fixtures/src/components"
     );
   }
+
+  #[test]
+  fn get_canonical_file_path_inside_yarn_pnp_archive() {
+    let package_dir = env::current_dir()
+      .unwrap()
+      .join("../stylex-path-resolver/fixtures/application-yarn-pnp")
+      .join(".yarn/cache/@stylexjs-open-props-npm-0.1.0-8c1f0e2b7a-3d9a41c6e5.zip")
+      .join("node_modules/@stylexjs/open-props")
+      .clean();
+
+    let stage_manager = StateManager::default();
+
+    let canonical_path = stage_manager.get_canonical_file_path(
+      package_dir.join("lib/colors.stylex.js").to_str().unwrap(),
+      &mut FxHashMap::default(),
+    );
+
+    // The same key the package gets from `node_modules`.
+    assert_eq!(canonical_path, "@stylexjs/open-props:lib/colors.stylex.js");
+  }
 }
@@ -2,4 +2,5 @@ mod flatten_raw_style_objects_test;
 mod gen_css_test;
 mod get_canonical_file_path_test;
 mod get_package_name_and_path_test;
+mod session_cache_test;
 mod state_manager_test;
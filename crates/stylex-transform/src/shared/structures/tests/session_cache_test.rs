@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod session_cache {
+  use std::{cell::Cell, path::Path, thread, time::Duration};
+
+  use rustc_hash::FxHashMap;
+  use stylex_path_resolver::package_json::PackageJsonExtended;
+
+  use crate::shared::structures::session_cache::{SessionCache, lock_process_caches};
+
+  const PACKAGE_JSON: &str = "/app/package.json";
+  const TOKENS: &str = "/app/src/tokens.stylex.js";
+
+  fn package_json() -> PackageJsonExtended {
+    PackageJsonExtended {
+      name: Some("app".to_string()),
+      ..Default::default()
+    }
+  }
+
+  /// Resolves `import_path` from the app's button, counting the resolutions
+  /// that were not answered from the cache.
+  fn resolve(
+    cache: &SessionCache,
+    import_path: &str,
+    resolved: Option<&str>,
+    computed: &Cell<usize>,
+  ) -> (Option<String>, FxHashMap<String, PackageJsonExtended>) {
+    let mut package_json_seen = FxHashMap::default();
+
+    let value = cache.resolve_import(
+      "/app/src/button.js",
+      import_path,
+      &mut package_json_seen,
+      |package_json_seen| {
+        computed.set(computed.get() + 1);
+        package_json_seen.insert(PACKAGE_JSON.to_string(), package_json());
+        resolved.map(str::to_string)
+      },
+    );
+
+    (value, package_json_seen)
+  }
+
+  #[test]
+  fn a_kept_resolution_reports_the_package_json_it_read() {
+    let cache = SessionCache::new();
+    let computed = Cell::new(0);
+
+    resolve(&cache, "./tokens.stylex", Some(TOKENS), &computed);
+    let (value, package_json_seen) = resolve(&cache, "./tokens.stylex", Some(TOKENS), &computed);
+
+    assert_eq!(computed.get(), 1);
+    assert_eq!(value.as_deref(), Some(TOKENS));
+    assert_eq!(
+      package_json_seen.keys().collect::<Vec<_>>(),
+      vec![PACKAGE_JSON]
+    );
+  }
+
+  #[test]
+  fn a_changed_target_is_resolved_again() {
+    let cache = SessionCache::new();
+    let computed = Cell::new(0);
+
+    resolve(&cache, "./tokens.stylex", Some(TOKENS), &computed);
+    resolve(
+      &cache,
+      "./colors.stylex",
+      Some("/app/src/colors.stylex.js"),
+      &computed,
+    );
+    cache.invalidate(&[Path::new(TOKENS)]);
+    resolve(&cache, "./tokens.stylex", Some(TOKENS), &computed);
+    resolve(
+      &cache,
+      "./colors.stylex",
+      Some("/app/src/colors.stylex.js"),
+      &computed,
+    );
+
+    assert_eq!(computed.get(), 3);
+  }
+
+  #[test]
+  fn an_unresolved_import_is_tried_again_after_any_change() {
+    let cache = SessionCache::new();
+    let computed = Cell::new(0);
+
+    resolve(&cache, "./missing.stylex", None, &computed);
+    resolve(&cache, "./missing.stylex", None, &computed);
+    cache.invalidate(&[Path::new("/app/src/missing.stylex.js")]);
+    let (value, _) = resolve(
+      &cache,
+      "./missing.stylex",
+      Some("/app/src/missing.stylex.js"),
+      &computed,
+    );
+
+    assert_eq!(computed.get(), 2);
+    assert_eq!(value.as_deref(), Some("/app/src/missing.stylex.js"));
+  }
+
+  #[test]
+  fn a_changed_package_json_drops_every_resolution() {
+    let cache = SessionCache::new();
+    let computed = Cell::new(0);
+    let canonical_path = |cache: &SessionCache| {
+      cache.canonical_path(TOKENS, &mut FxHashMap::default(), |_| {
+        computed.set(computed.get() + 1);
+        "app:src/tokens.stylex.js".to_string()
+      })
+    };
+
+    resolve(&cache, "./tokens.stylex", Some(TOKENS), &computed);
+    canonical_path(&cache);
+    cache.invalidate(&[Path::new(PACKAGE_JSON)]);
+    resolve(&cache, "./tokens.stylex", Some(TOKENS), &computed);
+    canonical_path(&cache);
+
+    assert_eq!(computed.get(), 4);
+  }
+
+  #[test]
+  fn invalidating_waits_for_the_running_compiles() {
+    let cache = SessionCache::new();
+    let compile = lock_process_caches();
+
+    thread::scope(|scope| {
+      let invalidate = scope.spawn(|| cache.invalidate(&[Path::new(TOKENS)]));
+
+      thread::sleep(Duration::from_millis(50));
+      assert!(!invalidate.is_finished());
+
+      drop(compile);
+      invalidate.join().unwrap();
+    });
+  }
+}
@@ -18,8 +18,9 @@ use crate::shared::{
     },
   },
   utils::{
-    ast::convertors::convert_expr_to_str, core::flatten_raw_style_object::flatten_raw_style_object,
-    validators::validate_namespace,
+    ast::convertors::convert_expr_to_str,
+    core::flatten_raw_style_object::flatten_raw_style_object,
+    validators::{validate_namespace, validate_property_values},
   },
 };
 use stylex_constants::constants::{
@@ -29,6 +30,21 @@ use stylex_constants::constants::{
 use stylex_types::enums::data_structures::injectable_style::InjectableStyleKind;
 use stylex_utils::hash::create_short_hash;
 
+/// The key a flattened property is stored under in a compiled namespace:
+/// hashed when minified keys are on, except for custom properties.
+pub(crate) fn compiled_property_key(key: &str, state: &StateManager) -> String {
+  if state.options.enable_minified_keys && !key.starts_with("--") {
+    let hashed_key = create_short_hash(&format!("<>{}", key));
+    if state.options.debug {
+      format!("{}-k{}", key, hashed_key)
+    } else {
+      format!("k{}", hashed_key)
+    }
+  } else {
+    key.to_string()
+  }
+}
+
 pub(crate) fn stylex_create_set(
   namespaces: &EvaluateResultValue,
   state: &mut EvaluationState,
@@ -44,6 +60,7 @@ pub(crate) fn stylex_create_set(
     None => stylex_panic!("{}", VALUES_MUST_BE_OBJECT),
   } {
     validate_namespace(namespace, &[], traversal_state);
+    validate_property_values(namespace, traversal_state);
 
     let mut class_paths_in_namespace: ClassPathsInNamespace = IndexMap::new();
 
@@ -64,16 +81,7 @@ pub(crate) fn stylex_create_set(
     let compiled_namespace_tuples = flattened_namespace
       .iter_mut()
       .map(|(key, value)| {
-        let key = if traversal_state.options.enable_minified_keys && !key.starts_with("--") {
-          let hashed_key = create_short_hash(&format!("<>{}", key));
-          if traversal_state.options.debug {
-            format!("{}-k{}", key, hashed_key)
-          } else {
-            format!("k{}", hashed_key)
-          }
-        } else {
-          key.clone()
-        };
+        let key = compiled_property_key(key, traversal_state);
 
         let compiled_value = match value {
           PreRules::PreRuleSet(rule_set) => rule_set.compiled(traversal_state),
@@ -131,11 +131,10 @@ pub(crate) fn stylex_keyframes(
   // NOTE: Use a direction-agnostic hash to keep LTR/RTL classnames stable across
   // builds. NOTE: '<>' and '-B' is used to keep existing hashes stable.
   // TODO: They should be removed in a future version.
-  let animation_name = format!(
-    "{}{}-B",
-    class_name_prefix,
-    create_hash(&format!("<>{}", stable_string))
-  );
+  let name_key = format!("<>{}", stable_string);
+  let animation_name = format!("{}{}-B", class_name_prefix, create_hash(&name_key));
+
+  state.record_class_name_key(&name_key, &animation_name);
 
   let ltr = format!("@keyframes {}{{{}}}", animation_name, ltr_string);
   let rtl = if ltr_string == rtl_string {
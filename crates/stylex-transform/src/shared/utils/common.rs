@@ -7,9 +7,9 @@ use swc_core::atoms::Atom;
 use swc_core::{
   common::{EqIgnoreSpan, FileName},
   ecma::ast::{
-    Decl, Expr, Ident, ImportDecl, ImportSpecifier, KeyValueProp, Module, ModuleDecl,
-    ModuleExportName, ModuleItem, ObjectPatProp, Pat, Prop, PropName, PropOrSpread, Stmt,
-    VarDeclarator,
+    Decl, DefaultDecl, Expr, FnDecl, Ident, ImportDecl, ImportSpecifier, KeyValueProp, Module,
+    ModuleDecl, ModuleExportName, ModuleItem, ObjectPatProp, Pat, Prop, PropName, PropOrSpread,
+    Stmt, VarDeclarator,
   },
 };
 
@@ -368,13 +368,29 @@ pub(crate) fn get_css_value(key_value: KeyValueProp) -> (Box<Expr>, Option<BaseC
   (key_value.value, None)
 }
 
+/// Records what sits at the top of the module: the initializer of every
+/// declared name, for the APIs that need to know a call is program level, and
+/// every function declaration, for the evaluator to call.
 pub fn fill_top_level_expressions(module: &Module, state: &mut StateManager) {
   module.body.iter().for_each(|item| match item {
-    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
-      if let Decl::Var(decl_var) = &export_decl.decl {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => match &export_decl.decl {
+      Decl::Var(decl_var) => {
         for decl in &decl_var.decls {
           record_top_level_declarator(state, TopLevelExpressionKind::NamedExport, decl);
         }
+      },
+      Decl::Fn(fn_decl) => state.add_top_level_function(fn_decl),
+      _ => {},
+    },
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_decl)) => {
+      if let DefaultDecl::Fn(fn_expr) = &export_decl.decl
+        && let Some(ident) = &fn_expr.ident
+      {
+        state.add_top_level_function(&FnDecl {
+          ident: ident.clone(),
+          declare: false,
+          function: fn_expr.function.clone(),
+        });
       }
     },
     ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_decl)) => {
@@ -400,6 +416,7 @@ pub fn fill_top_level_expressions(module: &Module, state: &mut StateManager) {
         record_top_level_declarator(state, TopLevelExpressionKind::Stmt, decl);
       }
     },
+    ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) => state.add_top_level_function(fn_decl),
     _ => {},
   });
 }
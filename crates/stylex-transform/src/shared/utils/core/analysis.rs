@@ -0,0 +1,564 @@
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+use stylex_constants::constants::common::COMPILED_KEY;
+use stylex_macros::diagnostic::SourceSpan;
+use stylex_structures::order_pair::OrderPair;
+use stylex_types::structures::{
+  analysis::{Analysis, AnalyzedRule, StyleAnalysis, TokenAnalysis},
+  meta_data::MetaData,
+};
+use swc_core::{
+  common::{BytePos, SourceFile, Span, Spanned},
+  ecma::{
+    ast::{
+      Decl, Expr, Id, Ident, ImportSpecifier, Lit, MemberExpr, Module, ModuleDecl,
+      ModuleExportName, ModuleItem, ObjectLit, Pat, Prop, PropOrSpread, Stmt, VarDeclarator,
+    },
+    visit::{Visit, VisitWith},
+  },
+};
+
+use crate::shared::{
+  structures::{
+    functions::FunctionMap,
+    pre_rule::PreRuleValue,
+    state_manager::{StateManager, ThemeImport},
+  },
+  transformers::stylex_create::compiled_property_key,
+  utils::{
+    ast::helpers::{namespace_name_from_member_prop, namespace_name_from_prop_key},
+    common::get_import_by_ident,
+    core::{
+      cross_file_parsing::parse_file, flat_map_expanded_shorthands::flat_map_expanded_shorthands,
+      unused_styles::source_span,
+    },
+    js::evaluate::evaluate,
+    log::build_code_frame_error::source_span_in,
+    validators::{is_create_call, is_define_vars_call},
+  },
+};
+
+/// What the analysis offset points at, found before the producers rewrite the
+/// module.
+pub(crate) enum AnalysisTarget {
+  Style {
+    variable: String,
+    namespace: String,
+    property: Option<String>,
+    /// The properties the namespace is written with, to name the keys it
+    /// compiles to.
+    properties: Vec<String>,
+    span: Span,
+  },
+  Token {
+    variable: String,
+    token: String,
+    span: Span,
+    declaration: TokenDeclaration,
+  },
+}
+
+/// Where a token read finds the `defineVars` call declaring it.
+pub(crate) enum TokenDeclaration {
+  /// A `defineVars` call in this module, with the spans of the token's key and
+  /// value in it.
+  Local {
+    key: Option<Span>,
+    value: Option<Span>,
+  },
+  /// An export of another file, which is only read once the module is done.
+  Imported {
+    read: MemberExpr,
+    import_path: String,
+    export_name: String,
+  },
+}
+
+/// A top-level `stylex.create` or `defineVars` binding.
+struct StyleBinding {
+  id: Id,
+  is_create: bool,
+}
+
+/// Finds the style key or token read at `offset`, a byte offset into the
+/// input source file.
+///
+/// Of several reads the innermost wins, so `styles[vars.key]` answers for
+/// whichever of the two the offset falls in. A read written in a `stylex.create`
+/// value wins over the key it is the value of, so `color: vars.accent` answers
+/// for the token on `vars.accent` and for the property anywhere else.
+pub(crate) fn find_analysis_target(
+  module: &Module,
+  state: &StateManager,
+  offset: u32,
+) -> Option<AnalysisTarget> {
+  let source_file = state.input_source_file.as_ref()?;
+  let pos = source_file.start_pos + BytePos(offset);
+
+  if pos > source_file.end_pos {
+    return None;
+  }
+
+  let bindings = style_bindings(module, state);
+
+  let mut finder = ReadFinder { pos, read: None };
+  module.visit_with(&mut finder);
+  let read = finder.read;
+
+  for (decl, binding) in &bindings {
+    let Some(object) = call_object_arg(decl) else {
+      continue;
+    };
+
+    for prop in &object.props {
+      let PropOrSpread::Prop(prop) = prop else {
+        continue;
+      };
+      let Prop::KeyValue(key_value) = prop.as_ref() else {
+        continue;
+      };
+
+      if !contains(prop.span(), pos)
+        || read
+          .as_ref()
+          .is_some_and(|read| contains(prop.span(), read.span.lo))
+      {
+        continue;
+      }
+
+      let key = namespace_name_from_prop_key(&key_value.key)?.to_string();
+      let variable = binding.id.0.to_string();
+
+      if !binding.is_create {
+        return Some(AnalysisTarget::Token {
+          variable,
+          token: key,
+          span: key_value.key.span(),
+          declaration: TokenDeclaration::Local {
+            key: Some(key_value.key.span()),
+            value: Some(key_value.value.span()),
+          },
+        });
+      }
+
+      let property = key_value.value.as_object().and_then(|styles| {
+        styles.props.iter().find_map(|prop| {
+          let key_value = prop.as_prop()?.as_key_value()?;
+
+          contains(key_value.span(), pos).then(|| {
+            Some((
+              namespace_name_from_prop_key(&key_value.key)?,
+              key_value.key.span(),
+            ))
+          })?
+        })
+      });
+
+      let properties = namespace_properties(&key_value.value);
+
+      return Some(match property {
+        Some((property, span)) => AnalysisTarget::Style {
+          variable,
+          namespace: key,
+          property: Some(property.to_string()),
+          properties,
+          span,
+        },
+        None => AnalysisTarget::Style {
+          variable,
+          namespace: key,
+          property: None,
+          properties,
+          span: key_value.key.span(),
+        },
+      });
+    }
+  }
+
+  let read = read?;
+
+  let object = read.obj.as_ident()?;
+  let key = namespace_name_from_member_prop(&read.prop)?.to_string();
+  let variable = object.sym.to_string();
+
+  if let Some((decl, binding)) = bindings
+    .iter()
+    .find(|(_, binding)| binding.id == object.to_id())
+  {
+    if binding.is_create {
+      let properties = call_object_arg(decl)
+        .and_then(|object| literal_value(object, &key))
+        .map(namespace_properties)
+        .unwrap_or_default();
+
+      return Some(AnalysisTarget::Style {
+        variable,
+        namespace: key,
+        property: None,
+        properties,
+        span: read.span,
+      });
+    }
+
+    let declared = call_object_arg(decl).and_then(|object| literal_key(object, &key));
+
+    return Some(AnalysisTarget::Token {
+      variable,
+      token: key,
+      span: read.span,
+      declaration: TokenDeclaration::Local {
+        key: declared.map(|(key, _)| key),
+        value: declared.map(|(_, value)| value),
+      },
+    });
+  }
+
+  let (import_path, export_name) = imported_name(object, state)?;
+
+  Some(AnalysisTarget::Token {
+    variable,
+    token: key,
+    span: read.span,
+    declaration: TokenDeclaration::Imported {
+      read,
+      import_path,
+      export_name,
+    },
+  })
+}
+
+/// What the target compiles to, read once the module has been compiled.
+pub(crate) fn analyze_target(
+  target: AnalysisTarget,
+  module: &Module,
+  state: &mut StateManager,
+) -> Option<Analysis> {
+  match target {
+    AnalysisTarget::Style {
+      variable,
+      namespace,
+      property,
+      properties,
+      span,
+    } => {
+      let styles = state.style_map.get(&variable)?.get(&namespace)?;
+
+      // A property is stored under the keys of the longhands it expands to,
+      // each hashed when minified keys are on.
+      let property_names: FxHashMap<String, &str> = properties
+        .iter()
+        .flat_map(|name| {
+          flat_map_expanded_shorthands((name.clone(), PreRuleValue::Null), &state.options)
+            .into_iter()
+            .map(move |OrderPair(longhand, _)| (longhand, name.as_str()))
+        })
+        .map(|(longhand, name)| (compiled_property_key(&longhand, state), name))
+        .collect();
+
+      let metadata: FxHashMap<&str, &MetaData> = state
+        .metadata()
+        .values()
+        .flatten()
+        .map(|metadata| (metadata.get_class_name(), metadata))
+        .collect();
+
+      let rules = styles
+        .iter()
+        .filter(|(key, _)| key.as_str() != COMPILED_KEY)
+        .map(|(key, value)| {
+          let name = property_names.get(key).copied().unwrap_or(key.as_str());
+          (name, value)
+        })
+        .filter(|(name, _)| property.as_deref().is_none_or(|property| property == *name))
+        .flat_map(|(name, value)| {
+          value
+            .as_string()
+            .into_iter()
+            .flat_map(|class_names| class_names.split_whitespace())
+            .filter_map(|class_name| metadata.get(class_name))
+            .map(|metadata| AnalyzedRule {
+              property: name.to_string(),
+              class_name: metadata.get_class_name().to_string(),
+              ltr: metadata.get_css().to_string(),
+              rtl: metadata.get_css_rtl().map(str::to_string),
+              priority: *metadata.get_priority(),
+            })
+        })
+        .collect();
+
+      Some(Analysis::Style(StyleAnalysis {
+        variable,
+        namespace,
+        property,
+        span: source_span(span, state),
+        rules,
+      }))
+    },
+    AnalysisTarget::Token {
+      variable,
+      token,
+      span,
+      declaration,
+    } => {
+      let span = source_span(span, state);
+
+      let analysis = match declaration {
+        TokenDeclaration::Local { key, value } => TokenAnalysis {
+          value: compiled_local_token(module, &variable, &token),
+          file: Some(state.get_filename().to_string()).filter(|file| !file.is_empty()),
+          declaration: key.and_then(|key| source_span(key, state)),
+          declared_value: value
+            .and_then(|value| source_text(state.input_source_file.as_ref()?, value)),
+          variable,
+          token,
+          span,
+        },
+        TokenDeclaration::Imported {
+          read,
+          import_path,
+          export_name,
+        } => {
+          // Only an import of a theme file holds tokens; any other `object.key`
+          // read of an import is not something StyleX compiled.
+          let mut package_json_seen = FxHashMap::default();
+          let ThemeImport {
+            canonical_path,
+            file_path,
+          } = state.resolve_theme_import(&import_path, &mut package_json_seen)?;
+          let file = Some(file_path.unwrap_or(canonical_path));
+
+          let evaluated = evaluate(&Expr::Member(read), state, &FunctionMap::default());
+          let value = evaluated
+            .confident
+            .then_some(evaluated.value.as_ref())
+            .flatten()
+            .and_then(|value| value.as_expr())
+            .and_then(|expr| match expr {
+              Expr::Lit(Lit::Str(strng)) => strng.value.as_str().map(str::to_string),
+              _ => None,
+            });
+
+          let declared = file
+            .as_deref()
+            .and_then(|file| declared_token(Path::new(file), &export_name, &token));
+
+          TokenAnalysis {
+            variable,
+            token,
+            span,
+            value,
+            file,
+            declaration: declared.as_ref().and_then(|(declaration, _)| *declaration),
+            declared_value: declared.and_then(|(_, value)| value),
+          }
+        },
+      };
+
+      Some(Analysis::Token(analysis))
+    },
+  }
+}
+
+fn contains(span: Span, pos: BytePos) -> bool {
+  !span.is_dummy() && span.lo <= pos && pos <= span.hi
+}
+
+/// Every top-level `stylex.create` and `defineVars` binding of the module.
+fn style_bindings<'a>(
+  module: &'a Module,
+  state: &StateManager,
+) -> Vec<(&'a VarDeclarator, StyleBinding)> {
+  top_level_declarators(module)
+    .filter_map(|decl| {
+      let Pat::Ident(binding) = &decl.name else {
+        return None;
+      };
+      let call = decl.init.as_deref()?.as_call()?;
+
+      let is_create = if is_create_call(call, state) {
+        true
+      } else if is_define_vars_call(call, state) {
+        false
+      } else {
+        return None;
+      };
+
+      Some((
+        decl,
+        StyleBinding {
+          id: binding.id.to_id(),
+          is_create,
+        },
+      ))
+    })
+    .collect()
+}
+
+fn top_level_declarators(module: &Module) -> impl Iterator<Item = &VarDeclarator> {
+  module.body.iter().flat_map(|item| {
+    let var_decl = match item {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => Some(var_decl),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => export_decl.decl.as_var(),
+      _ => None,
+    };
+
+    var_decl
+      .into_iter()
+      .flat_map(|var_decl| var_decl.decls.iter())
+  })
+}
+
+/// The object literal a declarator's call is given, if it is given one.
+fn call_object_arg(decl: &VarDeclarator) -> Option<&ObjectLit> {
+  let call = decl.init.as_deref()?.as_call()?;
+  let arg = call.args.first()?;
+
+  if arg.spread.is_some() {
+    return None;
+  }
+
+  arg.expr.as_object()
+}
+
+/// The value of the key `name` in `object`, when it is written literally.
+fn literal_value<'a>(object: &'a ObjectLit, name: &str) -> Option<&'a Expr> {
+  object.props.iter().find_map(|prop| {
+    let key_value = prop.as_prop()?.as_key_value()?;
+
+    (namespace_name_from_prop_key(&key_value.key)?.as_str() == name).then_some(&*key_value.value)
+  })
+}
+
+/// The property names a namespace's object literal is written with.
+fn namespace_properties(namespace: &Expr) -> Vec<String> {
+  namespace
+    .as_object()
+    .into_iter()
+    .flat_map(|styles| styles.props.iter())
+    .filter_map(|prop| {
+      let key_value = prop.as_prop()?.as_key_value()?;
+      Some(namespace_name_from_prop_key(&key_value.key)?.to_string())
+    })
+    .collect()
+}
+
+/// The spans of the key `name` and of its value in `object`, when it is
+/// written literally.
+fn literal_key(object: &ObjectLit, name: &str) -> Option<(Span, Span)> {
+  object.props.iter().find_map(|prop| {
+    let key_value = prop.as_prop()?.as_key_value()?;
+
+    (namespace_name_from_prop_key(&key_value.key)?.as_str() == name)
+      .then(|| (key_value.key.span(), key_value.value.span()))
+  })
+}
+
+/// The import `ident` is bound by, as the path it imports from and the name
+/// the imported file exports it under.
+fn imported_name(ident: &Ident, state: &StateManager) -> Option<(String, String)> {
+  let import = get_import_by_ident(ident, state)?;
+
+  let export_name = import
+    .specifiers
+    .iter()
+    .find_map(|specifier| match specifier {
+      ImportSpecifier::Named(named) if named.local.to_id() == ident.to_id() => {
+        Some(match &named.imported {
+          Some(ModuleExportName::Ident(imported)) => imported.sym.to_string(),
+          Some(ModuleExportName::Str(imported)) => imported.value.to_string_lossy().into_owned(),
+          None => named.local.sym.to_string(),
+        })
+      },
+      ImportSpecifier::Default(default) if default.local.to_id() == ident.to_id() => {
+        Some("default".to_string())
+      },
+      _ => None,
+    })?;
+
+  Some((import.src.value.to_string_lossy().into_owned(), export_name))
+}
+
+/// The string a local `defineVars` call compiled `token` to, read off the
+/// object literal the call was replaced with.
+fn compiled_local_token(module: &Module, variable: &str, token: &str) -> Option<String> {
+  top_level_declarators(module).find_map(|decl| {
+    let binding = decl.name.as_ident()?;
+
+    if binding.sym.as_str() != variable {
+      return None;
+    }
+
+    let object = decl.init.as_deref()?.as_object()?;
+
+    object.props.iter().find_map(|prop| {
+      let key_value = prop.as_prop()?.as_key_value()?;
+
+      if namespace_name_from_prop_key(&key_value.key)?.as_str() != token {
+        return None;
+      }
+
+      match key_value.value.as_ref() {
+        Expr::Lit(Lit::Str(strng)) => strng.value.as_str().map(str::to_string),
+        _ => None,
+      }
+    })
+  })
+}
+
+/// Where `file` declares `token` in the `defineVars` call it exports as
+/// `export_name`, and the token's value as written there.
+fn declared_token(
+  file: &Path,
+  export_name: &str,
+  token: &str,
+) -> Option<(Option<SourceSpan>, Option<String>)> {
+  let (source_file, program) = parse_file(file)?;
+  let module = program.as_module()?;
+
+  let decl = module.body.iter().find_map(|item| {
+    let export_decl = item.as_module_decl()?.as_export_decl()?;
+
+    export_decl.decl.as_var()?.decls.iter().find(|decl| {
+      decl
+        .name
+        .as_ident()
+        .is_some_and(|ident| ident.sym.as_str() == export_name)
+    })
+  })?;
+
+  let (key, value) = literal_key(call_object_arg(decl)?, token)?;
+
+  Some((
+    source_span_in(&source_file, key),
+    source_text(&source_file, value),
+  ))
+}
+
+/// The text `span` covers in `source_file`.
+fn source_text(source_file: &SourceFile, span: Span) -> Option<String> {
+  let start = (span.lo - source_file.start_pos).0 as usize;
+  let end = (span.hi - source_file.start_pos).0 as usize;
+
+  source_file.src.get(start..end).map(str::to_string)
+}
+
+/// The innermost `object.key` read whose span holds the offset.
+struct ReadFinder {
+  pos: BytePos,
+  read: Option<MemberExpr>,
+}
+
+impl Visit for ReadFinder {
+  fn visit_member_expr(&mut self, member: &MemberExpr) {
+    if !contains(member.span, self.pos) {
+      return;
+    }
+
+    if member.obj.is_ident() {
+      self.read = Some(member.clone());
+    }
+
+    member.visit_children_with(self);
+  }
+}
@@ -115,6 +115,8 @@ pub(crate) fn convert_style_to_class_name(
     format!("{}{}", prefix, create_hash(string_to_hash.as_str()))
   };
 
+  state.record_class_name_key(&string_to_hash, &class_name_hashed);
+
   let css_rules = generate_css_rule(
     class_name_hashed.as_str(),
     dashed_key.as_ref(),
@@ -0,0 +1,388 @@
+use std::{
+  cell::RefCell,
+  hash::{Hash, Hasher},
+  path::{Path, PathBuf},
+  sync::{Arc, LazyLock, Mutex, PoisonError},
+  time::SystemTime,
+};
+
+use log::debug;
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use swc_core::{
+  atoms::Atom,
+  common::{FileName, Mark, SourceFile, SourceMap, comments::NoopComments},
+  ecma::{
+    ast::{
+      Decl, Expr, Lit, Module, ModuleDecl, ModuleExportName, ModuleItem, Program, Prop, PropName,
+      PropOrSpread, Stmt, UnaryOp, VarDeclarator,
+    },
+    transforms::{base::resolver, typescript::strip},
+    utils::drop_span,
+    visit::visit_mut_pass,
+  },
+};
+
+use crate::{
+  StyleXTransform, compile::parse_source, shared::structures::state_manager::StateManager,
+};
+use stylex_path_resolver::pnp::{disk_metadata, read_to_string};
+use stylex_structures::{plugin_pass::PluginPass, stylex_state_options::StyleXStateOptions};
+
+/// How many `export const B = A` hops are followed before an export is
+/// treated as unreadable. Bounds a chain the transform left behind, never a
+/// cycle — top level `const`s cannot form one.
+const MAX_ALIAS_DEPTH: usize = 16;
+
+/// Exports of a theme or consts file, as its own transform left them.
+type ModuleExports = Arc<FxHashMap<String, Expr>>;
+
+/// Files a theme or consts file's own imports resolved to, so a module that
+/// reads it depends on them as well.
+type ModuleDependencies = Arc<[String]>;
+
+struct ParsedModule {
+  modified: SystemTime,
+  options_key: u64,
+  exports: ModuleExports,
+  dependencies: ModuleDependencies,
+}
+
+/// Per-process cache of parsed imported files, keyed by path. An entry is
+/// reused only while the file's mtime and the options that shape its hashes
+/// are unchanged, so a long-lived process picks up edits to a theme file.
+static PARSED_MODULES: LazyLock<Mutex<FxHashMap<PathBuf, ParsedModule>>> =
+  LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+thread_local! {
+  /// Files currently being parsed on this thread. Two theme files importing
+  /// each other would otherwise recurse without end; the second visit falls
+  /// back to a hashed reference instead.
+  static IN_PROGRESS: RefCell<FxHashSet<PathBuf>> = RefCell::new(FxHashSet::default());
+}
+
+/// Releases the `IN_PROGRESS` slot even when the nested transform panics, so
+/// a failed parse does not leave the file unreadable for the rest of the
+/// thread's life.
+struct InProgressGuard(PathBuf);
+
+impl Drop for InProgressGuard {
+  fn drop(&mut self) {
+    IN_PROGRESS.with(|in_progress| in_progress.borrow_mut().remove(&self.0));
+  }
+}
+
+/// The value `export_name` holds in `file_path` once that file has been
+/// transformed, or `None` when it cannot be read statically.
+///
+/// The file is run through the same StyleX transform it would get as an entry
+/// point, so `defineVars` and `defineConsts` calls are already replaced by the
+/// object literals they compile to. Only exports that are plain literals after
+/// that are returned; anything else is left for the hashed-reference path.
+///
+/// Every file the parsed file itself depends on is passed to
+/// `add_dependency`, whether or not the export is readable.
+pub(crate) fn resolve_imported_export(
+  file_path: &Path,
+  export_name: &str,
+  options: &StyleXStateOptions,
+  mut add_dependency: impl FnMut(&str),
+) -> Option<Expr> {
+  let (exports, dependencies) = parsed_module_exports(file_path, options)?;
+
+  for dependency in dependencies.iter() {
+    add_dependency(dependency);
+  }
+
+  exports.get(export_name).cloned()
+}
+
+fn parsed_module_exports(
+  file_path: &Path,
+  options: &StyleXStateOptions,
+) -> Option<(ModuleExports, ModuleDependencies)> {
+  let modified = match disk_metadata(file_path).and_then(|metadata| metadata.modified()) {
+    Ok(modified) => modified,
+    Err(err) => {
+      debug!("Could not stat {}: {}", file_path.display(), err);
+      return None;
+    },
+  };
+  let options_key = hashing_options_key(options);
+
+  if let Some(entry) = PARSED_MODULES
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+    .get(file_path)
+    && entry.modified == modified
+    && entry.options_key == options_key
+  {
+    return Some((Arc::clone(&entry.exports), Arc::clone(&entry.dependencies)));
+  }
+
+  let is_first_visit =
+    IN_PROGRESS.with(|in_progress| in_progress.borrow_mut().insert(file_path.to_path_buf()));
+
+  if !is_first_visit {
+    debug!("Import cycle through {}", file_path.display());
+    return None;
+  }
+
+  let (exports, dependencies) = {
+    let _guard = InProgressGuard(file_path.to_path_buf());
+    let (exports, dependencies) = parse_module_exports(file_path, options)?;
+
+    (Arc::new(exports), ModuleDependencies::from(dependencies))
+  };
+
+  PARSED_MODULES
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+    .insert(
+      file_path.to_path_buf(),
+      ParsedModule {
+        modified,
+        options_key,
+        exports: Arc::clone(&exports),
+        dependencies: Arc::clone(&dependencies),
+      },
+    );
+
+  Some((exports, dependencies))
+}
+
+/// Drops the parsed exports of each of `paths`, and of every file that read
+/// one of them, however recent their mtimes. A host watching the files calls
+/// this for each change, since a file rewritten within the mtime granularity
+/// of its filesystem would otherwise still be served from the cache.
+pub(crate) fn forget_parsed_modules(paths: &[&Path]) {
+  PARSED_MODULES
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+    .retain(|file_path, entry| {
+      !paths.contains(&file_path.as_path())
+        && !entry
+          .dependencies
+          .iter()
+          .any(|dependency| paths.contains(&Path::new(dependency.as_str())))
+    });
+}
+
+/// Hash of the options that change what a theme file compiles to. Two
+/// transforms in one process with different prefixes must not share entries.
+fn hashing_options_key(options: &StyleXStateOptions) -> u64 {
+  let mut hasher = FxHasher::default();
+
+  options.class_name_prefix.hash(&mut hasher);
+  options.debug.hash(&mut hasher);
+  options.dev.hash(&mut hasher);
+  options.enable_debug_class_names.hash(&mut hasher);
+  options
+    .unstable_module_resolution
+    .root_dir()
+    .hash(&mut hasher);
+  options
+    .unstable_module_resolution
+    .theme_file_extension()
+    .hash(&mut hasher);
+
+  // Aliases decide what the file's own imports resolve to. Sorted, since a
+  // map's iteration order is not part of its value.
+  let mut aliases: Vec<_> = options.aliases.iter().flatten().collect();
+  aliases.sort_unstable();
+  aliases.hash(&mut hasher);
+
+  hasher.finish()
+}
+
+/// The static exports of `file_path` and the files its transform resolved.
+fn parse_module_exports(
+  file_path: &Path,
+  options: &StyleXStateOptions,
+) -> Option<(FxHashMap<String, Expr>, Vec<String>)> {
+  let (fm, program) = parse_file(file_path)?;
+
+  let mut state = StateManager {
+    options: options.clone(),
+    ..Default::default()
+  };
+  state.set_plugin_pass(PluginPass {
+    cwd: None,
+    filename: FileName::Real(file_path.to_path_buf()),
+  });
+  state.set_input_source_file(fm);
+
+  let mut transform = StyleXTransform::with_state(NoopComments, state);
+
+  let unresolved_mark = Mark::new();
+  let top_level_mark = Mark::new();
+
+  let program = program
+    .apply(resolver(unresolved_mark, top_level_mark, true))
+    .apply(strip(unresolved_mark, top_level_mark))
+    .apply(&mut visit_mut_pass(&mut transform));
+
+  Some((
+    collect_static_exports(program.as_module()?),
+    transform.state.dependencies(),
+  ))
+}
+
+/// `file_path` as written, parsed but not transformed, with the source file its
+/// spans point into.
+pub(crate) fn parse_file(file_path: &Path) -> Option<(Arc<SourceFile>, Program)> {
+  let source = match read_to_string(file_path) {
+    Ok(source) => source,
+    Err(err) => {
+      debug!("Could not read {}: {}", file_path.display(), err);
+      return None;
+    },
+  };
+
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(file_path.to_path_buf()).into(), source);
+
+  match parse_source(&fm, None) {
+    Ok(program) => Some((fm, program)),
+    Err(err) => {
+      debug!("Could not parse {}: {:?}", file_path.display(), err);
+      None
+    },
+  }
+}
+
+/// Every export of `module` whose value is a literal, with aliases such as
+/// `export const C = constants` followed to the declaration they name.
+pub(crate) fn collect_static_exports(module: &Module) -> FxHashMap<String, Expr> {
+  let mut locals: FxHashMap<Atom, &Expr> = FxHashMap::default();
+  let mut exported: Vec<(String, Atom)> = vec![];
+
+  for item in &module.body {
+    match item {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+        record_declarators(&var_decl.decls, None, &mut locals);
+      },
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+        if let Decl::Var(var_decl) = &export_decl.decl {
+          record_declarators(&var_decl.decls, Some(&mut exported), &mut locals);
+        }
+      },
+      _ => {},
+    }
+  }
+
+  let mut exports = FxHashMap::default();
+
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(module_decl) = item else {
+      continue;
+    };
+
+    match module_decl {
+      ModuleDecl::ExportNamed(named_export) if named_export.src.is_none() => {
+        for specifier in &named_export.specifiers {
+          let Some(named) = specifier.as_named() else {
+            continue;
+          };
+
+          let ModuleExportName::Ident(orig) = &named.orig else {
+            continue;
+          };
+
+          let exported_name = match named.exported.as_ref().unwrap_or(&named.orig) {
+            ModuleExportName::Ident(ident) => ident.sym.to_string(),
+            ModuleExportName::Str(strng) => strng.value.to_string_lossy().into_owned(),
+          };
+
+          exported.push((exported_name, orig.sym.clone()));
+        }
+      },
+      ModuleDecl::ExportDefaultExpr(export_default) => {
+        if let Some(value) = resolve_static_value(&export_default.expr, &locals) {
+          exports.insert("default".to_string(), drop_span(value.clone()));
+        }
+      },
+      _ => {},
+    }
+  }
+
+  for (exported_name, local_name) in exported {
+    if let Some(value) = locals
+      .get(&local_name)
+      .and_then(|init| resolve_static_value(init, &locals))
+    {
+      exports.insert(exported_name, drop_span(value.clone()));
+    }
+  }
+
+  exports
+}
+
+/// Records each `name = init` declarator in `locals`, and in `exported` under
+/// its own name when the declaration is exported.
+fn record_declarators<'a>(
+  decls: &'a [VarDeclarator],
+  mut exported: Option<&mut Vec<(String, Atom)>>,
+  locals: &mut FxHashMap<Atom, &'a Expr>,
+) {
+  for decl in decls {
+    let (Some(ident), Some(init)) = (decl.name.as_ident(), decl.init.as_deref()) else {
+      continue;
+    };
+
+    locals.insert(ident.sym.clone(), init);
+
+    if let Some(exported) = exported.as_deref_mut() {
+      exported.push((ident.sym.to_string(), ident.sym.clone()));
+    }
+  }
+}
+
+fn resolve_static_value<'a>(
+  expr: &'a Expr,
+  locals: &FxHashMap<Atom, &'a Expr>,
+) -> Option<&'a Expr> {
+  let mut current = expr;
+
+  for _ in 0..MAX_ALIAS_DEPTH {
+    match current.unwrap_parens() {
+      Expr::Ident(ident) => current = locals.get(&ident.sym)?,
+      value if is_static_value(value) => return Some(value),
+      _ => return None,
+    }
+  }
+
+  None
+}
+
+fn is_static_value(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(lit) => matches!(lit, Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_)),
+    Expr::Paren(paren) => is_static_value(&paren.expr),
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Unary(unary) => {
+      unary.op == UnaryOp::Minus && matches!(*unary.arg, Expr::Lit(Lit::Num(_)))
+    },
+    Expr::Array(array) => array.elems.iter().all(|elem| {
+      elem
+        .as_ref()
+        .is_some_and(|elem| elem.spread.is_none() && is_static_value(&elem.expr))
+    }),
+    Expr::Object(object) => object.props.iter().all(|prop| match prop {
+      PropOrSpread::Prop(prop) => match prop.as_ref() {
+        Prop::KeyValue(key_value) => {
+          matches!(
+            key_value.key,
+            PropName::Ident(_) | PropName::Str(_) | PropName::Num(_)
+          ) && is_static_value(&key_value.value)
+        },
+        _ => false,
+      },
+      PropOrSpread::Spread(_) => false,
+    }),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+#[path = "tests/cross_file_parsing_test.rs"]
+mod tests;
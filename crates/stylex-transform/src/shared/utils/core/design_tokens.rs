@@ -0,0 +1,691 @@
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+use crate::shared::utils::core::theme_declarations::{property_name, string_literal};
+
+/// The `$extensions` entry holding a token's values in other modes, keyed by
+/// mode name, as Tokens Studio and Figma variable exports write it.
+const MODE_EXTENSION: &str = "mode";
+
+/// How a W3C design tokens file is turned into a `.stylex.ts` module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesignTokenOptions {
+  /// The package the module imports `stylex` from.
+  pub import_source: String,
+  /// The name the variable group is exported under.
+  pub export_name: String,
+  /// Modes compiled into conditions of the variables themselves, such as
+  /// `dark` → `@media (prefers-color-scheme: dark)`. Every other mode becomes
+  /// a theme.
+  pub mode_conditions: IndexMap<String, String>,
+}
+
+impl Default for DesignTokenOptions {
+  fn default() -> Self {
+    DesignTokenOptions {
+      import_source: "@stylexjs/stylex".to_string(),
+      export_name: "tokens".to_string(),
+      mode_conditions: IndexMap::new(),
+    }
+  }
+}
+
+/// The `.stylex.ts` module declaring the tokens of a W3C Design Tokens
+/// (DTCG) file.
+///
+/// The groups of the file become the groups of one
+/// `stylex.unstable_defineVarsNested` export, and each mode a token takes
+/// under `$extensions.mode` becomes an `stylex.unstable_createThemeNested`
+/// export overriding the tokens that differ in it, named after the mode
+/// (`dark` → `darkTheme`), unless `mode_conditions` folds the mode into the
+/// variables as a condition instead.
+///
+/// A `{group.token}` alias is replaced by the value of the token it names, in
+/// the same mode, and takes that token's `$type` when it has none of its own.
+/// A `$type` on a group applies to every token below it. Each value is written
+/// as the CSS its type calls for: a color object as a CSS color, a dimension
+/// object as `16px`, a font family list quoted and joined, a shadow, border,
+/// transition or gradient as the shorthand it stands for, and a typography
+/// token as a group of its properties.
+///
+/// The module is ordinary StyleX source: compiled, it gives the same variable
+/// names and rules as the same calls written by hand in the same file.
+pub fn design_tokens_to_stylex_source(
+  json: &str,
+  options: &DesignTokenOptions,
+) -> Result<String, String> {
+  let document: Value =
+    serde_json::from_str(json).map_err(|error| format!("Invalid design tokens file: {error}"))?;
+
+  let Value::Object(document) = document else {
+    return Err("A design tokens file must be a JSON object.".to_string());
+  };
+
+  let mut tokens = IndexMap::new();
+  let tree = collect_group(&document, &[], None, &mut tokens)?;
+
+  if tokens.is_empty() {
+    return Err("The design tokens file defines no tokens.".to_string());
+  }
+
+  let resolver = Resolver { tokens: &tokens };
+  let modes = mode_names(&tokens);
+
+  let mut themes = Vec::new();
+  for mode in &modes {
+    if options.mode_conditions.contains_key(mode) {
+      continue;
+    }
+
+    let export_name = theme_export_name(mode);
+    if export_name == options.export_name {
+      return Err(format!(
+        "The theme for mode \"{mode}\" would be exported as \"{export_name}\", the name of the variables."
+      ));
+    }
+
+    themes.push((export_name, theme_overrides(&tree, &resolver, mode)?));
+  }
+
+  // Conditions follow the order the modes appear in the file, whatever order
+  // the options give them in.
+  let conditions: Vec<(&str, &str)> = modes
+    .iter()
+    .filter_map(|mode| {
+      let condition = options.mode_conditions.get(mode)?;
+      Some((mode.as_str(), condition.as_str()))
+    })
+    .collect();
+
+  let vars = vars_tree(&tree, &resolver, &conditions)?;
+
+  let mut source = format!(
+    "import * as stylex from {};\n\nexport const {} = stylex.unstable_defineVarsNested({});\n",
+    string_literal(&options.import_source),
+    options.export_name,
+    render_object(&vars, 0)
+  );
+
+  for (export_name, overrides) in themes {
+    source.push_str(&format!(
+      "\nexport const {export_name} = stylex.unstable_createThemeNested({}, {});\n",
+      options.export_name,
+      render_object(&overrides, 0)
+    ));
+  }
+
+  Ok(source)
+}
+
+/// A token as the file declares it, before aliases are resolved.
+#[derive(Debug)]
+struct RawToken {
+  value: Value,
+  token_type: Option<String>,
+  modes: IndexMap<String, Value>,
+}
+
+/// A group of the file, with each token standing for its dotted path.
+enum Node {
+  Group(IndexMap<String, Node>),
+  Token(String),
+}
+
+fn collect_group(
+  group: &Map<String, Value>,
+  path: &[&str],
+  inherited_type: Option<&str>,
+  tokens: &mut IndexMap<String, RawToken>,
+) -> Result<IndexMap<String, Node>, String> {
+  let group_type = group
+    .get("$type")
+    .and_then(Value::as_str)
+    .or(inherited_type);
+
+  let mut nodes = IndexMap::new();
+
+  for (name, entry) in group {
+    // `$type`, `$description`, `$extensions` and the like describe the group.
+    if name.starts_with('$') {
+      continue;
+    }
+
+    let entry_path: Vec<&str> = path.iter().copied().chain([name.as_str()]).collect();
+    let dotted_path = entry_path.join(".");
+
+    if name.contains(['.', '{', '}']) {
+      return Err(format!(
+        "\"{dotted_path}\" is not a valid token name: names cannot contain '.', '{{' or '}}'."
+      ));
+    }
+
+    let Value::Object(entry) = entry else {
+      return Err(format!("\"{dotted_path}\" is neither a token nor a group."));
+    };
+
+    if let Some(value) = entry.get("$value") {
+      let modes = entry
+        .get("$extensions")
+        .and_then(|extensions| extensions.get(MODE_EXTENSION))
+        .and_then(Value::as_object)
+        .map(|modes| {
+          modes
+            .iter()
+            .map(|(mode, value)| (mode.clone(), value.clone()))
+            .collect()
+        })
+        .unwrap_or_default();
+
+      tokens.insert(
+        dotted_path.clone(),
+        RawToken {
+          value: value.clone(),
+          token_type: entry
+            .get("$type")
+            .and_then(Value::as_str)
+            .or(group_type)
+            .map(str::to_string),
+          modes,
+        },
+      );
+      nodes.insert(name.clone(), Node::Token(dotted_path));
+    } else {
+      let children = collect_group(entry, &entry_path, group_type, tokens)?;
+      nodes.insert(name.clone(), Node::Group(children));
+    }
+  }
+
+  Ok(nodes)
+}
+
+/// Every mode some token takes a value in, in the order they first appear.
+fn mode_names(tokens: &IndexMap<String, RawToken>) -> Vec<String> {
+  let mut modes: Vec<String> = Vec::new();
+
+  for token in tokens.values() {
+    for mode in token.modes.keys() {
+      if !modes.contains(mode) {
+        modes.push(mode.clone());
+      }
+    }
+  }
+
+  modes
+}
+
+/// `darkTheme` for `dark`, `highContrastTheme` for `high-contrast`.
+fn theme_export_name(mode: &str) -> String {
+  let mut name = String::new();
+  let mut upper_next = false;
+
+  for character in mode.chars() {
+    if character.is_ascii_alphanumeric() {
+      if upper_next && !name.is_empty() {
+        name.push(character.to_ascii_uppercase());
+      } else {
+        name.push(character);
+      }
+      upper_next = false;
+    } else {
+      upper_next = true;
+    }
+  }
+
+  if name.is_empty() || name.starts_with(|first: char| first.is_ascii_digit()) {
+    name.insert_str(0, "mode");
+  }
+
+  format!("{name}Theme")
+}
+
+struct Resolver<'a> {
+  tokens: &'a IndexMap<String, RawToken>,
+}
+
+impl Resolver<'_> {
+  /// The CSS value of the token at `path` in `mode`, or in no mode.
+  fn css_value(&self, path: &str, mode: Option<&str>) -> Result<TokenValue, String> {
+    let (value, token_type) = self.resolve(path, mode, &mut Vec::new())?;
+
+    css_value(&value, token_type.as_deref()).map_err(|error| format!("Token \"{path}\": {error}"))
+  }
+
+  /// The value of the token at `path` with every alias in it replaced, and its
+  /// type, its own or the one of the token it aliases.
+  fn resolve(
+    &self,
+    path: &str,
+    mode: Option<&str>,
+    stack: &mut Vec<String>,
+  ) -> Result<(Value, Option<String>), String> {
+    let Some(token) = self.tokens.get(path) else {
+      return Err(match stack.last() {
+        Some(alias) => format!("Token \"{alias}\" refers to \"{path}\", which is not a token."),
+        None => format!("\"{path}\" is not a token."),
+      });
+    };
+
+    if stack.iter().any(|seen| seen == path) {
+      stack.push(path.to_string());
+      return Err(format!("Circular alias: {}.", stack.join(" → ")));
+    }
+
+    let raw = mode
+      .and_then(|mode| token.modes.get(mode))
+      .unwrap_or(&token.value);
+
+    stack.push(path.to_string());
+    let (value, aliased_type) = self.resolve_value(raw, mode, stack)?;
+    stack.pop();
+
+    Ok((value, token.token_type.clone().or(aliased_type)))
+  }
+
+  fn resolve_value(
+    &self,
+    value: &Value,
+    mode: Option<&str>,
+    stack: &mut Vec<String>,
+  ) -> Result<(Value, Option<String>), String> {
+    match value {
+      Value::String(string) => match alias_path(string) {
+        Some(path) => self.resolve(path, mode, stack),
+        None => Ok((value.clone(), None)),
+      },
+      Value::Array(items) => {
+        let items = items
+          .iter()
+          .map(|item| Ok(self.resolve_value(item, mode, stack)?.0))
+          .collect::<Result<_, String>>()?;
+
+        Ok((Value::Array(items), None))
+      },
+      Value::Object(entries) => {
+        let entries = entries
+          .iter()
+          .map(|(key, entry)| Ok((key.clone(), self.resolve_value(entry, mode, stack)?.0)))
+          .collect::<Result<_, String>>()?;
+
+        Ok((Value::Object(entries), None))
+      },
+      _ => Ok((value.clone(), None)),
+    }
+  }
+}
+
+/// `color.accent` for `{color.accent}`: a value that is a whole alias.
+fn alias_path(value: &str) -> Option<&str> {
+  value
+    .strip_prefix('{')?
+    .strip_suffix('}')
+    .filter(|path| !path.is_empty() && !path.contains(['{', '}']))
+}
+
+/// The CSS a token compiles to: one value, or, for a typography token, a value
+/// per property.
+#[derive(Debug, Clone, PartialEq)]
+enum TokenValue {
+  Value(String),
+  Group(IndexMap<String, String>),
+}
+
+fn css_value(value: &Value, token_type: Option<&str>) -> Result<TokenValue, String> {
+  if token_type == Some("typography") {
+    let Value::Object(properties) = value else {
+      return Err("a typography value must be an object.".to_string());
+    };
+
+    return properties
+      .iter()
+      .map(|(property, value)| {
+        let property_type = match property.as_str() {
+          "fontFamily" => Some("fontFamily"),
+          "fontWeight" => Some("fontWeight"),
+          "fontSize" | "letterSpacing" => Some("dimension"),
+          _ => None,
+        };
+
+        Ok((property.clone(), css_string(value, property_type)?))
+      })
+      .collect::<Result<_, String>>()
+      .map(TokenValue::Group);
+  }
+
+  css_string(value, token_type).map(TokenValue::Value)
+}
+
+/// The CSS text of a value of `token_type`. A string is taken as CSS already,
+/// whatever the type.
+fn css_string(value: &Value, token_type: Option<&str>) -> Result<String, String> {
+  match (value, token_type) {
+    (Value::String(value), _) => Ok(value.clone()),
+    (Value::Number(number), _) => Ok(number.to_string()),
+    (Value::Object(color), Some("color")) => css_color(color),
+    (Value::Object(dimension), Some("dimension" | "duration")) => css_dimension(dimension),
+    (Value::Array(families), Some("fontFamily")) => families
+      .iter()
+      .map(|family| match family {
+        Value::String(family) => Ok(font_family_name(family)),
+        _ => Err("a font family must be a string.".to_string()),
+      })
+      .collect::<Result<Vec<_>, _>>()
+      .map(|families| families.join(", ")),
+    (Value::Array(points), Some("cubicBezier")) => {
+      let points = points
+        .iter()
+        .map(|point| css_string(point, Some("number")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+      if points.len() != 4 {
+        return Err("a cubic Bézier curve takes four numbers.".to_string());
+      }
+
+      Ok(format!("cubic-bezier({})", points.join(", ")))
+    },
+    (Value::Object(shadow), Some("shadow")) => css_shadow(shadow),
+    (Value::Array(shadows), Some("shadow")) => shadows
+      .iter()
+      .map(|shadow| match shadow {
+        Value::Object(shadow) => css_shadow(shadow),
+        _ => Err("a shadow must be an object.".to_string()),
+      })
+      .collect::<Result<Vec<_>, _>>()
+      .map(|shadows| shadows.join(", ")),
+    (Value::Object(border), Some("border")) => Ok(format!(
+      "{} {} {}",
+      css_string(field(border, "width")?, Some("dimension"))?,
+      css_string(field(border, "style")?, Some("strokeStyle"))?,
+      css_string(field(border, "color")?, Some("color"))?
+    )),
+    (Value::Object(transition), Some("transition")) => Ok(format!(
+      "{} {} {}",
+      css_string(field(transition, "duration")?, Some("duration"))?,
+      css_string(field(transition, "timingFunction")?, Some("cubicBezier"))?,
+      css_string(field(transition, "delay")?, Some("duration"))?
+    )),
+    (Value::Array(stops), Some("gradient")) => stops
+      .iter()
+      .map(|stop| {
+        let Value::Object(stop) = stop else {
+          return Err("a gradient stop must be an object.".to_string());
+        };
+        let position = field(stop, "position")?
+          .as_f64()
+          .ok_or("a gradient stop position must be a number.")?;
+
+        Ok(format!(
+          "{} {}%",
+          css_string(field(stop, "color")?, Some("color"))?,
+          trim_number(position * 100.0)
+        ))
+      })
+      .collect::<Result<Vec<_>, String>>()
+      .map(|stops| stops.join(", ")),
+    (Value::Bool(_) | Value::Null, _) => Err("the value is not a CSS value.".to_string()),
+    (_, Some(token_type)) => Err(format!("the value is not a valid {token_type}.")),
+    (_, None) => Err("the value has no $type to say how it is written in CSS.".to_string()),
+  }
+}
+
+fn field<'a>(object: &'a Map<String, Value>, name: &str) -> Result<&'a Value, String> {
+  object
+    .get(name)
+    .ok_or_else(|| format!("the value has no \"{name}\"."))
+}
+
+/// A DTCG color object as a CSS color: its `hex` when it is opaque, its
+/// components in its color space otherwise.
+fn css_color(color: &Map<String, Value>) -> Result<String, String> {
+  let alpha = color.get("alpha").and_then(Value::as_f64);
+
+  if let Some(Value::String(hex)) = color.get("hex")
+    && alpha.is_none_or(|alpha| alpha == 1.0)
+  {
+    return Ok(hex.clone());
+  }
+
+  let space = field(color, "colorSpace")?
+    .as_str()
+    .ok_or("a color space must be a string.")?;
+  let components = field(color, "components")?
+    .as_array()
+    .ok_or("color components must be an array.")?
+    .iter()
+    .map(|component| match component {
+      Value::Number(number) => Ok(number.to_string()),
+      Value::String(keyword) if keyword == "none" => Ok(keyword.clone()),
+      _ => Err("a color component must be a number or \"none\".".to_string()),
+    })
+    .collect::<Result<Vec<_>, _>>()?
+    .join(" ");
+
+  let components = match alpha {
+    Some(alpha) if alpha != 1.0 => format!("{components} / {}", trim_number(alpha)),
+    _ => components,
+  };
+
+  Ok(match space {
+    "hsl" | "hwb" | "lab" | "lch" | "oklab" | "oklch" => format!("{space}({components})"),
+    _ => format!("color({space} {components})"),
+  })
+}
+
+/// `{ "value": 16, "unit": "px" }` as `16px`.
+fn css_dimension(dimension: &Map<String, Value>) -> Result<String, String> {
+  let value = field(dimension, "value")?
+    .as_f64()
+    .ok_or("a dimension value must be a number.")?;
+  let unit = field(dimension, "unit")?
+    .as_str()
+    .ok_or("a dimension unit must be a string.")?;
+
+  Ok(format!("{}{unit}", trim_number(value)))
+}
+
+fn css_shadow(shadow: &Map<String, Value>) -> Result<String, String> {
+  let mut parts = Vec::new();
+
+  if shadow.get("inset").and_then(Value::as_bool) == Some(true) {
+    parts.push("inset".to_string());
+  }
+
+  for name in ["offsetX", "offsetY", "blur", "spread"] {
+    parts.push(css_string(field(shadow, name)?, Some("dimension"))?);
+  }
+  parts.push(css_string(field(shadow, "color")?, Some("color"))?);
+
+  Ok(parts.join(" "))
+}
+
+/// A family name quoted when it is more than one word, and not already.
+fn font_family_name(family: &str) -> String {
+  if family.contains(char::is_whitespace) && !family.starts_with(['"', '\'']) {
+    format!("\"{family}\"")
+  } else {
+    family.to_string()
+  }
+}
+
+/// A number without the float noise of arithmetic on it: `30` for
+/// `0.3 * 100`.
+fn trim_number(value: f64) -> String {
+  let rounded = (value * 10_000.0).round() / 10_000.0;
+
+  format!("{rounded}")
+}
+
+/// A value as the generated source writes it: a string, a condition object of
+/// a token, or a group.
+enum SourceValue {
+  Value(String),
+  Object(IndexMap<String, SourceValue>),
+}
+
+/// The argument of `unstable_defineVarsNested`: every token's value, with its
+/// value in each of the `(mode, condition)` pairs of `conditions` under the
+/// condition.
+fn vars_tree(
+  tree: &IndexMap<String, Node>,
+  resolver: &Resolver,
+  conditions: &[(&str, &str)],
+) -> Result<IndexMap<String, SourceValue>, String> {
+  let mut object = IndexMap::new();
+
+  for (name, node) in tree {
+    let value = match node {
+      Node::Group(children) => SourceValue::Object(vars_tree(children, resolver, conditions)?),
+      Node::Token(path) => {
+        let default = resolver.css_value(path, None)?;
+        let mut conditional = Vec::new();
+
+        for (mode, condition) in conditions {
+          let value = resolver.css_value(path, Some(mode))?;
+
+          if value != default {
+            conditional.push((condition.to_string(), value));
+          }
+        }
+
+        conditional_value(default, conditional)
+      },
+    };
+
+    object.insert(name.clone(), value);
+  }
+
+  check_not_conditional(&object)?;
+
+  Ok(object)
+}
+
+/// A token's value with its conditions: a plain value without any, a
+/// `default` object with them. A typography token gets one per property.
+fn conditional_value(default: TokenValue, conditions: Vec<(String, TokenValue)>) -> SourceValue {
+  match default {
+    TokenValue::Value(default) => {
+      if conditions.is_empty() {
+        return SourceValue::Value(default);
+      }
+
+      let mut object = IndexMap::from([("default".to_string(), SourceValue::Value(default))]);
+      for (condition, value) in conditions {
+        if let TokenValue::Value(value) = value {
+          object.insert(condition, SourceValue::Value(value));
+        }
+      }
+
+      SourceValue::Object(object)
+    },
+    TokenValue::Group(properties) => SourceValue::Object(
+      properties
+        .into_iter()
+        .map(|(property, default)| {
+          let conditions = conditions
+            .iter()
+            .filter_map(|(condition, value)| match value {
+              TokenValue::Group(values) => values
+                .get(&property)
+                .filter(|value| **value != default)
+                .map(|value| (condition.clone(), TokenValue::Value(value.clone()))),
+              TokenValue::Value(_) => None,
+            })
+            .collect();
+
+          (
+            property,
+            conditional_value(TokenValue::Value(default), conditions),
+          )
+        })
+        .collect(),
+    ),
+  }
+}
+
+/// The overrides of the theme for `mode`: the tokens whose value in it
+/// differs from their default, directly or through an alias.
+fn theme_overrides(
+  tree: &IndexMap<String, Node>,
+  resolver: &Resolver,
+  mode: &str,
+) -> Result<IndexMap<String, SourceValue>, String> {
+  let mut object = IndexMap::new();
+
+  for (name, node) in tree {
+    match node {
+      Node::Group(children) => {
+        let overrides = theme_overrides(children, resolver, mode)?;
+
+        if !overrides.is_empty() {
+          object.insert(name.clone(), SourceValue::Object(overrides));
+        }
+      },
+      Node::Token(path) => {
+        let default = resolver.css_value(path, None)?;
+        let value = resolver.css_value(path, Some(mode))?;
+
+        match (default, value) {
+          (default, value) if default == value => {},
+          (TokenValue::Group(defaults), TokenValue::Group(values)) => {
+            let changed: IndexMap<_, _> = values
+              .into_iter()
+              .filter(|(property, value)| defaults.get(property) != Some(value))
+              .map(|(property, value)| (property, SourceValue::Value(value)))
+              .collect();
+
+            object.insert(name.clone(), SourceValue::Object(changed));
+          },
+          (_, TokenValue::Value(value)) => {
+            object.insert(name.clone(), SourceValue::Value(value));
+          },
+          (TokenValue::Value(_), TokenValue::Group(_)) => {
+            return Err(format!(
+              "Token \"{path}\" is a typography value in mode \"{mode}\" only."
+            ));
+          },
+        }
+      },
+    }
+  }
+
+  Ok(object)
+}
+
+/// StyleX reads an object of only `default` and `@` keys as the conditions of
+/// one variable, so a group named that way would silently become one.
+fn check_not_conditional(group: &IndexMap<String, SourceValue>) -> Result<(), String> {
+  let looks_conditional = group.contains_key("default")
+    && group
+      .keys()
+      .all(|key| key == "default" || key.starts_with('@'));
+
+  if looks_conditional {
+    return Err(
+      "A group whose only names are \"default\" and @-rules would be read as the conditions of one variable."
+        .to_string(),
+    );
+  }
+
+  Ok(())
+}
+
+fn render_object(object: &IndexMap<String, SourceValue>, depth: usize) -> String {
+  let indent = "  ".repeat(depth + 1);
+  let mut output = String::from("{\n");
+
+  for (name, value) in object {
+    let value = match value {
+      SourceValue::Value(value) => string_literal(value),
+      SourceValue::Object(object) => render_object(object, depth + 1),
+    };
+
+    output.push_str(&format!("{indent}{}: {value},\n", property_name(name)));
+  }
+
+  output.push_str(&"  ".repeat(depth));
+  output.push('}');
+  output
+}
+
+#[cfg(test)]
+#[path = "tests/design_tokens_test.rs"]
+mod tests;
@@ -1,5 +1,11 @@
 use log::warn;
-use stylex_macros::stylex_panic;
+use std::borrow::Cow;
+use stylex_macros::{
+  diagnostic::{codes, report_warning},
+  panic_macros::{__stylex_panic, stylex_err},
+  stylex_error::StyleXError,
+  stylex_panic,
+};
 use swc_core::ecma::ast::{Expr, Lit};
 
 use crate::shared::{
@@ -29,9 +35,10 @@ pub(crate) fn flat_map_expanded_shorthands(
       let msg = "Cannot use fallbacks for shorthands. Use the expansion instead.";
       match options.property_validation_mode {
         PropertyValidationMode::Throw => {
-          stylex_panic!("{}", msg);
+          __stylex_panic(invalid_shorthand(msg));
         },
         PropertyValidationMode::Warn => {
+          report_warning(&invalid_shorthand(msg));
           warn!("{}", msg);
           return vec![];
         },
@@ -71,9 +78,10 @@ pub(crate) fn flat_map_expanded_shorthands(
         let msg = "Cannot use expressions for shorthands. Use the expansion instead.";
         match options.property_validation_mode {
           PropertyValidationMode::Throw => {
-            stylex_panic!("{}", msg);
+            __stylex_panic(invalid_shorthand(msg));
           },
           PropertyValidationMode::Warn => {
+            report_warning(&invalid_shorthand(msg));
             warn!("{}", msg);
             return vec![];
           },
@@ -107,9 +115,10 @@ pub(crate) fn flat_map_expanded_shorthands(
       Ok(expanded) => expanded,
       Err(error_message) => match options.property_validation_mode {
         PropertyValidationMode::Throw => {
-          stylex_panic!("{}", error_message);
+          __stylex_panic(invalid_shorthand(error_message));
         },
         PropertyValidationMode::Warn => {
+          report_warning(&invalid_shorthand(error_message.clone()));
           warn!("{}", error_message);
           vec![]
         },
@@ -126,3 +135,7 @@ pub(crate) fn flat_map_expanded_shorthands(
 
   vec_order_pair
 }
+
+fn invalid_shorthand(message: impl Into<Cow<'static, str>>) -> StyleXError {
+  stylex_err(message).with_code(codes::INVALID_SHORTHAND)
+}
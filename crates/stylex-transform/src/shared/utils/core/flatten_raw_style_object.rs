@@ -1,10 +1,13 @@
 use indexmap::IndexMap;
 use log::warn;
 use rustc_hash::FxHashSet;
-use stylex_css_parser::at_queries::media_query_transform::last_media_query_wins_transform;
+use stylex_css_parser::at_queries::{
+  media_query_transform::last_media_query_wins_transform, validate_container_query,
+  validate_supports_query,
+};
 use stylex_macros::stylex_panic;
 use swc_core::ecma::{
-  ast::{Expr, KeyValueProp, Lit, Prop, PropName, PropOrSpread},
+  ast::{Expr, KeyValueProp, Lit, ObjectLit, Prop, PropName, PropOrSpread},
   utils::quote_str,
 };
 
@@ -61,11 +64,15 @@ pub(crate) fn flatten_raw_style_object(
   traversal_state: &mut StateManager,
   fns: &FunctionMap,
 ) -> IndexMap<String, PreRules> {
-  let mut processed_style = style.to_vec();
+  let mut processed_style = if traversal_state.options.enable_at_rule_normalization {
+    normalize_at_rule_keys(style)
+  } else {
+    style.to_vec()
+  };
 
   if traversal_state.options.enable_media_query_order {
     let transform_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-      last_media_query_wins_transform(style)
+      last_media_query_wins_transform(&processed_style)
     }));
 
     match transform_result {
@@ -81,6 +88,72 @@ pub(crate) fn flatten_raw_style_object(
   flatten_raw_style_object_logic(&processed_style, &mut vec![], state, traversal_state, fns)
 }
 
+/// Respell every `@supports` and `@container` key at any depth of `style` in
+/// its canonical form, so equivalent conditions hash to one class.
+///
+/// Two sibling keys that respell to the same condition are one key, so the
+/// later value replaces the earlier one in the earlier one's place -- what an
+/// object literal does with a repeated key.
+fn normalize_at_rule_keys(style: &[KeyValueProp]) -> Vec<KeyValueProp> {
+  let mut normalized: Vec<KeyValueProp> = Vec::with_capacity(style.len());
+
+  for property in style {
+    let key = convert_key_value_to_str(property);
+    let canonical_key = if key.starts_with("@supports") {
+      validate_supports_query(&key)
+        .ok()
+        .map(|query| query.to_string())
+    } else if key.starts_with("@container") {
+      validate_container_query(&key)
+        .ok()
+        .map(|query| query.to_string())
+    } else {
+      None
+    };
+
+    let value = match property.value.as_ref() {
+      Expr::Object(object)
+        if object
+          .props
+          .iter()
+          .all(|prop| matches!(prop, PropOrSpread::Prop(prop) if prop.is_key_value())) =>
+      {
+        let props = normalize_at_rule_keys(&get_key_values_from_object(object))
+          .into_iter()
+          .map(|key_value| PropOrSpread::Prop(Box::new(Prop::KeyValue(key_value))))
+          .collect();
+
+        Box::new(Expr::Object(ObjectLit {
+          span: object.span,
+          props,
+        }))
+      },
+      _ => property.value.clone(),
+    };
+
+    let Some(canonical_key) = canonical_key else {
+      normalized.push(KeyValueProp {
+        key: property.key.clone(),
+        value,
+      });
+      continue;
+    };
+
+    match normalized
+      .iter_mut()
+      .find(|seen| convert_key_value_to_str(seen) == canonical_key)
+    {
+      Some(seen) => seen.value = value,
+      None => normalized.push(KeyValueProp {
+        key: PropName::Str(quote_str!(canonical_key)),
+        value,
+      }),
+    }
+  }
+
+  normalized
+}
+
 pub(crate) fn flatten_raw_style_object_logic(
   style: &[KeyValueProp],
   key_path: &mut Vec<String>,
@@ -1,7 +1,10 @@
 pub(crate) mod add_source_map_data;
+pub(crate) mod analysis;
 pub(crate) mod attrs;
 pub(crate) mod convert_style_to_class_name;
+pub(crate) mod cross_file_parsing;
 pub(crate) mod define_vars_utils;
+pub mod design_tokens;
 pub(crate) mod dev_class_name;
 pub mod evaluate_stylex_create_arg;
 pub(crate) mod flat_map_expanded_shorthands;
@@ -17,3 +20,5 @@ pub(crate) mod stylex_merge;
 pub(crate) mod stylex_nested_utils;
 #[cfg(test)]
 pub(crate) mod tests;
+pub(crate) mod theme_declarations;
+pub(crate) mod unused_styles;
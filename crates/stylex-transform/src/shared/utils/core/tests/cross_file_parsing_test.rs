@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use stylex_structures::{
+  core_stylex_options::CoreStyleXOptions, stylex_state_options::StyleXStateOptions,
+};
+use swc_core::{
+  common::{FileName, SourceMap, input::StringInput},
+  ecma::{
+    ast::{Expr, Lit, Module},
+    parser::{EsSyntax, Parser, Syntax, lexer::Lexer},
+  },
+};
+
+use super::{collect_static_exports, hashing_options_key};
+
+fn parse_module(code: &str) -> Module {
+  let source_map = SourceMap::default();
+  let source_file = source_map.new_source_file(
+    Arc::new(FileName::Custom("cross_file_fixture.stylex.js".to_string())),
+    code.to_string(),
+  );
+
+  let lexer = Lexer::new(
+    Syntax::Es(EsSyntax::default()),
+    Default::default(),
+    StringInput::from(&*source_file),
+    None,
+  );
+
+  match Parser::new_from(lexer).parse_module() {
+    Ok(module) => module,
+    Err(error) => panic!("failed to parse fixture: {:?}", error),
+  }
+}
+
+fn exports_of(code: &str) -> FxHashMap<String, Expr> {
+  collect_static_exports(&parse_module(code))
+}
+
+fn sorted_names(exports: &FxHashMap<String, Expr>) -> Vec<&str> {
+  let mut names: Vec<&str> = exports.keys().map(String::as_str).collect();
+  names.sort_unstable();
+  names
+}
+
+#[test]
+fn collects_exported_object_literals() {
+  let exports = exports_of(
+    r#"
+      export const colors = { accent: "hotpink", size: 4, negative: -1 };
+      export const list = ["a", `b`];
+    "#,
+  );
+
+  assert_eq!(sorted_names(&exports), vec!["colors", "list"]);
+  assert!(matches!(exports.get("colors"), Some(Expr::Object(_))));
+  assert!(matches!(exports.get("list"), Some(Expr::Array(_))));
+}
+
+#[test]
+fn follows_aliases_to_the_declaration_they_name() {
+  let exports = exports_of(
+    r#"
+      const constants = { YELLOW: "yellow" };
+      export const C = constants;
+      export { constants as renamed };
+    "#,
+  );
+
+  assert_eq!(sorted_names(&exports), vec!["C", "renamed"]);
+  assert_eq!(exports["C"], exports["renamed"]);
+}
+
+#[test]
+fn reads_a_literal_default_export() {
+  let exports = exports_of(r#"export default "red";"#);
+
+  assert!(matches!(
+    exports.get("default"),
+    Some(Expr::Lit(Lit::Str(_)))
+  ));
+}
+
+#[test]
+fn skips_exports_that_are_not_literals() {
+  let exports = exports_of(
+    r#"
+      import { other } from "./other.stylex";
+      export const fromImport = other;
+      export const call = compute();
+      export const spread = { ...other };
+      export const shorthand = { other };
+      export const dynamic = `${other}px`;
+      export let notInitialized;
+    "#,
+  );
+
+  assert!(
+    exports.is_empty(),
+    "unexpected exports: {:?}",
+    sorted_names(&exports)
+  );
+}
+
+#[test]
+fn options_key_depends_on_aliases_but_not_their_order() {
+  let with_aliases = |entries: &[(&str, &str)]| StyleXStateOptions {
+    core: CoreStyleXOptions {
+      aliases: Some(
+        entries
+          .iter()
+          .map(|(alias, target)| (alias.to_string(), vec![target.to_string()]))
+          .collect(),
+      ),
+      ..CoreStyleXOptions::default()
+    },
+    ..StyleXStateOptions::default()
+  };
+
+  let none = hashing_options_key(&StyleXStateOptions::default());
+  let theme = hashing_options_key(&with_aliases(&[
+    ("@theme/*", "src/theme/*"),
+    ("@ui/*", "ui/*"),
+  ]));
+  let reordered = hashing_options_key(&with_aliases(&[
+    ("@ui/*", "ui/*"),
+    ("@theme/*", "src/theme/*"),
+  ]));
+  let elsewhere = hashing_options_key(&with_aliases(&[
+    ("@theme/*", "lib/theme/*"),
+    ("@ui/*", "ui/*"),
+  ]));
+
+  assert_ne!(none, theme);
+  assert_eq!(theme, reordered);
+  assert_ne!(theme, elsewhere);
+}
@@ -0,0 +1,251 @@
+use indexmap::IndexMap;
+
+use super::*;
+
+fn source(json: &str) -> String {
+  design_tokens_to_stylex_source(json, &DesignTokenOptions::default()).expect("valid tokens")
+}
+
+fn error(json: &str) -> String {
+  design_tokens_to_stylex_source(json, &DesignTokenOptions::default()).expect_err("invalid tokens")
+}
+
+#[test]
+fn groups_become_nested_variables() {
+  assert_eq!(
+    source(
+      r##"{
+        "color": {
+          "$type": "color",
+          "$description": "Brand colors",
+          "accent": { "$value": "#0a84ff" },
+          "text-muted": { "$value": "#6e6e73" }
+        },
+        "space": {
+          "small": { "$type": "dimension", "$value": { "value": 4, "unit": "px" } }
+        }
+      }"##
+    ),
+    "import * as stylex from '@stylexjs/stylex';
+
+export const tokens = stylex.unstable_defineVarsNested({
+  color: {
+    accent: '#0a84ff',
+    'text-muted': '#6e6e73',
+  },
+  space: {
+    small: '4px',
+  },
+});
+"
+  );
+}
+
+#[test]
+fn aliases_resolve_to_the_value_they_name_in_the_same_mode() {
+  assert_eq!(
+    source(
+      r##"{
+        "palette": {
+          "$type": "color",
+          "gray900": { "$value": "#111", "$extensions": { "mode": { "dark": "#eee" } } }
+        },
+        "text": { "$value": "{palette.gray900}" },
+        "border": { "$value": "{text}" }
+      }"##
+    ),
+    "import * as stylex from '@stylexjs/stylex';
+
+export const tokens = stylex.unstable_defineVarsNested({
+  palette: {
+    gray900: '#111',
+  },
+  text: '#111',
+  border: '#111',
+});
+
+export const darkTheme = stylex.unstable_createThemeNested(tokens, {
+  palette: {
+    gray900: '#eee',
+  },
+  text: '#eee',
+  border: '#eee',
+});
+"
+  );
+}
+
+#[test]
+fn modes_can_become_conditions() {
+  let options = DesignTokenOptions {
+    export_name: "colors".to_string(),
+    mode_conditions: IndexMap::from([(
+      "dark".to_string(),
+      "@media (prefers-color-scheme: dark)".to_string(),
+    )]),
+    ..Default::default()
+  };
+
+  let source = design_tokens_to_stylex_source(
+    r##"{
+      "background": {
+        "$type": "color",
+        "$value": "white",
+        "$extensions": { "mode": { "dark": "black", "high-contrast": "white" } }
+      },
+      "accent": {
+        "$type": "color",
+        "$value": "blue",
+        "$extensions": { "mode": { "high-contrast": "yellow" } }
+      }
+    }"##,
+    &options,
+  )
+  .expect("valid tokens");
+
+  assert_eq!(
+    source,
+    "import * as stylex from '@stylexjs/stylex';
+
+export const colors = stylex.unstable_defineVarsNested({
+  background: {
+    default: 'white',
+    '@media (prefers-color-scheme: dark)': 'black',
+  },
+  accent: 'blue',
+});
+
+export const highContrastTheme = stylex.unstable_createThemeNested(colors, {
+  accent: 'yellow',
+});
+"
+  );
+}
+
+#[test]
+fn values_are_written_as_the_css_their_type_calls_for() {
+  assert_eq!(
+    source(
+      r##"{
+        "brand": {
+          "$type": "color",
+          "$value": { "colorSpace": "oklch", "components": [0.7, 0.1, 250], "alpha": 0.5 }
+        },
+        "solid": {
+          "$type": "color",
+          "$value": { "colorSpace": "srgb", "components": [0, 0.5, 1], "hex": "#0080ff" }
+        },
+        "body": { "$type": "fontFamily", "$value": ["Helvetica Neue", "Arial", "sans-serif"] },
+        "weight": { "$type": "fontWeight", "$value": 700 },
+        "ease": { "$type": "cubicBezier", "$value": [0.5, 0, 1, 1] },
+        "fast": { "$type": "duration", "$value": { "value": 150, "unit": "ms" } },
+        "raised": {
+          "$type": "shadow",
+          "$value": [
+            { "color": "#0003", "offsetX": "0px", "offsetY": "1px", "blur": "2px", "spread": "0px" },
+            { "color": "#0001", "offsetX": "0px", "offsetY": "0px", "blur": "1px", "spread": "1px", "inset": true }
+          ]
+        },
+        "divider": {
+          "$type": "border",
+          "$value": { "color": "{brand}", "width": "1px", "style": "solid" }
+        },
+        "fade": {
+          "$type": "transition",
+          "$value": { "duration": "{fast}", "delay": "0ms", "timingFunction": "{ease}" }
+        },
+        "sunset": {
+          "$type": "gradient",
+          "$value": [{ "color": "red", "position": 0 }, { "color": "orange", "position": 0.3 }]
+        },
+        "heading": {
+          "$type": "typography",
+          "$value": { "fontFamily": "{body}", "fontSize": { "value": 2, "unit": "rem" }, "fontWeight": 600, "lineHeight": 1.2 }
+        }
+      }"##
+    ),
+    "import * as stylex from '@stylexjs/stylex';
+
+export const tokens = stylex.unstable_defineVarsNested({
+  brand: 'oklch(0.7 0.1 250 / 0.5)',
+  solid: '#0080ff',
+  body: '\"Helvetica Neue\", Arial, sans-serif',
+  weight: '700',
+  ease: 'cubic-bezier(0.5, 0, 1, 1)',
+  fast: '150ms',
+  raised: '0px 1px 2px 0px #0003, inset 0px 0px 1px 1px #0001',
+  divider: '1px solid oklch(0.7 0.1 250 / 0.5)',
+  fade: '150ms cubic-bezier(0.5, 0, 1, 1) 0ms',
+  sunset: 'red 0%, orange 30%',
+  heading: {
+    fontFamily: '\"Helvetica Neue\", Arial, sans-serif',
+    fontSize: '2rem',
+    fontWeight: '600',
+    lineHeight: '1.2',
+  },
+});
+"
+  );
+}
+
+#[test]
+fn a_theme_only_overrides_what_the_mode_changes() {
+  assert_eq!(
+    source(
+      r##"{
+        "heading": {
+          "$type": "typography",
+          "$value": { "fontSize": "2rem", "lineHeight": 1.2 },
+          "$extensions": { "mode": { "compact": { "fontSize": "1.5rem", "lineHeight": 1.2 } } }
+        },
+        "gap": { "$value": "8px" }
+      }"##
+    ),
+    "import * as stylex from '@stylexjs/stylex';
+
+export const tokens = stylex.unstable_defineVarsNested({
+  heading: {
+    fontSize: '2rem',
+    lineHeight: '1.2',
+  },
+  gap: '8px',
+});
+
+export const compactTheme = stylex.unstable_createThemeNested(tokens, {
+  heading: {
+    fontSize: '1.5rem',
+  },
+});
+"
+  );
+}
+
+#[test]
+fn invalid_files_are_reported() {
+  assert!(error("[]").contains("must be a JSON object"));
+  assert!(error("{}").contains("defines no tokens"));
+  assert!(error(r#"{ "a": 1 }"#).contains("\"a\" is neither a token nor a group"));
+  assert!(
+    error(r#"{ "a": { "$value": "{b}" } }"#).contains("refers to \"b\", which is not a token")
+  );
+  assert!(
+    error(r#"{ "a": { "$value": "{b}" }, "b": { "$value": "{a}" } }"#)
+      .contains("Circular alias: a → b → a.")
+  );
+  assert!(error(r#"{ "a": { "$value": { "value": 1 } } }"#).contains("has no $type"));
+  assert!(
+    error(r#"{ "a": { "$type": "dimension", "$value": { "value": 1 } } }"#)
+      .contains("Token \"a\": the value has no \"unit\".")
+  );
+  assert!(
+    error(r#"{ "g": { "default": { "$value": "1px" }, "@media print": { "$value": "2px" } } }"#)
+      .contains("would be read as the conditions of one variable")
+  );
+}
+
+#[test]
+fn theme_export_names_are_identifiers() {
+  assert_eq!(theme_export_name("dark"), "darkTheme");
+  assert_eq!(theme_export_name("high-contrast"), "highContrastTheme");
+  assert_eq!(theme_export_name("2x"), "mode2xTheme");
+}
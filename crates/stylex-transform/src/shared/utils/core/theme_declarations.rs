@@ -0,0 +1,266 @@
+use indexmap::IndexSet;
+use swc_core::ecma::ast::{Expr, Lit, ObjectLit};
+
+use crate::shared::{
+  enums::data_structures::{
+    evaluate_result_value::EvaluateResultValue, flat_compiled_styles_value::FlatCompiledStylesValue,
+  },
+  structures::types::FlatCompiledStyles,
+  utils::ast::helpers::namespace_name_from_prop_key,
+};
+
+/// The package the emitted declarations take `VarGroup` and `stylex.types`
+/// from. It is the one published with the types, whichever import source the
+/// file itself uses.
+const TYPES_PACKAGE: &str = "@stylexjs/stylex";
+
+/// One `defineVars` or `defineConsts` export, as its declaration describes it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ThemeDeclaration {
+  /// A `defineVars` export: each token with the TypeScript type of the values
+  /// it can hold.
+  Vars {
+    export_name: String,
+    tokens: Vec<(String, TokenType)>,
+  },
+  /// A `defineConsts` export: each constant with the literal type of the value
+  /// it compiled to.
+  Consts {
+    export_name: String,
+    constants: Vec<(String, String)>,
+  },
+}
+
+/// The type of the values a `defineVars` token can hold.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TokenType {
+  /// `string`, `number`, or both.
+  Untyped(Vec<&'static str>),
+  /// Declared with the `stylex.types` function of this name, such as `color`.
+  Typed(String),
+}
+
+impl TokenType {
+  fn render(&self) -> String {
+    match self {
+      TokenType::Untyped(kinds) if kinds.is_empty() => "string".to_string(),
+      TokenType::Untyped(kinds) => kinds.join(" | "),
+      TokenType::Typed(function) => format!("ReturnType<typeof stylex.types.{function}>"),
+    }
+  }
+}
+
+/// The declaration of a `defineVars` export, from the object its call
+/// evaluated to.
+///
+/// A token's type is `string`, `number`, or both, after the values it takes
+/// under every condition: a theme may override it with any other value of the
+/// same kind, so the literal it defaults to would be too narrow. A token typed
+/// with `stylex.types.color(…)` and its siblings keeps that kind, which is what
+/// lets `createTheme` insist on a typed override.
+pub(crate) fn vars_declaration(export_name: &str, value: &EvaluateResultValue) -> ThemeDeclaration {
+  let tokens = value
+    .as_expr()
+    .and_then(Expr::as_object)
+    .map(|object| {
+      object
+        .props
+        .iter()
+        .filter_map(|prop| {
+          let key_value = prop.as_prop()?.as_key_value()?;
+          let name = namespace_name_from_prop_key(&key_value.key)?;
+
+          Some((name.to_string(), token_type(&key_value.value)))
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+
+  ThemeDeclaration::Vars {
+    export_name: export_name.to_string(),
+    tokens,
+  }
+}
+
+/// The declaration of a `defineConsts` export, from the object it compiled to.
+/// A constant is inlined wherever it is read, so its exact value is its type.
+pub(crate) fn consts_declaration(
+  export_name: &str,
+  compiled: &FlatCompiledStyles,
+) -> ThemeDeclaration {
+  let constants = compiled
+    .iter()
+    .filter_map(|(name, value)| match value.as_ref() {
+      FlatCompiledStylesValue::String(value) => Some((name.clone(), string_literal(value))),
+      _ => None,
+    })
+    .collect();
+
+  ThemeDeclaration::Consts {
+    export_name: export_name.to_string(),
+    constants,
+  }
+}
+
+/// The text of a `.d.ts` file declaring `declarations`, or `None` when there
+/// are none.
+pub(crate) fn render_theme_declarations<'a>(
+  declarations: impl IntoIterator<Item = &'a ThemeDeclaration>,
+) -> Option<String> {
+  let declarations: Vec<&ThemeDeclaration> = declarations.into_iter().collect();
+
+  if declarations.is_empty() {
+    return None;
+  }
+
+  let has_vars = declarations
+    .iter()
+    .any(|declaration| matches!(declaration, ThemeDeclaration::Vars { .. }));
+  let has_typed_tokens = declarations.iter().any(|declaration| match declaration {
+    ThemeDeclaration::Vars { tokens, .. } => tokens
+      .iter()
+      .any(|(_, ty)| matches!(ty, TokenType::Typed(_))),
+    ThemeDeclaration::Consts { .. } => false,
+  });
+
+  let mut output = String::new();
+
+  if has_typed_tokens {
+    output.push_str(&format!(
+      "import type * as stylex from '{}';\n",
+      TYPES_PACKAGE
+    ));
+  }
+  if has_vars {
+    output.push_str(&format!(
+      "import type {{ VarGroup }} from '{}';\n",
+      TYPES_PACKAGE
+    ));
+  }
+
+  for declaration in declarations {
+    if !output.is_empty() {
+      output.push('\n');
+    }
+
+    match declaration {
+      ThemeDeclaration::Vars {
+        export_name,
+        tokens,
+      } => {
+        // The tag makes each group its own type, so a theme for one group is
+        // not accepted for another with the same token names.
+        output.push_str(&format!(
+          "declare const _{export_name}Tag: unique symbol;\n\
+           export declare const {export_name}: VarGroup<\n  \
+           Readonly<{{\n{}  }}>,\n  \
+           typeof _{export_name}Tag\n>;\n",
+          members(tokens.iter().map(|(name, ty)| (name, ty.render())), "    ")
+        ));
+      },
+      ThemeDeclaration::Consts {
+        export_name,
+        constants,
+      } => {
+        output.push_str(&format!(
+          "export declare const {export_name}: Readonly<{{\n{}}}>;\n",
+          members(constants.iter().map(|(name, ty)| (name, ty.clone())), "  ")
+        ));
+      },
+    }
+  }
+
+  Some(output)
+}
+
+fn members<'a>(entries: impl Iterator<Item = (&'a String, String)>, indent: &str) -> String {
+  entries
+    .map(|(name, ty)| format!("{indent}{}: {ty};\n", property_name(name)))
+    .collect()
+}
+
+/// The type of the values a token takes, read off its evaluated value.
+fn token_type(value: &Expr) -> TokenType {
+  if let Some(object) = value.as_object()
+    && let Some(function) = typed_syntax(object)
+  {
+    return TokenType::Typed(function.to_string());
+  }
+
+  let mut kinds = IndexSet::new();
+  collect_value_kinds(value, &mut kinds);
+
+  TokenType::Untyped(kinds.into_iter().collect())
+}
+
+/// The `stylex.types` function an evaluated `stylex.types.*(…)` call came
+/// from: `color` for a value tagged with the `<color>` syntax.
+fn typed_syntax(object: &ObjectLit) -> Option<&str> {
+  object.props.iter().find_map(|prop| {
+    let key_value = prop.as_prop()?.as_key_value()?;
+
+    if namespace_name_from_prop_key(&key_value.key)?.as_str() != "syntax" {
+      return None;
+    }
+
+    match key_value.value.as_ref() {
+      Expr::Lit(Lit::Str(syntax)) => syntax.value.as_str()?.strip_prefix('<')?.strip_suffix('>'),
+      _ => None,
+    }
+  })
+}
+
+/// `string` and `number` for the leaves of a value, in the order they first
+/// appear; conditions are walked through, and `null` adds nothing.
+fn collect_value_kinds(value: &Expr, kinds: &mut IndexSet<&'static str>) {
+  match value {
+    Expr::Lit(Lit::Num(_)) => {
+      kinds.insert("number");
+    },
+    Expr::Lit(Lit::Null(_)) => {},
+    Expr::Object(object) => {
+      for prop in &object.props {
+        if let Some(key_value) = prop.as_prop().and_then(|prop| prop.as_key_value()) {
+          collect_value_kinds(&key_value.value, kinds);
+        }
+      }
+    },
+    _ => {
+      kinds.insert("string");
+    },
+  }
+}
+
+/// `name` as an object type member: bare when it is an identifier, quoted
+/// otherwise.
+pub(crate) fn property_name(name: &str) -> String {
+  let mut chars = name.chars();
+  let is_identifier = chars
+    .next()
+    .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+    && chars.all(|rest| rest.is_ascii_alphanumeric() || rest == '_' || rest == '$');
+
+  if is_identifier {
+    name.to_string()
+  } else {
+    string_literal(name)
+  }
+}
+
+pub(crate) fn string_literal(value: &str) -> String {
+  let mut literal = String::with_capacity(value.len() + 2);
+  literal.push('\'');
+
+  for character in value.chars() {
+    match character {
+      '\\' => literal.push_str("\\\\"),
+      '\'' => literal.push_str("\\'"),
+      '\n' => literal.push_str("\\n"),
+      '\r' => literal.push_str("\\r"),
+      _ => literal.push(character),
+    }
+  }
+
+  literal.push('\'');
+  literal
+}
@@ -0,0 +1,339 @@
+use indexmap::{IndexMap, IndexSet};
+use rustc_hash::{FxHashMap, FxHashSet};
+use stylex_constants::constants::common::COMPILED_KEY;
+use stylex_enums::style_vars_to_keep::NonNullProp;
+use stylex_macros::diagnostic::SourceSpan;
+use stylex_types::structures::unused_styles::{UnusedNamespace, UnusedStyles, UnusedToken};
+use swc_core::{
+  common::{Span, Spanned},
+  ecma::{
+    ast::{
+      Decl, ExportDecl, ExportNamedSpecifier, Expr, Id, Ident, MemberExpr, MemberProp, Module,
+      ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Pat, Prop, PropOrSpread, Stmt, VarDecl,
+      VarDeclarator,
+    },
+    visit::{Visit, VisitWith},
+  },
+};
+
+use crate::shared::{
+  structures::state_manager::{
+    StateManager, add_decl_uses, member_namespace_name, reachable_decls,
+  },
+  utils::{
+    ast::helpers::namespace_name_from_prop_key,
+    log::build_code_frame_error::source_span_in,
+    validators::{is_create_call, is_define_vars_call},
+  },
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StyleDeclarationKind {
+  Create,
+  DefineVars,
+}
+
+/// A top-level binding of a `stylex.create` or `defineVars` result.
+struct StyleDeclaration {
+  kind: StyleDeclarationKind,
+  id: Id,
+  /// The keys written literally in the call's object argument, with the span
+  /// of each key.
+  keys: IndexMap<String, Span>,
+}
+
+/// How the module reads its `stylex.create` and `defineVars` bindings.
+///
+/// Collected before the producer cycle: by the time the module is finalized,
+/// the `vars.token` reads inside `stylex.create` calls have been evaluated
+/// away, and so have the `styles.key` reads that `props` inlined.
+#[derive(Default)]
+pub(crate) struct StyleReferences {
+  declarations: Vec<StyleDeclaration>,
+  /// The static keys read off each binding: `styles.root`, `styles['root']`.
+  reads: FxHashMap<Id, FxHashSet<String>>,
+  /// Bindings that may have any key read: read with a computed key, passed
+  /// around whole, destructured or exported by name.
+  escaped: FxHashSet<Id>,
+  /// Bindings declared with `export`.
+  exported: FxHashSet<Id>,
+}
+
+/// Records every `stylex.create` and `defineVars` result the module binds at
+/// the top level, and every way the module reads those bindings.
+///
+/// A read only counts from code the module runs: its top level, and the
+/// declarators the use graph of the declaration sweep reaches from there. A
+/// read inside a helper nothing calls leaves its namespace unused.
+pub(crate) fn collect_style_references(module: &Module, state: &StateManager) -> StyleReferences {
+  let mut references = StyleReferences::default();
+  let mut exported_decls: FxHashSet<Id> = FxHashSet::default();
+
+  for (var_decl, exported) in top_level_var_decls(module) {
+    for decl in &var_decl.decls {
+      if exported && let Pat::Ident(binding) = &decl.name {
+        exported_decls.insert(binding.id.to_id());
+      }
+
+      if let Some(declaration) = style_declaration(decl, state) {
+        if exported {
+          references.exported.insert(declaration.id.clone());
+        }
+
+        references.declarations.push(declaration);
+      }
+    }
+  }
+
+  if references.declarations.is_empty() {
+    return references;
+  }
+
+  let mut decl_uses = FxHashMap::default();
+  let mut roots = exported_decls;
+  add_decl_uses(module, &mut decl_uses, &mut roots);
+  let live = reachable_decls(&roots, &decl_uses);
+
+  let tracked: FxHashSet<Id> = references
+    .declarations
+    .iter()
+    .map(|declaration| declaration.id.clone())
+    .collect();
+  let mut collector = StyleReadsCollector {
+    tracked: &tracked,
+    references: &mut references,
+  };
+
+  for item in &module.body {
+    let var_decl = match item {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => var_decl,
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        decl: Decl::Var(var_decl),
+        ..
+      })) => var_decl,
+      _ => {
+        item.visit_with(&mut collector);
+        continue;
+      },
+    };
+
+    for decl in &var_decl.decls {
+      let is_live = match &decl.name {
+        Pat::Ident(binding) => live.contains(&binding.id.to_id()),
+        // Left out of the graph, so kept by the sweep.
+        _ => true,
+      };
+
+      if is_live {
+        decl.visit_with(&mut collector);
+      }
+    }
+  }
+
+  references
+}
+
+/// The top-level `var`, `let` and `const` declarations of `module`, and
+/// whether each is exported.
+fn top_level_var_decls(module: &Module) -> impl Iterator<Item = (&VarDecl, bool)> {
+  module.body.iter().filter_map(|item| match item {
+    ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => Some((var_decl.as_ref(), false)),
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => export_decl
+      .decl
+      .as_var()
+      .map(|var_decl| (var_decl.as_ref(), true)),
+    _ => None,
+  })
+}
+
+fn style_declaration(decl: &VarDeclarator, state: &StateManager) -> Option<StyleDeclaration> {
+  let Pat::Ident(binding) = &decl.name else {
+    return None;
+  };
+  let call = decl.init.as_deref()?.as_call()?;
+
+  let kind = if is_create_call(call, state) {
+    StyleDeclarationKind::Create
+  } else if is_define_vars_call(call, state) {
+    StyleDeclarationKind::DefineVars
+  } else {
+    return None;
+  };
+
+  let keys = match call.args.first().map(|arg| arg.expr.as_ref()) {
+    Some(Expr::Object(object)) if call.args[0].spread.is_none() => literal_keys(object),
+    _ => IndexMap::new(),
+  };
+
+  Some(StyleDeclaration {
+    kind,
+    id: binding.id.to_id(),
+    keys,
+  })
+}
+
+fn literal_keys(object: &ObjectLit) -> IndexMap<String, Span> {
+  object
+    .props
+    .iter()
+    .filter_map(|prop| match prop {
+      PropOrSpread::Prop(prop) => match prop.as_ref() {
+        Prop::KeyValue(key_value) => Some(&key_value.key),
+        Prop::Method(method) => Some(&method.key),
+        _ => None,
+      },
+      PropOrSpread::Spread(_) => None,
+    })
+    .filter_map(|key| Some((namespace_name_from_prop_key(key)?.to_string(), key.span())))
+    .collect()
+}
+
+struct StyleReadsCollector<'a> {
+  tracked: &'a FxHashSet<Id>,
+  references: &'a mut StyleReferences,
+}
+
+impl Visit for StyleReadsCollector<'_> {
+  fn visit_member_expr(&mut self, member: &MemberExpr) {
+    if let Expr::Ident(ident) = member.obj.as_ref()
+      && self.tracked.contains(&ident.to_id())
+    {
+      match member_namespace_name(&member.prop) {
+        Some(NonNullProp::Atom(key)) => {
+          self
+            .references
+            .reads
+            .entry(ident.to_id())
+            .or_default()
+            .insert(key.to_string());
+        },
+        Some(NonNullProp::True) => {
+          self.references.escaped.insert(ident.to_id());
+        },
+        None => {},
+      }
+
+      if let MemberProp::Computed(computed) = &member.prop {
+        computed.visit_with(self);
+      }
+      return;
+    }
+
+    member.visit_children_with(self);
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if self.tracked.contains(&ident.to_id()) {
+      self.references.escaped.insert(ident.to_id());
+    }
+  }
+
+  fn visit_var_declarator(&mut self, decl: &VarDeclarator) {
+    // The binding a declaration introduces is not a read of it.
+    if !decl.name.is_ident() {
+      decl.name.visit_with(self);
+    }
+    decl.init.visit_with(self);
+  }
+
+  fn visit_export_named_specifier(&mut self, specifier: &ExportNamedSpecifier) {
+    if let ModuleExportName::Ident(ident) = &specifier.orig
+      && self.tracked.contains(&ident.to_id())
+    {
+      self.references.exported.insert(ident.to_id());
+    }
+  }
+}
+
+/// Compares what the module declared against what it read, once the
+/// transform has compiled every `stylex.create` call.
+///
+/// A `stylex.create` binding that is exported or escapes is never reported:
+/// any of its namespaces may be read where this file cannot see. A
+/// `defineVars` binding is always exported, so only escaping counts for it.
+pub(crate) fn find_unused_styles(
+  references: &StyleReferences,
+  state: &StateManager,
+) -> UnusedStyles {
+  let generated: FxHashSet<&str> = state
+    .metadata()
+    .values()
+    .flatten()
+    .map(|metadata| metadata.get_class_name())
+    .collect();
+
+  let mut unused = UnusedStyles::default();
+  let mut live_class_names: FxHashSet<String> = FxHashSet::default();
+  let mut unused_class_names: IndexSet<String> = IndexSet::new();
+
+  for declaration in &references.declarations {
+    let variable = declaration.id.0.to_string();
+    let escaped = references.escaped.contains(&declaration.id);
+    let reads = references.reads.get(&declaration.id);
+    let is_read = |key: &str| reads.is_some_and(|reads| reads.contains(key));
+
+    match declaration.kind {
+      StyleDeclarationKind::Create => {
+        let Some(namespaces) = state.style_map.get(&variable) else {
+          continue;
+        };
+        let may_be_read_anywhere = escaped || references.exported.contains(&declaration.id);
+
+        for (namespace, styles) in namespaces.iter() {
+          let class_names: Vec<String> = styles
+            .iter()
+            .filter(|(property, _)| property.as_str() != COMPILED_KEY)
+            .filter_map(|(_, value)| value.as_string())
+            .flat_map(|value| value.split_whitespace())
+            .filter(|class_name| generated.contains(class_name))
+            .map(str::to_string)
+            .collect();
+
+          if may_be_read_anywhere || is_read(namespace) {
+            live_class_names.extend(class_names);
+            continue;
+          }
+
+          unused_class_names.extend(class_names.iter().cloned());
+          unused.namespaces.push(UnusedNamespace {
+            variable: variable.clone(),
+            namespace: namespace.clone(),
+            span: declaration
+              .keys
+              .get(namespace)
+              .and_then(|span| source_span(*span, state)),
+            class_names,
+          });
+        }
+      },
+      StyleDeclarationKind::DefineVars => {
+        if escaped {
+          continue;
+        }
+
+        for (token, span) in &declaration.keys {
+          if !is_read(token) {
+            unused.tokens.push(UnusedToken {
+              variable: variable.clone(),
+              token: token.clone(),
+              span: source_span(*span, state),
+            });
+          }
+        }
+      },
+    }
+  }
+
+  unused.dead_class_names = unused_class_names
+    .into_iter()
+    .filter(|class_name| !live_class_names.contains(class_name))
+    .collect();
+
+  unused
+}
+
+/// Where `span` lies in the file the host parsed, when it handed the file
+/// over.
+pub(crate) fn source_span(span: Span, state: &StateManager) -> Option<SourceSpan> {
+  source_span_in(state.input_source_file.as_ref()?, span)
+}
@@ -19,16 +19,13 @@ use indexmap::IndexMap;
 use log::{debug, warn};
 use rustc_hash::{FxHashMap, FxHashSet};
 use stylex_macros::{stylex_panic, stylex_unreachable, unwrap_or_panic};
-use swc_core::{
-  atoms::Atom,
-  ecma::{
-    ast::{
-      ArrayLit, BlockStmtOrExpr, CallExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Ident,
-      ImportSpecifier, KeyValueProp, Lit, MemberProp, ModuleExportName, Number, ObjectLit,
-      OptChainBase, Pat, Prop, PropName, PropOrSpread, TplElement, VarDeclarator,
-    },
-    utils::ident::IdentLike,
+use swc_core::ecma::{
+  ast::{
+    ArrayLit, BlockStmtOrExpr, CallExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Ident,
+    ImportSpecifier, KeyValueProp, Lit, MemberProp, ModuleExportName, Number, ObjectLit,
+    OptChainBase, Pat, Prop, PropName, PropOrSpread, TplElement, VarDeclarator,
   },
+  utils::ident::IdentLike,
 };
 
 use crate::shared::{
@@ -40,7 +37,6 @@ use crate::shared::{
     state::EvaluationState,
     state_manager::{StateManager, add_import_expression},
     theme_ref::ThemeRef,
-    types::{FunctionMapIdentifiers, FunctionMapMemberExpression},
   },
   utils::{
     ast::convertors::{
@@ -77,7 +73,6 @@ use stylex_constants::constants::{
   },
 };
 use stylex_enums::{
-  import_path_resolution::ImportPathResolution,
   js::{ArrayJS, CallableGlobalJS, MathJS, ObjectJS, StringJS},
   misc::BinaryExprType,
   value_with_default::ValueWithDefault,
@@ -287,7 +282,9 @@ fn _evaluate(
   }
 
   let result: Option<EvaluateResultValue> = match normalized_path {
-    Expr::Arrow(arrow) => nodes::arrow_function::evaluate(arrow, state),
+    // A function expression written in place has no value of its own, as in
+    // the reference implementation; one a binding holds is still called.
+    Expr::Arrow(_) => nodes::function::evaluate(normalized_path, state),
     Expr::Ident(ident) => nodes::identifier::evaluate(ident, state),
     Expr::TsSatisfies(ts_satisfaies) => {
       nodes::typescript_expression::evaluate(&ts_satisfaies.expr, state, traversal_state, fns)
@@ -460,48 +457,52 @@ fn _evaluate(
 
       let imported = imported.unwrap_or_else(|| ModuleExportName::Ident(local_name.clone()));
 
-      let abs_path = traversal_state.import_path_resolver(
-        convert_atom_to_str_ref(&import_path.src.value),
-        &mut FxHashMap::default(),
-      );
+      let import_path_src = convert_atom_to_string(&import_path.src.value);
 
       let imported_name = match imported {
         ModuleExportName::Ident(ident) => ident.sym.to_string(),
         ModuleExportName::Str(strng) => convert_atom_to_string(&strng.value),
       };
 
-      let return_value = match abs_path {
-        ImportPathResolution::Resolved { path: value } => {
-          evaluate_theme_ref(&value, imported_name, traversal_state)
-        },
-        ImportPathResolution::Unresolved => {
-          return deopt(path, state, IMPORT_PATH_RESOLUTION_ERROR);
-        },
+      let Some(theme_import) =
+        traversal_state.resolve_theme_import(&import_path_src, &mut FxHashMap::default())
+      else {
+        return deopt(path, state, IMPORT_PATH_RESOLUTION_ERROR);
       };
 
-      if state.confident {
-        let import_path_src = convert_atom_to_string(&import_path.src.value);
-
-        if !state.added_imports.contains(&import_path_src)
-          && traversal_state.get_treeshake_compensation()
-        {
-          let prepend_import_module_item = add_import_expression(&import_path_src);
-          // Theme side-effect imports go under ThemeImports — the slot
-          // whose flush position matches the legacy
-          // `prepend_import_module_items` placement (between the
-          // runtime helpers and the existing import block,
-          // regardless of producer queue order). Dedup is by stable
-          // hash on the StateManager so it survives across
-          // evaluations.
-          traversal_state.queue_theme_import_if_absent(prepend_import_module_item);
-
-          state.added_imports.insert(import_path_src);
+      if let Some(exported_value) = theme_import
+        .file_path
+        .as_deref()
+        .and_then(|file_path| traversal_state.resolve_cross_file_export(file_path, &imported_name))
+      {
+        let result = evaluate_cached(&exported_value, state, traversal_state, fns);
+
+        if state.confident {
+          add_theme_side_effect_import(&import_path_src, state, traversal_state);
         }
 
+        return result;
+      }
+
+      let return_value =
+        evaluate_theme_ref(&theme_import.canonical_path, imported_name, traversal_state);
+
+      if state.confident {
+        add_theme_side_effect_import(&import_path_src, state, traversal_state);
+
         return Some(EvaluateResultValue::ThemeRef(return_value));
       }
     }
 
+    // A function declaration is hoisted, so its name is the function wherever
+    // it is read — unless the module reassigns it.
+    if !traversal_state.has_binding_write(ident)
+      && let Some(function) = nodes::function::top_level_function(ident, traversal_state)
+      && let Some(callback) = nodes::function::evaluate(&function, state)
+    {
+      return Some(callback);
+    }
+
     return check_ident_declaration(
       ident,
       &[
@@ -530,6 +531,32 @@ fn _evaluate(
   result
 }
 
+/// Keeps the imported theme file in the output when tree-shake compensation is
+/// on, so its injected styles still run once every reference to it has been
+/// folded away.
+fn add_theme_side_effect_import(
+  import_path_src: &str,
+  state: &mut EvaluationState,
+  traversal_state: &mut StateManager,
+) {
+  if state.added_imports.contains(import_path_src) || !traversal_state.get_treeshake_compensation()
+  {
+    return;
+  }
+
+  let prepend_import_module_item = add_import_expression(import_path_src);
+  // Theme side-effect imports go under ThemeImports — the slot
+  // whose flush position matches the legacy
+  // `prepend_import_module_items` placement (between the
+  // runtime helpers and the existing import block,
+  // regardless of producer queue order). Dedup is by stable
+  // hash on the StateManager so it survives across
+  // evaluations.
+  traversal_state.queue_theme_import_if_absent(prepend_import_module_item);
+
+  state.added_imports.insert(import_path_src.to_string());
+}
+
 #[cfg(test)]
 #[path = "tests/source_evaluation.rs"]
 pub(crate) mod source_evaluation;
@@ -1,4 +1,5 @@
 use super::super::*;
+use super::function;
 use crate::deopt_unsupported;
 use stylex_ast::ast::convertors::get_key_values_from_object;
 use stylex_utils::math::js_math_round;
@@ -153,7 +154,12 @@ pub(in super::super) fn evaluate(
   let mut func: Option<Box<FunctionConfig>> = None;
 
   if let Callee::Expr(callee_expr) = &call.callee {
-    if get_binding(callee_expr, traversal_state).is_none() && is_valid_callee(callee_expr) {
+    if get_binding(callee_expr, traversal_state).is_none()
+      && !callee_expr
+        .as_ident()
+        .is_some_and(|ident| traversal_state.top_level_function(ident).is_some())
+      && is_valid_callee(callee_expr)
+    {
       // A valid callee with no binding in scope is the global itself, not a
       // function the module declared, so calling it folds.
       let callee_name = get_callee_name(callee_expr);
@@ -191,6 +197,21 @@ pub(in super::super) fn evaluate(
             return deopt(path, state, NON_CONSTANT);
           },
         }
+      } else if let Some(function) = function::resolve(ident, traversal_state)
+        && let Some(local_function) = function::LocalFunction::from_expr(&function)
+      {
+        // A function the module declares is called here rather than handed
+        // back as a callback, so a body that cannot fold deopts with its own
+        // reason and a call inside the body can call in turn.
+        let args = evaluate_func_call_args(call, state, traversal_state, fns)?;
+
+        if !state.confident {
+          return None;
+        }
+
+        let scope = function::module_scope(state, traversal_state);
+
+        return function::call(&local_function, &scope, &args, path, state, traversal_state);
       } else {
         let _maybe_function = evaluate_cached(callee_expr, state, traversal_state, fns);
 
@@ -0,0 +1,742 @@
+use super::super::*;
+use std::mem;
+use stylex_constants::constants::evaluation_errors::{
+  UNCOMPARABLE_SWITCH_CASE, UNREADABLE_DESTRUCTURING, function_calls_too_deep,
+};
+use swc_core::ecma::ast::{
+  ArrowExpr, BlockStmt, Decl, FnExpr, ObjectPat, ObjectPatProp, Stmt, SwitchStmt, VarDeclKind,
+};
+
+/// How many calls to module functions can be in progress at once. Each call
+/// nests a full pass of the evaluator on the stack, so this is set by the
+/// stack of a test thread in a debug build rather than by any real helper,
+/// which rarely calls more than two or three deep.
+const MAX_FUNCTION_CALL_DEPTH: usize = 16;
+
+/// A function the module declares, in the shape a call needs: the parameters
+/// to bind and the body to run.
+pub(in super::super) struct LocalFunction<'a> {
+  params: Vec<&'a Pat>,
+  body: LocalFunctionBody<'a>,
+}
+
+enum LocalFunctionBody<'a> {
+  Expr(&'a Expr),
+  Block(&'a BlockStmt),
+}
+
+impl<'a> LocalFunction<'a> {
+  /// `None` for a function whose call does not produce its value — an `async`
+  /// function answers a promise and a generator an iterator — and for an
+  /// overload signature, which has no body to run.
+  pub(in super::super) fn from_expr(function: &'a Expr) -> Option<Self> {
+    match function {
+      Expr::Arrow(arrow) if !arrow.is_async && !arrow.is_generator => Some(Self {
+        params: arrow.params.iter().collect(),
+        body: match arrow.body.as_ref() {
+          BlockStmtOrExpr::Expr(expr) => LocalFunctionBody::Expr(expr),
+          BlockStmtOrExpr::BlockStmt(block) => LocalFunctionBody::Block(block),
+        },
+      }),
+      Expr::Fn(FnExpr { function, .. }) if !function.is_async && !function.is_generator => {
+        Some(Self {
+          params: function.params.iter().map(|param| &param.pat).collect(),
+          body: LocalFunctionBody::Block(function.body.as_ref()?),
+        })
+      },
+      _ => None,
+    }
+  }
+}
+
+/// The function a callee names, when it is one the module declares and never
+/// reassigns: a binding initialised with an arrow or function expression, or a
+/// top-level function declaration — which is hoisted, so it may be declared
+/// after the call that uses it.
+pub(in super::super) fn resolve(ident: &Ident, traversal_state: &StateManager) -> Option<Expr> {
+  if traversal_state.has_binding_write(ident) {
+    return None;
+  }
+
+  if let Some(var_decl) = get_var_decl_from(traversal_state, ident) {
+    let init = normalize_expr(var_decl.init.as_deref()?);
+
+    return matches!(init, Expr::Arrow(_) | Expr::Fn(_)).then(|| init.clone());
+  }
+
+  top_level_function(ident, traversal_state)
+}
+
+/// A top-level function declaration, as the function expression a call runs.
+pub(in super::super) fn top_level_function(
+  ident: &Ident,
+  traversal_state: &StateManager,
+) -> Option<Expr> {
+  traversal_state.top_level_function(ident).map(|fn_decl| {
+    Expr::Fn(FnExpr {
+      ident: Some(fn_decl.ident.clone()),
+      function: fn_decl.function.clone(),
+    })
+  })
+}
+
+/// A function as a value: a callback the array methods, and a style object
+/// holding a call, apply to their arguments.
+///
+/// `None` for a function [`LocalFunction`] cannot run, which the caller deopts
+/// on.
+pub(in super::super) fn evaluate(
+  function: &Expr,
+  state: &EvaluationState,
+) -> Option<EvaluateResultValue> {
+  LocalFunction::from_expr(function)?;
+
+  let function = function.clone();
+  let functions = Rc::clone(&state.functions);
+
+  // A body that does not fold hands back the body itself, which is what an
+  // unevaluated arrow already does when it produces no value at all; a block
+  // has no single expression to hand back, so it hands back the function. A
+  // callback cannot record a deopt — it answers an `Expr` — so falling back is
+  // how it refuses, and aborting here would fail a build over a callback that
+  // was only ever going to run at runtime.
+  let fallback = match &function {
+    Expr::Arrow(ArrowExpr { body, .. }) => match body.as_ref() {
+      BlockStmtOrExpr::Expr(body_expr) => *body_expr.clone(),
+      BlockStmtOrExpr::BlockStmt(_) => function.clone(),
+    },
+    _ => function.clone(),
+  };
+
+  let callback = move |args: Vec<EvaluateResultValue>, traversal_state: &mut StateManager| {
+    let Some(local_function) = LocalFunction::from_expr(&function) else {
+      return fallback.clone();
+    };
+
+    let mut state = EvaluationState {
+      functions: Rc::clone(&functions),
+      ..EvaluationState::new()
+    };
+
+    match call(
+      &local_function,
+      &functions,
+      &args,
+      &function,
+      &mut state,
+      traversal_state,
+    ) {
+      Some(EvaluateResultValue::Expr(expr)) => expr,
+      Some(EvaluateResultValue::Vec(items)) => {
+        evaluate_result_vec_to_array_expr(&items).unwrap_or_else(|| fallback.clone())
+      },
+      _ => fallback.clone(),
+    }
+  };
+
+  Some(EvaluateResultValue::Callback(Rc::new(callback)))
+}
+
+/// Calls a module function with arguments already evaluated, folding to what
+/// it returns.
+///
+/// `scope` is what the names the body does not bind itself mean: the bindings
+/// of the module for a function the module declares, and those of the call it
+/// was written in for a callback. Never the caller's, so a parameter of the
+/// caller does not shadow a module constant the callee reads.
+///
+/// The body runs against a cache of its own. The evaluator memoises a path by
+/// its shape, and inside a function the same shape means a different value on
+/// every call — `n * 4` is `8` for `spacing(2)` and `12` for `spacing(3)` — so
+/// entries made here are dropped when the call returns, and the caller's are
+/// out of reach until it does. Within the call the cache is cleared again
+/// whenever a name is bound or a block ends, since either changes what an
+/// identifier already looked up refers to.
+///
+/// Deopts are the caller's: a statement or value the body cannot fold records
+/// its reason on `state`, exactly as an expression in the caller would.
+pub(in super::super) fn call(
+  function: &LocalFunction,
+  scope: &Rc<FunctionMap>,
+  args: &[EvaluateResultValue],
+  path: &Expr,
+  state: &mut EvaluationState,
+  traversal_state: &mut StateManager,
+) -> Option<EvaluateResultValue> {
+  if traversal_state.function_call_depth >= MAX_FUNCTION_CALL_DEPTH {
+    return deopt(
+      path,
+      state,
+      &function_calls_too_deep(MAX_FUNCTION_CALL_DEPTH),
+    );
+  }
+
+  let guard = CallGuard::enter(traversal_state, scope);
+
+  let mut frame = Frame {
+    functions: (**scope).clone(),
+    path,
+  };
+
+  frame.call(function, args, state, guard.traversal_state)
+}
+
+/// The bindings a function the module declares closes over.
+pub(in super::super) fn module_scope(
+  state: &EvaluationState,
+  traversal_state: &StateManager,
+) -> Rc<FunctionMap> {
+  traversal_state
+    .module_functions
+    .clone()
+    .unwrap_or_else(|| Rc::clone(&state.functions))
+}
+
+/// The caller's share of the traversal state, set aside while a call runs and
+/// put back when it ends — by unwinding as well, since a body can panic on a
+/// style error the transform reports.
+struct CallGuard<'a> {
+  traversal_state: &'a mut StateManager,
+  seen: FxHashMap<u64, Rc<SeenValue>>,
+  module_functions: Option<Rc<FunctionMap>>,
+}
+
+impl<'a> CallGuard<'a> {
+  fn enter(traversal_state: &'a mut StateManager, scope: &Rc<FunctionMap>) -> Self {
+    let seen = mem::take(&mut traversal_state.seen);
+    let module_functions = traversal_state.module_functions.clone();
+
+    traversal_state.function_call_depth += 1;
+    traversal_state
+      .module_functions
+      .get_or_insert_with(|| Rc::clone(scope));
+
+    Self {
+      traversal_state,
+      seen,
+      module_functions,
+    }
+  }
+}
+
+impl Drop for CallGuard<'_> {
+  fn drop(&mut self) {
+    self.traversal_state.function_call_depth -= 1;
+    self.traversal_state.seen = mem::take(&mut self.seen);
+    self.traversal_state.module_functions = self.module_functions.take();
+  }
+}
+
+/// How a statement finished, for the statement list around it to act on.
+enum Completion {
+  Normal,
+  Break,
+  Return(EvaluateResultValue),
+}
+
+/// One call in progress: the names bound so far, and the call to blame when
+/// something in the body cannot be folded.
+struct Frame<'p> {
+  functions: FunctionMap,
+  path: &'p Expr,
+}
+
+impl Frame<'_> {
+  fn call(
+    &mut self,
+    function: &LocalFunction,
+    args: &[EvaluateResultValue],
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<EvaluateResultValue> {
+    for (index, param) in function.params.iter().enumerate() {
+      if let Pat::Rest(rest) = param {
+        let rest_args = args.get(index..).unwrap_or_default().to_vec();
+        self.bind_pattern(
+          &rest.arg,
+          EvaluateResultValue::Vec(rest_args),
+          state,
+          traversal_state,
+        )?;
+        break;
+      }
+
+      let arg = args.get(index).cloned().unwrap_or_else(js_undefined);
+      self.bind_pattern(param, arg, state, traversal_state)?;
+    }
+
+    match function.body {
+      LocalFunctionBody::Expr(body) => self.evaluate(body, state, traversal_state),
+      LocalFunctionBody::Block(block) => {
+        match self.run_statements(&block.stmts, state, traversal_state)? {
+          Completion::Return(value) => Some(value),
+          // Falling off the end of a body returns `undefined`. A `break` cannot
+          // reach here: the parser refuses one outside a `switch` or a loop,
+          // and a loop deopts before its body runs.
+          Completion::Normal | Completion::Break => Some(js_undefined()),
+        }
+      },
+    }
+  }
+
+  /// Evaluates an expression against the names bound so far.
+  fn evaluate(
+    &self,
+    expr: &Expr,
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<EvaluateResultValue> {
+    let functions = Rc::new(self.functions.clone());
+    let outer = mem::replace(&mut state.functions, Rc::clone(&functions));
+
+    let value = evaluate_cached(expr, state, traversal_state, &functions);
+
+    state.functions = outer;
+
+    if !state.confident {
+      return None;
+    }
+
+    match value {
+      Some(value) => Some(value),
+      None => deopt(
+        expr,
+        state,
+        &unsupported_expression(get_expr_node_kind(expr)),
+      ),
+    }
+  }
+
+  fn run_statements(
+    &mut self,
+    stmts: &[Stmt],
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<Completion> {
+    for stmt in stmts {
+      match self.run_statement(stmt, state, traversal_state)? {
+        Completion::Normal => {},
+        completion => return Some(completion),
+      }
+    }
+
+    Some(Completion::Normal)
+  }
+
+  fn run_statement(
+    &mut self,
+    stmt: &Stmt,
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<Completion> {
+    match stmt {
+      // `var` is scoped to the function rather than the block it is written
+      // in, and is left to the runtime rather than modelled.
+      Stmt::Decl(Decl::Var(var_decl)) if var_decl.kind != VarDeclKind::Var => {
+        for declarator in &var_decl.decls {
+          let value = match &declarator.init {
+            Some(init) => self.evaluate(init, state, traversal_state)?,
+            None => js_undefined(),
+          };
+
+          self.bind_pattern(&declarator.name, value, state, traversal_state)?;
+        }
+
+        Some(Completion::Normal)
+      },
+      // Types only, with nothing to run.
+      Stmt::Decl(Decl::TsInterface(_) | Decl::TsTypeAlias(_)) | Stmt::Empty(_) => {
+        Some(Completion::Normal)
+      },
+      Stmt::Return(return_stmt) => Some(Completion::Return(match &return_stmt.arg {
+        Some(arg) => self.evaluate(arg, state, traversal_state)?,
+        None => js_undefined(),
+      })),
+      Stmt::If(if_stmt) => {
+        let test = self.evaluate(&if_stmt.test, state, traversal_state)?;
+
+        match evaluate_result_to_js_boolean(&test) {
+          Some(true) => self.run_statement(&if_stmt.cons, state, traversal_state),
+          Some(false) => match &if_stmt.alt {
+            Some(alt) => self.run_statement(alt, state, traversal_state),
+            None => Some(Completion::Normal),
+          },
+          None => {
+            deopt(&if_stmt.test, state, NON_CONSTANT);
+
+            None
+          },
+        }
+      },
+      Stmt::Block(block) => self.run_block(&block.stmts, state, traversal_state),
+      Stmt::Switch(switch) => self.run_switch(switch, state, traversal_state),
+      Stmt::Break(break_stmt) if break_stmt.label.is_none() => Some(Completion::Break),
+      _ => {
+        deopt(
+          self.path,
+          state,
+          &unsupported_expression(statement_kind(stmt)),
+        );
+
+        None
+      },
+    }
+  }
+
+  /// Runs statements in a scope of their own, so a name they bind is gone once
+  /// they finish.
+  fn run_block(
+    &mut self,
+    stmts: &[Stmt],
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<Completion> {
+    let outer = self.functions.identifiers.clone();
+
+    let completion = self.run_statements(stmts, state, traversal_state);
+
+    self.functions.identifiers = outer;
+    traversal_state.seen.clear();
+
+    completion
+  }
+
+  /// Tests the cases in order until one is strictly equal to the value, falls
+  /// back to `default` wherever it is written, and runs from there until a
+  /// `break` or a `return` — falling through the cases in between, as the
+  /// language does.
+  fn run_switch(
+    &mut self,
+    switch: &SwitchStmt,
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<Completion> {
+    let discriminant = self.evaluate(&switch.discriminant, state, traversal_state)?;
+
+    let mut matched = None;
+
+    for (index, case) in switch.cases.iter().enumerate() {
+      let Some(test) = &case.test else {
+        continue;
+      };
+
+      let test_value = self.evaluate(test, state, traversal_state)?;
+
+      match discriminant
+        .as_expr()
+        .zip(test_value.as_expr())
+        .and_then(|(discriminant, test_value)| coercions::strict_equals(discriminant, test_value))
+      {
+        Some(true) => {
+          matched = Some(index);
+          break;
+        },
+        Some(false) => {},
+        None => {
+          deopt(test, state, UNCOMPARABLE_SWITCH_CASE);
+
+          return None;
+        },
+      }
+    }
+
+    let Some(start) = matched.or_else(|| switch.cases.iter().position(|case| case.test.is_none()))
+    else {
+      return Some(Completion::Normal);
+    };
+
+    // Every case shares the one scope the braces of a `switch` open.
+    let outer = self.functions.identifiers.clone();
+    let mut completion = Some(Completion::Normal);
+
+    for case in &switch.cases[start..] {
+      match self.run_statements(&case.cons, state, traversal_state) {
+        Some(Completion::Normal) => {},
+        Some(Completion::Break) => break,
+        other => {
+          completion = other;
+          break;
+        },
+      }
+    }
+
+    self.functions.identifiers = outer;
+    traversal_state.seen.clear();
+
+    completion
+  }
+
+  fn bind_pattern(
+    &mut self,
+    pat: &Pat,
+    value: EvaluateResultValue,
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<()> {
+    match pat {
+      Pat::Ident(binding) => self.bind(&binding.id, value, state, traversal_state),
+      // A default stands in for `undefined` only, not for `null`.
+      Pat::Assign(assign) => {
+        let value = if is_undefined(&value) {
+          self.evaluate(&assign.right, state, traversal_state)?
+        } else {
+          value
+        };
+
+        self.bind_pattern(&assign.left, value, state, traversal_state)
+      },
+      Pat::Object(object) => self.bind_object_pattern(object, &value, state, traversal_state),
+      Pat::Array(array) => {
+        let Some(items) = array_items(&value) else {
+          deopt(self.path, state, UNREADABLE_DESTRUCTURING);
+
+          return None;
+        };
+
+        for (index, elem) in array.elems.iter().enumerate() {
+          match elem {
+            None => {},
+            Some(Pat::Rest(rest)) => {
+              let rest_items = items.get(index..).unwrap_or_default().to_vec();
+
+              return self.bind_pattern(
+                &rest.arg,
+                EvaluateResultValue::Vec(rest_items),
+                state,
+                traversal_state,
+              );
+            },
+            Some(elem) => {
+              let item = items.get(index).cloned().unwrap_or_else(js_undefined);
+
+              self.bind_pattern(elem, item, state, traversal_state)?;
+            },
+          }
+        }
+
+        Some(())
+      },
+      Pat::Rest(_) | Pat::Expr(_) | Pat::Invalid(_) => {
+        deopt(self.path, state, UNREADABLE_DESTRUCTURING);
+
+        None
+      },
+    }
+  }
+
+  fn bind_object_pattern(
+    &mut self,
+    object: &ObjectPat,
+    value: &EvaluateResultValue,
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<()> {
+    let Some(entries) = object_entries(value) else {
+      deopt(self.path, state, UNREADABLE_DESTRUCTURING);
+
+      return None;
+    };
+
+    let mut taken = Vec::with_capacity(object.props.len());
+
+    for prop in &object.props {
+      match prop {
+        ObjectPatProp::KeyValue(key_value) => {
+          let key = match &key_value.key {
+            PropName::Computed(ComputedPropName { expr, .. }) => {
+              let key = self.evaluate(expr, state, traversal_state)?;
+
+              match evaluate_result_to_js_string(&key) {
+                Some(key) => key,
+                None => {
+                  deopt(expr, state, ILLEGAL_PROP_VALUE);
+
+                  return None;
+                },
+              }
+            },
+            key => match convert_key_to_string(key) {
+              Some(key) => key,
+              None => {
+                deopt(self.path, state, UNREADABLE_DESTRUCTURING);
+
+                return None;
+              },
+            },
+          };
+
+          let value = entry_value(&entries, &key);
+          taken.push(key);
+
+          self.bind_pattern(&key_value.value, value, state, traversal_state)?;
+        },
+        ObjectPatProp::Assign(assign) => {
+          let key = assign.key.sym.to_string();
+
+          let mut value = entry_value(&entries, &key);
+          taken.push(key);
+
+          if is_undefined(&value)
+            && let Some(default) = &assign.value
+          {
+            value = self.evaluate(default, state, traversal_state)?;
+          }
+
+          self.bind(&assign.key.id, value, state, traversal_state)?;
+        },
+        ObjectPatProp::Rest(rest) => {
+          let remaining = entries
+            .iter()
+            .filter(|(key, _)| !taken.contains(key))
+            .map(|(key, value)| create_ident_key_value_prop(key, value.clone()))
+            .collect::<Vec<PropOrSpread>>();
+
+          self.bind_pattern(
+            &rest.arg,
+            EvaluateResultValue::Expr(create_object_expression(remaining)),
+            state,
+            traversal_state,
+          )?;
+        },
+      }
+    }
+
+    Some(())
+  }
+
+  /// Binds a name for the rest of its scope. The name's value has to have an
+  /// expression form, which is how every binding the evaluator holds is read
+  /// back.
+  fn bind(
+    &mut self,
+    ident: &Ident,
+    value: EvaluateResultValue,
+    state: &mut EvaluationState,
+    traversal_state: &mut StateManager,
+  ) -> Option<()> {
+    let expr = match value {
+      EvaluateResultValue::Expr(expr) => Some(expr),
+      EvaluateResultValue::Null => js_undefined().as_expr().cloned(),
+      EvaluateResultValue::Vec(items) => evaluate_result_vec_to_array_expr(&items),
+      _ => None,
+    };
+
+    let Some(expr) = expr else {
+      deopt(self.path, state, ARGUMENT_NOT_EXPRESSION);
+
+      return None;
+    };
+
+    self.functions.identifiers.insert(
+      ident.sym.clone(),
+      Box::new(FunctionConfigType::Regular(FunctionConfig {
+        fn_ptr: FunctionType::Mapper(Rc::new(move || expr.clone())),
+        takes_path: false,
+      })),
+    );
+
+    traversal_state.seen.clear();
+
+    Some(())
+  }
+}
+
+fn is_undefined(value: &EvaluateResultValue) -> bool {
+  match value {
+    EvaluateResultValue::Expr(expr) => {
+      evaluate_result_is_nullish(value) && !matches!(expr, Expr::Lit(Lit::Null(_)))
+    },
+    EvaluateResultValue::Null => true,
+    _ => false,
+  }
+}
+
+/// The elements of an array a pattern takes apart, with a hole read as the
+/// `undefined` it holds.
+fn array_items(value: &EvaluateResultValue) -> Option<Vec<EvaluateResultValue>> {
+  match value {
+    EvaluateResultValue::Vec(items) => Some(items.clone()),
+    EvaluateResultValue::Expr(Expr::Array(array)) => array
+      .elems
+      .iter()
+      .map(|elem| match elem {
+        None => Some(js_undefined()),
+        Some(elem) if elem.spread.is_some() => None,
+        Some(elem) => Some(EvaluateResultValue::Expr(*elem.expr.clone())),
+      })
+      .collect(),
+    _ => None,
+  }
+}
+
+/// The own properties of an object a pattern takes apart, in source order.
+fn object_entries(value: &EvaluateResultValue) -> Option<Vec<(String, Expr)>> {
+  let EvaluateResultValue::Expr(Expr::Object(object)) = value else {
+    return None;
+  };
+
+  object
+    .props
+    .iter()
+    .map(|prop| {
+      let key_value = prop.as_prop()?.as_key_value()?;
+
+      Some((
+        convert_key_to_string(&key_value.key)?,
+        *key_value.value.clone(),
+      ))
+    })
+    .collect()
+}
+
+/// The value a key reads, which is the last property written under it, or
+/// `undefined` if there is none.
+fn entry_value(entries: &[(String, Expr)], key: &str) -> EvaluateResultValue {
+  entries
+    .iter()
+    .rev()
+    .find(|(entry_key, _)| entry_key == key)
+    .map(|(_, value)| EvaluateResultValue::Expr(value.clone()))
+    .unwrap_or_else(js_undefined)
+}
+
+fn convert_key_to_string(key: &PropName) -> Option<String> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym.to_string()),
+    PropName::Str(strng) => strng.value.as_str().map(ToString::to_string),
+    PropName::Num(num) => Some(stylex_utils::number::to_js_string(num.value)),
+    PropName::Computed(_) | PropName::BigInt(_) => None,
+  }
+}
+
+/// A statement's node type as the reference implementation names it, for the
+/// deopt a statement the evaluator does not run records.
+fn statement_kind(stmt: &Stmt) -> &'static str {
+  match stmt {
+    Stmt::Block(_) => "BlockStatement",
+    Stmt::Empty(_) => "EmptyStatement",
+    Stmt::Debugger(_) => "DebuggerStatement",
+    Stmt::With(_) => "WithStatement",
+    Stmt::Return(_) => "ReturnStatement",
+    Stmt::Labeled(_) => "LabeledStatement",
+    Stmt::Break(_) => "BreakStatement",
+    Stmt::Continue(_) => "ContinueStatement",
+    Stmt::If(_) => "IfStatement",
+    Stmt::Switch(_) => "SwitchStatement",
+    Stmt::Throw(_) => "ThrowStatement",
+    Stmt::Try(_) => "TryStatement",
+    Stmt::While(_) => "WhileStatement",
+    Stmt::DoWhile(_) => "DoWhileStatement",
+    Stmt::For(_) => "ForStatement",
+    Stmt::ForIn(_) => "ForInStatement",
+    Stmt::ForOf(_) => "ForOfStatement",
+    Stmt::Decl(Decl::Class(_)) => "ClassDeclaration",
+    Stmt::Decl(Decl::Fn(_)) => "FunctionDeclaration",
+    Stmt::Decl(Decl::Var(_) | Decl::Using(_)) => "VariableDeclaration",
+    Stmt::Decl(Decl::TsInterface(_)) => "TSInterfaceDeclaration",
+    Stmt::Decl(Decl::TsTypeAlias(_)) => "TSTypeAliasDeclaration",
+    Stmt::Decl(Decl::TsEnum(_)) => "TSEnumDeclaration",
+    Stmt::Decl(Decl::TsModule(_)) => "TSModuleDeclaration",
+    Stmt::Expr(_) => "ExpressionStatement",
+  }
+}
@@ -1,9 +1,9 @@
 pub(super) mod array_expression;
-pub(super) mod arrow_function;
 pub(super) mod await_expression;
 pub(super) mod binary_expression;
 pub(super) mod call_expression;
 pub(super) mod conditional_expression;
+pub(super) mod function;
 pub(super) mod identifier;
 pub(super) mod literal;
 pub(super) mod logical_expression;
@@ -379,8 +379,8 @@ fn an_unresolved_identifier_still_refuses() {
   assert_deopts("1 > 0 && someRuntimeValue");
 }
 
-/// A block-bodied arrow has no compile-time value — `nodes/arrow_function.rs`
-/// folds only expression bodies — so an arithmetic operand that is one refuses.
+/// A function is a callback to the evaluator, not a number or a string — it is
+/// only folded when called — so an arithmetic operand that is one refuses.
 /// Pinned in every operand position because the binary paths read a missing
 /// operand differently depending on which coercion claimed the operator.
 #[test]
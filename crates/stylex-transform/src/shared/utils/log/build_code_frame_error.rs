@@ -10,13 +10,19 @@ use std::{
   path::Path,
   sync::{Arc, Once, OnceLock},
 };
-use stylex_macros::{panic_macros::__stylex_panic, stylex_error::StyleXError, stylex_panic};
+use stylex_macros::{
+  diagnostic::{RelatedSpan, SourceSpan},
+  panic_macros::{__stylex_panic, stylex_err_with_file},
+  stylex_error::StyleXError,
+  stylex_panic,
+};
 use swc_compiler_base::{PrintArgs, SourceMapsConfig, TransformOutput, parse_js, print};
 use swc_config::is_module::IsModule;
 use swc_core::{
   atoms::Atom,
   common::{
-    BytePos, DUMMY_SP, EqIgnoreSpan, FileName, Mark, SourceMap, Span, Spanned, SyntaxContext,
+    BytePos, DUMMY_SP, EqIgnoreSpan, FileName, Mark, SourceFile, SourceMap, Span, Spanned,
+    SyntaxContext,
     errors::{Handler, *},
     util::take::Take,
   },
@@ -137,6 +143,42 @@ impl CodeFrame {
 
     catch_diagnostic_unwind(AssertUnwindSafe(|| self.get_span_line_number(span))).ok()
   }
+
+  /// Where `span` starts and ends in the file it belongs to, or `None` for a
+  /// dummy span or one outside every file.
+  pub(crate) fn try_get_source_span(&self, span: Span) -> Option<SourceSpan> {
+    let source_file = self.source_map.try_lookup_source_file(span.lo).ok()??;
+
+    source_span_in(&source_file, span)
+  }
+}
+
+/// Where `span` lies in `source_file`, or `None` for a dummy span or one
+/// outside it. Columns count characters from 1, as diagnostics do.
+pub fn source_span_in(source_file: &SourceFile, span: Span) -> Option<SourceSpan> {
+  if span.is_dummy() || span.lo < source_file.start_pos || span.hi > source_file.end_pos {
+    return None;
+  }
+
+  let (line, column) = line_and_column(source_file, span.lo)?;
+  let (end_line, end_column) = line_and_column(source_file, span.hi)?;
+
+  Some(SourceSpan {
+    line,
+    column,
+    end_line,
+    end_column,
+  })
+}
+
+fn line_and_column(source_file: &SourceFile, pos: BytePos) -> Option<(usize, usize)> {
+  let line = source_file.lookup_line(pos)?;
+  let line_start = (source_file.line_begin_pos(pos) - source_file.start_pos).0 as usize;
+  let offset = (pos - source_file.start_pos).0 as usize;
+
+  let column = source_file.src.get(line_start..offset)?.chars().count();
+
+  Some((line + 1, column + 1))
 }
 
 fn read_source_file(file_name: &FileName) -> Result<String, std::io::Error> {
@@ -832,50 +874,82 @@ impl Visit for ExpressionFinder {
   }
 }
 
-#[track_caller]
-#[cold]
-pub(crate) fn build_code_frame_error_and_panic(
+/// Emits the code frame for `fault_expression` and returns the error it
+/// shows, located at the expression, for the caller to raise or to report as
+/// a warning. When `wrapped_expression` is a different expression and can be
+/// found too, its location is attached as a related span.
+pub(crate) fn build_code_frame_stylex_error(
   wrapped_expression: &Expr,
   fault_expression: &Expr,
   error_message: &str,
   state: &mut StateManager,
-) -> ! {
-  let caller_location = std::panic::Location::caller();
+) -> StyleXError {
+  let filename = state.get_filename().to_owned();
+  let error = stylex_err_with_file(error_message.to_string(), filename.clone());
 
-  // Emit the code frame diagnostic to stderr (already [StyleX]-prefixed)
-  let (file, line) = match get_span_from_source_code(wrapped_expression, fault_expression, state) {
+  match get_span_from_source_code(wrapped_expression, fault_expression, state) {
     Ok((code_frame, span)) => {
       code_frame.emit_error(span, error_message);
-      let line_num = code_frame.try_get_span_line_number(span);
-      (Some(state.get_filename().to_owned()), line_num)
+
+      let error = match code_frame.try_get_source_span(span) {
+        Some(source_span) => error.with_span(source_span),
+        None => error,
+      };
+
+      if wrapped_expression.eq_ignore_span(fault_expression) {
+        return error;
+      }
+
+      let related = get_span_from_source_code(wrapped_expression, wrapped_expression, state)
+        .ok()
+        .and_then(|(code_frame, span)| code_frame.try_get_source_span(span));
+
+      match related {
+        Some(span) => error.with_related(RelatedSpan {
+          message: "In this expression".to_string(),
+          file: Some(filename),
+          span,
+        }),
+        None => error,
+      }
     },
-    Err(error) => {
+    Err(error_source) => {
       if log::log_enabled!(log::Level::Debug) {
         debug!(
           "Failed to generate code frame error: {:?}. File: {}. Expression: {:?}.",
-          error,
-          state.get_filename(),
-          fault_expression,
+          error_source, filename, fault_expression,
         );
       } else {
         warn!(
           "Failed to generate code frame error: {:?}. File: {}. For more information enable debug logging.",
-          error,
-          state.get_filename(),
+          error_source, filename,
         );
       }
-      (Some(state.get_filename().to_owned()), None)
+
+      error
     },
-  };
+  }
+}
 
-  let err = StyleXError {
-    message: error_message.to_string().into(),
-    file: file.map(Into::into),
-    key_path: None,
-    line,
-    col: None,
-    source_location: Some(format!("{}:{}", caller_location.file(), caller_location.line()).into()),
-  };
+#[track_caller]
+#[cold]
+pub(crate) fn build_code_frame_error_and_panic(
+  wrapped_expression: &Expr,
+  fault_expression: &Expr,
+  error_message: &str,
+  code: &'static str,
+  state: &mut StateManager,
+) -> ! {
+  let caller_location = std::panic::Location::caller();
+
+  let err =
+    build_code_frame_stylex_error(wrapped_expression, fault_expression, error_message, state)
+      .with_code(code)
+      .with_source_location(format!(
+        "{}:{}",
+        caller_location.file(),
+        caller_location.line()
+      ));
 
   __stylex_panic(err)
 }
@@ -885,9 +959,10 @@ pub(crate) fn build_code_frame_error_and_panic(
 pub(crate) fn build_code_frame_error_and_panic_at(
   expr: &Expr,
   error_message: &str,
+  code: &'static str,
   state: &mut StateManager,
 ) -> ! {
-  build_code_frame_error_and_panic(expr, expr, error_message, state)
+  build_code_frame_error_and_panic(expr, expr, error_message, code, state)
 }
 
 #[cfg(test)]
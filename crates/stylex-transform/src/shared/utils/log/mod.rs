@@ -1 +1 @@
-pub(crate) mod build_code_frame_error;
+pub mod build_code_frame_error;
@@ -8,6 +8,7 @@ use std::{
   },
 };
 
+use stylex_macros::diagnostic::codes;
 use swc_core::common::{BytePos, DUMMY_SP, FileName, GLOBALS, Globals, Span, SyntaxContext};
 use swc_core::ecma::ast::{
   CallExpr, Callee, Expr, ExprOrSpread, Ident, ImportDecl, ImportNamedSpecifier, ImportSpecifier,
@@ -334,7 +335,13 @@ fn panic_reports_real_message_for_multibyte_source() {
 
   let panic_payload = match std::panic::catch_unwind(AssertUnwindSafe(|| {
     GLOBALS.set(&Globals::default(), || {
-      build_code_frame_error_and_panic(&target, &target, error_message, &mut state)
+      build_code_frame_error_and_panic(
+        &target,
+        &target,
+        error_message,
+        codes::NON_STATIC_VALUE,
+        &mut state,
+      )
     })
   })) {
     Ok(()) => panic!("expected build_code_frame_error_and_panic to panic"),
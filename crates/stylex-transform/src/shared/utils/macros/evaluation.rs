@@ -26,6 +26,7 @@ macro_rules! stylex_panic_with_context {
       &paren_expr,
       $expr,
       $msg,
+      stylex_macros::diagnostic::codes::TRANSFORM_ERROR,
       $state,
     )
   }};
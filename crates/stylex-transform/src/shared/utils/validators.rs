@@ -1,5 +1,9 @@
+use log::warn;
 use rustc_hash::FxHashSet;
-use stylex_macros::stylex_panic;
+use stylex_macros::{
+  diagnostic::{codes, report_warning},
+  panic_macros::{__stylex_panic, stylex_err},
+};
 use stylex_structures::top_level_expression::TopLevelExpression;
 use swc_core::{
   atoms::Atom,
@@ -17,6 +21,7 @@ use crate::shared::{
     common::get_import_from,
     log::build_code_frame_error::{
       build_code_frame_error_and_panic, build_code_frame_error_and_panic_at,
+      build_code_frame_stylex_error,
     },
   },
 };
@@ -32,11 +37,14 @@ use stylex_constants::constants::{
     DUPLICATE_CONDITIONAL, EXPECTED_CSS_VAR, ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE,
     INVALID_PSEUDO_OR_AT_RULE, MEMBER_OBJ_NOT_IDENT, NO_OBJECT_SPREADS, NON_OBJECT_KEYFRAME,
     NON_STATIC_SECOND_ARG_CREATE_THEME_VALUE, ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS,
-    ONLY_OVERRIDE_DEFINE_VARS, illegal_argument_length, non_export_named_declaration,
-    non_static_value, non_style_object, unbound_call_value,
+    ONLY_OVERRIDE_DEFINE_VARS, illegal_argument_length, invalid_property_value,
+    non_export_named_declaration, non_static_value, non_style_object, unbound_call_value,
   },
 };
-use stylex_css::utils::condition::is_conditional_key;
+use stylex_css::{utils::condition::is_conditional_key, values::grammar::ValueGrammar};
+use stylex_css_parser::at_queries::{validate_container_query, validate_supports_query};
+use stylex_enums::property_validation_mode::PropertyValidationMode;
+use stylex_utils::string::json_stringify;
 
 use super::ast::convertors::{convert_key_value_to_str, convert_lit_to_string};
 use stylex_ast::ast::convertors::{get_key_values_from_object, normalize_expr};
@@ -52,6 +60,7 @@ fn validate_arg_count_for_expr(
     build_code_frame_error_and_panic_at(
       wrapped_expr,
       &illegal_argument_length(fn_name, expected),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -70,6 +79,7 @@ fn assert_first_arg_is_object(
       wrapped_expr,
       &first_arg.expr,
       &non_style_object(fn_name),
+      codes::NON_STYLE_OBJECT,
       state,
     );
   }
@@ -82,18 +92,30 @@ fn validate_single_object_arg_indent(
 ) {
   let init_expr = match var_decl.init.as_deref() {
     Some(init) => init,
-    None => stylex_panic!("{}", non_static_value(fn_name)),
+    None => {
+      __stylex_panic(stylex_err(non_static_value(fn_name)).with_code(codes::NON_STATIC_VALUE))
+    },
   };
 
   let init_call = init_expr.as_call().unwrap_or_else(|| {
-    build_code_frame_error_and_panic_at(init_expr, &non_static_value(fn_name), state);
+    build_code_frame_error_and_panic_at(
+      init_expr,
+      &non_static_value(fn_name),
+      codes::NON_STATIC_VALUE,
+      state,
+    );
   });
 
   if state
     .find_top_level_expr(init_call, |_| false, None)
     .is_none()
   {
-    build_code_frame_error_and_panic_at(init_expr, &unbound_call_value(fn_name), state);
+    build_code_frame_error_and_panic_at(
+      init_expr,
+      &unbound_call_value(fn_name),
+      codes::UNBOUND_CALL,
+      state,
+    );
   }
 
   validate_arg_count_for_expr(init_expr, init_call, 1, fn_name, state);
@@ -211,6 +233,7 @@ pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager)
     build_code_frame_error_and_panic_at(
       &Expr::Call(call.clone()),
       &unbound_call_value(STYLEX_CREATE),
+      codes::UNBOUND_CALL,
       state,
     );
   }
@@ -219,6 +242,7 @@ pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager)
     build_code_frame_error_and_panic_at(
       &Expr::Call(call.clone()),
       &illegal_argument_length(STYLEX_CREATE, 1),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -230,6 +254,7 @@ pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager)
       &Expr::Call(call.clone()),
       &first_arg.expr,
       &non_style_object(STYLEX_CREATE),
+      codes::NON_STYLE_OBJECT,
       state,
     );
   };
@@ -243,6 +268,7 @@ pub(crate) fn validate_stylex_create(call: &CallExpr, state: &mut StateManager)
       &Expr::Call(call.clone()),
       &first_arg.expr,
       NO_OBJECT_SPREADS,
+      codes::NO_OBJECT_SPREADS,
       state,
     );
   }
@@ -278,6 +304,7 @@ pub(crate) fn validate_stylex_default_marker_indent(call: &CallExpr, state: &mut
     build_code_frame_error_and_panic_at(
       &call_expr,
       &illegal_argument_length(STYLEX_DEFAULT_MARKER, 1),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -309,6 +336,7 @@ pub(crate) fn validate_stylex_create_theme_indent(
     build_code_frame_error_and_panic_at(
       &call_expr,
       &unbound_call_value(STYLEX_CREATE_THEME),
+      codes::UNBOUND_CALL,
       state,
     );
   });
@@ -317,6 +345,7 @@ pub(crate) fn validate_stylex_create_theme_indent(
     build_code_frame_error_and_panic_at(
       &call_expr,
       &unbound_call_value(STYLEX_CREATE_THEME),
+      codes::UNBOUND_CALL,
       state,
     );
   });
@@ -326,6 +355,7 @@ pub(crate) fn validate_stylex_create_theme_indent(
       init_expr,
       &call_expr,
       &non_static_value(STYLEX_CREATE_THEME),
+      codes::NON_STATIC_VALUE,
       state,
     );
   });
@@ -336,6 +366,7 @@ pub(crate) fn validate_stylex_create_theme_indent(
       init_expr,
       &call_expr,
       &unbound_call_value(STYLEX_CREATE_THEME),
+      codes::UNBOUND_CALL,
       state,
     ),
   };
@@ -345,6 +376,7 @@ pub(crate) fn validate_stylex_create_theme_indent(
       init_expr,
       &call_expr,
       &illegal_argument_length(STYLEX_CREATE_THEME, 1),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -362,6 +394,7 @@ pub(crate) fn validate_stylex_create_theme_indent(
       init_expr,
       &call_expr,
       NON_STATIC_SECOND_ARG_CREATE_THEME_VALUE,
+      codes::NON_STATIC_VALUE,
       state,
     );
   }
@@ -388,6 +421,7 @@ pub(crate) fn find_and_validate_stylex_define_vars(
         .unwrap_or_else(|| create_expr_or_spread(call_expr.clone()))
         .expr,
       &unbound_call_value(STYLEX_DEFINE_VARS),
+      codes::UNBOUND_CALL,
       state,
     ),
   };
@@ -402,6 +436,7 @@ pub(crate) fn find_and_validate_stylex_define_vars(
         .unwrap_or_else(|| create_expr_or_spread(call_expr.clone()))
         .expr,
       &illegal_argument_length(STYLEX_DEFINE_VARS, 1),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -410,6 +445,7 @@ pub(crate) fn find_and_validate_stylex_define_vars(
     build_code_frame_error_and_panic_at(
       &call_expr,
       &non_export_named_declaration(STYLEX_DEFINE_VARS),
+      codes::NON_EXPORTED_DEFINE,
       state,
     );
   }
@@ -431,6 +467,7 @@ pub(crate) fn validate_stylex_define_marker_indent(call: &CallExpr, state: &mut
     build_code_frame_error_and_panic_at(
       &fault_expr(),
       &illegal_argument_length(STYLEX_DEFINE_MARKER, 0),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -458,13 +495,19 @@ pub(crate) fn validate_stylex_define_marker_indent(call: &CallExpr, state: &mut
         .find_call_declaration_by_span(call)
         .is_some_and(|declaration| declaration.name.as_ident().is_some());
 
-      let error_message = if is_bound_to_a_bare_variable {
-        non_export_named_declaration(STYLEX_DEFINE_MARKER)
+      let (error_message, error_code) = if is_bound_to_a_bare_variable {
+        (
+          non_export_named_declaration(STYLEX_DEFINE_MARKER),
+          codes::NON_EXPORTED_DEFINE,
+        )
       } else {
-        unbound_call_value(STYLEX_DEFINE_MARKER)
+        (
+          unbound_call_value(STYLEX_DEFINE_MARKER),
+          codes::UNBOUND_CALL,
+        )
       };
 
-      build_code_frame_error_and_panic_at(&fault_expr(), &error_message, state)
+      build_code_frame_error_and_panic_at(&fault_expr(), &error_message, error_code, state)
     },
   };
 
@@ -472,6 +515,7 @@ pub(crate) fn validate_stylex_define_marker_indent(call: &CallExpr, state: &mut
     build_code_frame_error_and_panic_at(
       &fault_expr(),
       &non_export_named_declaration(STYLEX_DEFINE_MARKER),
+      codes::NON_EXPORTED_DEFINE,
       state,
     );
   }
@@ -498,6 +542,7 @@ pub(crate) fn find_and_validate_stylex_define_consts(
         .unwrap_or_else(|| create_expr_or_spread(call_expr.clone()))
         .expr,
       &unbound_call_value(STYLEX_DEFINE_CONSTS),
+      codes::UNBOUND_CALL,
       state,
     ),
   };
@@ -512,6 +557,7 @@ pub(crate) fn find_and_validate_stylex_define_consts(
         .unwrap_or_else(|| create_expr_or_spread(call_expr.clone()))
         .expr,
       &illegal_argument_length(STYLEX_DEFINE_CONSTS, 1),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -520,6 +566,7 @@ pub(crate) fn find_and_validate_stylex_define_consts(
     build_code_frame_error_and_panic_at(
       &call_expr,
       &non_export_named_declaration(STYLEX_DEFINE_CONSTS),
+      codes::NON_EXPORTED_DEFINE,
       state,
     );
   }
@@ -589,7 +636,9 @@ pub(crate) fn is_target_call(
             && state.is_stylex_namespace_import(
               match member.obj.as_ident() {
                 Some(ident) => ident,
-                None => stylex_panic!("{}", MEMBER_OBJ_NOT_IDENT),
+                None => {
+                  __stylex_panic(stylex_err(MEMBER_OBJ_NOT_IDENT).with_code(codes::INTERNAL_ERROR))
+                },
               }
               .sym
               .as_ref(),
@@ -612,17 +661,28 @@ pub(crate) fn validate_define_call(
     .find_top_level_expr(call, |_| false, None)
     .cloned()
     .unwrap_or_else(|| {
-      build_code_frame_error_and_panic_at(&call_expr, &unbound_call_value(api_name), state)
+      build_code_frame_error_and_panic_at(
+        &call_expr,
+        &unbound_call_value(api_name),
+        codes::UNBOUND_CALL,
+        state,
+      )
     });
 
   if require_export && !is_variable_named_exported(&top_level_expr, state) {
-    build_code_frame_error_and_panic_at(&call_expr, &non_export_named_declaration(api_name), state);
+    build_code_frame_error_and_panic_at(
+      &call_expr,
+      &non_export_named_declaration(api_name),
+      codes::NON_EXPORTED_DEFINE,
+      state,
+    );
   }
 
   if call.args.len() != arg_count {
     build_code_frame_error_and_panic_at(
       &call_expr,
       &illegal_argument_length(api_name, arg_count),
+      codes::ILLEGAL_ARGUMENT_LENGTH,
       state,
     );
   }
@@ -657,7 +717,12 @@ fn is_style_value_literal(lit: &Lit) -> bool {
 fn reject_unless_style_value_literal(lit: &Lit, state: &mut StateManager) {
   if !is_style_value_literal(lit) {
     let lit_expr = Expr::Lit(lit.clone());
-    build_code_frame_error_and_panic_at(&lit_expr, ILLEGAL_PROP_VALUE, state);
+    build_code_frame_error_and_panic_at(
+      &lit_expr,
+      ILLEGAL_PROP_VALUE,
+      codes::ILLEGAL_PROP_VALUE,
+      state,
+    );
   }
 }
 
@@ -681,7 +746,7 @@ fn reject_unless_style_value_array(array: &ArrayLit, message: &str, state: &mut
   for elem in array.elems.iter().flatten() {
     if !matches!(elem.expr.as_ref(), Expr::Lit(lit) if is_style_value_literal(lit)) {
       let array_expr = Expr::Array(array.clone());
-      build_code_frame_error_and_panic_at(&array_expr, message, state);
+      build_code_frame_error_and_panic_at(&array_expr, message, codes::ILLEGAL_PROP_VALUE, state);
     }
   }
 }
@@ -703,9 +768,16 @@ pub(crate) fn validate_namespace(
         if is_conditional_key(&key) {
           if conditions.contains(&key) {
             let object_expr = Expr::Object(object.clone());
-            build_code_frame_error_and_panic_at(&object_expr, DUPLICATE_CONDITIONAL, state);
+            build_code_frame_error_and_panic_at(
+              &object_expr,
+              DUPLICATE_CONDITIONAL,
+              codes::DUPLICATE_CONDITIONAL,
+              state,
+            );
           }
 
+          validate_at_rule_condition(&key, &namespace.value, state);
+
           let nested_key_values = get_key_values_from_object(object);
 
           let mut extended_conditions = conditions.to_vec();
@@ -736,6 +808,7 @@ pub(crate) fn validate_dynamic_style_params(
     build_code_frame_error_and_panic_at(
       &path_expr,
       ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS,
+      codes::INVALID_DYNAMIC_STYLE_PARAM,
       state,
     )
   }
@@ -754,17 +827,15 @@ pub(crate) fn validate_conditional_styles(
       || inner_key.starts_with("var(--")
       || inner_key == "default")
   {
-    {
-      stylex_panic!("{}", INVALID_PSEUDO_OR_AT_RULE);
-    }
+    __stylex_panic(stylex_err(INVALID_PSEUDO_OR_AT_RULE).with_code(codes::INVALID_CONDITION));
   }
 
   if conditions.contains(&inner_key) {
-    {
-      stylex_panic!("{}", DUPLICATE_CONDITIONAL);
-    }
+    __stylex_panic(stylex_err(DUPLICATE_CONDITIONAL).with_code(codes::DUPLICATE_CONDITIONAL));
   }
 
+  validate_at_rule_condition(&inner_key, &inner_value, state);
+
   // A value under a condition is the same kind of value as one written
   // directly, so it is held to the same literal set -- reached through
   // `is_style_value_literal` rather than restated, because two spellings of
@@ -786,7 +857,111 @@ pub(crate) fn validate_conditional_styles(
       }
     },
     Expr::Ident(_) => {},
-    _ => build_code_frame_error_and_panic_at(&inner_value, ILLEGAL_PROP_VALUE, state),
+    _ => build_code_frame_error_and_panic_at(
+      &inner_value,
+      ILLEGAL_PROP_VALUE,
+      codes::ILLEGAL_PROP_VALUE,
+      state,
+    ),
+  }
+}
+
+/// Reject an `@supports` or `@container` key whose condition does not parse,
+/// pointing at the styles it guards.
+///
+/// Only under `enableAtRuleNormalization`, which respells these conditions and
+/// so has to read them: a browser drops a rule it cannot read without a word,
+/// and a condition written for one is otherwise passed through as written.
+/// Media queries are checked where `enableMediaQueryOrder` rewrites them.
+fn validate_at_rule_condition(key: &str, value: &Expr, state: &mut StateManager) {
+  if !state.options.enable_at_rule_normalization {
+    return;
+  }
+
+  let result = if key.starts_with("@supports") {
+    validate_supports_query(key).map(drop)
+  } else if key.starts_with("@container") {
+    validate_container_query(key).map(drop)
+  } else {
+    Ok(())
+  };
+
+  if let Err(message) = result {
+    build_code_frame_error_and_panic_at(value, &message, codes::INVALID_AT_RULE_CONDITION, state);
+  }
+}
+
+/// Check every string a namespace declares against its property's value
+/// grammar, reporting a mismatch as `propertyValidationMode` asks.
+///
+/// A mismatch is only ever reported, never dropped: the declaration is emitted
+/// as written either way. Browsers discard an invalid declaration on their own,
+/// and leaving it in keeps `silent` -- the default -- compiling exactly what it
+/// compiled before values were checked at all.
+pub(crate) fn validate_property_values(namespace: &[KeyValueProp], state: &mut StateManager) {
+  if state.options.property_validation_mode == PropertyValidationMode::Silent {
+    return;
+  }
+
+  for key_value in namespace {
+    let key = convert_key_value_to_str(key_value);
+
+    match key_value.value.as_ref() {
+      Expr::Object(object) if is_conditional_key(&key) => {
+        validate_property_values(&get_key_values_from_object(object), state);
+      },
+      value => validate_property_value(&key, value, state),
+    }
+  }
+}
+
+/// Check one property's value, following it through fallback arrays and
+/// conditions down to the strings they hold. Anything that is not a string by
+/// now -- a number, `null`, a variable reference -- has no text to check.
+fn validate_property_value(property: &str, value: &Expr, state: &mut StateManager) {
+  let Some(grammar) = ValueGrammar::for_property(property) else {
+    return;
+  };
+
+  match value {
+    Expr::Lit(lit @ Lit::Str(_)) => {
+      let Some(css) = convert_lit_to_string(lit) else {
+        return;
+      };
+
+      if grammar.accepts(&css) {
+        return;
+      }
+
+      let message = invalid_property_value(property, &json_stringify(&css), grammar.expected());
+      let located_error = |state: &mut StateManager| {
+        build_code_frame_stylex_error(value, value, &message, state)
+          .with_code(codes::INVALID_PROPERTY_VALUE)
+          .with_suggestion(format!("Use a value matching {}.", grammar.expected()))
+      };
+
+      match state.options.property_validation_mode {
+        PropertyValidationMode::Throw => {
+          __stylex_panic(located_error(state));
+        },
+        PropertyValidationMode::Warn => {
+          report_warning(&located_error(state));
+          warn!("{}", message);
+        },
+        PropertyValidationMode::Silent => {},
+      }
+    },
+    Expr::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        validate_property_value(property, &elem.expr, state);
+      }
+    },
+    Expr::Object(object) => {
+      for conditional_value in get_key_values_from_object(object) {
+        validate_property_value(property, &conditional_value.value, state);
+      }
+    },
+    _ => {},
   }
 }
 
@@ -800,16 +975,28 @@ pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue, state: &mut Stat
           match key_value.value.as_ref() {
             Expr::Object(_) => {},
             _ => {
-              build_code_frame_error_and_panic_at(expr, NON_OBJECT_KEYFRAME, state);
+              build_code_frame_error_and_panic_at(
+                expr,
+                NON_OBJECT_KEYFRAME,
+                codes::NON_OBJECT_KEYFRAME,
+                state,
+              );
             },
           }
         }
       },
       _ => {
-        build_code_frame_error_and_panic_at(expr, &non_style_object(STYLEX_KEYFRAMES), state);
+        build_code_frame_error_and_panic_at(
+          expr,
+          &non_style_object(STYLEX_KEYFRAMES),
+          codes::NON_STYLE_OBJECT,
+          state,
+        );
       },
     },
-    _ => stylex_panic!("{}", non_static_value(STYLEX_KEYFRAMES)),
+    _ => __stylex_panic(
+      stylex_err(non_static_value(STYLEX_KEYFRAMES)).with_code(codes::NON_STATIC_VALUE),
+    ),
   }
 }
 
@@ -827,7 +1014,7 @@ pub(crate) fn assert_valid_properties(
     for key_value in key_values.iter() {
       let key = convert_key_value_to_str(key_value);
       if !valid_keys.contains(&key.as_str()) {
-        build_code_frame_error_and_panic_at(expr, error_message, state);
+        build_code_frame_error_and_panic_at(expr, error_message, codes::INVALID_PROPERTY, state);
       }
     }
   }
@@ -836,12 +1023,15 @@ pub(crate) fn assert_valid_properties(
 fn assert_stylex_arg(value: &EvaluateResultValue, state: &mut StateManager, fn_name: &str) {
   if let EvaluateResultValue::Expr(expr) = value {
     if !expr.is_object() {
-      build_code_frame_error_and_panic_at(expr, &non_style_object(fn_name), state);
+      build_code_frame_error_and_panic_at(
+        expr,
+        &non_style_object(fn_name),
+        codes::NON_STYLE_OBJECT,
+        state,
+      );
     }
   } else {
-    {
-      stylex_panic!("{}", non_static_value(fn_name));
-    }
+    __stylex_panic(stylex_err(non_static_value(fn_name)).with_code(codes::NON_STATIC_VALUE));
   }
 }
 
@@ -869,7 +1059,7 @@ pub(crate) fn validate_theme_variables(
       VAR_GROUP_HASH_KEY,
       create_string_expr(match value.as_css_var() {
         Some(v) => v,
-        None => stylex_panic!("{}", EXPECTED_CSS_VAR),
+        None => invalid_theme_override(EXPECTED_CSS_VAR),
       }),
     );
 
@@ -877,9 +1067,7 @@ pub(crate) fn validate_theme_variables(
   }
 
   if !variables.as_expr().is_some_and(|expr| expr.is_object()) {
-    {
-      stylex_panic!("{}", ONLY_OVERRIDE_DEFINE_VARS);
-    }
+    invalid_theme_override(ONLY_OVERRIDE_DEFINE_VARS);
   }
 
   match variables
@@ -906,6 +1094,11 @@ pub(crate) fn validate_theme_variables(
       None
     }) {
     Some(key_value) => key_value,
-    None => stylex_panic!("{}", ONLY_OVERRIDE_DEFINE_VARS),
+    None => invalid_theme_override(ONLY_OVERRIDE_DEFINE_VARS),
   }
 }
+
+#[track_caller]
+fn invalid_theme_override(message: &'static str) -> ! {
+  __stylex_panic(stylex_err(message).with_code(codes::INVALID_THEME_OVERRIDE))
+}
@@ -1,9 +1,12 @@
+use std::panic::{self, AssertUnwindSafe};
+
 use indexmap::{IndexMap, IndexSet};
 use rustc_hash::FxHashMap;
+use stylex_macros::diagnostic::report_panic;
 use swc_core::{
   common::{EqIgnoreSpan, Mark, comments::Comments},
   ecma::{
-    ast::{CallExpr, Callee, Expr, Id, MemberProp, Pass, VarDeclarator},
+    ast::{CallExpr, Callee, Expr, Id, MemberProp, ModuleItem, Pass, VarDeclarator},
     transforms::{base::resolver, typescript::strip},
     visit::visit_mut_pass,
   },
@@ -24,6 +27,30 @@ use stylex_structures::{
 pub(crate) mod stylex;
 mod visit_mut;
 
+/// Runs `visit` over each item of a module body. In check-only mode an item
+/// whose visit fails is reported as a diagnostic and put back as it was, and
+/// the walk goes on to the next item, so a failure outside any StyleX call --
+/// in an import, an atom, a declaration -- does not end a check either.
+pub(crate) fn visit_module_items_or_report(
+  check_only: bool,
+  module_items: &mut [ModuleItem],
+  mut visit: impl FnMut(&mut ModuleItem),
+) {
+  for module_item in module_items {
+    if !check_only {
+      visit(module_item);
+      continue;
+    }
+
+    let written = module_item.clone();
+
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| visit(module_item))) {
+      report_panic(&payload);
+      *module_item = written;
+    }
+  }
+}
+
 pub struct StyleXTransform<C>
 where
   C: Comments,
@@ -154,11 +181,26 @@ where
     self
   }
 
+  pub fn with_enable_native_logical_styles(mut self, val: bool) -> Self {
+    self.ensure_config().enable_native_logical_styles = Some(val);
+    self
+  }
+
   pub fn with_enable_font_size_px_to_rem(mut self, val: bool) -> Self {
     self.ensure_config().enable_font_size_px_to_rem = Some(val);
     self
   }
 
+  pub fn with_enable_color_normalization(mut self, val: bool) -> Self {
+    self.ensure_config().enable_color_normalization = Some(val);
+    self
+  }
+
+  pub fn with_enable_at_rule_normalization(mut self, val: bool) -> Self {
+    self.ensure_config().enable_at_rule_normalization = Some(val);
+    self
+  }
+
   pub fn with_enable_inlined_conditional_merge(mut self, val: bool) -> Self {
     self.ensure_config().enable_inlined_conditional_merge = Some(val);
     self
@@ -204,6 +246,21 @@ where
     self
   }
 
+  pub fn with_report_unused_styles(mut self, val: bool) -> Self {
+    self.ensure_config().report_unused_styles = Some(val);
+    self
+  }
+
+  pub fn with_emit_theme_declarations(mut self, val: bool) -> Self {
+    self.ensure_config().emit_theme_declarations = Some(val);
+    self
+  }
+
+  pub fn with_emit_class_name_keys(mut self, val: bool) -> Self {
+    self.ensure_config().emit_class_name_keys = Some(val);
+    self
+  }
+
   pub fn with_sx_prop_name(mut self, val: SxPropNameParam) -> Self {
     self.ensure_config().sx_prop_name = Some(val);
     self
@@ -270,6 +327,16 @@ where
     }
   }
 
+  /// Wraps an already configured state, for transforms the compiler runs on
+  /// its own behalf — such as reading an imported theme file.
+  pub(crate) fn with_state(comments: C, state: StateManager) -> Self {
+    StyleXTransform {
+      comments,
+      props_declaration: None,
+      state,
+    }
+  }
+
   /// Start building a test transform using the builder / `With` pattern.
   pub fn test(comments: C) -> StyleXTransformBuilder<C> {
     StyleXTransformBuilder {
@@ -319,6 +386,29 @@ where
     None
   }
 
+  /// `transform_call_expression`, except that in check-only mode a call that
+  /// fails is reported as a diagnostic and comes back as written, so the walk
+  /// goes on to the next call instead of failing the module.
+  ///
+  /// The failed call keeps whatever its evaluation recorded in the state up
+  /// to the error. Nothing downstream compiles against it: a check compiles a
+  /// copy of the module, which is thrown away.
+  pub(crate) fn transform_call_expression_or_report(&mut self, expr: &mut Expr) -> Option<Expr> {
+    if !self.state.check_only || !expr.is_call() {
+      return self.transform_call_expression(expr);
+    }
+
+    let written = expr.clone();
+
+    match panic::catch_unwind(AssertUnwindSafe(|| self.transform_call_expression(expr))) {
+      Ok(transformed) => transformed,
+      Err(payload) => {
+        report_panic(&payload);
+        Some(written)
+      },
+    }
+  }
+
   pub(crate) fn get_call_var_name(
     &mut self,
     call: &CallExpr,
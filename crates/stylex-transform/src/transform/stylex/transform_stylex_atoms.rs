@@ -23,6 +23,7 @@ use crate::{
       js_to_ast::{NestedStringObject, convert_object_to_ast},
     },
   },
+  transform::visit_module_items_or_report,
 };
 use stylex_ast::ast::factories::{
   create_key_value_prop, create_object_expression, create_string_key_value_prop,
@@ -53,8 +54,12 @@ where
       return;
     }
 
+    let check_only = self.state.check_only;
     let mut visitor = create_utility_styles_visitor(self);
-    module.visit_mut_with(&mut visitor);
+
+    visit_module_items_or_report(check_only, &mut module.body, |module_item| {
+      module_item.visit_mut_with(&mut visitor)
+    });
   }
 }
 
@@ -236,6 +236,7 @@ where
                             &expr,
                             &expr,
                             "Unsupported prop type encountered in stylex.create. Only object properties are allowed.",
+                            codes::NO_OBJECT_SPREADS,
                             &mut transform.state,
                           );
                         }
@@ -246,6 +247,7 @@ where
                           &expr,
                           &expr,
                           "Unsupported prop type encountered in stylex.create. Only object properties are allowed.",
+                          codes::NO_OBJECT_SPREADS,
                           &mut transform.state,
                         );
                       }
@@ -11,7 +11,7 @@ use std::{
   rc::Rc,
   sync::{Arc, LazyLock},
 };
-use stylex_macros::stylex_panic;
+use stylex_macros::{diagnostic::codes, stylex_panic};
 use stylex_path_resolver::package_json::PackageJsonExtended;
 
 use indexmap::IndexMap;
@@ -347,6 +347,7 @@ where
             &wrap_in_paren_ref(&call_expr),
             &call_expr,
             "Function type",
+            codes::TRANSFORM_ERROR,
             &mut self.state,
           )
         }
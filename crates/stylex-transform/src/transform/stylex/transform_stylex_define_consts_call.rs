@@ -18,7 +18,10 @@ use crate::{
     transformers::stylex_define_consts::stylex_define_consts,
     utils::{
       common::gen_file_based_identifier,
-      core::js_to_ast::{NestedStringObject, convert_object_to_ast},
+      core::{
+        js_to_ast::{NestedStringObject, convert_object_to_ast},
+        theme_declarations::consts_declaration,
+      },
       js::evaluate::evaluate,
       log::build_code_frame_error::build_code_frame_error,
       validators::{find_and_validate_stylex_define_consts, is_define_consts_call},
@@ -105,6 +108,13 @@ where
 
       let (transformed_js_output, js_output) = stylex_define_consts(&value, &mut self.state);
 
+      if self.state.options.emit_theme_declarations {
+        self.state.theme_declarations.insert(
+          export_name.to_string(),
+          consts_declaration(&export_name, &transformed_js_output),
+        );
+      }
+
       let result_ast = convert_object_to_ast(&NestedStringObject::FlatCompiledStylesValues(
         transformed_js_output,
       ));
@@ -31,7 +31,10 @@ use crate::{
     },
     utils::{
       common::gen_file_based_identifier,
-      core::js_to_ast::{NestedStringObject, convert_object_to_ast},
+      core::{
+        js_to_ast::{NestedStringObject, convert_object_to_ast},
+        theme_declarations::vars_declaration,
+      },
       js::evaluate::evaluate,
       log::build_code_frame_error::build_code_frame_error,
       validators::{find_and_validate_stylex_define_vars, is_define_vars_call},
@@ -231,6 +234,13 @@ where
       let value =
         normalize_define_vars_functions(value, &mut self.state, &function_map, call, &first_arg);
 
+      if self.state.options.emit_theme_declarations {
+        self
+          .state
+          .theme_declarations
+          .insert(export_name.clone(), vars_declaration(&export_name, &value));
+      }
+
       let (variables_obj, injected_styles_sans_keyframes) =
         stylex_define_vars(&value, &mut self.state);
 
@@ -25,7 +25,7 @@ where
     {
       let normalized_expr = normalize_expr_mut(&mut export_default_expr.expr);
 
-      if let Some(value) = self.transform_call_expression(normalized_expr) {
+      if let Some(value) = self.transform_call_expression_or_report(normalized_expr) {
         *export_default_expr.expr = value;
       }
     }
@@ -38,7 +38,7 @@ where
 
     if (self.state.cycle == TransformationCycle::TransformProducers
       || self.state.cycle == TransformationCycle::TransformConsumers)
-      && let Some(value) = self.transform_call_expression(normalized_expr)
+      && let Some(value) = self.transform_call_expression_or_report(normalized_expr)
     {
       *expr = value;
       return;
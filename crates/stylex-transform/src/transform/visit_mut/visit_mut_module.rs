@@ -18,7 +18,14 @@ use crate::{
     structures::state_manager::{
       build_decl_use_graph, compute_live_set, flush_pending_insertions, mark_style_vars_to_keep,
     },
-    utils::{ast::convertors::convert_atom_to_string, common::fill_top_level_expressions},
+    utils::{
+      ast::convertors::convert_atom_to_string,
+      common::fill_top_level_expressions,
+      core::{
+        analysis::{analyze_target, find_analysis_target},
+        unused_styles::{collect_style_references, find_unused_styles},
+      },
+    },
   },
 };
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -590,16 +597,46 @@ where
   }
 
   pub(crate) fn visit_mut_module_impl(&mut self, module: &mut Module) {
+    // A check reports on the module without changing it, so the cycles run on
+    // a copy.
+    if self.state.check_only {
+      let mut copy = module.clone();
+      self.compile_module(&mut copy);
+      return;
+    }
+
+    self.compile_module(module);
+  }
+
+  fn compile_module(&mut self, module: &mut Module) {
     if cfg!(debug_assertions) || !self.state.options.use_real_file_for_source {
       self.state.set_seen_module_source_code(module, None);
     }
 
     self.discover_module(module);
 
+    if self.state.options.report_unused_styles {
+      self.state.unused_styles = Some(Default::default());
+    }
+
     if !self.state.has_import_paths() && self.state.atom_imports.is_empty() {
       return;
     }
 
+    // Read before the producers evaluate the `vars.token` reads away.
+    let style_references = self
+      .state
+      .options
+      .report_unused_styles
+      .then(|| collect_style_references(module, &self.state));
+
+    // Found on the module as written: a key's span is gone once its call has
+    // compiled.
+    let analysis_target = self
+      .state
+      .analysis_offset
+      .and_then(|offset| find_analysis_target(module, &self.state, offset));
+
     // Binding writes are only read by the evaluator, which runs from here on,
     // so modules that never reach this point pay no pre-scan. When the `sx`
     // feature is on, `discover_module` already scanned (its output is needed
@@ -610,6 +647,14 @@ where
     self.transform_atoms(module);
     self.transform_consumers(module);
     self.finalize_module(module);
+
+    if let Some(style_references) = style_references {
+      self.state.unused_styles = Some(find_unused_styles(&style_references, &self.state));
+    }
+
+    if let Some(analysis_target) = analysis_target {
+      self.state.analysis = analyze_target(analysis_target, module, &mut self.state);
+    }
   }
 
   /// Run the discovery pass.
@@ -9,6 +9,7 @@ use swc_core::{
 use crate::{
   StyleXTransform,
   shared::utils::{ast::convertors::convert_atom_to_string, common::fill_state_declarations},
+  transform::visit_module_items_or_report,
 };
 use stylex_enums::core::TransformationCycle;
 use stylex_regex::regex::STYLEX_CONSTS_IMPORT_REGEX;
@@ -17,6 +18,15 @@ impl<C> StyleXTransform<C>
 where
   C: Comments,
 {
+  /// Visits each item, recovering from a failed one in check-only mode.
+  fn visit_module_items(&mut self, module_items: &mut [ModuleItem]) {
+    let check_only = self.state.check_only;
+
+    visit_module_items_or_report(check_only, module_items, |module_item| {
+      module_item.visit_mut_with(self)
+    });
+  }
+
   pub(crate) fn visit_mut_module_items_impl(&mut self, module_items: &mut Vec<ModuleItem>) {
     match self.state.cycle {
       TransformationCycle::Discover => {
@@ -36,7 +46,7 @@ where
 
         // Single descent: discovers stylex imports, transforms compiled-JSX
         // sx attributes, and accumulates ident / member-expr counts.
-        module_items.visit_mut_children_with(self);
+        self.visit_module_items(module_items);
 
         if !self.state.has_import_paths() {
           return;
@@ -78,11 +88,11 @@ where
         // Hoisted/queued items are merged once after the consumer walk
         // completes via `flush_pending_insertions`; the per-cycle
         // module-items hook only needs to descend into children.
-        module_items.visit_mut_children_with(self);
+        self.visit_module_items(module_items);
       },
       TransformationCycle::Finalize => {
         // We need it twice for a clear dead code after declaration transforms
-        module_items.visit_mut_children_with(self);
+        self.visit_module_items(module_items);
 
         // We remove `Stmt::Empty` from the statement list.
         // This is optional, but it's required if you don't want extra `;` in output.
@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".xrbpyxo{float:inline-start}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xodj72a{clear:inline-end}",
+    priority: 3000
+});
+export const styles = {
+    x: {
+        kyUFMd: "xrbpyxo",
+        klnimT: "xodj72a",
+        $$css: true
+    }
+};
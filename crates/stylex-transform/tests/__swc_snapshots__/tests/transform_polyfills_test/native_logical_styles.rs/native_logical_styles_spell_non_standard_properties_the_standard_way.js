@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x1hm9lzh{margin-inline-start:10px}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xtijo5x{inset-inline-end:0}",
+    priority: 3000
+});
+export const styles = {
+    x: {
+        keTefX: "x1hm9lzh",
+        kt4wiu: "xtijo5x",
+        $$css: true
+    }
+};
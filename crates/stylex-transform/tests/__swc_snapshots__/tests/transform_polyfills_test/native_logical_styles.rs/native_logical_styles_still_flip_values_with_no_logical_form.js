@@ -0,0 +1,20 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x14mnfz1{cursor:e-resize}",
+    priority: 3000,
+    rtl: ".x14mnfz1{cursor:w-resize}"
+});
+_inject2({
+    ltr: ".x12r8qvx{text-shadow:1px 1px 2px black}",
+    priority: 3000,
+    rtl: ".x12r8qvx{text-shadow:-1px 1px 2px black}"
+});
+export const styles = {
+    x: {
+        kkrTdU: "x14mnfz1",
+        kKMj4B: "x12r8qvx",
+        $$css: true
+    }
+};
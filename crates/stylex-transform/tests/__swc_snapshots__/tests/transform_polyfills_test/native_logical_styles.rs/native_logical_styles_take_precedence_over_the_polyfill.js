@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x135b78x{padding-inline-start:4px}",
+    priority: 3000
+});
+export const styles = {
+    x: {
+        kZCmMZ: "x135b78x",
+        $$css: true
+    }
+};
@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".xdqdrvq{padding:4px 8px}",
+    priority: 1000
+});
+export const styles = {
+    root: {
+        kmVPX3: "xdqdrvq",
+        $$css: true
+    }
+};
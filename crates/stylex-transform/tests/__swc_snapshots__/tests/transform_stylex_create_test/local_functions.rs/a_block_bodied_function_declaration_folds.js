@@ -0,0 +1,27 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+function spacing(n) {
+    if (n < 0) return 0;
+    return n * 4 + 'px';
+}
+_inject2({
+    ltr: ".xe8ttls{padding:8px}",
+    priority: 1000
+});
+_inject2({
+    ltr: ".x1ghz6dp{margin:0}",
+    priority: 1000
+});
+_inject2({
+    ltr: ".x1v2ro7d{gap:12px}",
+    priority: 2000
+});
+export const styles = {
+    root: {
+        kmVPX3: "xe8ttls",
+        kogj98: "x1ghz6dp",
+        kOIVth: "x1v2ro7d",
+        $$css: true
+    }
+};
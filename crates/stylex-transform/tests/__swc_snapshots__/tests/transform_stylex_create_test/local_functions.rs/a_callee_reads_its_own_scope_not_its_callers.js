@@ -0,0 +1,20 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+const size = 4;
+function unit() {
+    return size * 2;
+}
+function spacing(size) {
+    return unit() + size + 'px';
+}
+_inject2({
+    ltr: ".x1dypa6k{padding:18px}",
+    priority: 1000
+});
+export const styles = {
+    root: {
+        kmVPX3: "x1dypa6k",
+        $$css: true
+    }
+};
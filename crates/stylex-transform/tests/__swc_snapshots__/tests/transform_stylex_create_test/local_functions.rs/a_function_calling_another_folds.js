@@ -0,0 +1,20 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+const px = (n)=>n + 'px';
+function spacing(n) {
+    return px(n * 4);
+}
+function gutter(n) {
+    return spacing(n) + ' ' + spacing(n * 2);
+}
+_inject2({
+    ltr: ".xdqdrvq{padding:4px 8px}",
+    priority: 1000
+});
+export const styles = {
+    root: {
+        kmVPX3: "xdqdrvq",
+        $$css: true
+    }
+};
@@ -0,0 +1,17 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".xe8ttls{padding:8px}",
+    priority: 1000
+});
+export const styles = {
+    root: {
+        kmVPX3: "xe8ttls",
+        $$css: true
+    }
+};
+export function spacing(n) {
+    const unit = 4;
+    return n * unit + 'px';
+}
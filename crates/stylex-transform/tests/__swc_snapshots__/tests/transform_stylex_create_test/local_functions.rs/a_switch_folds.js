@@ -0,0 +1,41 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+function tone(name) {
+    let color = 'black';
+    switch(name){
+        case 1:
+            color = 'never';
+            break;
+        case 'primary':
+        case 'accent':
+            return 'blue';
+        case 'danger':
+            {
+                const red = 'red';
+                return red;
+            }
+        default:
+            return color;
+    }
+}
+_inject2({
+    ltr: ".xju2f9n{color:blue}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".x71xlcl{border-color:red}",
+    priority: 2000
+});
+_inject2({
+    ltr: ".xfu5tiv{outline-color:black}",
+    priority: 3000
+});
+export const styles = {
+    root: {
+        kMwMTN: "xju2f9n",
+        kVAM5u: "x71xlcl",
+        kjBf7l: "xfu5tiv",
+        $$css: true
+    }
+};
@@ -0,0 +1,26 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+function size(n = 2, { unit = 'px' } = {}) {
+    return n * 8 + unit;
+}
+_inject2({
+    ltr: ".x1kky2od{width:16px}",
+    priority: 4000
+});
+_inject2({
+    ltr: ".xpr1j0t{height:16em}",
+    priority: 4000
+});
+_inject2({
+    ltr: ".x1x67gh{max-width:32px}",
+    priority: 4000
+});
+export const styles = {
+    root: {
+        kzqmXN: "x1kky2od",
+        kZKoxP: "xpr1j0t",
+        ks0D6T: "x1x67gh",
+        $$css: true
+    }
+};
@@ -0,0 +1,23 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x1e2nbdu{color:red}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".x1t391ir{background-color:blue}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xfo62xy{width:2px}",
+    priority: 4000
+});
+export const styles = {
+    root: {
+        kMwMTN: "x1e2nbdu",
+        kWkggS: "x1t391ir",
+        kzqmXN: "xfo62xy",
+        $$css: true
+    }
+};
@@ -0,0 +1,15 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2({
+    ltr: ".x1mqxbix{color:black}",
+    priority: 3000
+});
+_inject2({
+    ltr: "@container (min-width:400px){.xbo3la2.xbo3la2{color:red}}",
+    priority: 3300
+});
+_inject2({
+    ltr: "@container (width >= 400px){.xhoe3zz.xhoe3zz{color:red}}",
+    priority: 3300
+});
@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2({
+    ltr: ".x1mqxbix{color:black}",
+    priority: 3000
+});
+_inject2({
+    ltr: "@container (width >= 400px){.x19c2mvh.x19c2mvh{color:blue}}",
+    priority: 3300
+});
@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2({
+    ltr: ".x8yag65{color:#FFFFFF}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".x1awj2ng{color:white}",
+    priority: 3000
+});
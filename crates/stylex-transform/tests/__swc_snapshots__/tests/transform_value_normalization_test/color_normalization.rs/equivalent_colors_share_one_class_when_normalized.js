@@ -0,0 +1,11 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2({
+    ltr: ".xfungia{color:#fff}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xynexqs{background-color:rgb(0 0 0/.5)}",
+    priority: 3000
+});
@@ -0,0 +1,21 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x1mqxbix{color:black}",
+    priority: 3000
+});
+_inject2({
+    ltr: "@supports (display: grid) and (gap: 1px) or (color: red){.x1sr9ysh.x1sr9ysh{color:red}}",
+    priority: 3030
+});
+_inject2({
+    ltr: "@container (min-widht: 400px){.xh4qsed.xh4qsed{color:blue}}",
+    priority: 3300
+});
+export const styles = {
+    root: {
+        kMwMTN: "x1mqxbix x1sr9ysh xh4qsed",
+        $$css: true
+    }
+};
@@ -0,0 +1,37 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x1mqxbix{color:black}",
+    priority: 3000
+});
+_inject2({
+    ltr: "@supports (display: grid) and selector(:has(a)){.x1j8uhhv.x1j8uhhv{color:red}}",
+    priority: 3030
+});
+_inject2({
+    ltr: "@supports not font-tech(variations){.x14trm29.x14trm29{color:green}}",
+    priority: 3030
+});
+_inject2({
+    ltr: "@container sidebar (width >= 400px){.xf0z4zi.xf0z4zi{color:blue}}",
+    priority: 3300
+});
+_inject2({
+    ltr: "@container style(--theme: dark){.xs9p6w6.xs9p6w6{color:white}}",
+    priority: 3300
+});
+_inject2({
+    ltr: "@supports (display: grid) or (foo){.x1g0ak1t.x1g0ak1t{color:gray}}",
+    priority: 3030
+});
+_inject2({
+    ltr: "@supports at-rule(@layer){.x1hb41ul.x1hb41ul{color:purple}}",
+    priority: 3030
+});
+export const styles = {
+    root: {
+        kMwMTN: "x1mqxbix x1j8uhhv x14trm29 xf0z4zi xs9p6w6 x1g0ak1t x1hb41ul",
+        $$css: true
+    }
+};
@@ -0,0 +1,18 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x15un1f1{color:rde}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xfc1sgo{transform:rotate(10)}",
+    priority: 3000
+});
+export const styles = {
+    root: {
+        kMwMTN: "x15un1f1",
+        k3aq6I: "xfc1sgo",
+        $$css: true
+    }
+};
@@ -0,0 +1,13 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x15un1f1{color:rde}",
+    priority: 3000
+});
+export const styles = {
+    root: {
+        kMwMTN: "x15un1f1",
+        $$css: true
+    }
+};
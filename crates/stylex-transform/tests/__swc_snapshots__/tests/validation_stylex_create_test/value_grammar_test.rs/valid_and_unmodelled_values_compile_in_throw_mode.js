@@ -0,0 +1,57 @@
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import * as stylex from '@stylexjs/stylex';
+_inject2({
+    ltr: ".x15ksv7a{color:currentcolor}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xcelmh9:hover{color:color-mix(in srgb,red,blue)}",
+    priority: 3130
+});
+_inject2({
+    ltr: ".x3zd539{background-color:#fff;background-color:oklch(.7 .1 120)}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".x11vw4cf{border-radius:4px / 8px}",
+    priority: 2000
+});
+_inject2({
+    ltr: ".xiak9yl{box-shadow:inset 0 0 0 1px var(--edge)}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xdzam76{clip-path:circle(50%) border-box}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".x8e2yfc{filter:blur(2px) drop-shadow(0 0 2px red)}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".x1de99jn{outline-width:2px}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".xozy240{transform:translate(-50%,-50%) rotate(45deg)}",
+    priority: 3000
+});
+_inject2({
+    ltr: ".x1jfob14{transition-timing-function:linear,cubic-bezier(.1,.7,1,.1)}",
+    priority: 3000
+});
+export const styles = {
+    root: {
+        kMwMTN: "x15ksv7a xcelmh9",
+        kWkggS: "x3zd539",
+        kaIpWk: "x11vw4cf",
+        kGVxlE: "xiak9yl",
+        kz4h6p: "xdzam76",
+        ku685b: "x8e2yfc",
+        kMeerF: "x1de99jn",
+        k3aq6I: "xozy240",
+        kAMwcw: "x1jfob14",
+        $$css: true
+    }
+};
@@ -133,6 +133,8 @@ stylex_test_transform!(
   "#
 );
 
+// An arrow is a function the evaluator holds but cannot write down, so a
+// receiver holding one refuses rather than dropping the key it sits under.
 stylex_test_transform!(
   object_keys_with_legal_non_object_args,
   |_tr| EvaluationStyleXFirstStatementTransform::default_with_pass(),
@@ -147,7 +149,7 @@ stylex_test_transform!(
 
     ["0", "1", "2"];
     ["0", "1", "2"];
-    ["0", "2"];
+    Object.keys([[1], [() => {}], [NaN]]);
     ["0", "1", "2"];
     ["0", "1", "2", "3", "4"];
   "#
@@ -190,7 +192,7 @@ stylex_test_transform!(
 
     [1, 2, 3];
     [[1], [2], [3]];
-    [[1], [NaN]];
+    Object.values([[1], [() => {}], [NaN]]);
     ["1", "2", "3"];
     [null, undefined, NaN, "1", 1]
   "#
@@ -233,7 +235,7 @@ stylex_test_transform!(
 
     [["0", 1], ["1", 2], ["2", 3]];
     [["0", [1]], ["1", [2]], ["2", [3]]];
-    [["0", [1]], ["2", [NaN]]];
+    Object.entries([[1], [() => {}], [NaN]]);
     [["0", "1"], ["1", "2"], ["2", "3"]];
     [["0", null], ["1", undefined], ["2", NaN], ["3", "1"], ["4", 1]];
   "#
@@ -1,2 +1,3 @@
 mod module_resolution_commonjs;
+mod module_resolution_cross_file_parsing;
 mod theme_name_hashing_based_on_filename_alone_works;
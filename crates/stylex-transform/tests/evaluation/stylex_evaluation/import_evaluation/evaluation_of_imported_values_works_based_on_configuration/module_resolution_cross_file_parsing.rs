@@ -0,0 +1,138 @@
+use crate::utils::prelude::*;
+use insta::assert_snapshot;
+use swc_core::ecma::{transforms::testing::Tester, visit::visit_mut_pass};
+
+use crate::utils::transform::stringify_js;
+
+fn transform_with(
+  input: &str,
+  module_resolution: fn(Option<String>) -> ModuleResolution,
+) -> String {
+  stringify_js(input, ts_syntax(), |tr| {
+    let cwd_path = std::env::current_dir().unwrap();
+
+    let fixture_path = cwd_path.join("tests/fixture/consts");
+
+    StyleXTransform::test(tr.comments.clone())
+      .with_cwd(fixture_path.clone())
+      .with_filename(fixture_path.clone().join("index.js").into())
+      .with_unstable_module_resolution(module_resolution(Some(
+        fixture_path.to_string_lossy().to_string(),
+      )))
+      .with_runtime_injection()
+      .into_pass()
+  })
+}
+
+fn transform(input: &str) -> String {
+  transform_with(input, ModuleResolution::cross_file_parsing)
+}
+
+#[test]
+fn inlines_imported_define_consts_values() {
+  let input = r#"import * as stylex from '@stylexjs/stylex';
+        import { constants } from './constants.stylex';
+        const styles = stylex.create({
+          root: {
+            color: constants.YELLOW,
+            height: constants.INPUT_HEIGHT,
+            [constants.mediaBig]: {
+              color: 'red',
+            },
+          }
+        });
+        stylex.props(styles.root);"#;
+
+  assert_snapshot!(transform(input));
+}
+
+#[test]
+fn follows_re_exported_consts() {
+  let input = r#"import * as stylex from '@stylexjs/stylex';
+        import { C } from './constants.stylex';
+        const styles = stylex.create({
+          root: {
+            color: C.ORANGE,
+          }
+        });
+        stylex.props(styles.root);"#;
+
+  assert_snapshot!(transform(input));
+}
+
+#[test]
+fn reads_imported_define_vars_with_the_same_hashes_as_commonjs() {
+  let input = r#"import * as stylex from '@stylexjs/stylex';
+        import { vars } from './constants.stylex';
+        const styles = stylex.create({
+          root: {
+            color: vars.blue,
+            outlineColor: vars.__varGroupHash__,
+          }
+        });
+        stylex.props(styles.root);"#;
+
+  assert_eq!(
+    transform(input),
+    transform_with(input, ModuleResolution::common_js)
+  );
+}
+
+#[test]
+fn falls_back_to_hashed_references_for_unknown_exports() {
+  let input = r#"import * as stylex from '@stylexjs/stylex';
+        import { missing } from './constants.stylex';
+        const styles = stylex.create({
+          root: {
+            color: missing.blue,
+          }
+        });
+        stylex.props(styles.root);"#;
+
+  assert_snapshot!(transform(input));
+}
+
+#[test]
+fn records_the_imported_theme_file_and_its_package_json_as_dependencies() {
+  let input = r#"import * as stylex from '@stylexjs/stylex';
+        import { constants } from './constants.stylex';
+        import { vars } from './constants.stylex';
+        const styles = stylex.create({
+          root: {
+            color: constants.YELLOW,
+            outlineColor: vars.blue,
+          }
+        });
+        stylex.props(styles.root);"#;
+
+  let fixture_path = std::env::current_dir()
+    .unwrap()
+    .join("tests/fixture/consts");
+
+  let dependencies = Tester::run(|tester| {
+    let mut transform = StyleXTransform::test(tester.comments.clone())
+      .with_cwd(fixture_path.clone())
+      .with_filename(fixture_path.join("index.js").into())
+      .with_unstable_module_resolution(ModuleResolution::cross_file_parsing(Some(
+        fixture_path.to_string_lossy().to_string(),
+      )))
+      .with_runtime_injection()
+      .build();
+
+    tester.apply_transform(
+      visit_mut_pass(&mut transform),
+      "input.js",
+      ts_syntax(),
+      None,
+      input,
+    )?;
+
+    Ok(transform.state.dependencies())
+  });
+
+  assert_eq!(
+    dependencies,
+    ["constants.stylex.js", "package.json"]
+      .map(|file| fixture_path.join(file).to_string_lossy().to_string())
+  );
+}
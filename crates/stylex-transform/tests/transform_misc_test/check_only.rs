@@ -0,0 +1,169 @@
+use crate::utils::prelude::*;
+use stylex_enums::property_validation_mode::PropertyValidationMode;
+use stylex_macros::{
+  diagnostic::{Diagnostic, DiagnosticCollector, Severity, codes},
+  stylex_error::SuppressPanicStderr,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{transforms::testing::Tester, visit::visit_mut_pass},
+};
+
+/// The diagnostics of checking `input` as `/stylex/packages/App.js`.
+fn check(input: &str) -> Vec<Diagnostic> {
+  check_with(input, |b| b).0
+}
+
+/// The diagnostics of checking `input` as `/stylex/packages/App.js` with the
+/// options `customize` sets, and the module the check hands back, printed.
+fn check_with(
+  input: &str,
+  customize: impl FnOnce(TestBuilder) -> TestBuilder,
+) -> (Vec<Diagnostic>, String) {
+  let _suppress = SuppressPanicStderr::new();
+  let collector = DiagnosticCollector::new();
+
+  let output = Tester::run(|tester| {
+    let builder = StyleXTransform::test(tester.comments.clone())
+      .with_filename(FileName::Real("/stylex/packages/App.js".into()))
+      .with_unstable_module_resolution(ModuleResolution::common_js(Some(
+        "/stylex/packages/".to_string(),
+      )));
+    let mut transform = customize(builder).build();
+    transform.state.set_check_only();
+
+    let program = tester.apply_transform(
+      visit_mut_pass(&mut transform),
+      "App.js",
+      ts_syntax(),
+      None,
+      input,
+    )?;
+
+    Ok(tester.print(&program, &tester.comments.clone()))
+  });
+
+  (collector.finish(), output)
+}
+
+fn messages(diagnostics: &[Diagnostic]) -> Vec<String> {
+  diagnostics
+    .iter()
+    .map(|diagnostic| diagnostic.message.clone())
+    .collect()
+}
+
+#[test]
+fn every_failing_call_is_reported() {
+  let diagnostics = check(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+
+      export const vars = stylex.defineVars({ accent: 'blue' });
+
+      const valid = stylex.create({ root: { color: 'red' } });
+
+      const empty = stylex.create();
+
+      stylex.create({ root: { color: 'blue' } });
+
+      export const className = stylex.props(valid.root);
+    "#,
+  );
+
+  assert_eq!(
+    messages(&diagnostics),
+    [
+      "Unable to generate hash for defineVars(). Check that the file has a valid extension and that unstable_moduleResolution is configured.",
+      "create() should have 1 argument.",
+      "create() calls must be bound to a bare variable.",
+    ]
+  );
+  assert!(
+    diagnostics
+      .iter()
+      .all(|diagnostic| diagnostic.severity == Severity::Error)
+  );
+}
+
+#[test]
+fn each_failure_is_reported_with_the_code_of_its_kind() {
+  let diagnostics = check(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+
+      const empty = stylex.create();
+
+      stylex.create({ root: { color: 'blue' } });
+    "#,
+  );
+
+  let reported: Vec<_> = diagnostics
+    .iter()
+    .map(|diagnostic| diagnostic.code.as_ref())
+    .collect();
+
+  assert_eq!(
+    reported,
+    [codes::ILLEGAL_ARGUMENT_LENGTH, codes::UNBOUND_CALL]
+  );
+}
+
+#[test]
+fn a_failed_call_does_not_fail_the_calls_after_it() {
+  let diagnostics = check(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+
+      const empty = stylex.create();
+
+      const styles = stylex.create({ root: { color: 'red' } });
+
+      export const className = stylex.props(styles.root, styles.missing);
+    "#,
+  );
+
+  assert_eq!(messages(&diagnostics), ["create() should have 1 argument."]);
+}
+
+#[test]
+fn a_valid_file_has_nothing_to_report() {
+  let diagnostics = check(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+
+      const styles = stylex.create({ root: { color: 'red' } });
+
+      export const className = stylex.props(styles.root);
+    "#,
+  );
+
+  assert_eq!(diagnostics, vec![]);
+}
+
+#[test]
+fn a_failure_outside_a_call_is_reported_with_the_rest() {
+  let (diagnostics, output) = check_with(
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      import css from '@stylexjs/atoms';
+
+      const empty = stylex.create();
+
+      const styles = stylex.create({ root: { color: 'red' } });
+
+      export const className = stylex.props(styles.root, css.border['1px solid red']);
+    "#,
+    |b| b.with_property_validation_mode(PropertyValidationMode::Throw),
+  );
+
+  assert_eq!(
+    messages(&diagnostics),
+    [
+      "create() should have 1 argument.",
+      "border is not supported. Use border-width, border-style and border-color instead.",
+    ]
+  );
+  assert!(output.contains("stylex.create({"), "{}", output);
+  assert!(output.contains("css.border['1px solid red']"), "{}", output);
+}
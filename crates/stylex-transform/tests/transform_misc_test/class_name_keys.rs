@@ -0,0 +1,79 @@
+use crate::utils::prelude::*;
+use swc_core::ecma::{transforms::testing::Tester, visit::visit_mut_pass};
+
+fn class_name_keys(
+  input: &str,
+  configure: impl FnOnce(TestBuilder) -> TestBuilder,
+) -> Option<Vec<(String, String)>> {
+  Tester::run(|tester| {
+    let mut transform = configure(StyleXTransform::test(tester.comments.clone())).build();
+
+    tester.apply_transform(
+      visit_mut_pass(&mut transform),
+      "input.js",
+      ts_syntax(),
+      None,
+      input,
+    )?;
+
+    Ok(transform.state.class_name_keys().map(|keys| {
+      keys
+        .iter()
+        .map(|(key, class_name)| (key.clone(), class_name.clone()))
+        .collect()
+    }))
+  })
+}
+
+const STYLES: &str = r#"
+  import * as stylex from '@stylexjs/stylex';
+  const fade = stylex.keyframes({ from: { opacity: 0 }, to: { opacity: 1 } });
+  export const styles = stylex.create({
+    root: {
+      color: { default: 'red', ':hover': 'blue' },
+      animationName: fade,
+    },
+  });
+"#;
+
+#[test]
+fn records_the_declaration_each_class_name_is_hashed_from() {
+  let keys = class_name_keys(STYLES, |builder| builder.with_emit_class_name_keys(true))
+    .expect("keys are recorded");
+
+  assert_eq!(
+    keys,
+    [
+      (
+        "<>from{opacity:0;}to{opacity:1;}".to_string(),
+        "x18re5ia-B".to_string()
+      ),
+      ("<>colorrednull".to_string(), "x1e2nbdu".to_string()),
+      ("<>colorblue:hover".to_string(), "x17z2mba".to_string()),
+      (
+        "<>animation-namex18re5ia-Bnull".to_string(),
+        "xqcmdr3".to_string()
+      ),
+    ]
+  );
+}
+
+#[test]
+fn keys_stay_when_the_prefix_renames_every_class() {
+  let keys = class_name_keys(STYLES, |builder| {
+    builder
+      .with_emit_class_name_keys(true)
+      .with_class_name_prefix("y")
+  })
+  .expect("keys are recorded");
+
+  assert_eq!(
+    keys[1],
+    ("<>colorrednull".to_string(), "y1e2nbdu".to_string())
+  );
+}
+
+#[test]
+fn nothing_is_recorded_unless_asked_for() {
+  assert_eq!(class_name_keys(STYLES, |builder| builder), None);
+}
@@ -0,0 +1,88 @@
+use crate::utils::prelude::*;
+use stylex_transform::shared::utils::core::design_tokens::{
+  DesignTokenOptions, design_tokens_to_stylex_source,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{transforms::testing::Tester, visit::visit_mut_pass},
+};
+
+/// The compiled code and the rules of `input`, compiled as
+/// `/stylex/packages/tokens.stylex.ts`.
+fn compile(input: &str) -> (String, Vec<String>) {
+  Tester::run(|tester| {
+    let mut transform = StyleXTransform::test(tester.comments.clone())
+      .with_filename(FileName::Real("/stylex/packages/tokens.stylex.ts".into()))
+      .with_unstable_module_resolution(ModuleResolution::common_js(Some(
+        "/stylex/packages/".to_string(),
+      )))
+      .build();
+
+    let program = tester.apply_transform(
+      visit_mut_pass(&mut transform),
+      "tokens.stylex.ts",
+      ts_syntax(),
+      None,
+      input,
+    )?;
+
+    let rules = transform
+      .state
+      .metadata()
+      .values()
+      .flatten()
+      .map(|metadata| format!("{:?}", metadata))
+      .collect();
+
+    Ok((tester.print(&program, &tester.comments.clone()), rules))
+  })
+}
+
+#[test]
+fn imported_tokens_compile_like_the_same_tokens_written_by_hand() {
+  let source = design_tokens_to_stylex_source(
+    r##"{
+      "color": {
+        "$type": "color",
+        "accent": {
+          "$value": "#0a84ff",
+          "$extensions": { "mode": { "dark": "#64d2ff" } }
+        },
+        "link": { "$value": "{color.accent}" }
+      },
+      "radius": { "$type": "dimension", "$value": { "value": 4, "unit": "px" } }
+    }"##,
+    &DesignTokenOptions::default(),
+  )
+  .expect("valid tokens");
+
+  let by_hand = r#"
+    import * as stylex from '@stylexjs/stylex';
+
+    export const tokens = stylex.unstable_defineVarsNested({
+      color: {
+        accent: '#0a84ff',
+        link: '#0a84ff',
+      },
+      radius: '4px',
+    });
+
+    export const darkTheme = stylex.unstable_createThemeNested(tokens, {
+      color: {
+        accent: '#64d2ff',
+        link: '#64d2ff',
+      },
+    });
+  "#;
+
+  let (imported_code, imported_rules) = compile(&source);
+  let (by_hand_code, by_hand_rules) = compile(by_hand);
+
+  assert_eq!(imported_code, by_hand_code);
+  assert_eq!(imported_rules, by_hand_rules);
+  assert_eq!(
+    imported_rules.len(),
+    2,
+    "the variables and the dark theme: {imported_rules:#?}"
+  );
+}
@@ -0,0 +1,196 @@
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::utils::prelude::*;
+use stylex_macros::{
+  diagnostic::{DiagnosticCollector, codes},
+  stylex_error::SuppressPanicStderr,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{transforms::testing::Tester, visit::visit_mut_pass},
+};
+
+/// The code of the error transforming `input` as `/stylex/packages/App.js`
+/// fails with, under the options `customize` sets.
+fn error_code_with(input: &str, customize: impl FnOnce(TestBuilder) -> TestBuilder) -> String {
+  let _suppress = SuppressPanicStderr::new();
+  let collector = DiagnosticCollector::new();
+
+  let outcome = catch_unwind(AssertUnwindSafe(|| {
+    Tester::run(|tester| {
+      let builder = StyleXTransform::test(tester.comments.clone())
+        .with_filename(FileName::Real("/stylex/packages/App.js".into()))
+        .with_unstable_module_resolution(ModuleResolution::common_js(Some(
+          "/stylex/packages/".to_string(),
+        )));
+      let mut transform = customize(builder).build();
+
+      tester.apply_transform(
+        visit_mut_pass(&mut transform),
+        "App.js",
+        ts_syntax(),
+        None,
+        input,
+      )?;
+
+      Ok(())
+    })
+  }));
+
+  let payload = match outcome {
+    Ok(()) => panic!("expected the transform to fail"),
+    Err(payload) => payload,
+  };
+
+  let diagnostics = collector.finish_with_panic(&payload);
+
+  diagnostics
+    .last()
+    .map(|diagnostic| diagnostic.code.to_string())
+    .unwrap_or_default()
+}
+
+fn error_code(input: &str) -> String {
+  error_code_with(input, |b| b)
+}
+
+fn in_module(body: &str) -> String {
+  format!("import * as stylex from '@stylexjs/stylex';\n{body}")
+}
+
+#[test]
+fn an_unbound_call() {
+  assert_eq!(
+    error_code(&in_module("stylex.create({ root: { color: 'red' } });")),
+    codes::UNBOUND_CALL
+  );
+}
+
+#[test]
+fn a_call_with_the_wrong_number_of_arguments() {
+  assert_eq!(
+    error_code(&in_module("export const styles = stylex.create();")),
+    codes::ILLEGAL_ARGUMENT_LENGTH
+  );
+}
+
+#[test]
+fn a_non_object_argument() {
+  assert_eq!(
+    error_code(&in_module("export const styles = stylex.create('root');")),
+    codes::NON_STYLE_OBJECT
+  );
+}
+
+#[test]
+fn an_object_spread() {
+  assert_eq!(
+    error_code(&in_module(
+      "const base = { root: { color: 'red' } };\nexport const styles = stylex.create({ ...base });"
+    )),
+    codes::NO_OBJECT_SPREADS
+  );
+}
+
+#[test]
+fn a_define_that_is_not_exported() {
+  assert_eq!(
+    error_code(&in_module(
+      "const vars = stylex.defineVars({ accent: 'blue' });"
+    )),
+    codes::NON_EXPORTED_DEFINE
+  );
+}
+
+#[test]
+fn a_non_static_argument() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const theme = stylex.createTheme(vars, 'dark');"
+    )),
+    codes::NON_STATIC_VALUE
+  );
+}
+
+#[test]
+fn an_illegal_style_value() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const styles = stylex.create({ root: { color: true } });"
+    )),
+    codes::ILLEGAL_PROP_VALUE
+  );
+}
+
+#[test]
+fn a_condition_that_is_neither_a_pseudo_class_nor_an_at_rule() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const styles = stylex.create({ root: { color: { default: 'red', hover: 'blue' } } });"
+    )),
+    codes::INVALID_CONDITION
+  );
+}
+
+#[test]
+fn a_condition_nested_in_itself() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const styles = stylex.create({ root: { color: { ':hover': { ':hover': 'blue' } } } });"
+    )),
+    codes::DUPLICATE_CONDITIONAL
+  );
+}
+
+#[test]
+fn an_at_rule_condition_that_does_not_parse() {
+  assert_eq!(
+    error_code_with(
+      &in_module(
+        "export const styles = stylex.create({ root: { color: { default: 'red', '@supports (display: grid) and (a) or (b)': 'blue' } } });"
+      ),
+      |b| b.with_enable_at_rule_normalization(true)
+    ),
+    codes::INVALID_AT_RULE_CONDITION
+  );
+}
+
+#[test]
+fn a_dynamic_style_with_a_destructured_parameter() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const styles = stylex.create({ root: ({ color }) => ({ color }) });"
+    )),
+    codes::INVALID_DYNAMIC_STYLE_PARAM
+  );
+}
+
+#[test]
+fn a_keyframe_that_is_not_an_object() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const fade = stylex.keyframes({ from: 'red' });"
+    )),
+    codes::NON_OBJECT_KEYFRAME
+  );
+}
+
+#[test]
+fn a_property_position_try_does_not_accept() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const fallback = stylex.positionTry({ color: 'red' });"
+    )),
+    codes::INVALID_PROPERTY
+  );
+}
+
+#[test]
+fn a_theme_of_something_other_than_define_vars() {
+  assert_eq!(
+    error_code(&in_module(
+      "export const theme = stylex.createTheme({}, {});"
+    )),
+    codes::INVALID_THEME_OVERRIDE
+  );
+}
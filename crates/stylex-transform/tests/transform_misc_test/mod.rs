@@ -1,2 +1,8 @@
+mod check_only;
+mod class_name_keys;
+mod design_tokens;
+mod diagnostic_codes;
 mod react;
 mod scope;
+mod theme_declarations;
+mod unused_styles;
@@ -0,0 +1,108 @@
+use crate::utils::prelude::*;
+use insta::assert_snapshot;
+use swc_core::{
+  common::FileName,
+  ecma::{transforms::testing::Tester, visit::visit_mut_pass},
+};
+
+fn theme_declarations(filename: &str, input: &str, emit: bool) -> Option<String> {
+  Tester::run(|tester| {
+    let mut transform = StyleXTransform::test(tester.comments.clone())
+      .with_filename(FileName::Real(
+        format!("/stylex/packages/{}", filename).into(),
+      ))
+      .with_unstable_module_resolution(ModuleResolution::common_js(Some(
+        "/stylex/packages/".to_string(),
+      )))
+      .with_emit_theme_declarations(emit)
+      .build();
+
+    tester.apply_transform(
+      visit_mut_pass(&mut transform),
+      "input.js",
+      ts_syntax(),
+      None,
+      input,
+    )?;
+
+    Ok(transform.state.theme_declarations())
+  })
+}
+
+#[test]
+fn describes_define_vars_tokens_with_their_kinds() {
+  let declarations = theme_declarations(
+    "tokens.stylex.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const colors = stylex.defineVars({
+        accent: 'blue',
+        text: {
+          default: 'black',
+          '@media (prefers-color-scheme: dark)': 'white',
+        },
+        gutter: 4,
+        '--custom-name': 'red',
+      });
+      export const typed = stylex.defineVars({
+        background: stylex.types.color({
+          default: 'white',
+          '@media (prefers-color-scheme: dark)': 'black',
+        }),
+        radius: stylex.types.length('4px'),
+        rotation: stylex.types.angle('0deg'),
+      });
+    "#,
+    true,
+  )
+  .expect("the file exports vars");
+
+  assert_snapshot!(declarations);
+}
+
+#[test]
+fn describes_define_consts_with_their_compiled_values() {
+  let declarations = theme_declarations(
+    "constants.stylex.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const breakpoints = stylex.defineConsts({
+        small: '@media (max-width: 600px)',
+        quote: "it's",
+        gap: 8,
+      });
+    "#,
+    true,
+  )
+  .expect("the file exports consts");
+
+  assert_snapshot!(declarations);
+}
+
+#[test]
+fn nothing_is_described_for_a_file_without_theme_exports() {
+  let declarations = theme_declarations(
+    "App.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const styles = stylex.create({ root: { color: 'red' } });
+    "#,
+    true,
+  );
+
+  assert_eq!(declarations, None);
+}
+
+#[test]
+fn nothing_is_described_unless_asked_for() {
+  let declarations = theme_declarations(
+    "tokens.stylex.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const colors = stylex.defineVars({ accent: 'blue' });
+    "#,
+    false,
+  );
+
+  assert_eq!(declarations, None);
+}
@@ -0,0 +1,157 @@
+use crate::utils::prelude::*;
+use stylex_types::structures::unused_styles::UnusedStyles;
+use swc_core::{
+  common::FileName,
+  ecma::{transforms::testing::Tester, visit::visit_mut_pass},
+};
+
+fn unused_styles(filename: &str, input: &str) -> UnusedStyles {
+  Tester::run(|tester| {
+    let mut transform = StyleXTransform::test(tester.comments.clone())
+      .with_filename(FileName::Real(
+        format!("/stylex/packages/{}", filename).into(),
+      ))
+      .with_unstable_module_resolution(ModuleResolution::common_js(Some(
+        "/stylex/packages/".to_string(),
+      )))
+      .with_report_unused_styles(true)
+      .build();
+
+    tester.apply_transform(
+      visit_mut_pass(&mut transform),
+      "input.js",
+      ts_syntax(),
+      None,
+      input,
+    )?;
+
+    Ok(
+      transform
+        .state
+        .unused_styles()
+        .cloned()
+        .expect("the report is on"),
+    )
+  })
+}
+
+fn unused_namespaces(report: &UnusedStyles) -> Vec<(&str, &str)> {
+  report
+    .namespaces
+    .iter()
+    .map(|namespace| (namespace.variable.as_str(), namespace.namespace.as_str()))
+    .collect()
+}
+
+#[test]
+fn reports_the_namespaces_props_never_receives() {
+  let report = unused_styles(
+    "App.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      const styles = stylex.create({
+        root: { color: 'red' },
+        active: { color: 'blue' },
+        hidden: { display: 'none', color: 'red' },
+      });
+      const unusedStyles = stylex.create({
+        card: { padding: 4 },
+      });
+      export const App = ({ isActive }) => (
+        <div {...stylex.props(styles.root, isActive && styles['active'])} />
+      );
+    "#,
+  );
+
+  assert_eq!(
+    unused_namespaces(&report),
+    [("styles", "hidden"), ("unusedStyles", "card")]
+  );
+  assert!(report.tokens.is_empty());
+
+  let hidden = &report.namespaces[0];
+  assert_eq!(hidden.class_names.len(), 2);
+
+  // `color: 'red'` is the same class as `root`'s, which is still rendered.
+  let shared = &hidden.class_names[1];
+  assert!(!report.dead_class_names.contains(shared));
+  assert_eq!(
+    report.dead_class_names,
+    [
+      hidden.class_names[0].clone(),
+      report.namespaces[1].class_names[0].clone()
+    ]
+  );
+}
+
+#[test]
+fn says_nothing_of_styles_read_where_the_file_cannot_see() {
+  let report = unused_styles(
+    "App.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const exported = stylex.create({ root: { color: 'red' } });
+      const computed = stylex.create({ a: { color: 'red' }, b: { color: 'blue' } });
+      const passed = stylex.create({ root: { color: 'green' } });
+      const reexported = stylex.create({ root: { color: 'pink' } });
+      export { reexported };
+      export const Button = ({ variant }) => (
+        <Base {...stylex.props(computed[variant])} styles={passed} />
+      );
+    "#,
+  );
+
+  assert!(report.is_empty(), "{:?}", report);
+  assert!(report.dead_class_names.is_empty());
+}
+
+#[test]
+fn a_read_only_counts_from_code_the_module_runs() {
+  let report = unused_styles(
+    "App.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      const styles = stylex.create({
+        root: { color: 'red' },
+        called: { color: 'blue' },
+        orphaned: { color: 'green' },
+      });
+      const calledHelper = () => stylex.props(styles.called);
+      const orphanedHelper = () => stylex.props(styles.orphaned);
+      export const App = () => <div {...stylex.props(styles.root)} {...calledHelper()} />;
+    "#,
+  );
+
+  assert_eq!(unused_namespaces(&report), [("styles", "orphaned")]);
+}
+
+#[test]
+fn reports_the_define_vars_tokens_the_file_never_reads() {
+  let report = unused_styles(
+    "vars.stylex.js",
+    r#"
+      import * as stylex from '@stylexjs/stylex';
+      export const vars = stylex.defineVars({
+        accent: 'blue',
+        background: 'white',
+        foreground: () => vars.accent,
+      });
+    "#,
+  );
+
+  let tokens: Vec<_> = report
+    .tokens
+    .iter()
+    .map(|token| (token.variable.as_str(), token.token.as_str()))
+    .collect();
+
+  assert_eq!(tokens, [("vars", "background"), ("vars", "foreground")]);
+  assert!(report.namespaces.is_empty());
+}
+
+#[test]
+fn a_file_without_stylex_has_an_empty_report() {
+  let report = unused_styles("App.js", "export const App = () => null;");
+
+  assert_eq!(report, UnusedStyles::default());
+}
@@ -1,2 +1,3 @@
 mod css_property_polyfills;
 mod css_value_polyfills;
+mod native_logical_styles;
@@ -0,0 +1,54 @@
+use crate::utils::prelude::*;
+
+stylex_test!(
+  native_logical_styles_keep_float_and_clear_logical,
+  |tr| StyleXTransform::test(tr.comments.clone())
+    .with_enable_native_logical_styles(true)
+    .with_runtime_injection()
+    .into_pass(),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({ x: { float: 'start', clear: 'end' } });
+  "#
+);
+
+stylex_test!(
+  native_logical_styles_spell_non_standard_properties_the_standard_way,
+  |tr| StyleXTransform::test(tr.comments.clone())
+    .with_enable_native_logical_styles(true)
+    .with_runtime_injection()
+    .into_pass(),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({ x: { marginStart: 10, insetInlineEnd: 0 } });
+  "#
+);
+
+stylex_test!(
+  native_logical_styles_take_precedence_over_the_polyfill,
+  |tr| StyleXTransform::test(tr.comments.clone())
+    .with_style_resolution(StyleResolution::LegacyExpandShorthands)
+    .with_enable_logical_styles_polyfill(true)
+    .with_enable_native_logical_styles(true)
+    .with_runtime_injection()
+    .into_pass(),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({ x: { paddingInlineStart: 4 } });
+  "#
+);
+
+stylex_test!(
+  native_logical_styles_still_flip_values_with_no_logical_form,
+  |tr| StyleXTransform::test(tr.comments.clone())
+    .with_enable_native_logical_styles(true)
+    .with_enable_legacy_value_flipping(true)
+    .with_runtime_injection()
+    .into_pass(),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      x: { cursor: 'e-resize', textShadow: '1px 1px 2px black' },
+    });
+  "#
+);
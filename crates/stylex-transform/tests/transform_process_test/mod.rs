@@ -0,0 +1 @@
+mod process_stylex_rules;
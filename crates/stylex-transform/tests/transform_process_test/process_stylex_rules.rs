@@ -0,0 +1,875 @@
+use crate::utils::prelude::*;
+use stylex_css::stylesheet::{LayersOptions, ProcessStylexRulesOptions, process_stylex_rules};
+use stylex_types::structures::meta_data::MetaData;
+use swc_core::{
+  common::FileName,
+  ecma::{parser::EsSyntax, parser::Syntax, transforms::testing::Tester, visit::visit_mut_pass},
+};
+
+const TOKENS: &str = r#"
+import * as stylex from '@stylexjs/stylex';
+export const constants = stylex.defineConsts({
+  YELLOW: 'yellow',
+  ORANGE: 'var(--orange-theme-color)',
+  mediaBig: '@media (max-width: 1000px)',
+  mediaSmall: '@media (max-width: 500px)'
+});
+export const vars = stylex.defineVars({
+  blue: 'blue',
+  marginTokens: {
+    default: "10px",
+    "@media (min-width: 600px)": "20px"
+  },
+  colorTokens: {
+    default: 'red',
+    '@media (prefers-color-scheme: dark)': {
+      default: 'lightblue',
+      '@supports (color: oklab(0 0 0))': 'oklab(0.7 -0.3 -0.4)',
+    }
+  },
+});
+"#;
+
+const OTHER_TOKENS: &str = r#"
+import * as stylex from '@stylexjs/stylex';
+export const spacing = stylex.defineVars({
+  small: '2px',
+  medium: '4px',
+  large: '8px'
+});
+"#;
+
+const FIXTURE: &str = r#"
+import * as stylex from '@stylexjs/stylex';
+export const themeColor = stylex.createTheme(vars, {
+  blue: 'lightblue'
+});
+export const themeSpacing = stylex.createTheme(spacing, {
+  small: '5px',
+  medium: '10px',
+  large: '20px'
+});
+export const styles = stylex.create({
+  root: {
+    animationName: stylex.keyframes({
+      '0%': {
+        boxShadow: '1px 2px 3px 4px red',
+        color: constants.YELLOW
+      },
+      '100%': {
+        boxShadow: '10px 20px 30px 40px green',
+        color: constants.ORANGE
+      }
+    }),
+    backgroundColor: {
+      default: 'red',
+      ':hover': 'blue',
+      [stylex.when.ancestor(':focus')]: 'green',
+      '@media (max-width: 1000px)': {
+        default: 'yellow',
+        [stylex.when.descendant(':focus')]: 'purple',
+        [stylex.when.anySibling(':active')]: 'orange',
+      }
+    },
+    margin: vars.marginTokens,
+    borderColor: {
+      default: 'green',
+      [constants.mediaBig]: {
+        default: vars.blue,
+        [constants.mediaSmall]: 'yellow',
+      }
+    },
+    outlineColor: vars.colorTokens,
+    textShadow: {
+      default: '1px 2px 3px 4px red',
+      '@media (min-width:320px)': '10px 20px 30px 40px green'
+    },
+    padding: spacing.large,
+    margin: '10px 20px',
+    float: 'inline-start'
+  },
+  overrideColor: {
+    [constants.ORANGE]: 'red'
+  },
+  dynamic: (color) => ({ color })
+});
+"#;
+
+const LOGICAL_STYLES: &str = r#"
+import * as stylex from '@stylexjs/stylex';
+export const styles = stylex.create({
+  container: {
+    margin: '10px 20px',
+    padding: '5px 15px',
+    float: 'inline-start'
+  }
+});
+"#;
+
+const THEMES: &str = r#"
+import * as stylex from '@stylexjs/stylex';
+export const themeColor = stylex.createTheme(vars, {
+  blue: 'lightblue'
+});
+export const themeSpacing = stylex.createTheme(spacing, {
+  small: '5px',
+  medium: '10px',
+  large: '20px'
+});
+"#;
+
+const STYLEX_IMPORT: &str = "import * as stylex from '@stylexjs/stylex';";
+
+/// The plugin options of the reference test, adjusted by `customize`.
+fn compile_one(
+  filename: &str,
+  source: &str,
+  customize: &dyn Fn(TestBuilder) -> TestBuilder,
+) -> (String, Vec<MetaData>) {
+  Tester::run(|tester| {
+    let builder = StyleXTransform::test(tester.comments.clone())
+      .with_filename(FileName::Real(filename.into()))
+      .with_debug(true)
+      .with_enable_debug_class_names(true)
+      .with_style_resolution(StyleResolution::PropertySpecificity)
+      .with_unstable_module_resolution(ModuleResolution::common_js(Some("/src/app/".to_string())));
+    let mut transform = customize(builder).build();
+
+    let program = tester.apply_transform(
+      visit_mut_pass(&mut transform),
+      filename,
+      Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+      }),
+      None,
+      source,
+    )?;
+
+    let metadata = transform
+      .state
+      .metadata()
+      .values()
+      .flatten()
+      .cloned()
+      .collect();
+
+    Ok((tester.print(&program, &tester.comments.clone()), metadata))
+  })
+}
+
+/// The reference test's `transform`: the token files compiled on their own,
+/// then their output and `source` compiled together as one module, with the
+/// rules of all three.
+fn transform(
+  source: &str,
+  filename: &str,
+  customize: impl Fn(TestBuilder) -> TestBuilder,
+) -> Vec<MetaData> {
+  let (tokens, mut metadata) = compile_one("/src/app/tokens.stylex.js", TOKENS, &customize);
+  let (other_tokens, other_metadata) =
+    compile_one("/src/app/otherTokens.stylex.js", OTHER_TOKENS, &customize);
+  let main_source = format!(
+    "{}\n{}\n{}",
+    tokens,
+    other_tokens.replace(STYLEX_IMPORT, ""),
+    source.replace(STYLEX_IMPORT, "")
+  );
+  let (_, main_metadata) = compile_one(filename, &main_source, &customize);
+
+  metadata.extend(other_metadata);
+  metadata.extend(main_metadata);
+  metadata
+}
+
+fn fixture_rules() -> Vec<MetaData> {
+  transform(FIXTURE, "/src/app/main.js", |builder| builder)
+}
+
+#[test]
+fn no_rules() {
+  let metadata = transform("", "/src/app/main.js", |builder| builder);
+
+  assert_eq!(
+    process_stylex_rules(&metadata, &ProcessStylexRulesOptions::default()),
+    r##":root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}"##
+  );
+}
+
+#[test]
+fn all_rules_without_layers() {
+  let metadata = transform(FIXTURE, "/src/app/components/main.js", |builder| builder);
+
+  assert_eq!(
+    process_stylex_rules(&metadata, &ProcessStylexRulesOptions::default()),
+    r##"@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+.margin-xymmreb:not(#\#){margin:10px 20px}
+.padding-xss17vw:not(#\#){padding:var(--large-x1ec7iuc)}
+.borderColor-x1bg2uv5:not(#\#):not(#\#){border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c:not(#\#):not(#\#){border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys:not(#\#):not(#\#){border-color:yellow}}}
+.animationName-x13ah0pd:not(#\#):not(#\#):not(#\#){animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc:not(#\#):not(#\#):not(#\#){background-color:red}
+.color-x14rh7hd:not(#\#):not(#\#):not(#\#){color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f:not(#\#):not(#\#):not(#\#){float:left}
+html[dir='rtl'] .float-x1kmio9f:not(#\#):not(#\#):not(#\#){float:right}
+.outlineColor-x184ctg8:not(#\#):not(#\#):not(#\#){outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i:not(#\#):not(#\#):not(#\#){text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *):not(#\#):not(#\#):not(#\#){background-color:green}
+.backgroundColor-xbrh7vm:hover:not(#\#):not(#\#):not(#\#){background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn:not(#\#):not(#\#):not(#\#){background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id:not(#\#):not(#\#):not(#\#){text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)):not(#\#):not(#\#):not(#\#){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)):not(#\#):not(#\#):not(#\#){background-color:orange}}"##
+  );
+}
+
+#[test]
+fn all_rules_with_layers() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions::default()),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer priority1, priority2, priority3, priority4;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn layers_before() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions {
+          before: vec!["reset".into(), "typography".into()],
+          after: vec![],
+          prefix: None,
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer reset, typography, priority1, priority2, priority3, priority4;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn layers_after() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions {
+          before: vec![],
+          after: vec!["overrides".into(), "xds.theme".into()],
+          prefix: None,
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer priority1, priority2, priority3, priority4, overrides, xds.theme;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn layers_before_and_after() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions {
+          before: vec!["reset".into()],
+          after: vec!["xds.theme".into()],
+          prefix: None,
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer reset, priority1, priority2, priority3, priority4, xds.theme;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn layers_prefix() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions {
+          before: vec![],
+          after: vec![],
+          prefix: Some("stylex".into()),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer stylex.priority1, stylex.priority2, stylex.priority3, stylex.priority4;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer stylex.priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer stylex.priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer stylex.priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn layers_prefix_before_and_after() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions {
+          before: vec!["reset".into(), "typography".into()],
+          after: vec!["xds.theme".into()],
+          prefix: Some("stylex".into()),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer reset, typography, stylex.priority1, stylex.priority2, stylex.priority3, stylex.priority4, xds.theme;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer stylex.priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer stylex.priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer stylex.priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn layers_multi_segment_prefix() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions {
+          before: vec!["xds.reset".into(), "xds.typography".into()],
+          after: vec!["xds.theme".into()],
+          prefix: Some("xds.base".into()),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer xds.reset, xds.typography, xds.base.priority1, xds.base.priority2, xds.base.priority3, xds.base.priority4, xds.theme;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer xds.base.priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer xds.base.priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer xds.base.priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn layers_empty_before_and_after() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        use_layers: Some(LayersOptions::default()),
+        ..Default::default()
+      }
+    ),
+    r##"
+@layer priority1, priority2, priority3, priority4;
+@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+@layer priority2{
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+}
+@layer priority3{
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+}
+@layer priority4{
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}
+}"##
+  );
+}
+
+#[test]
+fn all_rules_legacy_disable_layers() {
+  let metadata = fixture_rules();
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        legacy_disable_layers: true,
+        ..Default::default()
+      }
+    ),
+    r##"@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}
+.--orange-theme-color-xufgesz{--orange-theme-color:red}
+.margin-xymmreb{margin:10px 20px}
+.padding-xss17vw{padding:var(--large-x1ec7iuc)}
+.borderColor-x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.borderColor-x5ugf7c.borderColor-x5ugf7c{border-color:var(--blue-xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.borderColor-xqiy1ys.borderColor-xqiy1ys.borderColor-xqiy1ys{border-color:yellow}}}
+.animationName-x13ah0pd{animation-name:x35atj5-B}
+.backgroundColor-xrkmrrc{background-color:red}
+.color-x14rh7hd{color:var(--x-color)}
+html:not([dir='rtl']) .float-x1kmio9f{float:left}
+html[dir='rtl'] .float-x1kmio9f{float:right}
+.outlineColor-x184ctg8{outline-color:var(--colorTokens-xkxfyv)}
+.textShadow-x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.backgroundColor-xfy810d.backgroundColor-xfy810d:where(.x-default-marker:focus *){background-color:green}
+.backgroundColor-xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.backgroundColor-xahc4vn.backgroundColor-xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.textShadow-xtj17id.textShadow-xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c.backgroundColor-x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.backgroundColor-x975j7z.backgroundColor-x975j7z.backgroundColor-x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}"##
+  );
+}
+
+#[test]
+fn legacy_expand_shorthands_with_logical_styles_polyfill() {
+  let metadata = transform(LOGICAL_STYLES, "/src/app/main.js", |builder| {
+    builder
+      .with_style_resolution(StyleResolution::LegacyExpandShorthands)
+      .with_enable_logical_styles_polyfill(true)
+  });
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        enable_ltr_rtl_comments: true,
+        ..Default::default()
+      }
+    ),
+    r##":root, [dir="ltr"] {
+  --stylex-logical-start: left;
+  --stylex-logical-end: right;
+}
+[dir="rtl"] {
+  --stylex-logical-start: right;
+  --stylex-logical-end: left;
+}
+:root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.float-xj87blo:not(#\#){float:var(--stylex-logical-start)}
+/* @ltr begin */.marginInlineStart-xqsn43r:not(#\#){margin-left:20px}/* @ltr end */
+/* @rtl begin */.marginInlineStart-xqsn43r:not(#\#){margin-right:20px}/* @rtl end */
+/* @ltr begin */.marginInlineEnd-x3aesyq:not(#\#){margin-right:20px}/* @ltr end */
+/* @rtl begin */.marginInlineEnd-x3aesyq:not(#\#){margin-left:20px}/* @rtl end */
+/* @ltr begin */.paddingInlineStart-x1gx403c:not(#\#){padding-left:15px}/* @ltr end */
+/* @rtl begin */.paddingInlineStart-x1gx403c:not(#\#){padding-right:15px}/* @rtl end */
+/* @ltr begin */.paddingInlineEnd-x1q3ajuy:not(#\#){padding-right:15px}/* @ltr end */
+/* @rtl begin */.paddingInlineEnd-x1q3ajuy:not(#\#){padding-left:15px}/* @rtl end */
+.marginBottom-xyorhqc:not(#\#):not(#\#){margin-bottom:10px}
+.marginTop-x1anpbxc:not(#\#):not(#\#){margin-top:10px}
+.paddingBottom-xs9asl8:not(#\#):not(#\#){padding-bottom:5px}
+.paddingTop-x123j3cw:not(#\#):not(#\#){padding-top:5px}"##
+  );
+}
+
+#[test]
+fn legacy_expand_shorthands_duplicates_theme_selectors() {
+  let metadata = transform(THEMES, "/src/app/main.js", |builder| {
+    builder.with_style_resolution(StyleResolution::LegacyExpandShorthands)
+  });
+
+  assert_eq!(
+    process_stylex_rules(&metadata, &ProcessStylexRulesOptions::default()),
+    r##":root, .xsg933n{--blue-xpqh4lw:blue;--marginTokens-x8nt2k2:10px;--colorTokens-xkxfyv:red;}
+:root, .xbiwvf9{--small-x19twipt:2px;--medium-xypjos2:4px;--large-x1ec7iuc:8px;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--marginTokens-x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--colorTokens-xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x6xqkwy.x6xqkwy, .x6xqkwy.x6xqkwy:root{--blue-xpqh4lw:lightblue;}
+.x57uvma.x57uvma, .x57uvma.x57uvma:root{--large-x1ec7iuc:20px;--medium-xypjos2:10px;--small-x19twipt:5px;}"##
+  );
+}
+
+#[test]
+fn classnames_sort_by_declaration() {
+  let metadata = transform(FIXTURE, "/src/app/main.js", |builder| {
+    builder.with_enable_debug_class_names(false)
+  });
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        enable_ltr_rtl_comments: true,
+        legacy_disable_layers: true,
+        ..Default::default()
+      }
+    ),
+    r##"@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xbiwvf9{--x19twipt:2px;--xypjos2:4px;--x1ec7iuc:8px;}
+:root, .xsg933n{--xpqh4lw:blue;--x8nt2k2:10px;--xkxfyv:red;}
+@media (min-width: 600px){:root, .xsg933n{--x8nt2k2:20px;}}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--xkxfyv:lightblue;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x4hn0rr.x4hn0rr, .x4hn0rr.x4hn0rr:root{--x1ec7iuc:20px;--xypjos2:10px;--x19twipt:5px;}
+.x1coplze.x1coplze, .x1coplze.x1coplze:root{--xpqh4lw:lightblue;}
+.xufgesz{--orange-theme-color:red}
+.xymmreb{margin:10px 20px}
+.x1s2izit{padding:var(--x1ec7iuc)}
+.x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.xio2edn.xio2edn{border-color:var(--xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.xqiy1ys.xqiy1ys.xqiy1ys{border-color:yellow}}}
+.x13ah0pd{animation-name:x35atj5-B}
+.xrkmrrc{background-color:red}
+.x14rh7hd{color:var(--x-color)}
+/* @ltr begin */.x1kmio9f{float:left}/* @ltr end */
+/* @rtl begin */.x1kmio9f{float:right}/* @rtl end */
+.x18abd1y{outline-color:var(--xkxfyv)}
+.x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.xfy810d.xfy810d:where(.x-default-marker:focus *){background-color:green}
+.xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.xahc4vn.xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.xtj17id.xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.x1t4kl4c.x1t4kl4c.x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.x975j7z.x975j7z.x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}"##
+  );
+}
+
+#[test]
+fn classnames_sort_by_class_name() {
+  let metadata = transform(FIXTURE, "/src/app/main.js", |builder| {
+    builder.with_enable_debug_class_names(false)
+  });
+
+  assert_eq!(
+    process_stylex_rules(
+      &metadata,
+      &ProcessStylexRulesOptions {
+        enable_ltr_rtl_comments: true,
+        use_legacy_classnames_sort: true,
+        legacy_disable_layers: true,
+        ..Default::default()
+      }
+    ),
+    r##"@property --x-color { syntax: "*"; inherits: false;}
+@keyframes x35atj5-B{0%{box-shadow:1px 2px 3px 4px red;color:yellow;}100%{box-shadow:10px 20px 30px 40px green;color:var(--orange-theme-color);}}
+:root, .xbiwvf9{--x19twipt:2px;--xypjos2:4px;--x1ec7iuc:8px;}
+:root, .xsg933n{--xpqh4lw:blue;--x8nt2k2:10px;--xkxfyv:red;}
+@media (prefers-color-scheme: dark){:root, .xsg933n{--xkxfyv:lightblue;}}
+@media (min-width: 600px){:root, .xsg933n{--x8nt2k2:20px;}}
+@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root, .xsg933n{--xkxfyv:oklab(0.7 -0.3 -0.4);}}}
+.x1coplze.x1coplze, .x1coplze.x1coplze:root{--xpqh4lw:lightblue;}
+.x4hn0rr.x4hn0rr, .x4hn0rr.x4hn0rr:root{--x1ec7iuc:20px;--xypjos2:10px;--x19twipt:5px;}
+.xufgesz{--orange-theme-color:red}
+.x1s2izit{padding:var(--x1ec7iuc)}
+.xymmreb{margin:10px 20px}
+.x1bg2uv5{border-color:green}
+@media (max-width: 1000px){.xio2edn.xio2edn{border-color:var(--xpqh4lw)}}
+@media (max-width: 500px){@media (max-width: 1000px){.xqiy1ys.xqiy1ys.xqiy1ys{border-color:yellow}}}
+.x13ah0pd{animation-name:x35atj5-B}
+.x14rh7hd{color:var(--x-color)}
+.x18abd1y{outline-color:var(--xkxfyv)}
+/* @ltr begin */.x1kmio9f{float:left}/* @ltr end */
+/* @rtl begin */.x1kmio9f{float:right}/* @rtl end */
+.x1skrh0i{text-shadow:1px 2px 3px 4px red}
+.xrkmrrc{background-color:red}
+.xfy810d.xfy810d:where(.x-default-marker:focus *){background-color:green}
+.xbrh7vm:hover{background-color:blue}
+@media (max-width: 1000px){.xahc4vn.xahc4vn{background-color:yellow}}
+@media (min-width: 320px){.xtj17id.xtj17id{text-shadow:10px 20px 30px 40px green}}
+@media (max-width: 1000px){.x1t4kl4c.x1t4kl4c.x1t4kl4c:where(:has(.x-default-marker:focus)){background-color:purple}}
+@media (max-width: 1000px){.x975j7z.x975j7z.x975j7z:where(.x-default-marker:active ~ *, :has(~ .x-default-marker:active)){background-color:orange}}"##
+  );
+}
@@ -0,0 +1,246 @@
+//! Functions the module declares, called from a style value.
+//!
+//! A call folds when the function is declared in the module, never reassigned,
+//! and its body sticks to what the evaluator can run: `const`/`let`, `if`,
+//! `switch`, `return`, destructured and defaulted parameters. The reference
+//! implementation folds expression-bodied arrows only, so a block body or a
+//! function declaration is accepted here where it would be refused there; the
+//! values an expression-bodied arrow produces, and the class names they hash
+//! to, are the same in both.
+
+use crate::utils::prelude::*;
+
+fn stylex_transform(
+  comments: TestComments,
+  customize: impl FnOnce(TestBuilder) -> TestBuilder,
+) -> impl Pass {
+  build_test_transform(comments, |b| customize(b.with_runtime_injection()))
+}
+
+// ── Functions that fold ─────────────────────────────────────────────
+
+// The shape the reference implementation already folds, pinned so the wider
+// evaluator keeps producing the same class name for it.
+stylex_test!(
+  an_expression_bodied_arrow_folds,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    const spacing = (n) => n * 4 + 'px';
+    export const styles = stylex.create({
+      root: { padding: spacing(2) },
+    });
+  "#
+);
+
+// Both branches of the `if` run, one per call, and each call gets its own value
+// for `n` rather than the one the previous call cached.
+stylex_test!(
+  a_block_bodied_function_declaration_folds,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    function spacing(n) {
+      if (n < 0) return 0;
+      return n * 4 + 'px';
+    }
+    export const styles = stylex.create({
+      root: { padding: spacing(2), margin: spacing(-1), gap: spacing(3) },
+    });
+  "#
+);
+
+// A declaration is hoisted, so the call may come before it.
+stylex_test!(
+  a_function_declared_after_its_call_folds,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: { padding: spacing(2) },
+    });
+    export function spacing(n) {
+      const unit = 4;
+      return n * unit + 'px';
+    }
+  "#
+);
+
+stylex_test!(
+  destructured_parameters_and_locals_fold,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    const pick = ({ a, b }) => {
+      const x = a ?? b;
+      return x;
+    };
+    const first = ([head, ...rest]) => rest.length + head;
+    export const styles = stylex.create({
+      root: {
+        color: pick({ a: null, b: 'red' }),
+        backgroundColor: pick({ a: 'blue', b: 'red' }),
+        width: first(['px', 1, 2]),
+      },
+    });
+  "#
+);
+
+// A default stands in for a missing argument and for an explicit `undefined`,
+// but not for `null`.
+stylex_test!(
+  default_parameters_fold,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    function size(n = 2, { unit = 'px' } = {}) {
+      return n * 8 + unit;
+    }
+    export const styles = stylex.create({
+      root: {
+        width: size(),
+        height: size(undefined, { unit: 'em' }),
+        maxWidth: size(4),
+      },
+    });
+  "#
+);
+
+// Cases are compared strictly — `'1'` does not match `1` — and fall through to
+// the next one until a `break` or `return`.
+stylex_test!(
+  a_switch_folds,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    function tone(name) {
+      let color = 'black';
+      switch (name) {
+        case 1:
+          color = 'never';
+          break;
+        case 'primary':
+        case 'accent':
+          return 'blue';
+        case 'danger': {
+          const red = 'red';
+          return red;
+        }
+        default:
+          return color;
+      }
+    }
+    export const styles = stylex.create({
+      root: { color: tone('accent'), borderColor: tone('danger'), outlineColor: tone('1') },
+    });
+  "#
+);
+
+stylex_test!(
+  a_function_calling_another_folds,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    const px = (n) => n + 'px';
+    function spacing(n) {
+      return px(n * 4);
+    }
+    function gutter(n) {
+      return spacing(n) + ' ' + spacing(n * 2);
+    }
+    export const styles = stylex.create({
+      root: { padding: gutter(1) },
+    });
+  "#
+);
+
+// `unit` reads the module's `size`, which is 4, not the `size` parameter of
+// the `spacing` call it runs inside: a name means what it means where the
+// function is written. The padding is `18px`.
+stylex_test!(
+  a_callee_reads_its_own_scope_not_its_callers,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    const size = 4;
+    function unit() {
+      return size * 2;
+    }
+    function spacing(size) {
+      return unit() + size + 'px';
+    }
+    export const styles = stylex.create({
+      root: { padding: spacing(10) },
+    });
+  "#
+);
+
+// A block-bodied arrow handed to an array method runs once per element.
+stylex_test!(
+  a_block_bodied_callback_folds,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    const sizes = [1, 2].map((n) => {
+      const size = n * 4;
+      return size + 'px';
+    });
+    export const styles = stylex.create({
+      root: { padding: sizes[0] + ' ' + sizes[1] },
+    });
+  "#
+);
+
+// ── Functions that refuse ───────────────────────────────────────────
+
+// A loop is not run, so a call to a function holding one refuses and names the
+// statement.
+stylex_test_panic!(
+  a_loop_in_the_body_refuses,
+  "Unsupported expression: ForStatement",
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    function spacing(n) {
+      let total = 0;
+      for (let i = 0; i < n; i++) {}
+      return total + 'px';
+    }
+    export const styles = stylex.create({
+      root: { padding: spacing(2) },
+    });
+  "#
+);
+
+// A function the module reassigns may not be the one declared by the time the
+// call runs.
+stylex_test_panic!(
+  a_reassigned_function_refuses,
+  "Referenced value is not a constant.",
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    let spacing = (n) => n * 4 + 'px';
+    spacing = (n) => n + 'px';
+    export const styles = stylex.create({
+      root: { padding: spacing(2) },
+    });
+  "#
+);
+
+// Recursion with no base case stops at the call-depth limit instead of
+// overflowing the stack.
+stylex_test_panic!(
+  runaway_recursion_refuses,
+  "Function calls are nested too deeply to evaluate at compile time.",
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    function forever(n) {
+      return forever(n + 1);
+    }
+    export const styles = stylex.create({
+      root: { padding: forever(0) },
+    });
+  "#
+);
@@ -0,0 +1,79 @@
+use crate::utils::prelude::*;
+
+fn stylex_transform(
+  comments: TestComments,
+  customize: impl FnOnce(TestBuilder) -> TestBuilder,
+) -> impl Pass {
+  build_test_transform(comments, |b| {
+    customize(b.with_runtime_injection_option(RuntimeInjection::Boolean(true)))
+  })
+}
+
+stylex_test!(
+  equivalent_conditions_share_one_class_when_normalized,
+  |tr| stylex_transform(tr.comments.clone(), |b| b
+    .with_enable_at_rule_normalization(true)),
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      minWidth: {
+        color: {
+          default: 'black',
+          '@container (min-width:400px)': 'red',
+        },
+      },
+      range: {
+        color: {
+          default: 'black',
+          '@container (width >= 400px)': 'red',
+        },
+      },
+      supports: {
+        color: {
+          default: 'black',
+          '@supports (display:grid) and ((gap:1px))': 'blue',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test!(
+  conditions_that_respell_alike_collapse_into_one_key,
+  |tr| stylex_transform(tr.comments.clone(), |b| b
+    .with_enable_at_rule_normalization(true)),
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@container (min-width: 400px)': 'red',
+          '@container (400px <= width)': 'blue',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test!(
+  conditions_keep_their_authored_spelling_by_default,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      minWidth: {
+        color: {
+          default: 'black',
+          '@container (min-width:400px)': 'red',
+        },
+      },
+      range: {
+        color: {
+          default: 'black',
+          '@container (width >= 400px)': 'red',
+        },
+      },
+    });
+  "#
+);
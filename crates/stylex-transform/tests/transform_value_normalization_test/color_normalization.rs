@@ -0,0 +1,52 @@
+use crate::utils::prelude::*;
+
+fn stylex_transform(
+  comments: TestComments,
+  customize: impl FnOnce(TestBuilder) -> TestBuilder,
+) -> impl Pass {
+  build_test_transform(comments, |b| {
+    customize(b.with_runtime_injection_option(RuntimeInjection::Boolean(true)))
+  })
+}
+
+stylex_test!(
+  equivalent_colors_share_one_class_when_normalized,
+  |tr| stylex_transform(tr.comments.clone(), |b| b
+    .with_enable_color_normalization(true)),
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      hex: {
+        color: '#FFFFFF',
+      },
+      shortHex: {
+        color: '#fff',
+      },
+      named: {
+        color: 'white',
+      },
+      modern: {
+        color: 'rgb(255 255 255)',
+      },
+      translucent: {
+        backgroundColor: 'rgba(0, 0, 0, 0.5)',
+      },
+    });
+  "#
+);
+
+stylex_test!(
+  colors_keep_their_authored_spelling_by_default,
+  |tr| stylex_transform(tr.comments.clone(), |b| b),
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      hex: {
+        color: '#FFFFFF',
+      },
+      named: {
+        color: 'white',
+      },
+    });
+  "#
+);
@@ -1,4 +1,6 @@
+mod at_rule_normalization;
 mod class_name_edge_cases;
 mod class_name_rejections;
+mod color_normalization;
 mod css_value_normalization;
 mod transform_font_size_with;
@@ -0,0 +1,146 @@
+use crate::utils::prelude::*;
+
+fn normalizing_transform(comments: TestComments) -> impl Pass {
+  build_test_transform(comments, |b| {
+    b.with_runtime_injection()
+      .with_enable_at_rule_normalization(true)
+  })
+}
+
+stylex_test!(
+  valid_supports_and_container_conditions,
+  |tr| normalizing_transform(tr.comments.clone()),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@supports (display: grid) and selector(:has(a))': 'red',
+          '@supports not (font-tech(variations))': 'green',
+          '@container sidebar (min-width: 400px)': 'blue',
+          '@container style(--theme: dark)': 'white',
+          '@supports (display: grid) or (foo)': 'gray',
+          '@supports at-rule(@layer)': 'purple',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  invalid_supports_condition_mixes_and_with_or,
+  "Invalid @supports query syntax.",
+  |tr| normalizing_transform(tr.comments.clone()),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@supports (display: grid) and (gap: 1px) or (color: red)': 'red',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  invalid_supports_condition_selector_list,
+  "Invalid @supports query syntax.",
+  |tr| normalizing_transform(tr.comments.clone()),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@supports selector(a, b)': 'red',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  invalid_supports_condition_unbalanced_parens,
+  "Unbalanced parentheses in @supports query.",
+  |tr| normalizing_transform(tr.comments.clone()),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@supports (display: grid': 'red',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  invalid_container_condition_unknown_feature,
+  "Invalid @container query syntax.",
+  |tr| normalizing_transform(tr.comments.clone()),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@container (min-widht: 400px)': 'red',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  invalid_container_condition_reserved_name,
+  "Invalid @container query syntax.",
+  |tr| normalizing_transform(tr.comments.clone()),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@container none (width > 400px)': 'red',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  invalid_container_condition_as_top_level_key,
+  "Invalid @container query syntax.",
+  |tr| normalizing_transform(tr.comments.clone()),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        '@container (width >)': {
+          color: 'red',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test!(
+  conditions_are_not_read_without_normalization,
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'black',
+          '@supports (display: grid) and (gap: 1px) or (color: red)': 'red',
+          '@container (min-widht: 400px)': 'blue',
+        },
+      },
+    });
+  "#
+);
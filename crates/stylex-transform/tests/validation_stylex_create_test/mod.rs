@@ -1,6 +1,8 @@
+mod at_rule_conditions;
 mod invalid_properties;
 mod invalid_values;
 mod property_validation_mode_test;
 mod style_declarations;
 mod style_rules;
 mod stylex_validation_create;
+mod value_grammar_test;
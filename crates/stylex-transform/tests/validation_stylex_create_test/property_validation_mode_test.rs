@@ -1,5 +1,7 @@
 use crate::utils::prelude::*;
 use stylex_enums::property_validation_mode::PropertyValidationMode;
+use stylex_macros::diagnostic::{DiagnosticCollector, Severity, codes};
+use swc_core::ecma::transforms::testing::Tester;
 
 fn stylex_transform(
   comments: TestComments,
@@ -330,3 +332,48 @@ stylex_test!(
     });
   "#
 );
+
+#[test]
+fn warn_mode_reports_every_problem_as_a_warning_diagnostic() {
+  let input = r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        border: '1px solid red',
+        color: 'not-a-color',
+      },
+    });
+  "#;
+
+  let collector = DiagnosticCollector::new();
+  Tester::run(|tester| {
+    tester.apply_transform(
+      stylex_transform(tester.comments.clone(), |b| {
+        b.with_property_validation_mode(PropertyValidationMode::Warn)
+      }),
+      "input.js",
+      ts_syntax(),
+      None,
+      input,
+    )?;
+
+    Ok(())
+  });
+  let diagnostics = collector.finish();
+
+  let mut reported: Vec<_> = diagnostics
+    .iter()
+    .map(|diagnostic| diagnostic.code.as_ref())
+    .collect();
+  reported.sort_unstable();
+
+  assert_eq!(
+    reported,
+    [codes::INVALID_PROPERTY_VALUE, codes::INVALID_SHORTHAND]
+  );
+  assert!(
+    diagnostics
+      .iter()
+      .all(|diagnostic| diagnostic.severity == Severity::Warning)
+  );
+}
@@ -0,0 +1,153 @@
+use crate::utils::prelude::*;
+use stylex_enums::property_validation_mode::PropertyValidationMode;
+
+fn stylex_transform(comments: TestComments, mode: PropertyValidationMode) -> impl Pass {
+  build_test_transform(comments, |b| {
+    b.with_runtime_injection()
+      .with_property_validation_mode(mode)
+  })
+}
+
+// A value that fails its property's grammar still compiles as written unless
+// the mode asks for more.
+stylex_test!(
+  invalid_value_compiles_as_written_in_silent_mode,
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Silent),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'rde',
+        transform: 'rotate(10)',
+      },
+    });
+  "#
+);
+
+stylex_test!(
+  invalid_value_compiles_as_written_in_warn_mode,
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Warn),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'rde',
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  throws_for_a_misspelled_color,
+  "\"rde\" is not a valid value for color. Expected <color>.",
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Throw),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: 'rde',
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  throws_for_a_unitless_rotation,
+  "\"rotate(10)\" is not a valid value for transform. Expected none | <transform-list>.",
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Throw),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        transform: 'rotate(10)',
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  throws_for_an_invalid_value_under_a_condition,
+  "\"bounce\" is not a valid value for transitionTimingFunction.",
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Throw),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        transitionTimingFunction: {
+          default: 'ease',
+          '@media (prefers-reduced-motion: no-preference)': 'bounce',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  throws_for_an_invalid_value_in_a_fallback_array,
+  "\"1px 1px 1px rde\" is not a valid value for boxShadow.",
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Throw),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        boxShadow: ['0 0 1px red', '1px 1px 1px rde'],
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  throws_for_an_invalid_value_under_a_top_level_pseudo,
+  "\"blur(2)\" is not a valid value for filter.",
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Throw),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        ':hover': {
+          filter: 'blur(2)',
+        },
+      },
+    });
+  "#
+);
+
+stylex_test_panic!(
+  checks_the_value_a_constant_resolves_to,
+  "\"rde\" is not a valid value for backgroundColor.",
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Throw),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    const accent = 'rde';
+    export const styles = stylex.create({
+      root: {
+        backgroundColor: accent,
+      },
+    });
+  "#
+);
+
+// Values the grammars cannot read in full pass unchecked rather than fail.
+stylex_test!(
+  valid_and_unmodelled_values_compile_in_throw_mode,
+  |tr| stylex_transform(tr.comments.clone(), PropertyValidationMode::Throw),
+  r#"
+    import * as stylex from '@stylexjs/stylex';
+    export const styles = stylex.create({
+      root: {
+        color: {
+          default: 'currentcolor',
+          ':hover': 'color-mix(in srgb, red, blue)',
+        },
+        backgroundColor: ['#fff', 'oklch(0.7 0.1 120)'],
+        borderRadius: '4px / 8px',
+        boxShadow: 'inset 0 0 0 1px var(--edge)',
+        clipPath: 'circle(50%) border-box',
+        filter: 'blur(2px) drop-shadow(0 0 2px red)',
+        outlineWidth: 2,
+        transform: 'translate(-50%, -50%) rotate(45deg)',
+        transitionTimingFunction: 'linear, cubic-bezier(0.1, 0.7, 1, 0.1)',
+      },
+    });
+  "#
+);
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use stylex_macros::diagnostic::SourceSpan;
+
+/// What the code at one offset of a file compiles to, for an editor to show
+/// on hover or jump from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Analysis {
+  /// A `stylex.create` namespace, or a property inside one, or a
+  /// `styles.namespace` read of one.
+  Style(StyleAnalysis),
+  /// A `vars.token` read of a `defineVars` token.
+  Token(TokenAnalysis),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StyleAnalysis {
+  /// The variable the `stylex.create` result is bound to.
+  pub variable: String,
+  pub namespace: String,
+  /// The property under the offset, when it is inside one.
+  pub property: Option<String>,
+  /// The key or read under the offset.
+  pub span: Option<SourceSpan>,
+  /// The atomic rules the namespace, or only the property, compiles to.
+  pub rules: Vec<AnalyzedRule>,
+}
+
+/// One atomic rule, as it reaches the stylesheet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzedRule {
+  pub property: String,
+  pub class_name: String,
+  pub ltr: String,
+  /// Set only when the rule flips in right-to-left layouts.
+  pub rtl: Option<String>,
+  pub priority: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAnalysis {
+  /// The variable the `defineVars` result is bound to where it is read.
+  pub variable: String,
+  pub token: String,
+  /// The read under the offset.
+  pub span: Option<SourceSpan>,
+  /// What the read compiles to, such as `var(--x1a2b3c)`, when it resolves.
+  pub value: Option<String>,
+  /// The file declaring the token: this one, for a local `defineVars`.
+  pub file: Option<String>,
+  /// Where the token is declared in `file`.
+  pub declaration: Option<SourceSpan>,
+  /// The token's value as written in its declaration.
+  pub declared_value: Option<String>,
+}
@@ -1,6 +1,8 @@
+pub mod analysis;
 pub mod injectable_style;
 pub mod meta_data;
 pub mod style_key;
+pub mod unused_styles;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,78 @@
+//! Tests for the analysis an editor reads: its JSON shape.
+
+use serde_json::json;
+use stylex_macros::diagnostic::SourceSpan;
+
+use crate::structures::analysis::{Analysis, AnalyzedRule, StyleAnalysis, TokenAnalysis};
+
+fn span() -> SourceSpan {
+  SourceSpan {
+    line: 3,
+    column: 5,
+    end_line: 3,
+    end_column: 9,
+  }
+}
+
+/// The kind tags the object, so JavaScript can switch on one field.
+#[test]
+fn a_style_serializes_tagged_with_its_kind() {
+  let analysis = Analysis::Style(StyleAnalysis {
+    variable: "styles".to_string(),
+    namespace: "root".to_string(),
+    property: Some("marginStart".to_string()),
+    span: Some(span()),
+    rules: vec![AnalyzedRule {
+      property: "marginStart".to_string(),
+      class_name: "x1ys307a".to_string(),
+      ltr: ".x1ys307a{margin-left:4px}".to_string(),
+      rtl: Some(".x1ys307a{margin-right:4px}".to_string()),
+      priority: 3000.0,
+    }],
+  });
+
+  assert_eq!(
+    serde_json::to_value(&analysis).unwrap(),
+    json!({
+      "kind": "style",
+      "variable": "styles",
+      "namespace": "root",
+      "property": "marginStart",
+      "span": { "line": 3, "column": 5, "endLine": 3, "endColumn": 9 },
+      "rules": [{
+        "property": "marginStart",
+        "className": "x1ys307a",
+        "ltr": ".x1ys307a{margin-left:4px}",
+        "rtl": ".x1ys307a{margin-right:4px}",
+        "priority": 3000.0,
+      }],
+    })
+  );
+}
+
+#[test]
+fn a_token_serializes_tagged_with_its_kind() {
+  let analysis = Analysis::Token(TokenAnalysis {
+    variable: "vars".to_string(),
+    token: "accent".to_string(),
+    span: None,
+    value: Some("var(--x1e8sbxr)".to_string()),
+    file: Some("/app/tokens.stylex.js".to_string()),
+    declaration: Some(span()),
+    declared_value: Some("'blue'".to_string()),
+  });
+
+  assert_eq!(
+    serde_json::to_value(&analysis).unwrap(),
+    json!({
+      "kind": "token",
+      "variable": "vars",
+      "token": "accent",
+      "span": null,
+      "value": "var(--x1e8sbxr)",
+      "file": "/app/tokens.stylex.js",
+      "declaration": { "line": 3, "column": 5, "endLine": 3, "endColumn": 9 },
+      "declaredValue": "'blue'",
+    })
+  );
+}
@@ -1,4 +1,6 @@
+mod analysis_test;
 mod injectable_style_test;
 mod meta_data_serialization_test;
 mod meta_data_test;
 mod style_key_test;
+mod unused_styles_test;
@@ -0,0 +1,67 @@
+//! Tests for the unused-styles report: its emptiness and its JSON shape.
+
+use serde_json::json;
+use stylex_macros::diagnostic::SourceSpan;
+
+use crate::structures::unused_styles::{UnusedNamespace, UnusedStyles, UnusedToken};
+
+fn unused_namespace() -> UnusedNamespace {
+  UnusedNamespace {
+    variable: "styles".to_string(),
+    namespace: "hidden".to_string(),
+    span: Some(SourceSpan {
+      line: 4,
+      column: 3,
+      end_line: 4,
+      end_column: 9,
+    }),
+    class_names: vec!["x1s85apg".to_string()],
+  }
+}
+
+/// Dead class names follow from unused namespaces, so they alone never make a
+/// report non-empty.
+#[test]
+fn a_report_is_empty_without_namespaces_or_tokens() {
+  assert!(UnusedStyles::default().is_empty());
+
+  let with_namespace = UnusedStyles {
+    namespaces: vec![unused_namespace()],
+    ..Default::default()
+  };
+  assert!(!with_namespace.is_empty());
+
+  let with_token = UnusedStyles {
+    tokens: vec![UnusedToken {
+      variable: "vars".to_string(),
+      token: "accent".to_string(),
+      span: None,
+    }],
+    ..Default::default()
+  };
+  assert!(!with_token.is_empty());
+}
+
+/// Field names cross into JavaScript and CI scripts, so they are camelCase.
+#[test]
+fn serializes_with_camel_case_fields() {
+  let report = UnusedStyles {
+    namespaces: vec![unused_namespace()],
+    tokens: vec![],
+    dead_class_names: vec!["x1s85apg".to_string()],
+  };
+
+  assert_eq!(
+    serde_json::to_value(&report).unwrap(),
+    json!({
+      "namespaces": [{
+        "variable": "styles",
+        "namespace": "hidden",
+        "span": { "line": 4, "column": 3, "endLine": 4, "endColumn": 9 },
+        "classNames": ["x1s85apg"],
+      }],
+      "tokens": [],
+      "deadClassNames": ["x1s85apg"],
+    })
+  );
+}
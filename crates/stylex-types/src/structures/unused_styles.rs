@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use stylex_macros::diagnostic::SourceSpan;
+
+/// What one file declares with `stylex.create` and `defineVars` and never
+/// reads. Everything here is local to the file: a token unused in its own
+/// `.stylex` file is usually read elsewhere, and a class name dead here may
+/// still be generated by another file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedStyles {
+  pub namespaces: Vec<UnusedNamespace>,
+  pub tokens: Vec<UnusedToken>,
+  /// Class names generated only by the unused namespaces, which no element
+  /// rendered by this file can carry.
+  pub dead_class_names: Vec<String>,
+}
+
+impl UnusedStyles {
+  pub fn is_empty(&self) -> bool {
+    self.namespaces.is_empty() && self.tokens.is_empty()
+  }
+}
+
+/// A `stylex.create` namespace that `props`, `attrs` or `sx` never receives.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedNamespace {
+  /// The variable the `stylex.create` result is bound to.
+  pub variable: String,
+  pub namespace: String,
+  /// Where the namespace key is written, when it is written literally.
+  pub span: Option<SourceSpan>,
+  pub class_names: Vec<String>,
+}
+
+/// A `defineVars` token the file never reads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedToken {
+  /// The exported variable the `defineVars` result is bound to.
+  pub variable: String,
+  pub token: String,
+  pub span: Option<SourceSpan>,
+}
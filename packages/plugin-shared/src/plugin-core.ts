@@ -1,7 +1,7 @@
 import path from 'path';
 
-import stylexBabelPlugin from '@stylexjs/babel-plugin';
 import type { Rule as StyleXRule } from '@stylexjs/babel-plugin';
+import { processStylexRules } from '@stylexswc/rs-compiler';
 import type { StyleXOptions, TransformedOptions } from '@stylexswc/rs-compiler';
 
 import {
@@ -355,7 +355,7 @@ export class StyleXPluginCore {
     // Take styles for the modules that were included in the last compilation.
     const allRules: StyleXRule[] = Array.from(this.stylexRules.values()).flat();
 
-    return stylexBabelPlugin.processStylexRules(allRules, this.transformedOptions);
+    return processStylexRules(allRules, this.transformedOptions);
   }
 
   /**
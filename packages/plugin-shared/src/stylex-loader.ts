@@ -65,6 +65,12 @@ export default async function stylexLoader(
       this.sourceMap
     );
 
+    // Theme files and package.json files the output was computed from, so
+    // editing a token recompiles the files that use it in watch mode.
+    for (const dependency of metadata?.dependencies ?? []) {
+      this.addDependency(dependency);
+    }
+
     let parsedMap: SourceMap = undefined;
 
     if (map) {
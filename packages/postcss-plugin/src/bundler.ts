@@ -64,7 +64,8 @@ export default function createBundler() {
     let transformResult: ReturnType<typeof stylexTransform> = {
       code: sourceCode,
       map: undefined,
-      metadata: { stylex: [] },
+      metadata: { stylex: [], dependencies: [] },
+      diagnostics: [],
     };
 
     try {
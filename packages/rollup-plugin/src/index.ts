@@ -144,6 +144,11 @@ export default function stylexPlugin({
         return { code: inputCode };
       }
 
+      // Recompile this module when a theme file it reads tokens from changes.
+      for (const dependency of metadata.dependencies ?? []) {
+        this.addWatchFile(dependency);
+      }
+
       if (this.meta.watchMode) {
         const ast = this.parse(code);
         for (const stmt of ast.body) {
@@ -369,7 +369,7 @@ export const unpluginFactory: UnpluginFactory<UnpluginStylexRSOptions | undefine
           }
         }
 
-        const { code, map } = transformStyleXCode(
+        const { code, map, metadata } = transformStyleXCode(
           file,
           inputCode,
           normalizedOptions,
@@ -378,6 +378,11 @@ export const unpluginFactory: UnpluginFactory<UnpluginStylexRSOptions | undefine
           inputSourceMap
         );
 
+        // Recompile this module when a theme file it reads tokens from changes.
+        for (const dependency of metadata.dependencies ?? []) {
+          this.addWatchFile(dependency);
+        }
+
         // Invalidate CSS modules in dev mode (initial load only)
         // For subsequent HMR, handleHotUpdate handles the CSS module inclusion
         if (normalizedOptions.useCssPlaceholder && viteDevServer && !hasInvalidatedInitialCSS) {
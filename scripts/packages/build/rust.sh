@@ -19,7 +19,13 @@ if [ "$build_rust" = true ]; then
     verbose_flag=""
   fi
 
-  cargo_name=$(grep '^name' Cargo.toml | sed 's/name = "\(.*\)"/\1/')
+  # A `[[bin]]` table names the executable; without one it is named after the
+  # package.
+  cargo_name=$(sed -n '/^\[\[bin\]\]/,/^\[/s/^name = "\(.*\)"/\1/p' Cargo.toml | head -1)
+
+  if [ -z "$cargo_name" ]; then
+    cargo_name=$(grep '^name' Cargo.toml | sed 's/name = "\(.*\)"/\1/')
+  fi
 
   mkdir -p ./dist || handle_error "Failed to create the dist directory"
 